@@ -14,6 +14,7 @@ use iroha_config::{
     genesis::ParsedConfiguration as ParsedGenesisConfiguration,
     iroha::{Configuration, ConfigurationProxy},
     path::Path,
+    sumeragi::NodeRole,
     telemetry::Configuration as TelemetryConfiguration,
 };
 use iroha_core::{
@@ -28,12 +29,12 @@ use iroha_core::{
     smartcontracts::isi::Registrable as _,
     snapshot::{try_read_snapshot, SnapshotMaker, SnapshotMakerHandle},
     sumeragi::{SumeragiHandle, SumeragiStartArgs},
-    tx::PeerId,
     IrohaNetwork,
 };
 use iroha_data_model::prelude::*;
 use iroha_genesis::GenesisNetwork;
 use iroha_logger::actor::LoggerHandle;
+use iroha_p2p::peer::message::PeerMessage;
 use iroha_torii::Torii;
 use tokio::{
     signal,
@@ -119,7 +120,10 @@ impl NetworkRelay {
         }
     }
 
-    async fn handle_message(&mut self, msg: iroha_core::NetworkMessage) {
+    async fn handle_message(
+        &mut self,
+        PeerMessage(peer_id, msg): PeerMessage<iroha_core::NetworkMessage>,
+    ) {
         use iroha_core::NetworkMessage::*;
 
         #[cfg(debug_assertions)]
@@ -134,8 +138,8 @@ impl NetworkRelay {
             SumeragiControlFlow(data) => {
                 self.sumeragi.incoming_control_flow_message(*data);
             }
-            BlockSync(data) => self.block_sync.message(*data).await,
-            TransactionGossiper(data) => self.gossiper.gossip(*data).await,
+            BlockSync(data) => self.block_sync.message(*data, peer_id).await,
+            TransactionGossiper(data) => self.gossiper.gossip(*data, peer_id).await,
             Health => {}
         }
     }
@@ -206,9 +210,13 @@ impl Iroha {
         logger: LoggerHandle,
     ) -> Result<Self> {
         let listen_addr = config.torii.p2p_addr.clone();
-        let network = IrohaNetwork::start(listen_addr, config.sumeragi.key_pair.clone())
-            .await
-            .wrap_err("Unable to start P2P-network")?;
+        let network = IrohaNetwork::start(
+            listen_addr,
+            config.sumeragi.key_pair.clone(),
+            &config.network,
+        )
+        .await
+        .wrap_err("Unable to start P2P-network")?;
 
         let (events_sender, _) = broadcast::channel(10000);
         let world = World::with(
@@ -223,6 +231,7 @@ impl Iroha {
         let block_count = kura.init()?;
         let wsv = try_read_snapshot(
             &config.snapshot.dir_path,
+            config.chain_id.clone(),
             &kura,
             live_query_store_handle.clone(),
             block_count,
@@ -231,6 +240,7 @@ impl Iroha {
             |error| {
                 iroha_logger::warn!(%error, "Failed to load wsv from snapshot, creating empty wsv");
                 WorldStateView::from_configuration(
+                    config.chain_id.clone(),
                     *config.wsv,
                     world,
                     Arc::clone(&kura),
@@ -274,10 +284,11 @@ impl Iroha {
             &config.block_sync,
             sumeragi.clone(),
             Arc::clone(&kura),
-            PeerId::new(config.torii.p2p_addr.clone(), config.public_key.clone()),
             network.clone(),
-        )
-        .start();
+        );
+        #[cfg(feature = "byzantine")]
+        let block_sync = block_sync.with_byzantine_faults(config.sumeragi.debug_byzantine_faults);
+        let block_sync = block_sync.start();
 
         let gossiper = TransactionGossiper::from_configuration(
             config.chain_id.clone(),
@@ -319,7 +330,8 @@ impl Iroha {
             sumeragi.clone(),
             live_query_store_handle,
             Arc::clone(&kura),
-        );
+        )
+        .wrap_err("Failed to configure Torii")?;
 
         Self::spawn_configuration_updates_broadcasting(kiso.clone(), logger.clone());
 
@@ -556,6 +568,10 @@ pub fn read_config(
         mutate_nested_option!(cfg, [snapshot, dir_path], join_to_config_dir);
         mutate_nested_option!(cfg, [kura, block_store_path], join_to_config_dir);
         mutate_nested_option!(cfg, [telemetry, file, self], join_to_config_dir);
+        mutate_nested_option!(cfg, [network, address_book_path, self], join_to_config_dir);
+        mutate_nested_option!(cfg, [torii, tls_cert_path, self], join_to_config_dir);
+        mutate_nested_option!(cfg, [torii, tls_key_path, self], join_to_config_dir);
+        mutate_nested_option!(cfg, [torii, tls_client_ca_path, self], join_to_config_dir);
 
         cfg
     } else {
@@ -584,6 +600,26 @@ pub fn read_config(
         "));
     }
 
+    if submit_genesis && config.sumeragi.node_role == NodeRole::Observer {
+        return Err(eyre!(
+            "Observer can't submit the genesis block, it only follows the chain of the validators"
+        ));
+    }
+
+    if config.sumeragi.node_role == NodeRole::Observer
+        && config
+            .sumeragi
+            .trusted_peers
+            .peers
+            .iter()
+            .any(|peer| peer.public_key == config.public_key)
+    {
+        return Err(eyre!("\
+            Observer is listed in `sumeragi.trusted_peers`, which would make it part of the topology. \
+            Remove this peer from `sumeragi.trusted_peers` or set `sumeragi.node_role` to `validator`.
+        "));
+    }
+
     let genesis = if let ParsedGenesisConfiguration::Full {
         key_pair,
         raw_block,
@@ -637,6 +673,7 @@ mod tests {
 
     mod config_integration {
         use assertables::{assert_contains, assert_contains_as_result};
+        use iroha_config::sumeragi::TrustedPeers;
         use iroha_crypto::KeyPair;
         use iroha_genesis::{ExecutorMode, ExecutorPath};
         use iroha_primitives::addr::socket_addr;
@@ -757,5 +794,48 @@ mod tests {
 
             Ok(())
         }
+
+        #[test]
+        fn fails_with_observer_in_trusted_peers() -> Result<()> {
+            // Given
+
+            let config = {
+                let mut cfg = config_factory()?;
+                let public_key = cfg.public_key.clone().unwrap();
+                let other_peer = KeyPair::generate()?;
+                let sumeragi = cfg.sumeragi.as_mut().unwrap();
+                sumeragi.node_role = Some(NodeRole::Observer);
+                sumeragi.trusted_peers = Some(TrustedPeers {
+                    peers: [
+                        PeerId::new(socket_addr!(127.0.0.1:1337), public_key),
+                        PeerId::new(
+                            socket_addr!(127.0.0.1:1338),
+                            other_peer.public_key().clone(),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                });
+                cfg
+            };
+
+            let dir = tempfile::tempdir()?;
+            std::fs::write(
+                dir.path().join("config.json"),
+                serde_json::to_string(&config)?,
+            )?;
+            let config_path = Path::user_provided(dir.path().join("config.json"))?;
+
+            // When & Then
+
+            let report = read_config(&config_path, false).unwrap_err();
+
+            assert_contains!(
+                format!("{report}"),
+                "Observer is listed in `sumeragi.trusted_peers`"
+            );
+
+            Ok(())
+        }
     }
 }
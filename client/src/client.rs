@@ -20,7 +20,9 @@ use parity_scale_codec::DecodeAll;
 use rand::Rng;
 use url::Url;
 
-use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
+use self::{
+    blocks_api::AsyncBlockStream, events_api::AsyncEventStream, query_stream_api::AsyncQueryStream,
+};
 use crate::{
     config::{api::ConfigurationDTO, Configuration},
     crypto::{HashOf, KeyPair},
@@ -29,7 +31,7 @@ use crate::{
         isi::Instruction,
         predicate::PredicateBox,
         prelude::*,
-        query::{Pagination, Query, Sorting},
+        query::{stream::QueryStreamRequest, Pagination, Query, Sorting},
         transaction::TransactionPayload,
         BatchedResponse, ChainId, ValidationFail,
     },
@@ -101,7 +103,9 @@ where
                 | StatusCode::UNAUTHORIZED
                 | StatusCode::FORBIDDEN
                 | StatusCode::NOT_FOUND
-                | StatusCode::UNPROCESSABLE_ENTITY => Err(ValidationFail::decode_all(
+                | StatusCode::UNPROCESSABLE_ENTITY
+                | StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::SERVICE_UNAVAILABLE => Err(ValidationFail::decode_all(
                     &mut resp.body().as_ref(),
                 )
                 .map_or_else(
@@ -238,6 +242,19 @@ impl<T> ResultSet<T> {
     pub fn batch_len(&self) -> usize {
         self.iter.len()
     }
+
+    /// Get the id of the live query kept by Iroha for this result set.
+    ///
+    /// It is `None` if all results were already returned by Iroha.
+    /// Can be passed to [`Client::drop_query`] to release the query early.
+    pub fn query_id(&self) -> Option<&QueryId> {
+        match &self.query_handler.query_request.request {
+            crate::data_model::query::QueryRequest::Cursor(cursor) => {
+                cursor.cursor().and(cursor.query_id().as_ref())
+            }
+            crate::data_model::query::QueryRequest::Query(_) => None,
+        }
+    }
 }
 
 impl<T: Clone> Iterator for ResultSet<T>
@@ -886,6 +903,39 @@ impl Client {
         Ok(output)
     }
 
+    /// Drop the live query kept by Iroha before all of its results are fetched.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or Iroha doesn't keep a query with such id
+    pub fn drop_query(&self, query_id: QueryId) -> QueryResult<()> {
+        let request = SignedDropQuery::new(query_id, self.account_id.clone(), &self.key_pair);
+        let response = DefaultRequestBuilder::new(
+            HttpMethod::DELETE,
+            self.torii_url
+                .join(crate::config::torii::QUERY)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .body(request.encode_versioned())
+        .build()?
+        .send()?;
+
+        if response.status() == StatusCode::OK {
+            return Ok(());
+        }
+
+        Err(
+            ValidationFail::decode_all(&mut response.body().as_ref()).map_or_else(
+                |_| {
+                    ResponseReport::with_msg("Failed to drop query", &response)
+                        .unwrap_or_else(core::convert::identity)
+                        .into()
+                },
+                ClientQueryError::Validation,
+            ),
+        )
+    }
+
     /// Query API entry point.
     /// Creates a [`QueryRequestBuilder`] which can be used to configure requests queries from `Iroha` peers.
     ///
@@ -900,6 +950,75 @@ impl Client {
         QueryRequestBuilder::new(self, request)
     }
 
+    /// Connect asynchronously (through `WebSocket`) to stream the results of an iterable query
+    /// with pagination, sorting and filter.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::query_stream_handler`]
+    /// - Forwards from [`query_stream_api::AsyncQueryStream::new`]
+    #[allow(clippy::future_not_send)]
+    pub(crate) async fn stream_with_filter_and_pagination_and_sorting<R, T>(
+        &self,
+        request: R,
+        pagination: Pagination,
+        fetch_size: FetchSize,
+        sorting: Sorting,
+        filter: PredicateBox,
+    ) -> Result<AsyncQueryStream<T>>
+    where
+        R: Query<Output = Vec<T>> + Debug,
+        Vec<T>: QueryOutput,
+        <Vec<T> as TryFrom<Value>>::Error: Into<eyre::Error>,
+    {
+        iroha_logger::trace!(?request, %pagination, ?sorting, ?filter, "Async streaming");
+        query_stream_api::AsyncQueryStream::new(
+            self.query_stream_handler(request, filter, pagination, sorting, fetch_size)?,
+        )
+        .await
+    }
+
+    /// Connect asynchronously (through `WebSocket`) to stream the results of an iterable query.
+    /// Shorthand for `self.build_query(r).execute_stream()`.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::query_stream_handler`]
+    /// - Forwards from [`query_stream_api::AsyncQueryStream::new`]
+    #[allow(clippy::future_not_send)]
+    pub async fn stream_query_async<R, T>(&self, request: R) -> Result<AsyncQueryStream<T>>
+    where
+        R: Query<Output = Vec<T>> + Debug,
+        Vec<T>: QueryOutput,
+        <Vec<T> as TryFrom<Value>>::Error: Into<eyre::Error>,
+    {
+        self.build_query(request).execute_stream().await
+    }
+
+    /// Constructs a Query Stream API handler. With it, you can use any WS client you want.
+    ///
+    /// # Errors
+    /// Fails if handler construction fails
+    pub fn query_stream_handler<R: Query>(
+        &self,
+        request: R,
+        filter: PredicateBox,
+        pagination: Pagination,
+        sorting: Sorting,
+        fetch_size: FetchSize,
+    ) -> Result<query_stream_api::flow::Init> {
+        let query_builder = QueryBuilder::new(request, self.account_id.clone()).with_filter(filter);
+        let query = self.sign_query(query_builder);
+
+        query_stream_api::flow::Init::new(
+            QueryStreamRequest::new(crate::data_model::query::QueryWithParameters::new(
+                query, sorting, pagination, fetch_size,
+            )),
+            self.headers.clone(),
+            self.torii_url
+                .join(crate::config::torii::QUERY_STREAM)
+                .expect("Valid URI"),
+        )
+    }
+
     /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
     ///
     /// # Errors
@@ -1420,6 +1539,247 @@ mod blocks_api {
     pub type AsyncBlockStream = stream_api::AsyncStream<flow::Events>;
 }
 
+/// Logic related to Query Stream API client implementation.
+pub mod query_stream_api {
+    use std::num::NonZeroU32;
+
+    use futures_util::{SinkExt, Stream};
+
+    use super::*;
+    use crate::{
+        data_model::query::{
+            cursor::ForwardCursor,
+            stream::{QueryStreamDemand, QueryStreamMessage, QueryStreamRequest},
+        },
+        http::ws::{
+            conn_flow::{Events as FlowEvents, Init as FlowInit, InitData},
+            transform_ws_url,
+        },
+        http_default::DefaultWebSocketRequestBuilder,
+    };
+
+    /// Number of batches requested from Iroha ahead of their consumption.
+    // SAFETY: `2` is greater than `0`
+    #[allow(unsafe_code)]
+    pub const DEFAULT_PREFETCH: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(2) };
+
+    /// Query Stream API flow. For documentation and usage examples, refer to [`crate::http::ws::conn_flow`].
+    pub mod flow {
+        use super::*;
+
+        /// Initialization struct for Query Stream API flow.
+        pub struct Init {
+            /// Signed query with its parameters
+            request: QueryStreamRequest,
+            /// HTTP request headers
+            headers: HashMap<String, String>,
+            /// TORII URL
+            url: Url,
+        }
+
+        impl Init {
+            /// Construct new item with provided request, headers and url.
+            ///
+            /// # Errors
+            /// Fails if [`transform_ws_url`] fails.
+            #[inline]
+            pub(in super::super) fn new(
+                request: QueryStreamRequest,
+                headers: HashMap<String, String>,
+                url: Url,
+            ) -> Result<Self> {
+                Ok(Self {
+                    request,
+                    headers,
+                    url: transform_ws_url(url)?,
+                })
+            }
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for Init {
+            type Next = Events;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let Self {
+                    request,
+                    headers,
+                    url,
+                } = self;
+
+                let msg = request.encode();
+                InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
+            }
+        }
+
+        /// Events handler for Query Stream API flow.
+        ///
+        /// Note that Iroha sends only as many batches as were requested
+        /// with [`QueryStreamDemand`] messages.
+        #[derive(Debug, Copy, Clone)]
+        pub struct Events;
+
+        impl FlowEvents for Events {
+            type Event = BatchedResponse<Value>;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                match QueryStreamMessage::decode_all(&mut message.as_slice())? {
+                    QueryStreamMessage::Batch(batch) => Ok(batch),
+                    QueryStreamMessage::Error(error) => Err(error.into()),
+                }
+            }
+        }
+    }
+
+    /// Async stream for getting the results of an iterable query from the `WebSocket` stream.
+    ///
+    /// Next batches are requested from Iroha only as the already received ones are consumed,
+    /// keeping at most [`DEFAULT_PREFETCH`] batches in flight.
+    pub struct AsyncQueryStream<T> {
+        stream: AsyncWebSocketStream,
+        handler: flow::Events,
+        batch: std::vec::IntoIter<T>,
+        demand_pending: bool,
+        is_depleted: bool,
+    }
+
+    impl<T> AsyncQueryStream<T> {
+        /// Construct [`AsyncQueryStream`], send the query and request the first batches.
+        ///
+        /// # Errors
+        /// - Request failed to build
+        /// - `connect_async` failed
+        /// - Sending failed
+        #[allow(clippy::future_not_send)]
+        pub async fn new<I: FlowInit<DefaultWebSocketRequestBuilder, Next = flow::Events>>(
+            handler: I,
+        ) -> Result<Self> {
+            trace!("Creating `AsyncQueryStream`");
+            let InitData {
+                first_message,
+                req,
+                next: next_handler,
+            } = FlowInit::<DefaultWebSocketRequestBuilder>::init(handler);
+
+            let mut stream = req.build()?.connect_async().await?;
+            stream.send(WebSocketMessage::Binary(first_message)).await?;
+            stream
+                .send(WebSocketMessage::Binary(
+                    QueryStreamDemand::new(DEFAULT_PREFETCH).encode(),
+                ))
+                .await?;
+
+            trace!("`AsyncQueryStream` created successfully");
+            Ok(Self {
+                stream,
+                handler: next_handler,
+                batch: Vec::new().into_iter(),
+                demand_pending: false,
+                is_depleted: false,
+            })
+        }
+
+        /// Close websocket.
+        ///
+        /// Failures to close the connection gracefully, i.e. if the server doesn't
+        /// respond with `Close` message, are logged and otherwise ignored.
+        #[allow(clippy::future_not_send)]
+        pub async fn close(mut self) {
+            let close = async {
+                self.stream.close(None).await?;
+                if let Some(msg) = self.stream.next().await {
+                    if !msg?.is_close() {
+                        eyre::bail!("Server hasn't sent `Close` message for websocket handshake");
+                    }
+                }
+                Ok(())
+            };
+
+            trace!("Closing WebSocket connection");
+            let _ = close.await.map_err(|e| error!(%e));
+            trace!("WebSocket connection closed");
+        }
+
+        fn poll_send_demand(
+            &mut self,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), WebSocketError>> {
+            if self.demand_pending {
+                futures_util::ready!(self.stream.poll_ready_unpin(cx))?;
+                self.stream.start_send_unpin(WebSocketMessage::Binary(
+                    QueryStreamDemand::new(NonZeroU32::MIN).encode(),
+                ))?;
+                self.demand_pending = false;
+            }
+
+            self.stream.poll_flush_unpin(cx)
+        }
+    }
+
+    impl<T: Unpin> Stream for AsyncQueryStream<T>
+    where
+        Vec<T>: QueryOutput,
+        <Vec<T> as TryFrom<Value>>::Error: Into<eyre::Error>,
+    {
+        type Item = QueryResult<T>;
+
+        fn poll_next(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<Self::Item>> {
+            use std::task::Poll;
+
+            loop {
+                if let Some(item) = self.batch.next() {
+                    return Poll::Ready(Some(Ok(item)));
+                }
+                if self.is_depleted {
+                    return Poll::Ready(None);
+                }
+
+                // Demand for the next batch is sent only once the previous one is consumed
+                if let Err(err) = futures_util::ready!(self.poll_send_demand(cx)) {
+                    return Poll::Ready(Some(Err(ClientQueryError::Other(err.into()))));
+                }
+
+                let message = match futures_util::ready!(self.stream.poll_next_unpin(cx)) {
+                    Some(Ok(WebSocketMessage::Binary(message))) => message,
+                    Some(Ok(_)) => continue,
+                    Some(Err(err)) => {
+                        return Poll::Ready(Some(Err(ClientQueryError::Other(err.into()))))
+                    }
+                    None => {
+                        self.is_depleted = true;
+                        return Poll::Ready(Some(Err(ClientQueryError::Other(eyre!(
+                            "Query stream was closed before the last batch was received"
+                        )))));
+                    }
+                };
+
+                let (batch, cursor): (Value, ForwardCursor) = match self.handler.message(message) {
+                    Ok(batch) => batch.into(),
+                    Err(err) => {
+                        self.is_depleted = true;
+                        return Poll::Ready(Some(Err(err
+                            .downcast::<ValidationFail>()
+                            .map_or_else(ClientQueryError::Other, ClientQueryError::Validation))));
+                    }
+                };
+                let batch = match Vec::<T>::try_from(batch)
+                    .map_err(Into::into)
+                    .wrap_err("Unexpected type")
+                {
+                    Ok(batch) => batch,
+                    Err(err) => return Poll::Ready(Some(Err(ClientQueryError::Other(err)))),
+                };
+
+                self.is_depleted = cursor.cursor().is_none();
+                self.demand_pending = !self.is_depleted;
+                self.batch = batch.into_iter();
+            }
+        }
+    }
+}
+
 pub mod account {
     //! Module with queries for account
     use super::*;
@@ -1468,6 +1828,11 @@ pub mod asset {
     pub fn by_id(asset_id: AssetId) -> FindAssetById {
         FindAssetById::new(asset_id)
     }
+
+    /// Construct a query to get all NFT assets owned by account
+    pub fn nfts_by_owner(account_id: AccountId) -> FindNftsByOwner {
+        FindNftsByOwner::new(account_id)
+    }
 }
 
 pub mod block {
@@ -1705,6 +2070,10 @@ mod tests {
                     )),
                 ),
                 (StatusCode::UNPROCESSABLE_ENTITY, ValidationFail::TooComplex),
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    ValidationFail::QueryFailed(QueryExecutionFail::TooManyLiveQueries),
+                ),
             ];
             for (status_code, err) in responses {
                 let resp = Response::builder().status(status_code).body(err.encode())?;
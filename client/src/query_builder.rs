@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 
 use crate::{
-    client::{Client, QueryOutput, QueryResult},
+    client::{query_stream_api::AsyncQueryStream, Client, QueryOutput, QueryResult},
     data_model::{
         predicate::PredicateBox,
         query::{sorting::Sorting, FetchSize, Pagination, Query},
@@ -65,3 +65,32 @@ where
         )
     }
 }
+
+impl<R, T> QueryRequestBuilder<'_, R>
+where
+    R: Query<Output = Vec<T>> + Debug,
+    Vec<T>: QueryOutput,
+    <Vec<T> as TryFrom<Value>>::Error: Into<eyre::Error>,
+{
+    /// Stream the results of the query through `WebSocket` instead of fetching them with cursors.
+    ///
+    /// Filter, sorting, pagination and fetch size of the builder are applied the same way as in
+    /// [`Self::execute`], fetch size being the size of a single batch sent over the stream.
+    /// The stream counts against the live query limits of the peer until it's dropped
+    /// and is closed by the peer if no batch is requested for the query idle time.
+    ///
+    /// # Errors
+    /// Fails if connecting to the peer or sending the query fails
+    #[allow(clippy::future_not_send)]
+    pub async fn execute_stream(self) -> eyre::Result<AsyncQueryStream<T>> {
+        self.client
+            .stream_with_filter_and_pagination_and_sorting(
+                self.request,
+                self.pagination,
+                self.fetch_size,
+                self.sorting,
+                self.filter,
+            )
+            .await
+    }
+}
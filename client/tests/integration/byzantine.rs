@@ -0,0 +1,143 @@
+use std::thread;
+
+use iroha_client::{
+    client::{self, Client, QueryResult},
+    data_model::{prelude::*, Level},
+};
+use iroha_config::{iroha::Configuration, sumeragi::ByzantineFaults};
+use test_network::*;
+use tokio::runtime::Runtime;
+
+const N_TRANSACTIONS: u32 = 10;
+
+#[test]
+fn equivocating_leader_is_tolerated() {
+    byzantine_network(
+        4,
+        1,
+        ByzantineFaults {
+            equivocate_block_created: true,
+            ..ByzantineFaults::default()
+        },
+        11_300,
+    );
+}
+
+#[test]
+fn withheld_block_signatures_are_tolerated() {
+    byzantine_network(
+        4,
+        1,
+        ByzantineFaults {
+            withhold_block_signed: true,
+            ..ByzantineFaults::default()
+        },
+        11_325,
+    );
+}
+
+#[test]
+fn invalid_view_change_proofs_are_tolerated() {
+    byzantine_network(
+        4,
+        1,
+        ByzantineFaults {
+            invalid_view_change_proofs: true,
+            ..ByzantineFaults::default()
+        },
+        11_350,
+    );
+}
+
+#[test]
+fn lying_block_sync_is_tolerated() {
+    byzantine_network(
+        4,
+        1,
+        ByzantineFaults {
+            lie_in_share_blocks: true,
+            ..ByzantineFaults::default()
+        },
+        11_375,
+    );
+}
+
+#[test]
+fn all_faults_7_peers_2_byzantine() {
+    byzantine_network(
+        7,
+        2,
+        ByzantineFaults {
+            equivocate_block_created: true,
+            withhold_block_signed: true,
+            invalid_view_change_proofs: true,
+            lie_in_share_blocks: true,
+        },
+        11_400,
+    );
+}
+
+fn byzantine_network(n_peers: u32, n_byzantine_peers: u32, faults: ByzantineFaults, port: u16) {
+    if let Err(error) = iroha_logger::install_panic_hook() {
+        eprintln!("Installing panic hook failed: {error}");
+    }
+    let rt = Runtime::test();
+    // Given
+    let network = rt.block_on(async {
+        let mut configuration = Configuration::test();
+        configuration.logger.level = Level::INFO;
+        Network::new_with_peer_configuration(
+            Some(configuration),
+            n_peers,
+            0,
+            Some(port),
+            |n, mut configuration| {
+                if n < n_byzantine_peers as usize {
+                    configuration.sumeragi.debug_byzantine_faults = faults;
+                }
+                configuration
+            },
+        )
+        .await
+        .expect("Failed to init peers")
+    });
+    wait_for_genesis_committed(&network.clients(), 0);
+
+    let iroha_client = Client::test(&network.genesis.api_address);
+
+    let account_id: AccountId = "alice@wonderland".parse().expect("Valid");
+    let asset_definition_id: AssetDefinitionId = "camomile#wonderland".parse().expect("Valid");
+    let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+    let register_asset = Register::asset_definition(AssetDefinition::quantity(asset_definition_id));
+    iroha_client
+        .submit_blocking(register_asset)
+        .expect("Failed to register asset");
+
+    // When
+    for _ in 0..N_TRANSACTIONS {
+        iroha_client
+            .submit(Mint::asset_quantity(1_u32, asset_id.clone()))
+            .expect("Failed to submit transaction");
+        thread::sleep(Configuration::pipeline_time());
+    }
+
+    // Then
+    // NOTE: Faults only affect outgoing messages, so Byzantine peers must also follow the chain
+    for peer_client in network.clients() {
+        peer_client
+            .poll_request_with_period(
+                client::asset::by_account_id(account_id.clone()),
+                Configuration::pipeline_time(),
+                2 * N_TRANSACTIONS,
+                |result| {
+                    let assets = result.collect::<QueryResult<Vec<_>>>().expect("Valid");
+
+                    assets.iter().any(|asset| {
+                        *asset.id() == asset_id
+                            && *asset.value() == AssetValue::Quantity(N_TRANSACTIONS)
+                    })
+                },
+            )
+            .expect("Peers must commit all transactions");
+    }
+}
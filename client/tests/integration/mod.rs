@@ -3,6 +3,7 @@ mod add_domain;
 mod asset;
 mod asset_propagation;
 mod burn_public_keys;
+mod byzantine;
 mod config;
 mod connected_peers;
 mod domain_owner;
@@ -11,6 +12,7 @@ mod multiple_blocks_created;
 mod multisignature_account;
 mod multisignature_transaction;
 mod non_mintable;
+mod observer;
 mod offline_peers;
 mod pagination;
 mod permissions;
@@ -18,8 +20,10 @@ mod queries;
 mod query_errors;
 mod restart_peer;
 mod roles;
+mod rotate_peer_key;
 mod set_parameter;
 mod sorting;
+mod threshold_signature;
 mod transfer_asset;
 mod triggers;
 mod tx_history;
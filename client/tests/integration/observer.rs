@@ -0,0 +1,68 @@
+use std::iter;
+
+use eyre::Result;
+use iroha_client::{
+    client::{self, Client, QueryResult},
+    data_model::prelude::*,
+};
+use iroha_config::{iroha::Configuration, sumeragi::NodeRole};
+use iroha_primitives::unique_vec::UniqueVec;
+use test_network::*;
+use tokio::runtime::Runtime;
+
+#[test]
+fn observer_follows_chain_without_taking_part_in_consensus() -> Result<()> {
+    let rt = Runtime::test();
+    // Given
+    let mut configuration = Configuration::test();
+    configuration.network.accept_observers = true;
+    let network = rt.block_on(Network::new_with_offline_peers(
+        Some(configuration),
+        4,
+        0,
+        Some(11_500),
+    ))?;
+    wait_for_genesis_committed(&network.clients(), 0);
+
+    let mut observer_configuration = Configuration::test();
+    observer_configuration.sumeragi.node_role = NodeRole::Observer;
+    observer_configuration.sumeragi.trusted_peers.peers = network
+        .peers()
+        .map(|peer| peer.id.clone())
+        .collect::<UniqueVec<_>>();
+    let observer = rt.block_on(
+        PeerBuilder::new()
+            .with_configuration(observer_configuration)
+            .with_into_genesis(WithGenesis::None)
+            .with_port(11_525)
+            .start(),
+    );
+    let observer_client = Client::test(&observer.api_address);
+    wait_for_genesis_committed(&[observer_client.clone()], 0);
+
+    // When
+    // NOTE: Observer forwards transactions to the validators
+    let asset_definition_id: AssetDefinitionId = "observed#wonderland".parse()?;
+    observer_client.submit(Register::asset_definition(AssetDefinition::quantity(
+        asset_definition_id.clone(),
+    )))?;
+
+    // Then
+    for peer_client in network.clients().iter().chain(iter::once(&observer_client)) {
+        peer_client.poll_request(client::asset::all_definitions(), |result| {
+            let asset_definitions = result.collect::<QueryResult<Vec<_>>>().expect("Valid");
+
+            asset_definitions
+                .iter()
+                .any(|asset_definition| *asset_definition.id() == asset_definition_id)
+        })?;
+    }
+
+    let peers = observer_client
+        .request(FindAllPeers)?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert_eq!(peers.len(), 4);
+    assert!(peers.iter().all(|peer| *peer.id() != observer.id));
+
+    Ok(())
+}
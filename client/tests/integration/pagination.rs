@@ -1,9 +1,14 @@
 use std::num::{NonZeroU32, NonZeroU64};
 
 use eyre::Result;
+use futures_util::TryStreamExt as _;
 use iroha_client::{
-    client::{asset, Client, QueryResult},
-    data_model::{asset::AssetDefinition, prelude::*, query::Pagination},
+    client::{asset, Client, ClientQueryError, QueryResult},
+    data_model::{
+        asset::AssetDefinition,
+        prelude::*,
+        query::{error::QueryExecutionFail, Pagination},
+    },
 };
 use test_network::*;
 
@@ -45,6 +50,55 @@ fn fetch_size_should_work() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn query_stream_should_work() -> Result<()> {
+    let (rt, _peer, client) = <PeerBuilder>::new().with_port(11_150).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    register_assets(&client)?;
+
+    let asset_definitions = rt.block_on(async {
+        let stream = client
+            .build_query(asset::all_definitions())
+            .with_pagination(Pagination {
+                limit: NonZeroU32::new(20),
+                start: NonZeroU64::new(0),
+            })
+            .with_fetch_size(FetchSize::new(Some(NonZeroU32::new(3).expect("Valid"))))
+            .execute_stream()
+            .await?;
+        stream
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(eyre::Report::from)
+    })?;
+    assert_eq!(asset_definitions.len(), 20);
+    Ok(())
+}
+
+#[test]
+fn drop_query_should_work() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_250).start_with_runtime();
+    wait_for_genesis_committed(&vec![client.clone()], 0);
+
+    register_assets(&client)?;
+
+    let iter = client
+        .build_query(asset::all_definitions())
+        .with_fetch_size(FetchSize::new(Some(NonZeroU32::new(3).expect("Valid"))))
+        .execute()?;
+    let query_id = iter.query_id().expect("Query should be live").clone();
+
+    client.drop_query(query_id.clone())?;
+    assert!(matches!(
+        client.drop_query(query_id),
+        Err(ClientQueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::UnknownCursor
+        )))
+    ));
+    Ok(())
+}
+
 fn register_assets(client: &Client) -> Result<()> {
     let register: Vec<InstructionBox> = ('a'..='z')
         .map(|c| c.to_string())
@@ -0,0 +1,96 @@
+use std::num::{NonZeroU32, NonZeroU64};
+
+use eyre::Result;
+use iroha_client::{
+    client::{self, Client},
+    config::torii::JSON_QUERY,
+    data_model::{
+        prelude::*,
+        query::{cursor::ForwardCursor, error::QueryExecutionFail},
+    },
+};
+use serde_json::{json, Value as JsonValue};
+use test_network::*;
+
+fn post_json_query(client: &Client, request: &JsonValue) -> Result<JsonValue> {
+    let response = attohttpc::post(client.torii_url.join(JSON_QUERY)?)
+        .header("Content-Type", "application/json")
+        .bytes(serde_json::to_vec(request)?)
+        .send()?;
+    Ok(serde_json::from_slice(&response.bytes()?)?)
+}
+
+fn signed_query(client: &Client, query: impl Into<QueryBox>) -> Result<JsonValue> {
+    let SignedQuery::V1(signed) =
+        client.sign_query(QueryBuilder::new(query, client.account_id.clone()));
+    Ok(serde_json::to_value(signed)?)
+}
+
+fn register_domains(client: &Client) -> Result<()> {
+    let register: Vec<InstructionBox> = ["a", "b", "c"]
+        .into_iter()
+        .map(|name| Register::domain(Domain::new(name.parse().expect("Valid"))).into())
+        .collect();
+    let _ = client.submit_all_blocking(register)?;
+    Ok(())
+}
+
+#[test]
+fn json_query_batches_are_fetched_with_cursor() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_260).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+    register_domains(&client)?;
+
+    let response = post_json_query(
+        &client,
+        &json!({
+            "query": {
+                "signed": signed_query(&client, FindAllDomains)?,
+                "fetch_size": FetchSize::new(NonZeroU32::new(1)),
+            },
+            "fields": ["id"],
+        }),
+    )?;
+    assert_eq!(response["data"].as_array().map(Vec::len), Some(1));
+    assert!(response["data"][0]["id"].is_string());
+    assert!(response["data"][0].get("metadata").is_none());
+
+    let response = post_json_query(&client, &json!({ "cursor": response["cursor"] }))?;
+    assert_eq!(response["data"].as_array().map(Vec::len), Some(1));
+    assert!(response.get("error").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn json_query_rejects_queries_which_are_not_exposed() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_265).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+    register_domains(&client)?;
+
+    let response = post_json_query(
+        &client,
+        &json!({ "query": { "signed": signed_query(&client, FindAllRoles)? } }),
+    )?;
+    assert!(matches!(
+        serde_json::from_value::<ValidationFail>(response["error"].clone())?,
+        ValidationFail::NotPermitted(_)
+    ));
+
+    // Cursors of the queries started through the SCALE endpoint can't be used
+    let result_set = client
+        .build_query(client::domain::all())
+        .with_fetch_size(FetchSize::new(NonZeroU32::new(1)))
+        .execute()?;
+    let query_id = result_set.query_id().expect("Query should be live").clone();
+    let cursor = ForwardCursor::new(Some(query_id), NonZeroU64::new(1));
+
+    let response = post_json_query(&client, &json!({ "cursor": cursor }))?;
+    assert!(response.get("data").is_none());
+    assert!(matches!(
+        serde_json::from_value::<ValidationFail>(response["error"].clone())?,
+        ValidationFail::QueryFailed(QueryExecutionFail::UnknownCursor)
+    ));
+
+    Ok(())
+}
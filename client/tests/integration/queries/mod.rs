@@ -12,6 +12,7 @@ use test_network::*;
 
 mod account;
 mod asset;
+mod json;
 mod role;
 
 #[test]
@@ -0,0 +1,66 @@
+use eyre::Result;
+use iroha_client::{
+    client::{self, Client, QueryResult},
+    crypto::KeyPair,
+    data_model::prelude::*,
+};
+use iroha_config::iroha::Configuration;
+use iroha_primitives::unique_vec;
+use test_network::*;
+use tokio::runtime::Runtime;
+
+#[test]
+fn peer_signs_blocks_with_rotated_key() -> Result<()> {
+    let rt = Runtime::test();
+    let mut peer = PeerBuilder::new()
+        .with_port(11_270)
+        .build()
+        .expect("Failed to build a peer");
+    let next_key_pair = KeyPair::generate()?;
+
+    let mut configuration = Configuration::test();
+    configuration.sumeragi.trusted_peers.peers = unique_vec![peer.id.clone()];
+    configuration.sumeragi.next_key_pair = Some(next_key_pair.clone());
+    rt.block_on(
+        PeerBuilder::new()
+            .with_configuration(configuration)
+            .start_with_peer(&mut peer),
+    );
+    let client = Client::test(&peer.api_address);
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    client.submit_blocking(RotatePeerKey::new(
+        &client.chain_id,
+        peer.id.clone(),
+        next_key_pair.public_key().clone(),
+        &peer.key_pair,
+    ))?;
+
+    let peers = client
+        .request(FindAllPeers)?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert_eq!(peers.len(), 1);
+    assert_eq!(peers[0].id().public_key(), next_key_pair.public_key());
+
+    // The only peer has to sign the following blocks with the new key for them to be committed
+    client.submit_blocking(Register::domain(Domain::new("rotated".parse()?)))?;
+    let blocks = client
+        .request(client::block::all())?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert!(blocks.iter().any(|block| block
+        .signatures()
+        .into_iter()
+        .any(|signature| signature.public_key() == next_key_pair.public_key())));
+
+    // Replaying the rotation is rejected
+    assert!(client
+        .submit_blocking(RotatePeerKey::new(
+            &client.chain_id,
+            peer.id.clone(),
+            next_key_pair.public_key().clone(),
+            &peer.key_pair,
+        ))
+        .is_err());
+
+    Ok(())
+}
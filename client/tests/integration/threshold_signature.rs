@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use eyre::Result;
+use iroha_client::{
+    client,
+    crypto::threshold::{self, dkg, KeyShare, ParticipantId, PublicKeyPackage, SigningPackage},
+    data_model::prelude::*,
+};
+use test_network::*;
+
+/// Run the key generation of `max_signers` participants in process
+fn run_dkg(max_signers: u16, min_signers: u16) -> Result<Vec<(KeyShare, PublicKeyPackage)>> {
+    let round1 = (1..=max_signers)
+        .map(|id| {
+            let id = ParticipantId::new(id).expect("Valid");
+            Ok((id, dkg::part1(id, max_signers, min_signers)?))
+        })
+        .collect::<Result<BTreeMap<_, _>>>()?;
+    let others = |id: ParticipantId| {
+        round1
+            .iter()
+            .filter(|(other, _)| **other != id)
+            .map(|(&other, (_, package))| (other, package.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    let mut round2 = BTreeMap::<_, BTreeMap<_, _>>::new();
+    for (&id, (secret, _)) in &round1 {
+        for (receiver, package) in dkg::part2(secret, &others(id))? {
+            round2.entry(receiver).or_default().insert(id, package);
+        }
+    }
+
+    round1
+        .iter()
+        .map(|(&id, (secret, _))| Ok(dkg::part3(secret, &others(id), &round2[&id])?))
+        .collect()
+}
+
+#[test]
+fn transaction_signed_by_threshold_of_signers_is_committed() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_275).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    // No participant ever holds the private key of the admin account
+    let participants = run_dkg(3, 2)?;
+    let public_key_package = &participants[0].1;
+    let admin_id: AccountId = "admin@wonderland".parse()?;
+    client.submit_blocking(Register::account(Account::new(
+        admin_id.clone(),
+        [public_key_package.public_key()],
+    )))?;
+
+    let domain_id: DomainId = "threshold".parse()?;
+    let transaction = TransactionBuilder::new(get_chain_id(), admin_id)
+        .with_instructions([Register::domain(Domain::new(domain_id.clone()))]);
+
+    // Any 2 of 3 participants sign the hash of the transaction payload
+    let signers = [&participants[0].0, &participants[2].0];
+    let (nonces, commitments): (Vec<_>, BTreeMap<_, _>) = signers
+        .iter()
+        .map(|key_share| {
+            let (nonces, commitments) = threshold::commit(key_share);
+            (nonces, (key_share.id(), commitments))
+        })
+        .unzip();
+    let package = SigningPackage::new(commitments, transaction.hash().as_ref().to_vec());
+    let signature_shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(key_share, nonces)| {
+            Ok((
+                key_share.id(),
+                threshold::sign(&package, nonces, key_share)?,
+            ))
+        })
+        .collect::<Result<BTreeMap<_, _>>>()?;
+    let signature = threshold::aggregate(&package, &signature_shares, public_key_package)?;
+
+    client.submit_transaction_blocking(&transaction.sign_with(signature)?)?;
+    client.request(client::domain::by_id(domain_id))?;
+
+    Ok(())
+}
@@ -822,6 +822,7 @@ mod asset {
                 AssetValueType::BigQuantity => AssetDefinition::big_quantity(definition_id),
                 AssetValueType::Fixed => AssetDefinition::fixed(definition_id),
                 AssetValueType::Store => AssetDefinition::store(definition_id),
+                AssetValueType::Nft => AssetDefinition::nft(definition_id),
             };
             if unmintable {
                 asset_definition = asset_definition.mintable_once();
@@ -1041,6 +1042,8 @@ mod asset {
 }
 
 mod peer {
+    use iroha_client::crypto::ProofOfPossession;
+
     use super::*;
 
     /// Subcommand for dealing with peer
@@ -1070,6 +1073,9 @@ mod peer {
         /// Public key of the peer
         #[arg(short, long)]
         pub key: PublicKey,
+        /// Proof of possession of the private key of the peer, required for BLS keys
+        #[arg(short, long)]
+        pub proof_of_possession: Option<ProofOfPossession>,
         #[command(flatten)]
         pub metadata: MetadataArgs,
     }
@@ -1079,10 +1085,14 @@ mod peer {
             let Self {
                 address,
                 key,
+                proof_of_possession,
                 metadata,
             } = self;
-            let register_peer =
-                iroha_client::data_model::isi::Register::peer(Peer::new(PeerId::new(address, key)));
+            let mut peer = Peer::new(PeerId::new(address, key));
+            if let Some(proof_of_possession) = proof_of_possession {
+                peer = peer.with_proof_of_possession(proof_of_possession);
+            }
+            let register_peer = iroha_client::data_model::isi::Register::peer(peer);
             submit([register_peer], metadata.load()?, context).wrap_err("Failed to register peer")
         }
     }
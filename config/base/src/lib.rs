@@ -290,6 +290,16 @@ pub mod derive {
         /// Key pair creation failed, most likely because the keys don't form a pair
         Crypto(#[from] iroha_crypto::error::Error),
 
+        /// Failed to load the keystore `{path}`
+        #[serde(skip)]
+        Keystore {
+            /// Path to the keystore file
+            path: String,
+            /// Unified error
+            #[source]
+            error: eyre::Report,
+        },
+
         // IMO this variant should not exist. If the value is inferred, we should only warn people if the inferred value is different from the provided one.
         /// You should remove the field `{field}` as its value is determined by other configuration parameters
         #[serde(skip)]
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 const DEFAULT_BLOCK_BATCH_SIZE: u32 = 4;
 const DEFAULT_GOSSIP_PERIOD_MS: u64 = 10000;
 const DEFAULT_ACTOR_CHANNEL_CAPACITY: u32 = 100;
+const DEFAULT_PARALLEL_DOWNLOAD_PEERS: u32 = 4;
 
 /// Configuration for `BlockSynchronizer`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Proxy)]
@@ -18,6 +19,9 @@ pub struct Configuration {
     pub block_batch_size: u32,
     /// Buffer capacity of actor's MPSC channel
     pub actor_channel_capacity: u32,
+    /// The maximal number of peers to download blocks from in parallel once this peer
+    /// receives a full batch, i.e. is far behind. `1` disables parallel download.
+    pub parallel_download_peers: u32,
 }
 
 impl Default for ConfigurationProxy {
@@ -26,6 +30,7 @@ impl Default for ConfigurationProxy {
             gossip_period_ms: Some(DEFAULT_GOSSIP_PERIOD_MS),
             block_batch_size: Some(DEFAULT_BLOCK_BATCH_SIZE),
             actor_channel_capacity: Some(DEFAULT_ACTOR_CHANNEL_CAPACITY),
+            parallel_download_peers: Some(DEFAULT_PARALLEL_DOWNLOAD_PEERS),
         }
     }
 }
@@ -42,9 +47,10 @@ pub mod tests {
                 gossip_period_ms in prop::option::of(Just(DEFAULT_GOSSIP_PERIOD_MS)),
                 block_batch_size in prop::option::of(Just(DEFAULT_BLOCK_BATCH_SIZE)),
                 actor_channel_capacity in prop::option::of(Just(DEFAULT_ACTOR_CHANNEL_CAPACITY)),
+                parallel_download_peers in prop::option::of(Just(DEFAULT_PARALLEL_DOWNLOAD_PEERS)),
             )
             -> ConfigurationProxy {
-            ConfigurationProxy { gossip_period_ms, block_batch_size, actor_channel_capacity }
+            ConfigurationProxy { gossip_period_ms, block_batch_size, actor_channel_capacity, parallel_download_peers }
         }
     }
 }
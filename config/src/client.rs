@@ -5,11 +5,18 @@ use std::num::NonZeroU64;
 use derive_more::Display;
 use eyre::{Result, WrapErr};
 use iroha_config_base::derive::{Error as ConfigError, Proxy};
-use iroha_crypto::prelude::*;
+use iroha_crypto::{
+    hd::{DerivationPath, Mnemonic},
+    prelude::*,
+    KeyGenConfiguration,
+};
 use iroha_data_model::{prelude::*, ChainId};
 use iroha_primitives::small::SmallStr;
 use serde::{Deserialize, Serialize};
 use url::Url;
+use zeroize::Zeroize as _;
+
+use crate::keystore::KeystoreReference;
 
 #[allow(unsafe_code)]
 const DEFAULT_TRANSACTION_TIME_TO_LIVE_MS: NonZeroU64 =
@@ -62,6 +69,68 @@ pub struct BasicAuth {
     pub password: SmallStr,
 }
 
+/// Environment variable holding the mnemonic phrase of the key derivation by default
+pub const DEFAULT_MNEMONIC_ENV: &str = "IROHA_MNEMONIC";
+
+fn default_mnemonic_env() -> String {
+    DEFAULT_MNEMONIC_ENV.to_owned()
+}
+
+/// Hierarchical deterministic derivation of the account keys from a BIP-0039 mnemonic phrase.
+/// Like the password of a [`KeystoreReference`], the mnemonic phrase and its passphrase are never
+/// stored in the configuration, they're read from the environment instead.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct KeyDerivation {
+    /// Name of the environment variable holding the mnemonic phrase
+    #[serde(default = "default_mnemonic_env")]
+    pub mnemonic_env: String,
+    /// Name of the environment variable holding the passphrase protecting the mnemonic phrase,
+    /// the mnemonic phrase isn't protected by a passphrase if not given
+    #[serde(default)]
+    pub passphrase_env: Option<String>,
+    /// Derivation path of the account key, e.g. `m/44'/0'/0'`
+    pub path: DerivationPath,
+    /// Algorithm of the account key, only `ed25519` and `secp256k1` are supported
+    #[serde(default)]
+    pub algorithm: Algorithm,
+}
+
+impl KeyDerivation {
+    /// Derive the key pair
+    ///
+    /// # Errors
+    /// - The mnemonic or the passphrase environment variable isn't set
+    /// - The mnemonic phrase is invalid
+    /// - Derivation isn't supported for the algorithm or the path
+    pub fn key_pair(&self) -> Result<KeyPair> {
+        let mut phrase = read_secret(&self.mnemonic_env, "mnemonic")?;
+        let mnemonic = phrase.parse::<Mnemonic>();
+        phrase.zeroize();
+        // NOTE: Parsed mnemonic is zeroized on drop
+        let mnemonic = mnemonic.wrap_err("Failed to parse mnemonic")?;
+
+        let mut passphrase = self
+            .passphrase_env
+            .as_ref()
+            .map(|passphrase_env| read_secret(passphrase_env, "passphrase"))
+            .transpose()?
+            .unwrap_or_default();
+        let seed = mnemonic.to_seed(&passphrase);
+        passphrase.zeroize();
+
+        let configuration = KeyGenConfiguration::from_seed(seed)
+            .with_derivation_path(self.path.clone())
+            .with_algorithm(self.algorithm);
+        KeyPair::generate_with_configuration(configuration)
+            .wrap_err("Failed to derive key pair from the mnemonic")
+    }
+}
+
+fn read_secret(env: &str, secret: &str) -> Result<String> {
+    std::env::var(env)
+        .wrap_err_with(|| format!("Failed to read {secret} from `{env}` environment variable"))
+}
+
 /// `Configuration` provides an ability to define client parameters such as `TORII_URL`.
 #[derive(Debug, Clone, Deserialize, Serialize, Proxy, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -76,6 +145,10 @@ pub struct Configuration {
     pub private_key: PrivateKey,
     /// User account id.
     pub account_id: AccountId,
+    /// Encrypted keystore to load the account keys from, used in place of `PUBLIC_KEY` and `PRIVATE_KEY`
+    pub keystore: Option<KeystoreReference>,
+    /// Derivation of the account keys from a mnemonic phrase, used in place of `PUBLIC_KEY` and `PRIVATE_KEY`
+    pub key_derivation: Option<KeyDerivation>,
     /// Basic Authentication credentials
     pub basic_auth: Option<BasicAuth>,
     /// Torii URL.
@@ -95,6 +168,8 @@ impl Default for ConfigurationProxy {
             public_key: None,
             private_key: None,
             account_id: None,
+            keystore: Some(None),
+            key_derivation: Some(None),
             basic_auth: Some(None),
             torii_api_url: None,
             transaction_time_to_live_ms: Some(Some(DEFAULT_TRANSACTION_TIME_TO_LIVE_MS)),
@@ -115,7 +190,33 @@ impl ConfigurationProxy {
     /// - If the [`self.transaction_time_to_live_ms`] field is too small
     /// - If the [`self.transaction_status_timeout_ms`] field is smaller than [`self.transaction_time_to_live_ms`]
     /// - If the [`self.torii_api_url`] is malformed or had the wrong protocol
+    /// - If the [`self.key_derivation`] is specified together with the keys or the derivation fails
+    /// - If the [`self.keystore`] is specified together with the keys or can't be loaded
     pub fn finish(&mut self) -> Result<()> {
+        if let Some(Some(keystore)) = &self.keystore {
+            if self.public_key.is_some() || self.private_key.is_some() {
+                eyre::bail!(ConfigError::ProvidedInferredField {
+                    field: "PUBLIC_KEY and PRIVATE_KEY",
+                    message: "The key pair is loaded from the `KEYSTORE`. Please set `PUBLIC_KEY` and `PRIVATE_KEY` to `null` or omit them entirely.",
+                });
+            }
+            let (public_key, private_key) = keystore.load()?.into();
+            self.public_key = Some(public_key);
+            self.private_key = Some(private_key);
+        }
+        if let Some(Some(key_derivation)) = &self.key_derivation {
+            if self.public_key.is_some() || self.private_key.is_some() {
+                eyre::bail!(ConfigError::InsaneValue {
+                    field: "KEY_DERIVATION",
+                    value: key_derivation.path.to_string(),
+                    message: ", because `PUBLIC_KEY` and `PRIVATE_KEY` are specified as well"
+                        .to_owned(),
+                });
+            }
+            let (public_key, private_key) = key_derivation.key_pair()?.into();
+            self.public_key = Some(public_key);
+            self.private_key = Some(private_key);
+        }
         if let Some(Some(tx_ttl)) = self.transaction_time_to_live_ms {
             // Really small TTL would be detrimental to performance
             if u64::from(tx_ttl) < TTL_TOO_SMALL_THRESHOLD {
@@ -168,7 +269,6 @@ impl ConfigurationProxy {
 #[cfg(test)]
 mod tests {
     use iroha_config_base::proxy::LoadFromDisk;
-    use iroha_crypto::KeyGenConfiguration;
     use proptest::prelude::*;
 
     use super::*;
@@ -204,6 +304,8 @@ mod tests {
                 chain_id in prop::option::of(Just(crate::iroha::tests::placeholder_chain_id())),
                 (public_key, private_key) in arb_keys_with_option(),
                 account_id in prop::option::of(Just(placeholder_account())),
+                keystore in prop::option::of(Just(None)),
+                key_derivation in prop::option::of(Just(None)),
                 basic_auth in prop::option::of(Just(None)),
                 torii_api_url in prop::option::of(Just(format!("http://{DEFAULT_API_ADDR}").parse().unwrap())),
                 transaction_time_to_live_ms in prop::option::of(Just(Some(DEFAULT_TRANSACTION_TIME_TO_LIVE_MS))),
@@ -211,10 +313,53 @@ mod tests {
                 add_transaction_nonce in prop::option::of(Just(DEFAULT_ADD_TRANSACTION_NONCE)),
             )
             -> ConfigurationProxy {
-            ConfigurationProxy { chain_id, public_key, private_key, account_id, basic_auth, torii_api_url, transaction_time_to_live_ms, transaction_status_timeout_ms, add_transaction_nonce }
+            ConfigurationProxy { chain_id, public_key, private_key, account_id, keystore, key_derivation, basic_auth, torii_api_url, transaction_time_to_live_ms, transaction_status_timeout_ms, add_transaction_nonce }
         }
     }
 
+    #[test]
+    fn keys_are_derived_from_mnemonic() {
+        let mnemonic_env = "IROHA_TEST_KEYS_ARE_DERIVED_FROM_MNEMONIC";
+        std::env::set_var(mnemonic_env, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+        let key_derivation = KeyDerivation {
+            mnemonic_env: mnemonic_env.to_owned(),
+            passphrase_env: None,
+            path: "m/44'/0'/0'".parse().unwrap(),
+            algorithm: Algorithm::Ed25519,
+        };
+        let key_pair = key_derivation.key_pair().unwrap();
+
+        let mut proxy = ConfigurationProxy::from_path(CONFIGURATION_PATH);
+        proxy.key_derivation = Some(Some(key_derivation));
+        assert!(proxy.clone().build().is_err());
+
+        proxy.public_key = None;
+        proxy.private_key = None;
+        let cfg = proxy.build().expect("Keys should be derived");
+        assert_eq!(cfg.public_key, *key_pair.public_key());
+        assert_eq!(cfg.private_key, *key_pair.private_key());
+    }
+
+    #[test]
+    fn passphrase_is_read_from_environment() {
+        let mnemonic_env = "IROHA_TEST_PASSPHRASE_IS_READ_FROM_ENVIRONMENT_MNEMONIC";
+        let passphrase_env = "IROHA_TEST_PASSPHRASE_IS_READ_FROM_ENVIRONMENT_PASSPHRASE";
+        std::env::set_var(mnemonic_env, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+        let mut key_derivation = KeyDerivation {
+            mnemonic_env: mnemonic_env.to_owned(),
+            passphrase_env: Some(passphrase_env.to_owned()),
+            path: "m/44'/0'/0'".parse().unwrap(),
+            algorithm: Algorithm::Ed25519,
+        };
+        assert!(key_derivation.key_pair().is_err(), "Passphrase isn't set");
+
+        std::env::set_var(passphrase_env, "hunter2");
+        let protected = key_derivation.key_pair().unwrap();
+        key_derivation.passphrase_env = None;
+        let unprotected = key_derivation.key_pair().unwrap();
+        assert_ne!(protected, unprotected);
+    }
+
     proptest! {
         #[test]
         fn client_proxy_build_fails_on_none(proxy in arb_proxy()) {
@@ -7,6 +7,7 @@ use iroha_data_model::ChainId;
 use serde::{Deserialize, Serialize};
 
 use super::*;
+use crate::keystore::KeystoreReference;
 
 // Generate `ConfigurationView` without the private key
 view! {
@@ -24,6 +25,10 @@ view! {
         /// Private key of this peer
         #[view(ignore)]
         pub private_key: PrivateKey,
+        /// Encrypted keystore to load the key pair of this peer from, used in place of `PUBLIC_KEY` and `PRIVATE_KEY`
+        pub keystore: Option<KeystoreReference>,
+        /// Encrypted keystore to load the key pair this peer switches to once its key is rotated with `RotatePeerKey`
+        pub next_keystore: Option<KeystoreReference>,
         /// `Kura` configuration
         #[config(inner)]
         pub kura: Box<kura::Configuration>,
@@ -71,6 +76,8 @@ impl Default for ConfigurationProxy {
             chain_id: None,
             public_key: None,
             private_key: None,
+            keystore: Some(None),
+            next_keystore: Some(None),
             kura: Some(Box::default()),
             sumeragi: Some(Box::default()),
             torii: Some(Box::default()),
@@ -95,7 +102,20 @@ impl ConfigurationProxy {
     ///
     /// # Errors
     /// - If the relevant uppermost Iroha config fields were not provided.
+    /// - If the keystore is provided together with the keys or can't be loaded.
+    /// - If the next key pair of sumeragi is provided in plaintext instead of the `NEXT_KEYSTORE`.
     pub fn finish(&mut self) -> Result<(), ConfigError> {
+        if let Some(Some(keystore)) = &self.keystore {
+            if self.public_key.is_some() || self.private_key.is_some() {
+                return Err(ConfigError::ProvidedInferredField {
+                    field: "PUBLIC_KEY and PRIVATE_KEY",
+                    message: "The key pair is loaded from the `KEYSTORE`. Please set `PUBLIC_KEY` and `PRIVATE_KEY` to `null` or omit them entirely.",
+                });
+            }
+            let (public_key, private_key) = keystore.load()?.into();
+            self.public_key = Some(public_key);
+            self.private_key = Some(private_key);
+        }
         if let Some(sumeragi_proxy) = &mut self.sumeragi {
             // First, iroha public/private key and sumeragi keypair are interchangeable, but
             // the user is allowed to provide only the former, and keypair is generated automatically,
@@ -106,6 +126,15 @@ impl ConfigurationProxy {
                     message: "Sumeragi should not be provided with `KEY_PAIR` directly. That value is computed from the other config parameters. Please set the `KEY_PAIR` to `null` or omit entirely."
                 });
             }
+            if let Some(Some(_)) = sumeragi_proxy.next_key_pair {
+                return Err(ConfigError::ProvidedInferredField {
+                    field: "next_key_pair",
+                    message: "Sumeragi should not be provided with `NEXT_KEY_PAIR` directly. The next key pair is loaded from the encrypted `NEXT_KEYSTORE`. Please set the `NEXT_KEY_PAIR` to `null` or omit entirely."
+                });
+            }
+            if let Some(Some(next_keystore)) = &self.next_keystore {
+                sumeragi_proxy.next_key_pair = Some(Some(next_keystore.load()?));
+            }
             if let (Some(public_key), Some(private_key)) = (&self.public_key, &self.private_key) {
                 sumeragi_proxy.key_pair =
                     Some(KeyPair::new(public_key.clone(), private_key.clone())?);
@@ -147,7 +176,10 @@ impl ConfigurationProxy {
                 });
             }
 
-            sumeragi_proxy.insert_self_as_trusted_peers()
+            // Observers must not be part of the topology, which is built from the trusted peers
+            if sumeragi_proxy.node_role != Some(sumeragi::NodeRole::Observer) {
+                sumeragi_proxy.insert_self_as_trusted_peers()
+            }
         }
 
         Ok(())
@@ -213,6 +245,8 @@ pub mod tests {
         fn arb_proxy()(
             chain_id in prop::option::of(Just(placeholder_chain_id())),
             (public_key, private_key) in arb_keys(),
+            keystore in prop::option::of(Just(None)),
+            next_keystore in prop::option::of(Just(None)),
             kura in prop::option::of(kura::tests::arb_proxy().prop_map(Box::new)),
             sumeragi in (prop::option::of(sumeragi::tests::arb_proxy().prop_map(Box::new))),
             torii in (prop::option::of(torii::tests::arb_proxy().prop_map(Box::new))),
@@ -226,7 +260,7 @@ pub mod tests {
             snapshot in prop::option::of(snapshot::tests::arb_proxy().prop_map(Box::new)),
             live_query_store in prop::option::of(live_query_store::tests::arb_proxy()),
             ) -> ConfigurationProxy {
-            ConfigurationProxy { chain_id, public_key, private_key, kura, sumeragi, torii, block_sync, queue,
+            ConfigurationProxy { chain_id, public_key, private_key, keystore, next_keystore, kura, sumeragi, torii, block_sync, queue,
                                  logger, genesis, wsv, network, telemetry, snapshot, live_query_store }
         }
     }
@@ -0,0 +1,106 @@
+//! Module for referencing encrypted keystores in place of plaintext private keys
+use std::path::PathBuf;
+
+use eyre::WrapErr as _;
+use iroha_config_base::derive::Error as ConfigError;
+use iroha_crypto::{keystore::Keystore, KeyPair};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize as _;
+
+/// Environment variable holding the keystore password by default
+pub const DEFAULT_PASSWORD_ENV: &str = "IROHA_KEYSTORE_PASSWORD";
+
+fn default_password_env() -> String {
+    DEFAULT_PASSWORD_ENV.to_owned()
+}
+
+/// Reference to an encrypted keystore file (see [`iroha_crypto::keystore`]).
+/// The password is never stored in the configuration, it's read from the environment instead.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct KeystoreReference {
+    /// Path to the keystore file
+    pub path: PathBuf,
+    /// Name of the environment variable holding the keystore password
+    #[serde(default = "default_password_env")]
+    pub password_env: String,
+}
+
+impl KeystoreReference {
+    /// Read the keystore and decrypt the key pair
+    ///
+    /// # Errors
+    /// - The keystore file can't be read or parsed
+    /// - The password environment variable isn't set
+    /// - Decryption fails, e.g. because of a wrong password
+    pub fn load(&self) -> Result<KeyPair, ConfigError> {
+        std::env::var(&self.password_env)
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to read keystore password from `{}` environment variable",
+                    self.password_env
+                )
+            })
+            .and_then(|mut password| {
+                let key_pair = self.decrypt(password.as_bytes());
+                password.zeroize();
+                key_pair
+            })
+            .map_err(|error| self.error(error))
+    }
+
+    /// Read the keystore and decrypt the key pair with the given `password`
+    ///
+    /// # Errors
+    /// - The keystore file can't be read or parsed
+    /// - Decryption fails, e.g. because of a wrong password
+    pub fn load_with_password(&self, password: &[u8]) -> Result<KeyPair, ConfigError> {
+        self.decrypt(password).map_err(|error| self.error(error))
+    }
+
+    fn decrypt(&self, password: &[u8]) -> eyre::Result<KeyPair> {
+        let keystore = std::fs::read(&self.path).wrap_err("Failed to read keystore file")?;
+        let keystore: Keystore =
+            serde_json::from_slice(&keystore).wrap_err("Failed to parse keystore")?;
+
+        keystore
+            .decrypt(password)
+            .wrap_err("Failed to decrypt keystore")
+    }
+
+    fn error(&self, error: eyre::Report) -> ConfigError {
+        ConfigError::Keystore {
+            path: self.path.display().to_string(),
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::keystore::ScryptParams;
+
+    use super::*;
+
+    #[test]
+    fn load_keystore() {
+        let key_pair = KeyPair::generate().expect("Failed to generate key pair");
+        let keystore = Keystore::encrypt(&key_pair, b"password", ScryptParams::new(4, 8, 1))
+            .expect("Failed to encrypt key pair");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+        std::fs::write(&path, serde_json::to_vec(&keystore).unwrap()).unwrap();
+
+        let reference = KeystoreReference {
+            path,
+            password_env: "IROHA_TEST_LOAD_KEYSTORE_PASSWORD_UNSET".to_owned(),
+        };
+        assert!(reference.load().is_err(), "Password isn't set");
+        assert!(reference.load_with_password(b"wrong password").is_err());
+        assert_eq!(
+            reference
+                .load_with_password(b"password")
+                .expect("Failed to load keystore"),
+            key_pair
+        );
+    }
+}
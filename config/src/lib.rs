@@ -6,6 +6,7 @@ pub mod client;
 pub mod client_api;
 pub mod genesis;
 pub mod iroha;
+pub mod keystore;
 pub mod kura;
 pub mod live_query_store;
 pub mod logger;
@@ -1,6 +1,6 @@
 //! Module for `LiveQueryStore`-related configuration and structs.
 
-use std::num::NonZeroU64;
+use std::num::{NonZeroU32, NonZeroU64};
 
 use iroha_config_base::derive::Proxy;
 use serde::{Deserialize, Serialize};
@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 /// Default max time a query can remain in the store unaccessed
 pub static DEFAULT_QUERY_IDLE_TIME_MS: once_cell::sync::Lazy<NonZeroU64> =
     once_cell::sync::Lazy::new(|| NonZeroU64::new(30_000).unwrap());
+/// Default max number of live queries a single authority can keep in the store
+pub static DEFAULT_MAX_QUERIES_PER_AUTHORITY: once_cell::sync::Lazy<NonZeroU32> =
+    once_cell::sync::Lazy::new(|| NonZeroU32::new(128).unwrap());
+/// Default max total size (in bytes) of all live queries kept in the store
+pub static DEFAULT_MAX_TOTAL_SIZE_BYTES: once_cell::sync::Lazy<NonZeroU64> =
+    once_cell::sync::Lazy::new(|| NonZeroU64::new(256 * 2_u64.pow(20)).unwrap());
 
 /// Configuration for `QueryService`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, Proxy)]
@@ -16,12 +22,18 @@ pub static DEFAULT_QUERY_IDLE_TIME_MS: once_cell::sync::Lazy<NonZeroU64> =
 pub struct Configuration {
     /// Time query can remain in the store if unaccessed
     pub query_idle_time_ms: NonZeroU64,
+    /// Max number of live queries a single authority can keep in the store
+    pub max_queries_per_authority: NonZeroU32,
+    /// Max total size (in bytes of SCALE-encoded output) of all live queries kept in the store
+    pub max_total_size_bytes: NonZeroU64,
 }
 
 impl Default for ConfigurationProxy {
     fn default() -> Self {
         Self {
             query_idle_time_ms: Some(*DEFAULT_QUERY_IDLE_TIME_MS),
+            max_queries_per_authority: Some(*DEFAULT_MAX_QUERIES_PER_AUTHORITY),
+            max_total_size_bytes: Some(*DEFAULT_MAX_TOTAL_SIZE_BYTES),
         }
     }
 }
@@ -36,9 +48,11 @@ pub mod tests {
         pub fn arb_proxy()
             (
                 query_idle_time_ms in prop::option::of(Just(*DEFAULT_QUERY_IDLE_TIME_MS)),
+                max_queries_per_authority in prop::option::of(Just(*DEFAULT_MAX_QUERIES_PER_AUTHORITY)),
+                max_total_size_bytes in prop::option::of(Just(*DEFAULT_MAX_TOTAL_SIZE_BYTES)),
             )
             -> ConfigurationProxy {
-            ConfigurationProxy { query_idle_time_ms }
+            ConfigurationProxy { query_idle_time_ms, max_queries_per_authority, max_total_size_bytes }
         }
     }
 }
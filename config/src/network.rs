@@ -1,22 +1,74 @@
 //! Module for network-related configuration and structs
+use std::path::PathBuf;
+
 use iroha_config_base::derive::Proxy;
+use iroha_crypto::PublicKey;
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_ACTOR_CHANNEL_CAPACITY: u32 = 100;
+const DEFAULT_MAX_FRAME_SIZE: u32 = 2_u32.pow(25);
+const DEFAULT_PEER_MESSAGE_RATE_LIMIT: u32 = 1000;
+const DEFAULT_PEER_BAN_DURATION_MS: u64 = 60_000;
+
+/// Transport over which peers connect to each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Transport {
+    /// Single TCP stream per connection.
+    #[default]
+    Tcp,
+    /// QUIC connection, consensus, block sync and transaction gossip are sent over separate streams,
+    /// so that they don't delay each other.
+    Quic,
+}
 
 /// Network Configuration parameters
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Proxy)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Proxy)]
 #[serde(rename_all = "UPPERCASE")]
 #[config(env_prefix = "IROHA_NETWORK_")]
 pub struct Configuration {
     /// Buffer capacity of actor's MPSC channel
     pub actor_channel_capacity: u32,
+    /// File to persist the addresses of the peers learned from each other.
+    /// The addresses are only kept in memory if not set.
+    #[config(serde_as_str)]
+    pub address_book_path: Option<PathBuf>,
+    /// Accept connections from peers outside of the topology, which follow the chain as observers.
+    pub accept_observers: bool,
+    /// Reject handshakes of peers which are neither in the topology nor in `observer_keys` right away,
+    /// instead of letting them connect first. Only the observers from `observer_keys` are accepted in this mode.
+    pub allowlist: bool,
+    /// Keys of the peers outside of the topology which are allowed to connect as observers.
+    pub observer_keys: Vec<PublicKey>,
+    /// Compress messages exchanged with peers, if the peer has it enabled as well.
+    pub compression: bool,
+    /// Maximum size of a single message exchanged with peers in bytes.
+    /// Connection to a peer is dropped if it sends a bigger one.
+    /// Connected peers agree on the smaller of their sizes during the handshake.
+    pub max_frame_size: u32,
+    /// Maximum number of messages of every lane accepted from a single peer per second.
+    /// Peers of the topology can send bursts of up to ten times as many messages.
+    /// Excess messages are dropped and lower the score of the peer.
+    pub peer_message_rate_limit: u32,
+    /// Duration in milliseconds for which peers are banned once their score drops to zero.
+    pub peer_ban_duration_ms: u64,
+    /// Transport over which peers connect to each other. All peers in the network must use the same one.
+    pub transport: Transport,
 }
 
 impl Default for ConfigurationProxy {
     fn default() -> Self {
         Self {
             actor_channel_capacity: Some(DEFAULT_ACTOR_CHANNEL_CAPACITY),
+            address_book_path: Some(None),
+            accept_observers: Some(false),
+            allowlist: Some(false),
+            observer_keys: Some(Vec::new()),
+            compression: Some(true),
+            max_frame_size: Some(DEFAULT_MAX_FRAME_SIZE),
+            peer_message_rate_limit: Some(DEFAULT_PEER_MESSAGE_RATE_LIMIT),
+            peer_ban_duration_ms: Some(DEFAULT_PEER_BAN_DURATION_MS),
+            transport: Some(Transport::default()),
         }
     }
 }
@@ -31,9 +83,29 @@ pub mod tests {
         pub fn arb_proxy()
             (
                 actor_channel_capacity in prop::option::of(Just(DEFAULT_ACTOR_CHANNEL_CAPACITY)),
+                address_book_path in prop::option::of(Just(None)),
+                accept_observers in prop::option::of(Just(false)),
+                allowlist in prop::option::of(Just(false)),
+                observer_keys in prop::option::of(Just(Vec::new())),
+                compression in prop::option::of(Just(true)),
+                max_frame_size in prop::option::of(Just(DEFAULT_MAX_FRAME_SIZE)),
+                peer_message_rate_limit in prop::option::of(Just(DEFAULT_PEER_MESSAGE_RATE_LIMIT)),
+                peer_ban_duration_ms in prop::option::of(Just(DEFAULT_PEER_BAN_DURATION_MS)),
+                transport in prop::option::of(Just(Transport::default())),
             )
             -> ConfigurationProxy {
-            ConfigurationProxy { actor_channel_capacity }
+            ConfigurationProxy {
+                actor_channel_capacity,
+                address_book_path,
+                accept_observers,
+                allowlist,
+                observer_keys,
+                compression,
+                max_frame_size,
+                peer_message_rate_limit,
+                peer_ban_duration_ms,
+                transport,
+            }
         }
     }
 }
@@ -60,10 +60,19 @@ view! {
         pub gossip_batch_size: u32,
         /// Period in milliseconds for pending transaction gossiping between peers.
         pub gossip_period_ms: u64,
+        /// The key pair to switch to once the key of this peer is rotated with `RotatePeerKey`.
+        /// Loaded from the encrypted `NEXT_KEYSTORE` of the Iroha configuration, never provided in plaintext.
+        #[view(ignore)]
+        pub next_key_pair: Option<KeyPair>,
+        /// Whether this peer takes part in consensus or only follows the chain.
+        pub node_role: NodeRole,
         #[cfg(debug_assertions)]
         /// Only used in testing. Causes the genesis peer to withhold blocks when it
         /// is the proxy tail.
         pub debug_force_soft_fork: bool,
+        #[cfg(feature = "byzantine")]
+        /// Only used in testing. Makes this peer deviate from the consensus protocol.
+        pub debug_byzantine_faults: ByzantineFaults,
     }
 }
 
@@ -79,8 +88,12 @@ impl Default for ConfigurationProxy {
             gossip_batch_size: Some(DEFAULT_GOSSIP_BATCH_SIZE),
             gossip_period_ms: Some(DEFAULT_GOSSIP_PERIOD_MS),
             max_transactions_in_block: Some(DEFAULT_MAX_TRANSACTIONS_IN_BLOCK),
+            next_key_pair: Some(None),
+            node_role: Some(NodeRole::default()),
             #[cfg(debug_assertions)]
             debug_force_soft_fork: Some(false),
+            #[cfg(feature = "byzantine")]
+            debug_byzantine_faults: Some(ByzantineFaults::default()),
         }
     }
 }
@@ -117,6 +130,35 @@ impl Configuration {
     }
 }
 
+/// Role of the peer in the network.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeRole {
+    /// Takes part in consensus once it's registered as a peer.
+    #[default]
+    Validator,
+    /// Follows the chain through block sync and never takes part in consensus, even if registered as a peer.
+    /// Must not be listed in its own `TRUSTED_PEERS` and needs validators which accept observers.
+    Observer,
+}
+
+/// Faults injected into the behaviour of a peer to test the fault tolerance of consensus.
+/// Every fault is disabled by default. Only takes effect with the `byzantine` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[serde(default)]
+#[allow(clippy::struct_excessive_bools)] // NOTE: Faults are independent switches
+pub struct ByzantineFaults {
+    /// When leader, send conflicting blocks to the two halves of the validating peers.
+    pub equivocate_block_created: bool,
+    /// When validating or observing, never send the block signature to the proxy tail.
+    pub withhold_block_signed: bool,
+    /// Broadcast view change proofs signed by keys outside of the topology.
+    pub invalid_view_change_proofs: bool,
+    /// Answer block sync requests with blocks which were tampered with.
+    pub lie_in_share_blocks: bool,
+}
+
 /// Part of the [`Configuration`]. It is separated from the main structure in order to be able
 /// to load it from a separate file (see [`TrustedPeers::from_path`]).
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
@@ -165,6 +207,8 @@ pub mod tests {
              gossip_batch_size in prop::option::of(Just(DEFAULT_GOSSIP_BATCH_SIZE)),
              gossip_period_ms in prop::option::of(Just(DEFAULT_GOSSIP_PERIOD_MS)),
             max_transactions_in_block in prop::option::of(Just(DEFAULT_MAX_TRANSACTIONS_IN_BLOCK)),
+             next_key_pair in prop::option::of(Just(None)),
+             node_role in prop::option::of(Just(NodeRole::default())),
              debug_force_soft_fork in prop::option::of(Just(false)),
             )
             -> ConfigurationProxy {
@@ -178,8 +222,12 @@ pub mod tests {
                 actor_channel_capacity,
                 gossip_batch_size,
                 gossip_period_ms,
+                next_key_pair,
+                node_role,
                 #[cfg(debug_assertions)]
-                debug_force_soft_fork
+                debug_force_soft_fork,
+                #[cfg(feature = "byzantine")]
+                debug_byzantine_faults: Some(ByzantineFaults::default()),
             }
         }
     }
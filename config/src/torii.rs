@@ -1,5 +1,7 @@
 //! `Torii` configuration as well as the default values for the URLs used for the main endpoints: `p2p`, `telemetry`, but not `api`.
 
+use std::{num::NonZeroU32, path::PathBuf};
+
 use iroha_config_base::derive::Proxy;
 use iroha_primitives::addr::{socket_addr, SocketAddr};
 use serde::{Deserialize, Serialize};
@@ -11,6 +13,18 @@ pub const DEFAULT_TORII_MAX_TRANSACTION_SIZE: u32 = 2_u32.pow(15);
 /// Default upper bound on `content-length` specified in the HTTP request header
 pub const DEFAULT_TORII_MAX_CONTENT_LENGTH: u32 = 2_u32.pow(12) * 4000;
 
+/// Quota of the requests of a single client, replenished at a constant rate.
+///
+/// Address of the clients connecting over TLS isn't known, so they're only limited per authority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct Quota {
+    /// Number of requests replenished per second
+    pub rate_per_sec: NonZeroU32,
+    /// Maximum number of requests which can be made at once
+    pub burst: NonZeroU32,
+}
+
 /// Structure that defines the configuration parameters of `Torii` which is the routing module.
 /// For example the `p2p_addr`, which is used for consensus and block-synchronisation purposes,
 /// as well as `max_transaction_size`.
@@ -28,6 +42,23 @@ pub struct Configuration {
     pub max_transaction_size: u32,
     /// Maximum number of bytes in raw message. Used to prevent from DOS attacks.
     pub max_content_len: u32,
+    /// Certificate chain in PEM format to serve the client API over TLS with.
+    /// The client API is served over plain HTTP if not set.
+    #[config(serde_as_str)]
+    pub tls_cert_path: Option<PathBuf>,
+    /// Private key of `tls_cert_path` in PEM format.
+    #[config(serde_as_str)]
+    pub tls_key_path: Option<PathBuf>,
+    /// Certificates of the authorities in PEM format, one of which has to sign the certificate presented by the client.
+    /// Clients aren't asked for a certificate if not set.
+    #[config(serde_as_str)]
+    pub tls_client_ca_path: Option<PathBuf>,
+    /// Quota of transaction submissions per client IP address and per authority. Unlimited if not set.
+    pub transaction_quota: Option<Quota>,
+    /// Quota of queries per client IP address and per authority. Unlimited if not set.
+    pub query_quota: Option<Quota>,
+    /// Quota of subscriptions to events, blocks and query streams per client IP address. Unlimited if not set.
+    pub subscription_quota: Option<Quota>,
 }
 
 impl Default for ConfigurationProxy {
@@ -37,6 +68,12 @@ impl Default for ConfigurationProxy {
             api_url: None,
             max_transaction_size: Some(DEFAULT_TORII_MAX_TRANSACTION_SIZE),
             max_content_len: Some(DEFAULT_TORII_MAX_CONTENT_LENGTH),
+            tls_cert_path: Some(None),
+            tls_key_path: Some(None),
+            tls_client_ca_path: Some(None),
+            transaction_quota: Some(None),
+            query_quota: Some(None),
+            subscription_quota: Some(None),
         }
     }
 }
@@ -49,6 +86,10 @@ pub mod uri {
         iroha_primitives::addr::socket_addr!(127.0.0.1:8080);
     /// Query URI is used to handle incoming Query requests.
     pub const QUERY: &str = "query";
+    /// The web socket uri used to stream the results of a query.
+    pub const QUERY_STREAM: &str = "query/stream";
+    /// Read-only JSON query URI for clients which can't use SCALE.
+    pub const JSON_QUERY: &str = "query/json";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "transaction";
     /// Block URI is used to handle incoming Block requests.
@@ -91,9 +132,26 @@ pub mod tests {
                 api_url in prop::option::of(Just(uri::DEFAULT_API_ADDR)),
                 max_transaction_size in prop::option::of(Just(DEFAULT_TORII_MAX_TRANSACTION_SIZE)),
                 max_content_len in prop::option::of(Just(DEFAULT_TORII_MAX_CONTENT_LENGTH)),
+                tls_cert_path in prop::option::of(Just(None)),
+                tls_key_path in prop::option::of(Just(None)),
+                tls_client_ca_path in prop::option::of(Just(None)),
+                transaction_quota in prop::option::of(Just(None)),
+                query_quota in prop::option::of(Just(None)),
+                subscription_quota in prop::option::of(Just(None)),
             )
             -> ConfigurationProxy {
-            ConfigurationProxy { p2p_addr, api_url, max_transaction_size, max_content_len }
+            ConfigurationProxy {
+                p2p_addr,
+                api_url,
+                max_transaction_size,
+                max_content_len,
+                tls_cert_path,
+                tls_key_path,
+                tls_client_ca_path,
+                transaction_quota,
+                query_quota,
+                subscription_quota,
+            }
         }
     }
 }
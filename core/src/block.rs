@@ -4,7 +4,7 @@
 //! 2. If a block is received, i.e. deserialized:
 //!     `SignedBlock` -> `ValidBlock` -> `CommittedBlock`
 //! [`Block`]s are organised into a linear sequence over time (also known as the block chain).
-use std::error::Error as _;
+use std::{error::Error as _, time::Duration};
 
 use iroha_config::sumeragi::default::DEFAULT_CONSENSUS_ESTIMATION_MS;
 use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignatureOf, SignaturesOf};
@@ -86,6 +86,8 @@ pub enum SignatureVerificationError {
     ProxyTailMissing,
     /// The block doesn't have leader signature
     LeaderMissing,
+    /// The block's commit certificate doesn't correspond to block payload
+    InvalidCommitCertificate,
 }
 
 /// Builder for blocks
@@ -110,6 +112,8 @@ mod pending {
         transactions: Vec<AcceptedTransaction>,
         /// Event recommendations for use in triggers and off-chain work
         event_recommendations: Vec<Event>,
+        /// Time since the unix epoch to be stored in the block header
+        timestamp: Duration,
     }
 
     impl BlockBuilder<Pending> {
@@ -130,17 +134,26 @@ mod pending {
                 commit_topology,
                 transactions,
                 event_recommendations,
+                timestamp: iroha_data_model::current_time(),
             })
         }
 
+        /// Set the time of block creation, which is the current time by default.
+        #[must_use]
+        pub fn with_timestamp(mut self, timestamp: Duration) -> Self {
+            self.0.timestamp = timestamp;
+            self
+        }
+
         fn make_header(
+            timestamp: Duration,
             previous_height: u64,
             previous_block_hash: Option<HashOf<SignedBlock>>,
             view_change_index: u64,
             transactions: &[TransactionValue],
         ) -> BlockHeader {
             BlockHeader {
-                timestamp_ms: iroha_data_model::current_time()
+                timestamp_ms: timestamp
                     .as_millis()
                     .try_into()
                     .expect("Time should fit into u64"),
@@ -184,7 +197,7 @@ mod pending {
 
         /// Chain the block with existing blockchain.
         ///
-        /// Upon executing this method current timestamp is stored in the block header.
+        /// Upon executing this method the timestamp of the block is stored in the block header.
         pub fn chain(
             self,
             view_change_index: u64,
@@ -194,6 +207,7 @@ mod pending {
 
             BlockBuilder(Chained(BlockPayload {
                 header: Self::make_header(
+                    self.0.timestamp,
                     wsv.height(),
                     wsv.latest_block_hash(),
                     view_change_index,
@@ -223,6 +237,7 @@ mod chained {
                 SignedBlockV1 {
                     payload: self.0 .0,
                     signatures: SignaturesOf::from(signature),
+                    commit_certificate: None,
                 }
                 .into(),
             )
@@ -334,6 +349,7 @@ mod valid {
                 SignedBlockV1 {
                     payload: block.payload,
                     signatures: block.signatures,
+                    commit_certificate: block.commit_certificate,
                 }
                 .into(),
             ))
@@ -443,6 +459,16 @@ mod valid {
             self.0.add_signature(signature)
         }
 
+        /// Create a block conflicting with [`Self`] at the same height, signed by `key_pair`.
+        /// Only used to make a leader equivocate in testing.
+        #[cfg(feature = "byzantine")]
+        #[must_use]
+        pub(crate) fn equivocate(&self, key_pair: &KeyPair) -> Self {
+            let mut payload = self.payload().clone();
+            payload.header.timestamp_ms += 1;
+            BlockBuilder(Chained(payload)).sign(key_pair)
+        }
+
         #[cfg(test)]
         pub(crate) fn new_dummy() -> Self {
             BlockBuilder(Chained(BlockPayload {
@@ -470,6 +496,10 @@ mod valid {
         /// - Not enough signatures
         /// - Missing proxy tail signature
         fn verify_signatures(&self, topology: &Topology) -> Result<(), SignatureVerificationError> {
+            if let Some(certificate) = self.0.commit_certificate() {
+                return self.verify_commit_certificate(certificate, topology);
+            }
+
             // TODO: Should the peer that serves genesis have a fixed role of ProxyTail in topology?
             if !self.payload().header.is_genesis()
                 && topology.is_consensus_required().is_some()
@@ -506,6 +536,66 @@ mod valid {
         }
     }
 
+    impl ValidBlock {
+        /// Check if block's commit certificate meets the same requirements for given
+        /// topology as individual signatures in [`Self::verify_signatures`].
+        ///
+        /// The leader's signature has to be kept individually alongside the certificate.
+        ///
+        /// # Errors
+        /// - Aggregated signature is invalid
+        /// - Missing leader signature
+        /// - Not enough signers
+        /// - Missing proxy tail among the signers
+        fn verify_commit_certificate(
+            &self,
+            certificate: &BlockCommitCertificate,
+            topology: &Topology,
+        ) -> Result<(), SignatureVerificationError> {
+            if certificate.verify(self.payload()).is_err() {
+                return Err(SignatureVerificationError::InvalidCommitCertificate);
+            }
+
+            if topology
+                .filter_signatures_by_roles(&[Role::Leader], self.signatures())
+                .is_empty()
+            {
+                return Err(SignatureVerificationError::LeaderMissing);
+            }
+
+            let signers = certificate
+                .signers(&self.payload().commit_topology)
+                .map(PeerId::public_key)
+                .collect::<Vec<_>>();
+
+            if topology.is_consensus_required().is_some()
+                && topology
+                    .filter_public_keys_by_roles(&[Role::ProxyTail], signers.iter().copied())
+                    .is_empty()
+            {
+                return Err(SignatureVerificationError::ProxyTailMissing);
+            }
+
+            let roles = [
+                Role::ValidatingPeer,
+                Role::Leader,
+                Role::ProxyTail,
+                Role::ObservingPeer,
+            ];
+            let votes_count = topology
+                .filter_public_keys_by_roles(&roles, signers.iter().copied())
+                .len();
+            if votes_count < topology.min_votes_for_commit() {
+                return Err(SignatureVerificationError::NotEnoughSignatures {
+                    votes_count,
+                    min_votes_for_commit: topology.min_votes_for_commit(),
+                });
+            }
+
+            Ok(())
+        }
+    }
+
     impl From<ValidBlock> for SignedBlock {
         fn from(source: ValidBlock) -> Self {
             source.0
@@ -514,6 +604,8 @@ mod valid {
 
     #[cfg(test)]
     mod tests {
+        use iroha_crypto::{Algorithm, KeyGenConfiguration};
+
         use super::*;
         use crate::sumeragi::network_topology::test_peers;
 
@@ -562,6 +654,82 @@ mod valid {
             assert_eq!(block.verify_signatures(&topology), Ok(()));
         }
 
+        #[test]
+        fn commit_certificate_verification() {
+            let key_pairs = core::iter::repeat_with(|| {
+                KeyPair::generate_with_configuration(
+                    KeyGenConfiguration::from_random().with_algorithm(Algorithm::BlsNormal),
+                )
+                .expect("Failed to generate key pair")
+            })
+            .take(7)
+            .collect::<Vec<_>>();
+            let mut key_pairs_iter = key_pairs.iter();
+            let peers = test_peers![0, 1, 2, 3, 4, 5, 6: key_pairs_iter];
+            let topology = Topology::new(peers);
+
+            let mut block = ValidBlock::new_dummy();
+            block.0.payload_mut().commit_topology = topology.ordered_peers.clone();
+            let payload = block.payload().clone();
+            let leader_signature = SignaturesOf::new(&key_pairs[0], &payload);
+
+            // Leader, validating peers and proxy tail
+            let signatures = key_pairs[..5]
+                .iter()
+                .map(|key_pair| SignatureOf::new(key_pair, &payload))
+                .collect::<SignaturesOf<_>>();
+            let certificate = BlockCommitCertificate::aggregate(&payload, &signatures)
+                .expect("Failed to aggregate signatures");
+            block
+                .0
+                .set_commit_certificate(certificate, leader_signature.clone())
+                .expect("Certificate is valid");
+            assert_eq!(block.verify_signatures(&topology), Ok(()));
+
+            // Leader's signature isn't kept individually
+            let SignedBlock::V1(inner) = &mut block.0;
+            let retained_signatures = core::mem::replace(
+                &mut inner.signatures,
+                SignaturesOf::from(SignatureOf::new(&key_pairs[1], &payload)),
+            );
+            assert_eq!(
+                block.verify_signatures(&topology),
+                Err(SignatureVerificationError::LeaderMissing)
+            );
+            let SignedBlock::V1(inner) = &mut block.0;
+            inner.signatures = retained_signatures;
+
+            // Leader and validating peers only
+            let signatures = key_pairs[..4]
+                .iter()
+                .map(|key_pair| SignatureOf::new(key_pair, &payload))
+                .collect::<SignaturesOf<_>>();
+            let certificate = BlockCommitCertificate::aggregate(&payload, &signatures)
+                .expect("Failed to aggregate signatures");
+            let SignedBlock::V1(inner) = &mut block.0;
+            inner.commit_certificate = Some(certificate);
+            assert_eq!(
+                block.verify_signatures(&topology),
+                Err(SignatureVerificationError::ProxyTailMissing)
+            );
+
+            // Certificate of a different payload
+            let mut other_payload = payload.clone();
+            other_payload.header.height += 1;
+            let signatures = key_pairs
+                .iter()
+                .map(|key_pair| SignatureOf::new(key_pair, &other_payload))
+                .collect::<SignaturesOf<_>>();
+            let certificate = BlockCommitCertificate::aggregate(&other_payload, &signatures)
+                .expect("Failed to aggregate signatures");
+            let SignedBlock::V1(inner) = &mut block.0;
+            inner.commit_certificate = Some(certificate);
+            assert_eq!(
+                block.verify_signatures(&topology),
+                Err(SignatureVerificationError::InvalidCommitCertificate)
+            );
+        }
+
         /// Check requirement of having at least $2f + 1$ signatures in $3f + 1$ network
         #[test]
         fn signature_verification_not_enough_signatures() {
@@ -622,6 +790,7 @@ mod valid {
 
 mod commit {
     use super::*;
+    use crate::sumeragi::network_topology::Role;
 
     /// Represents a block accepted by consensus.
     /// Every [`Self`] will have a different height.
@@ -642,6 +811,62 @@ mod commit {
         pub fn signatures(&self) -> &SignaturesOf<BlockPayload> {
             self.0.signatures()
         }
+
+        /// Replace signatures of the peers which approved this block with a single
+        /// [`BlockCommitCertificate`], keeping only the leader's signature individually.
+        ///
+        /// Block is left unchanged if it is genesis, already has a certificate
+        /// or if the signatures can't be aggregated (e.g. some peers don't use BLS keys).
+        #[must_use]
+        pub(crate) fn aggregate_signatures(mut self, topology: &Topology) -> Self {
+            if self.payload().header.is_genesis()
+                || self.0.commit_certificate().is_some()
+                || !self.is_signed_with_same_bls_algorithm()
+            {
+                return self;
+            }
+
+            let certificate =
+                match BlockCommitCertificate::aggregate(self.payload(), self.signatures()) {
+                    Ok(certificate) => certificate,
+                    Err(error) => {
+                        iroha_logger::warn!(
+                            ?error,
+                            "Failed to aggregate block signatures, keeping them individually"
+                        );
+                        return self;
+                    }
+                };
+            let leader_signatures = topology
+                .filter_signatures_by_roles(&[Role::Leader], self.signatures())
+                .into_iter()
+                .collect::<SignaturesOf<_>>();
+
+            if let Err(error) = self
+                .0
+                .set_commit_certificate(certificate, leader_signatures)
+            {
+                iroha_logger::warn!(?error, "Failed to set block commit certificate");
+            }
+
+            self
+        }
+
+        /// Whether all peers of the commit topology use BLS keys of the same algorithm
+        fn is_signed_with_same_bls_algorithm(&self) -> bool {
+            let mut algorithms = self
+                .payload()
+                .commit_topology
+                .iter()
+                .map(|peer| peer.public_key().algorithm());
+
+            algorithms.next().is_some_and(|first| {
+                matches!(
+                    first,
+                    iroha_crypto::Algorithm::BlsNormal | iroha_crypto::Algorithm::BlsSmall
+                ) && algorithms.all(|algorithm| algorithm == first)
+            })
+        }
     }
 
     impl CommittedBlock {
@@ -1,30 +1,51 @@
 //! This module contains structures and messages for synchronization of blocks between peers.
-use std::{fmt::Debug, sync::Arc, time::Duration};
+//!
+//! Normally a peer asks a random online peer for the blocks following its latest block once
+//! every gossip period. Once it receives a full batch of blocks, i.e. it is far behind, it
+//! switches to downloading consecutive ranges of blocks from several peers in parallel
+//! until it catches up.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use iroha_config::block_sync::Configuration;
 use iroha_crypto::HashOf;
 use iroha_data_model::{block::SignedBlock, prelude::*};
 use iroha_logger::prelude::*;
 use iroha_macro::*;
-use iroha_p2p::Post;
+use iroha_p2p::{reputation::Misbehaviour, Post};
+use iroha_primitives::unique_vec::UniqueVec;
 use parity_scale_codec::{Decode, Encode};
 use tokio::sync::mpsc;
 
-use crate::{kura::Kura, sumeragi::SumeragiHandle, IrohaNetwork, NetworkMessage};
+use crate::{
+    kura::Kura,
+    sumeragi::{message::BlockMessage, network_topology::Topology, SumeragiHandle},
+    IrohaNetwork, NetworkMessage,
+};
+
+/// Duration during which a peer which sent invalid or stale blocks isn't asked for blocks.
+const PENALTY_DURATION: Duration = Duration::from_secs(60);
+
+/// Bytes of a frame reserved for the encoding of [`message::ShareBlocks`] around the blocks and for the encryption.
+const FRAME_RESERVE: usize = 1024;
 
 /// [`BlockSynchronizer`] actor handle.
 #[derive(Clone)]
 pub struct BlockSynchronizerHandle {
-    message_sender: mpsc::Sender<message::Message>,
+    message_sender: mpsc::Sender<(message::Message, PeerId)>,
 }
 
 impl BlockSynchronizerHandle {
-    /// Send [`message::Message`] to [`BlockSynchronizer`] actor.
+    /// Send [`message::Message`] received from the authenticated `peer_id` to [`BlockSynchronizer`] actor.
     ///
     /// # Errors
     /// Fail if [`BlockSynchronizer`] actor is shutdown.
-    pub async fn message(&self, message: message::Message) {
-        self.message_sender.send(message).await.expect(
+    pub async fn message(&self, message: message::Message, peer_id: PeerId) {
+        self.message_sender.send((message, peer_id)).await.expect(
             "BlockSynchronizer must handle messages until there is at least one handle to it",
         )
     }
@@ -34,12 +55,20 @@ impl BlockSynchronizerHandle {
 pub struct BlockSynchronizer {
     sumeragi: SumeragiHandle,
     kura: Arc<Kura>,
-    peer_id: PeerId,
     gossip_period: Duration,
     block_batch_size: u32,
     network: IrohaNetwork,
     latest_hash: Option<HashOf<SignedBlock>>,
     previous_hash: Option<HashOf<SignedBlock>>,
+    parallel_download_peers: u32,
+    /// Present while catching up by downloading blocks from several peers in parallel
+    download: Option<ParallelDownload>,
+    /// Peers which sent invalid or stale blocks and the instants until which they are ignored
+    penalised_peers: BTreeMap<PeerId, Instant>,
+    /// Numbers of blocks received in the parts of the responses whose last part wasn't received yet
+    partially_received: BTreeMap<PeerId, usize>,
+    #[cfg(feature = "byzantine")]
+    lie_in_share_blocks: bool,
 }
 
 impl BlockSynchronizer {
@@ -51,7 +80,7 @@ impl BlockSynchronizer {
     }
 
     /// [`Self`] task.
-    async fn run(mut self, mut message_receiver: mpsc::Receiver<message::Message>) {
+    async fn run(mut self, mut message_receiver: mpsc::Receiver<(message::Message, PeerId)>) {
         let mut gossip_period = tokio::time::interval(self.gossip_period);
         loop {
             tokio::select! {
@@ -64,32 +93,157 @@ impl BlockSynchronizer {
                     self.previous_hash = previous_hash;
                 }
                 msg = message_receiver.recv() => {
-                    let Some(msg) = msg else {
+                    let Some((msg, peer_id)) = msg else {
                         info!("All handler to BlockSynchronizer are dropped. Shutting down...");
                         break;
                     };
-                    msg.handle_message(&mut self).await;
+                    msg.handle_message(&mut self, peer_id).await;
                 }
             }
             tokio::task::yield_now().await;
         }
     }
 
-    /// Sends request for latest blocks to a random peer
+    /// Sends request for latest blocks to a random peer,
+    /// or continues the parallel download if there is one.
     async fn request_block(&mut self) {
-        if let Some(random_peer) = self.network.online_peers(Self::random_peer) {
-            self.request_latest_blocks_from_peer(random_peer.id().clone())
-                .await;
+        if self.download.is_some() {
+            if let Some(download) = self.download.as_mut() {
+                download.expire_requests(Instant::now(), self.gossip_period);
+            }
+            self.request_block_ranges().await;
+
+            if self
+                .download
+                .as_ref()
+                .is_some_and(ParallelDownload::is_finished)
+            {
+                info!("Parallel block download finished, falling back to sequential download");
+                self.download = None;
+            }
+            return;
+        }
+
+        if let Some(random_peer) = self.random_peers(1).pop() {
+            self.request_latest_blocks_from_peer(random_peer).await;
         }
     }
 
-    /// Get a random online peer.
-    #[allow(clippy::disallowed_types)]
-    pub fn random_peer(peers: &std::collections::HashSet<PeerId>) -> Option<Peer> {
+    /// Get up to `amount` random online peers which aren't penalised.
+    fn random_peers(&mut self, amount: usize) -> Vec<PeerId> {
         use rand::{seq::IteratorRandom, SeedableRng};
 
+        let now = Instant::now();
+        self.penalised_peers.retain(|_, until| *until > now);
+
         let rng = &mut rand::rngs::StdRng::from_entropy();
-        peers.iter().choose(rng).map(|id| Peer::new(id.clone()))
+        let penalised_peers = &self.penalised_peers;
+        self.network.online_peers(|peers| {
+            peers
+                .iter()
+                .filter(|peer_id| !penalised_peers.contains_key(*peer_id))
+                .cloned()
+                .choose_multiple(rng, amount)
+        })
+    }
+
+    /// Ignore `peer_id` for [`PENALTY_DURATION`].
+    fn penalise(&mut self, peer_id: PeerId, reason: DownloadError) {
+        warn!(peer=%peer_id, %reason, "Penalising peer for sending bad blocks");
+        let misbehaviour = match reason {
+            DownloadError::InvalidSignatures | DownloadError::NotCommitted => {
+                Misbehaviour::InvalidSignature
+            }
+            _ => Misbehaviour::InvalidBlock,
+        };
+        self.network
+            .report_misbehaviour(peer_id.public_key().clone(), misbehaviour);
+        self.penalised_peers
+            .insert(peer_id, Instant::now() + PENALTY_DURATION);
+    }
+
+    /// Start downloading blocks from several peers in parallel after the given `block`.
+    async fn start_download(&mut self, block: &SignedBlock) {
+        if self.parallel_download_peers <= 1 || self.download.is_some() {
+            return;
+        }
+
+        info!(
+            height = block.payload().header.height,
+            "Far behind other peers, downloading blocks in parallel"
+        );
+        self.download = Some(ParallelDownload::new(
+            block.payload().header.height,
+            block.hash(),
+            self.block_batch_size.into(),
+            block.payload().commit_topology.clone(),
+        ));
+        self.request_block_ranges().await;
+    }
+
+    /// Request the next ranges of blocks from the peers without a pending request.
+    async fn request_block_ranges(&mut self) {
+        let peers = self.random_peers(self.parallel_download_peers as usize);
+        let Some(download) = self.download.as_mut() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let requests = peers
+            .into_iter()
+            .filter_map(|peer_id| {
+                download
+                    .assign(peer_id.clone(), now)
+                    .map(|start_height| (peer_id, start_height))
+            })
+            .collect::<Vec<_>>();
+
+        for (peer_id, start_height) in requests {
+            message::Message::GetBlocksInRange(message::GetBlocksInRange::new(
+                start_height,
+                self.block_batch_size,
+            ))
+            .send_to(&self.network, peer_id)
+            .await;
+        }
+    }
+
+    /// Handle blocks received from `peer_id` as a part of the parallel download.
+    async fn receive_block_range(
+        &mut self,
+        peer_id: PeerId,
+        blocks: Vec<SignedBlock>,
+        is_partial: bool,
+    ) {
+        let Some(download) = self.download.as_mut() else {
+            return;
+        };
+
+        if let Err(error) = download.receive(&peer_id, blocks, is_partial) {
+            self.penalise(peer_id, error);
+        }
+        let Some(download) = self.download.as_mut() else {
+            return;
+        };
+        let (ready, misbehaved) = download.take_ready();
+        if let Some((peer_id, error)) = misbehaved {
+            self.penalise(peer_id, error);
+        }
+
+        for block in ready {
+            self.sumeragi
+                .incoming_block_message(BlockMessage::BlockSyncUpdate(block.into()));
+        }
+
+        self.request_block_ranges().await;
+        if self
+            .download
+            .as_ref()
+            .is_some_and(ParallelDownload::is_finished)
+        {
+            info!("Parallel block download finished");
+            self.download = None;
+        }
     }
 
     /// Sends request for latest blocks to a chosen peer
@@ -97,24 +251,45 @@ impl BlockSynchronizer {
         message::Message::GetBlocksAfter(message::GetBlocksAfter::new(
             self.latest_hash,
             self.previous_hash,
-            self.peer_id.clone(),
         ))
         .send_to(&self.network, peer_id)
         .await;
     }
 
+    /// Send `blocks` to `peer_id`, split into parts which fit into the maximum frame size.
+    #[cfg_attr(not(feature = "byzantine"), allow(unused_mut))]
+    async fn share_blocks(&self, mut blocks: Vec<SignedBlock>, peer_id: PeerId) {
+        #[cfg(feature = "byzantine")]
+        if self.lie_in_share_blocks {
+            // NOTE: Tampering with the payload invalidates the signatures of the block
+            for block in &mut blocks {
+                block.payload_mut().header.timestamp_ms += 1;
+            }
+        }
+
+        let max_size = (self.network.max_frame_size() as usize).saturating_sub(FRAME_RESERVE);
+        let parts = split_by_encoded_size(blocks, max_size);
+        let last_part = parts.len() - 1;
+        for (i, blocks) in parts.into_iter().enumerate() {
+            message::Message::ShareBlocks(message::ShareBlocks {
+                blocks,
+                is_partial: i != last_part,
+            })
+            .send_to(&self.network, peer_id.clone())
+            .await;
+        }
+    }
+
     /// Create [`Self`] from [`Configuration`]
     pub fn from_configuration(
         config: &Configuration,
         sumeragi: SumeragiHandle,
         kura: Arc<Kura>,
-        peer_id: PeerId,
         network: IrohaNetwork,
     ) -> Self {
         let (latest_hash, previous_hash) =
             sumeragi.apply_wsv(|wsv| (wsv.latest_block_hash(), wsv.previous_block_hash()));
         Self {
-            peer_id,
             sumeragi,
             kura,
             gossip_period: Duration::from_millis(config.gossip_period_ms),
@@ -122,8 +297,334 @@ impl BlockSynchronizer {
             network,
             latest_hash,
             previous_hash,
+            parallel_download_peers: config.parallel_download_peers,
+            download: None,
+            penalised_peers: BTreeMap::new(),
+            partially_received: BTreeMap::new(),
+            #[cfg(feature = "byzantine")]
+            lie_in_share_blocks: false,
         }
     }
+
+    /// Make [`Self`] misbehave as configured in `faults`. Only used in testing.
+    #[cfg(feature = "byzantine")]
+    #[must_use]
+    pub fn with_byzantine_faults(
+        mut self,
+        faults: iroha_config::sumeragi::ByzantineFaults,
+    ) -> Self {
+        self.lie_in_share_blocks = faults.lie_in_share_blocks;
+        self
+    }
+}
+
+/// Reason to penalise a peer during the parallel download.
+#[derive(Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+enum DownloadError {
+    /// Block at height {actual} was sent instead of the block at height {expected}
+    UnexpectedHeight { expected: u64, actual: u64 },
+    /// More blocks were sent than requested
+    TooManyBlocks,
+    /// Block doesn't follow the preceding block
+    NotChained,
+    /// Block signatures are invalid
+    InvalidSignatures,
+    /// Block isn't signed by enough peers of the topology at its height
+    NotCommitted,
+}
+
+/// Download of consecutive ranges of blocks from several peers in parallel.
+///
+/// Every peer is asked for one range at a time. Blocks are passed on to sumeragi
+/// strictly in the order of their heights.
+///
+/// The topology following a block is only known after executing it, so blocks are only
+/// downloaded in parallel while they are committed by the same topology. Once the topology
+/// changes the download is interrupted and sequential download takes over.
+#[derive(Debug)]
+struct ParallelDownload {
+    /// Number of blocks requested from a peer at once
+    batch_size: u64,
+    /// Height of the latest block passed on to sumeragi
+    latest_height: u64,
+    /// Hash of the latest block passed on to sumeragi
+    latest_hash: HashOf<SignedBlock>,
+    /// Topology which has to commit the downloaded blocks
+    topology: Topology,
+    /// Height of the first block which wasn't requested yet
+    next_height: u64,
+    /// Heights of the first blocks the peers don't have, known once a peer sends fewer blocks than requested
+    end_heights: BTreeMap<PeerId, u64>,
+    /// Set once the following blocks can't be verified without executing the preceding ones
+    is_interrupted: bool,
+    /// Start heights of the ranges which have to be requested again
+    retry: BTreeSet<u64>,
+    /// Start heights of the pending requests, heights of the first blocks not received yet
+    /// and instants the requests were sent at by the requested peer
+    requested: BTreeMap<PeerId, (u64, u64, Instant)>,
+    /// Received blocks waiting for the preceding blocks by height, with the peers which sent them
+    received: BTreeMap<u64, (PeerId, SignedBlock)>,
+}
+
+impl ParallelDownload {
+    /// Maximal number of ranges requested ahead of the latest block passed on to sumeragi
+    const MAX_RANGES_AHEAD: u64 = 16;
+
+    fn new(
+        latest_height: u64,
+        latest_hash: HashOf<SignedBlock>,
+        batch_size: u64,
+        commit_topology: UniqueVec<PeerId>,
+    ) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            latest_height,
+            latest_hash,
+            topology: Topology::new(commit_topology),
+            next_height: latest_height + 1,
+            end_heights: BTreeMap::new(),
+            is_interrupted: false,
+            retry: BTreeSet::new(),
+            requested: BTreeMap::new(),
+            received: BTreeMap::new(),
+        }
+    }
+
+    fn is_requested_from(&self, peer_id: &PeerId) -> bool {
+        self.requested.contains_key(peer_id)
+    }
+
+    /// Download is finished once no peer is asked for blocks after assigning ranges,
+    /// i.e. none of the peers has more blocks to share, or once it was interrupted.
+    fn is_finished(&self) -> bool {
+        self.is_interrupted || self.requested.is_empty()
+    }
+
+    /// Assign the next range to `peer_id` unless it has a pending request.
+    /// Returns the height of the first block of the range.
+    fn assign(&mut self, peer_id: PeerId, now: Instant) -> Option<u64> {
+        if self.is_interrupted || self.requested.contains_key(&peer_id) {
+            return None;
+        }
+
+        let first_missing_height = self.latest_height + 1;
+        let batch_size = self.batch_size;
+        self.retry
+            .retain(|start_height| start_height + batch_size > first_missing_height);
+
+        // NOTE: Peer isn't asked for blocks it doesn't have
+        let end_height = self.end_heights.get(&peer_id).copied().unwrap_or(u64::MAX);
+        let start_height = if let Some(&start_height) = self.retry.range(..end_height).next() {
+            self.retry.remove(&start_height);
+            start_height
+        } else {
+            let start_height = self.next_height;
+            if start_height >= end_height
+                || start_height > self.latest_height + self.batch_size * Self::MAX_RANGES_AHEAD
+            {
+                return None;
+            }
+            self.next_height += self.batch_size;
+            start_height
+        };
+
+        self.requested
+            .insert(peer_id, (start_height, start_height, now));
+        Some(start_height)
+    }
+
+    /// Release the requests sent at least `timeout` before `now`, so that their
+    /// ranges are requested from other peers.
+    fn expire_requests(&mut self, now: Instant, timeout: Duration) {
+        let retry = &mut self.retry;
+        self.requested.retain(|_, (start_height, _, requested_at)| {
+            let is_expired = now.duration_since(*requested_at) >= timeout;
+            if is_expired {
+                retry.insert(*start_height);
+            }
+            !is_expired
+        });
+    }
+
+    /// Verify `blocks` received from `peer_id` in response to its pending request.
+    /// The request stays pending if the blocks are a part of the response followed by other parts.
+    ///
+    /// # Errors
+    /// If the blocks aren't the requested ones
+    fn receive(
+        &mut self,
+        peer_id: &PeerId,
+        blocks: Vec<SignedBlock>,
+        is_partial: bool,
+    ) -> Result<(), DownloadError> {
+        let Some((start_height, next_height, requested_at)) = self.requested.remove(peer_id) else {
+            return Ok(());
+        };
+
+        let range_end = start_height + self.batch_size;
+        if let Err(error) = Self::verify_range(next_height, range_end - next_height, &blocks) {
+            self.retry.insert(start_height);
+            return Err(error);
+        }
+
+        let end_height = next_height + blocks.len() as u64;
+        if is_partial {
+            self.requested
+                .insert(peer_id.clone(), (start_height, end_height, requested_at));
+        } else if end_height < range_end {
+            // NOTE: This peer has no more blocks, but others may have,
+            // so the rest of the range is requested from them
+            self.end_heights.insert(peer_id.clone(), end_height);
+            self.retry.insert(end_height);
+        }
+
+        for block in blocks {
+            self.received
+                .insert(block.payload().header.height, (peer_id.clone(), block));
+        }
+
+        Ok(())
+    }
+
+    fn verify_range(
+        start_height: u64,
+        batch_size: u64,
+        blocks: &[SignedBlock],
+    ) -> Result<(), DownloadError> {
+        if blocks.len() as u64 > batch_size {
+            return Err(DownloadError::TooManyBlocks);
+        }
+
+        let mut previous_hash = None;
+        for (expected, block) in (start_height..).zip(blocks) {
+            let actual = block.payload().header.height;
+            if actual != expected {
+                return Err(DownloadError::UnexpectedHeight { expected, actual });
+            }
+            if previous_hash.is_some()
+                && block.payload().header.previous_block_hash != previous_hash
+            {
+                return Err(DownloadError::NotChained);
+            }
+            if !has_valid_signatures(block) {
+                return Err(DownloadError::InvalidSignatures);
+            }
+            previous_hash = Some(block.hash());
+        }
+
+        Ok(())
+    }
+
+    /// Take the received blocks which follow the latest block passed on to sumeragi.
+    ///
+    /// Also returns the peer which sent a block not committed by the topology, if any.
+    /// Blocks of this peer are dropped and their range is requested again.
+    ///
+    /// The download is interrupted if a block is committed by another topology or doesn't
+    /// follow the latest block, because neither can be told apart from a topology change or
+    /// a soft fork without executing the blocks.
+    fn take_ready(&mut self) -> (Vec<SignedBlock>, Option<(PeerId, DownloadError)>) {
+        let mut ready = Vec::new();
+
+        while let Some(entry) = self.received.first_entry() {
+            let height = *entry.key();
+            if height <= self.latest_height {
+                // NOTE: Range was requested again after its request expired
+                entry.remove();
+                continue;
+            }
+            if height != self.latest_height + 1 {
+                break;
+            }
+
+            let (peer_id, block) = entry.remove();
+            let is_same_topology = block
+                .payload()
+                .commit_topology
+                .iter()
+                .collect::<BTreeSet<_>>()
+                == self.topology.ordered_peers.iter().collect::<BTreeSet<_>>();
+            if !is_same_topology {
+                self.is_interrupted = true;
+                break;
+            }
+            if !is_committed_by(&block, &self.topology) {
+                let range_end = height + self.batch_size;
+                self.received
+                    .retain(|&height, (sender, _)| height >= range_end || *sender != peer_id);
+                self.retry.insert(height);
+                return (ready, Some((peer_id, DownloadError::NotCommitted)));
+            }
+            if block.payload().header.previous_block_hash != Some(self.latest_hash) {
+                self.is_interrupted = true;
+                break;
+            }
+
+            self.latest_height = height;
+            self.latest_hash = block.hash();
+            ready.push(block);
+        }
+
+        (ready, None)
+    }
+}
+
+/// Split `blocks` into parts of at most `max_size` encoded bytes, keeping their order.
+/// Blocks bigger than `max_size` are put into parts of their own.
+/// There is always at least one part, so that the receiving peer gets a response.
+fn split_by_encoded_size(blocks: Vec<SignedBlock>, max_size: usize) -> Vec<Vec<SignedBlock>> {
+    let mut parts = vec![Vec::new()];
+    let mut part_size = 0;
+
+    for block in blocks {
+        let size = block.encoded_size();
+        if part_size + size > max_size && parts.last().is_some_and(|part| !part.is_empty()) {
+            parts.push(Vec::new());
+            part_size = 0;
+        }
+        part_size += size;
+        parts
+            .last_mut()
+            .expect("There is always a part")
+            .push(block);
+    }
+
+    parts
+}
+
+/// Check that enough peers of the `topology` signed the block. Signatures are assumed to be valid.
+fn is_committed_by(block: &SignedBlock, topology: &Topology) -> bool {
+    let payload = block.payload();
+    let mut signers = block
+        .signatures()
+        .into_iter()
+        .map(|signature| signature.public_key())
+        .collect::<BTreeSet<_>>();
+    if let Some(certificate) = block.commit_certificate() {
+        signers.extend(
+            certificate
+                .signers(&payload.commit_topology)
+                .map(PeerId::public_key),
+        );
+    }
+
+    topology
+        .ordered_peers
+        .iter()
+        .filter(|peer_id| signers.contains(peer_id.public_key()))
+        .count()
+        >= topology.min_votes_for_commit()
+}
+
+/// Check that the block is signed and that all of its signatures are valid.
+fn has_valid_signatures(block: &SignedBlock) -> bool {
+    let payload = block.payload();
+
+    block.signatures().len() > 0
+        && block.signatures().verify(payload).is_ok()
+        && block
+            .commit_certificate()
+            .map_or(true, |certificate| certificate.verify(payload).is_ok())
 }
 
 pub mod message {
@@ -131,14 +632,12 @@ pub mod message {
     use super::*;
 
     /// Get blocks after some block
-    #[derive(Debug, Clone, Decode, Encode)]
+    #[derive(Debug, Clone, Copy, Decode, Encode)]
     pub struct GetBlocksAfter {
         /// Hash of latest available block
         pub latest_hash: Option<HashOf<SignedBlock>>,
         /// Hash of second to latest block
         pub previous_hash: Option<HashOf<SignedBlock>>,
-        /// Peer id
-        pub peer_id: PeerId,
     }
 
     impl GetBlocksAfter {
@@ -146,12 +645,29 @@ pub mod message {
         pub const fn new(
             latest_hash: Option<HashOf<SignedBlock>>,
             previous_hash: Option<HashOf<SignedBlock>>,
-            peer_id: PeerId,
         ) -> Self {
             Self {
                 latest_hash,
                 previous_hash,
-                peer_id,
+            }
+        }
+    }
+
+    /// Get blocks at the heights starting from `start_height`
+    #[derive(Debug, Clone, Copy, Decode, Encode)]
+    pub struct GetBlocksInRange {
+        /// Height of the first requested block
+        pub start_height: u64,
+        /// Number of requested blocks
+        pub count: u32,
+    }
+
+    impl GetBlocksInRange {
+        /// Construct [`GetBlocksInRange`].
+        pub const fn new(start_height: u64, count: u32) -> Self {
+            Self {
+                start_height,
+                count,
             }
         }
     }
@@ -161,35 +677,40 @@ pub mod message {
     pub struct ShareBlocks {
         /// Blocks
         pub blocks: Vec<SignedBlock>,
-        /// Peer id
-        pub peer_id: PeerId,
+        /// Set on all but the last part of the blocks split to fit into the maximum frame size
+        pub is_partial: bool,
     }
 
     impl ShareBlocks {
-        /// Construct [`ShareBlocks`].
-        pub const fn new(blocks: Vec<SignedBlock>, peer_id: PeerId) -> Self {
-            Self { blocks, peer_id }
+        /// Construct [`ShareBlocks`] with all of the shared blocks.
+        pub const fn new(blocks: Vec<SignedBlock>) -> Self {
+            Self {
+                blocks,
+                is_partial: false,
+            }
         }
     }
 
     /// Message's variants that are used by peers to communicate in the process of consensus.
     #[derive(Debug, Clone, Decode, Encode, FromVariant)]
     pub enum Message {
-        /// Request for blocks after the block with `Hash` for the peer with `PeerId`.
+        /// Request for blocks after the block with `Hash`.
         GetBlocksAfter(GetBlocksAfter),
-        /// The response to `GetBlocksAfter`. Contains the requested blocks and the id of the peer who shared them.
+        /// The response to `GetBlocksAfter` and `GetBlocksInRange`. Contains the requested blocks.
         ShareBlocks(ShareBlocks),
+        /// Request for the blocks at the given heights.
+        /// Used to download blocks from several peers in parallel.
+        GetBlocksInRange(GetBlocksInRange),
     }
 
     impl Message {
-        /// Handles the incoming message.
+        /// Handles the incoming message received from the authenticated `peer_id`.
         #[iroha_futures::telemetry_future]
-        pub async fn handle_message(&self, block_sync: &mut BlockSynchronizer) {
+        pub async fn handle_message(&self, block_sync: &mut BlockSynchronizer, peer_id: PeerId) {
             match self {
                 Message::GetBlocksAfter(GetBlocksAfter {
                     latest_hash,
                     previous_hash,
-                    peer_id,
                 }) => {
                     if block_sync.block_batch_size == 0 {
                         warn!("Error: not sending any blocks as batch_size is equal to zero.");
@@ -227,18 +748,65 @@ pub mod message {
                         error!(hash=?previous_hash, "Blocks array is empty but shouldn't be.");
                     } else {
                         trace!(hash=?previous_hash, "Sharing blocks after hash");
-                        Message::ShareBlocks(ShareBlocks::new(blocks, block_sync.peer_id.clone()))
-                            .send_to(&block_sync.network, peer_id.clone())
-                            .await;
+                        block_sync.share_blocks(blocks, peer_id).await;
                     }
                 }
-                Message::ShareBlocks(ShareBlocks { blocks, .. }) => {
-                    use crate::sumeragi::message::BlockMessage;
+                Message::GetBlocksInRange(GetBlocksInRange {
+                    start_height,
+                    count,
+                }) => {
+                    let count = (*count).min(block_sync.block_batch_size);
+                    if count == 0 {
+                        warn!("Error: not sending any blocks as requested count is equal to zero.");
+                        return;
+                    }
+
+                    // NOTE: Blocks are sent even if there are none, so that the
+                    // requesting peer learns that it has caught up
+                    let blocks = (*start_height..)
+                        .take(count as usize)
+                        .map_while(|height| block_sync.kura.get_block_by_height(height))
+                        .map(|block| (*block).clone())
+                        .collect::<Vec<_>>();
+
+                    trace!(
+                        start_height,
+                        count = blocks.len(),
+                        "Sharing blocks in range"
+                    );
+                    block_sync.share_blocks(blocks, peer_id).await;
+                }
+                Message::ShareBlocks(ShareBlocks { blocks, is_partial }) => {
+                    if block_sync
+                        .download
+                        .as_ref()
+                        .is_some_and(|download| download.is_requested_from(&peer_id))
+                    {
+                        block_sync
+                            .receive_block_range(peer_id, blocks.clone(), *is_partial)
+                            .await;
+                        return;
+                    }
+
                     for block in blocks.clone() {
                         block_sync
                             .sumeragi
                             .incoming_block_message(BlockMessage::BlockSyncUpdate(block.into()));
                     }
+
+                    let received =
+                        block_sync.partially_received.remove(&peer_id).unwrap_or(0) + blocks.len();
+                    if *is_partial {
+                        block_sync.partially_received.insert(peer_id, received);
+                        return;
+                    }
+
+                    // NOTE: A full batch means that the sending peer has more blocks
+                    if received >= block_sync.block_batch_size as usize {
+                        if let Some(block) = blocks.last() {
+                            block_sync.start_download(block).await;
+                        }
+                    }
                 }
             }
         }
@@ -256,3 +824,276 @@ pub mod message {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::{KeyPair, SignaturesOf};
+    use iroha_data_model::block::{BlockHeader, BlockPayload, SignedBlockV1};
+    use iroha_primitives::unique_vec::UniqueVec;
+
+    use super::*;
+    use crate::sumeragi::network_topology::test_peers;
+
+    fn block(
+        height: u64,
+        previous_block_hash: Option<HashOf<SignedBlock>>,
+        signer: &KeyPair,
+        commit_topology: &UniqueVec<PeerId>,
+    ) -> SignedBlock {
+        let payload = BlockPayload {
+            header: BlockHeader {
+                height,
+                timestamp_ms: 0,
+                previous_block_hash,
+                transactions_hash: None,
+                view_change_index: 0,
+                consensus_estimation_ms: 0,
+            },
+            transactions: Vec::new(),
+            commit_topology: commit_topology.clone(),
+            event_recommendations: Vec::new(),
+        };
+
+        SignedBlockV1 {
+            signatures: SignaturesOf::new(signer, &payload),
+            payload,
+            commit_certificate: None,
+        }
+        .into()
+    }
+
+    fn chain(
+        length: u64,
+        signer: &KeyPair,
+        commit_topology: &UniqueVec<PeerId>,
+    ) -> Vec<SignedBlock> {
+        let mut blocks = Vec::<SignedBlock>::new();
+        for height in 1..=length {
+            let previous_block_hash = blocks.last().map(SignedBlock::hash);
+            blocks.push(block(height, previous_block_hash, signer, commit_topology));
+        }
+        blocks
+    }
+
+    /// Key pair of the only peer of the topology which commits the blocks, and the topology
+    fn committing_peer() -> (KeyPair, UniqueVec<PeerId>) {
+        let key_pair = KeyPair::generate().expect("Valid");
+        let commit_topology = test_peers![0: core::iter::once(&key_pair)];
+        (key_pair, commit_topology)
+    }
+
+    fn heights(blocks: &[SignedBlock]) -> Vec<u64> {
+        blocks
+            .iter()
+            .map(|block| block.payload().header.height)
+            .collect()
+    }
+
+    #[test]
+    fn blocks_are_passed_on_in_order() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(9, &signer, &commit_topology);
+        let peers = test_peers![1, 2].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology);
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        assert_eq!(download.assign(peers[1].clone(), now), Some(6));
+        assert_eq!(download.assign(peers[1].clone(), now), None);
+
+        download
+            .receive(&peers[1], blocks[5..9].to_vec(), false)
+            .expect("Valid blocks");
+        assert!(download.take_ready().0.is_empty());
+
+        download
+            .receive(&peers[0], blocks[1..5].to_vec(), false)
+            .expect("Valid blocks");
+        let (ready, misbehaved) = download.take_ready();
+        assert_eq!(heights(&ready), (2..=9).collect::<Vec<_>>());
+        assert!(misbehaved.is_none());
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(10));
+        assert!(!download.is_finished());
+        download
+            .receive(&peers[0], Vec::new(), false)
+            .expect("Valid blocks");
+        assert!(download.is_finished());
+
+        // NOTE: Only this peer has no more blocks
+        assert_eq!(download.assign(peers[0].clone(), now), None);
+        assert_eq!(download.assign(peers[1].clone(), now), Some(10));
+        assert!(!download.is_finished());
+    }
+
+    #[test]
+    fn short_response_is_continued_by_another_peer() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(5, &signer, &commit_topology);
+        let peers = test_peers![1, 2].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology);
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        download
+            .receive(&peers[0], blocks[1..3].to_vec(), false)
+            .expect("Valid blocks");
+        assert_eq!(heights(&download.take_ready().0), vec![2, 3]);
+
+        assert_eq!(download.assign(peers[0].clone(), now), None);
+        assert_eq!(download.assign(peers[1].clone(), now), Some(4));
+        download
+            .receive(&peers[1], blocks[3..5].to_vec(), false)
+            .expect("Valid blocks");
+        assert_eq!(heights(&download.take_ready().0), vec![4, 5]);
+    }
+
+    #[test]
+    fn response_split_into_parts_is_received() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(9, &signer, &commit_topology);
+        let peers = test_peers![1].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology);
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        download
+            .receive(&peers[0], blocks[1..3].to_vec(), true)
+            .expect("Valid blocks");
+        assert_eq!(heights(&download.take_ready().0), vec![2, 3]);
+        // NOTE: Request is pending until the last part is received
+        assert_eq!(download.assign(peers[0].clone(), now), None);
+
+        download
+            .receive(&peers[0], blocks[3..5].to_vec(), false)
+            .expect("Valid blocks");
+        assert_eq!(heights(&download.take_ready().0), vec![4, 5]);
+        assert_eq!(download.assign(peers[0].clone(), now), Some(6));
+    }
+
+    #[test]
+    fn blocks_are_split_by_encoded_size() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(5, &signer, &commit_topology);
+        // NOTE: Genesis is smaller than the following blocks referring to the previous ones
+        let block_size = blocks[1].encoded_size();
+
+        let parts = split_by_encoded_size(blocks.clone(), 2 * block_size);
+        assert_eq!(
+            parts
+                .iter()
+                .map(Vec::as_slice)
+                .map(heights)
+                .collect::<Vec<_>>(),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+
+        // NOTE: Blocks bigger than the maximum size are sent on their own
+        let parts = split_by_encoded_size(blocks, block_size / 2);
+        assert_eq!(parts.len(), 5);
+
+        let parts = split_by_encoded_size(Vec::new(), block_size);
+        assert!(matches!(parts.as_slice(), [part] if part.is_empty()));
+    }
+
+    #[test]
+    fn block_not_committed_by_topology_is_requested_again() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(5, &signer, &commit_topology);
+        let peers = test_peers![1, 2].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology.clone());
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        assert_eq!(download.assign(peers[1].clone(), now), Some(6));
+        // NOTE: Signatures are valid, but made by a peer outside of the topology
+        let outsider = KeyPair::generate().expect("Valid");
+        let forged = chain(5, &outsider, &commit_topology);
+        download
+            .receive(&peers[0], forged[1..5].to_vec(), false)
+            .expect("Valid signatures");
+        download
+            .receive(&peers[1], Vec::new(), false)
+            .expect("Valid blocks");
+
+        let (ready, misbehaved) = download.take_ready();
+        assert!(ready.is_empty());
+        assert!(matches!(
+            misbehaved,
+            Some((peer_id, DownloadError::NotCommitted)) if peer_id == peers[0]
+        ));
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        download
+            .receive(&peers[0], blocks[1..5].to_vec(), false)
+            .expect("Valid blocks");
+        let (ready, misbehaved) = download.take_ready();
+        assert_eq!(heights(&ready), vec![2, 3, 4, 5]);
+        assert!(misbehaved.is_none());
+    }
+
+    #[test]
+    fn changed_topology_interrupts_download() {
+        let (_, commit_topology) = committing_peer();
+        let (other_signer, other_commit_topology) = committing_peer();
+        let blocks = chain(5, &other_signer, &other_commit_topology);
+        let peers = test_peers![1].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology);
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        download
+            .receive(&peers[0], blocks[1..5].to_vec(), false)
+            .expect("Valid blocks");
+
+        let (ready, misbehaved) = download.take_ready();
+        assert!(ready.is_empty());
+        assert!(misbehaved.is_none());
+        assert!(download.is_finished());
+        assert_eq!(download.assign(peers[0].clone(), now), None);
+    }
+
+    #[test]
+    fn invalid_range_is_requested_again() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(5, &signer, &commit_topology);
+        let peers = test_peers![1, 2].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology);
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        let stale = blocks[..4].to_vec();
+        assert!(matches!(
+            download.receive(&peers[0], stale, false),
+            Err(DownloadError::UnexpectedHeight {
+                expected: 2,
+                actual: 1
+            })
+        ));
+
+        assert_eq!(download.assign(peers[1].clone(), now), Some(2));
+        let mut tampered = blocks[1..5].to_vec();
+        tampered[2].payload_mut().header.timestamp_ms += 1;
+        assert!(matches!(
+            download.receive(&peers[1], tampered, false),
+            Err(DownloadError::InvalidSignatures)
+        ));
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+    }
+
+    #[test]
+    fn expired_request_is_requested_from_another_peer() {
+        let (signer, commit_topology) = committing_peer();
+        let blocks = chain(1, &signer, &commit_topology);
+        let peers = test_peers![1, 2].into_iter().collect::<Vec<_>>();
+        let now = Instant::now();
+        let mut download = ParallelDownload::new(1, blocks[0].hash(), 4, commit_topology);
+
+        assert_eq!(download.assign(peers[0].clone(), now), Some(2));
+        download.expire_requests(now + Duration::from_secs(10), Duration::from_secs(5));
+
+        assert!(!download.is_requested_from(&peers[0]));
+        assert_eq!(download.assign(peers[1].clone(), now), Some(2));
+    }
+}
@@ -1,10 +1,30 @@
 //! Gossiper is actor which is responsible for transaction gossiping
+//!
+//! Peers periodically announce the hashes of the transactions in their queues and only request
+//! the bodies of the transactions missing from their own queues. Hashes known to a peer,
+//! either announced by it, requested by it or sent to it, aren't announced to it again.
+//! Announcing a hash doesn't make it known to the peer, so that it's announced again
+//! in case the request of the peer or the reply to it gets lost.
+//!
+//! Along with the hashes peers announce the numbers of signatures of the transactions, so that
+//! signatures of multisignature transactions merged in the queue of a peer are propagated:
+//! transactions are announced again once they have more signatures than known to a peer,
+//! and requested if they have more signatures than the queued ones.
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use iroha_config::sumeragi::Configuration;
-use iroha_data_model::{transaction::SignedTransaction, ChainId};
-use iroha_p2p::Broadcast;
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    peer::PeerId,
+    transaction::{SignedTransaction, TransactionPayload},
+    ChainId,
+};
+use iroha_p2p::Post;
 use parity_scale_codec::{Decode, Encode};
 use tokio::sync::mpsc;
 
@@ -16,14 +36,14 @@ use crate::{
 /// [`Gossiper`] actor handle.
 #[derive(Clone)]
 pub struct TransactionGossiperHandle {
-    message_sender: mpsc::Sender<TransactionGossip>,
+    message_sender: mpsc::Sender<(TransactionGossip, PeerId)>,
 }
 
 impl TransactionGossiperHandle {
-    /// Send [`TransactionGossip`] to actor
-    pub async fn gossip(&self, gossip: TransactionGossip) {
+    /// Send [`TransactionGossip`] received from the authenticated `peer_id` to actor
+    pub async fn gossip(&self, gossip: TransactionGossip, peer_id: PeerId) {
         self.message_sender
-            .send(gossip)
+            .send((gossip, peer_id))
             .await
             .expect("Gossiper must handle messages until there is at least one handle to it")
     }
@@ -47,6 +67,8 @@ pub struct TransactionGossiper {
     sumeragi: SumeragiHandle,
     /// Local clone of [`WorldStateView`]
     wsv: WorldStateView,
+    /// Transactions known to other peers and requested from them
+    known: KnownTransactions,
 }
 
 impl TransactionGossiper {
@@ -75,10 +97,11 @@ impl TransactionGossiper {
             gossip_batch_size: configuration.gossip_batch_size,
             gossip_period: Duration::from_millis(configuration.gossip_period_ms),
             wsv,
+            known: KnownTransactions::default(),
         }
     }
 
-    async fn run(mut self, mut message_receiver: mpsc::Receiver<TransactionGossip>) {
+    async fn run(mut self, mut message_receiver: mpsc::Receiver<(TransactionGossip, PeerId)>) {
         let mut gossip_period = tokio::time::interval(self.gossip_period);
         loop {
             tokio::select! {
@@ -87,36 +110,126 @@ impl TransactionGossiper {
                     self.wsv = self.sumeragi.wsv_clone();
                 }
                 transaction_gossip = message_receiver.recv() => {
-                    let Some(transaction_gossip) = transaction_gossip else {
+                    let Some((transaction_gossip, peer_id)) = transaction_gossip else {
                         iroha_logger::info!("All handler to Gossiper are dropped. Shutting down...");
                         break;
                     };
-                    self.handle_transaction_gossip(transaction_gossip);
+                    self.handle_transaction_gossip(transaction_gossip, peer_id);
                 }
             }
             tokio::task::yield_now().await;
         }
     }
 
-    fn gossip_transactions(&self) {
-        let txs = self
+    fn post(&self, gossip: TransactionGossip, peer_id: PeerId) {
+        self.network.post(Post {
+            data: NetworkMessage::TransactionGossiper(Box::new(gossip)),
+            peer_id,
+        });
+    }
+
+    fn gossip_transactions(&mut self) {
+        self.known
+            .forget(&self.queue, Instant::now(), self.gossip_period);
+
+        let transactions = self
             .queue
-            .n_random_transactions(self.gossip_batch_size, &self.wsv);
+            .n_random_transactions(self.gossip_batch_size, &self.wsv)
+            .iter()
+            .map(|tx| AnnouncedTransaction::new(tx.payload().hash(), tx.signatures().len()))
+            .collect::<Vec<_>>();
+        if transactions.is_empty() {
+            return;
+        }
+
+        let online_peers = self
+            .network
+            .online_peers(|peers| peers.iter().cloned().collect::<Vec<_>>());
+        self.known.retain_peers(&online_peers);
 
+        for peer_id in online_peers {
+            let unseen = self.known.unseen(&peer_id, &transactions);
+
+            if !unseen.is_empty() {
+                iroha_logger::trace!(peer=%peer_id, tx_count = unseen.len(), "Announcing transactions");
+                self.post(
+                    TransactionGossip::Announce(TransactionAnnouncement {
+                        transactions: unseen,
+                    }),
+                    peer_id,
+                );
+            }
+        }
+    }
+
+    fn handle_transaction_gossip(&mut self, gossip: TransactionGossip, peer_id: PeerId) {
+        match gossip {
+            TransactionGossip::Announce(TransactionAnnouncement { transactions }) => {
+                self.handle_announcement(&transactions, peer_id);
+            }
+            TransactionGossip::Request(TransactionRequest { hashes }) => {
+                self.handle_request(&hashes, peer_id);
+            }
+            TransactionGossip::Transactions(TransactionBatch { txs }) => {
+                self.handle_transactions(txs, &peer_id);
+            }
+        }
+    }
+
+    fn handle_announcement(&mut self, transactions: &[AnnouncedTransaction], peer_id: PeerId) {
+        iroha_logger::trace!(
+            size = transactions.len(),
+            "Received transaction announcement"
+        );
+        self.known.mark_seen(&peer_id, transactions.iter().copied());
+
+        let missing = self.known.missing(
+            &self.queue,
+            transactions,
+            self.gossip_batch_size as usize,
+            Instant::now(),
+        );
+        if missing.is_empty() {
+            return;
+        }
+
+        iroha_logger::trace!(peer=%peer_id, size = missing.len(), "Requesting transactions");
+        self.post(
+            TransactionGossip::Request(TransactionRequest { hashes: missing }),
+            peer_id,
+        );
+    }
+
+    fn handle_request(&mut self, hashes: &[HashOf<TransactionPayload>], peer_id: PeerId) {
+        iroha_logger::trace!(size = hashes.len(), "Received transaction request");
+
+        let txs = hashes
+            .iter()
+            .take(self.gossip_batch_size as usize)
+            .filter_map(|hash| self.queue.get(hash, &self.wsv))
+            .collect::<Vec<_>>();
+        self.known.mark_seen(
+            &peer_id,
+            txs.iter()
+                .map(|tx| AnnouncedTransaction::new(tx.payload().hash(), tx.signatures().len())),
+        );
         if txs.is_empty() {
             return;
         }
 
-        iroha_logger::trace!(tx_count = txs.len(), "Gossiping transactions");
-        self.network.broadcast(Broadcast {
-            data: NetworkMessage::TransactionGossiper(Box::new(TransactionGossip::new(txs))),
-        });
+        self.post(
+            TransactionGossip::Transactions(TransactionBatch::new(txs)),
+            peer_id,
+        );
     }
 
-    fn handle_transaction_gossip(&self, TransactionGossip { txs }: TransactionGossip) {
-        iroha_logger::trace!(size = txs.len(), "Received new transaction gossip");
+    fn handle_transactions(&mut self, txs: Vec<SignedTransaction>, peer_id: &PeerId) {
+        iroha_logger::trace!(size = txs.len(), "Received requested transactions");
 
         for tx in txs {
+            let announced = AnnouncedTransaction::new(tx.payload().hash(), tx.signatures().len());
+            self.known.received(peer_id, announced);
+
             let transaction_limits = &self.wsv.config.transaction_limits;
 
             match AcceptedTransaction::accept(tx, &self.chain_id, transaction_limits) {
@@ -138,14 +251,151 @@ impl TransactionGossiper {
     }
 }
 
-/// Message for gossiping batches of transactions.
+/// Transactions known to other peers and requested from them.
+#[derive(Debug, Default)]
+struct KnownTransactions {
+    /// Highest numbers of signatures of the queued transactions known to each of the peers
+    seen: BTreeMap<PeerId, BTreeMap<HashOf<TransactionPayload>, u32>>,
+    /// Hashes of the transactions requested from other peers and instants they were requested at
+    requested: BTreeMap<HashOf<TransactionPayload>, Instant>,
+}
+
+impl KnownTransactions {
+    /// Forget transactions which left the queue and requests older than `request_timeout`,
+    /// so that memory stays bounded.
+    fn forget(&mut self, queue: &Queue, now: Instant, request_timeout: Duration) {
+        for transactions in self.seen.values_mut() {
+            transactions.retain(|hash, _| queue.contains(hash));
+        }
+        self.requested
+            .retain(|_, requested_at| now.duration_since(*requested_at) < request_timeout);
+    }
+
+    /// Forget peers which went offline.
+    fn retain_peers(&mut self, online_peers: &[PeerId]) {
+        self.seen
+            .retain(|peer_id, _| online_peers.contains(peer_id));
+    }
+
+    /// Mark transactions as known to `peer_id` with the given numbers of signatures.
+    fn mark_seen(
+        &mut self,
+        peer_id: &PeerId,
+        transactions: impl IntoIterator<Item = AnnouncedTransaction>,
+    ) {
+        let seen = self.seen.entry(peer_id.clone()).or_default();
+        for AnnouncedTransaction { hash, signatures } in transactions {
+            let known = seen.entry(hash).or_default();
+            *known = (*known).max(signatures);
+        }
+    }
+
+    /// Mark transaction sent by `peer_id` as received.
+    fn received(&mut self, peer_id: &PeerId, transaction: AnnouncedTransaction) {
+        self.requested.remove(&transaction.hash);
+        self.mark_seen(peer_id, [transaction]);
+    }
+
+    /// Transactions which have more signatures than known to `peer_id`.
+    fn unseen(
+        &self,
+        peer_id: &PeerId,
+        transactions: &[AnnouncedTransaction],
+    ) -> Vec<AnnouncedTransaction> {
+        let seen = self.seen.get(peer_id);
+        transactions
+            .iter()
+            .filter(|tx| {
+                let known = seen
+                    .and_then(|seen| seen.get(&tx.hash))
+                    .copied()
+                    .unwrap_or_default();
+                known < tx.signatures
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Hashes of the announced transactions which are missing from the queue or have more
+    /// signatures than the queued ones and haven't been requested yet.
+    /// Returned hashes are marked as requested at `now`.
+    fn missing(
+        &mut self,
+        queue: &Queue,
+        transactions: &[AnnouncedTransaction],
+        limit: usize,
+        now: Instant,
+    ) -> Vec<HashOf<TransactionPayload>> {
+        let missing = transactions
+            .iter()
+            .filter(|tx| {
+                queue
+                    .signatures_count(&tx.hash)
+                    .map_or(true, |count| count < tx.signatures as usize)
+            })
+            .map(|tx| tx.hash)
+            .filter(|hash| !self.requested.contains_key(hash))
+            .take(limit)
+            .collect::<Vec<_>>();
+
+        for hash in &missing {
+            self.requested.insert(*hash, now);
+        }
+        missing
+    }
+}
+
+/// Message for gossiping transactions.
+#[derive(Decode, Encode, Debug, Clone)]
+pub enum TransactionGossip {
+    /// Hashes of the transactions in the queue of the announcing peer.
+    Announce(TransactionAnnouncement),
+    /// Request of the announced transactions missing from the queue of the requesting peer.
+    Request(TransactionRequest),
+    /// Transactions sent in response to [`TransactionGossip::Request`].
+    Transactions(TransactionBatch),
+}
+
+/// Announcement of the transactions in the queue of a peer.
+#[derive(Decode, Encode, Debug, Clone)]
+pub struct TransactionAnnouncement {
+    /// Announced transactions.
+    pub transactions: Vec<AnnouncedTransaction>,
+}
+
+/// Transaction in the queue of the announcing peer.
+#[derive(Decode, Encode, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnnouncedTransaction {
+    /// Hash of the transaction payload.
+    pub hash: HashOf<TransactionPayload>,
+    /// Number of signatures of the transaction.
+    pub signatures: u32,
+}
+
+impl AnnouncedTransaction {
+    fn new(hash: HashOf<TransactionPayload>, signatures: usize) -> Self {
+        Self {
+            hash,
+            signatures: signatures.try_into().unwrap_or(u32::MAX),
+        }
+    }
+}
+
+/// Request of the transactions with the given hashes.
 #[derive(Decode, Encode, Debug, Clone)]
-pub struct TransactionGossip {
+pub struct TransactionRequest {
+    /// Hashes of the requested transactions.
+    pub hashes: Vec<HashOf<TransactionPayload>>,
+}
+
+/// Batch of transactions.
+#[derive(Decode, Encode, Debug, Clone)]
+pub struct TransactionBatch {
     /// Batch of transactions.
     pub txs: Vec<SignedTransaction>,
 }
 
-impl TransactionGossip {
+impl TransactionBatch {
     /// Constructor.
     pub fn new(txs: Vec<AcceptedTransaction>) -> Self {
         Self {
@@ -155,3 +405,148 @@ impl TransactionGossip {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr as _;
+
+    use iroha_config::{base::proxy::Builder as _, queue::ConfigurationProxy};
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::{prelude::*, transaction::TransactionLimits};
+
+    use super::*;
+    use crate::{
+        kura::Kura, query::store::LiveQueryStore, smartcontracts::isi::Registrable as _,
+        wsv::World, PeersIds,
+    };
+
+    fn peer(port: u16) -> PeerId {
+        let key_pair = KeyPair::generate().expect("Valid");
+        PeerId::new(([0, 0, 0, 0], port).into(), key_pair.public_key().clone())
+    }
+
+    /// Queue and world state view with the multisignature account `alice@wonderland`.
+    fn queue_with_multisignature_account(key_pairs: &[KeyPair]) -> (Queue, WorldStateView) {
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let account_id = AccountId::from_str("alice@wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&account_id);
+        let mut account = Account::new(
+            account_id.clone(),
+            key_pairs.iter().map(KeyPair::public_key).cloned(),
+        )
+        .build(&account_id);
+        account.signature_check_condition = SignatureCheckCondition::all_account_signatures();
+        assert!(domain.add_account(account).is_none());
+        let wsv = WorldStateView::new(
+            World::with([domain], PeersIds::new()),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::test().start(),
+        );
+
+        let queue = Queue::from_configuration(
+            &ConfigurationProxy::default()
+                .build()
+                .expect("Default queue config should always build"),
+        );
+        (queue, wsv)
+    }
+
+    fn transaction(message: &str) -> TransactionBuilder {
+        TransactionBuilder::new(
+            ChainId::new("0"),
+            AccountId::from_str("alice@wonderland").expect("Valid"),
+        )
+        .with_instructions([Fail {
+            message: message.to_owned(),
+        }])
+    }
+
+    fn partially_signed_tx(tx: &TransactionBuilder, key_pair: &KeyPair) -> AcceptedTransaction {
+        let chain_id = ChainId::new("0");
+        let tx = tx.clone().sign(key_pair);
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        AcceptedTransaction::accept(tx, &chain_id, &limits).expect("Failed to accept Transaction.")
+    }
+
+    fn announced(queue: &Queue, hash: HashOf<TransactionPayload>) -> AnnouncedTransaction {
+        let signatures = queue
+            .signatures_count(&hash)
+            .expect("Transaction is queued");
+        AnnouncedTransaction::new(hash, signatures)
+    }
+
+    #[tokio::test]
+    async fn merged_signatures_are_announced_again() {
+        let key_pairs = [KeyPair::generate().unwrap(), KeyPair::generate().unwrap()];
+        let (queue, wsv) = queue_with_multisignature_account(&key_pairs);
+        let (peer, sender) = (peer(1), peer(2));
+        let mut known = KnownTransactions::default();
+
+        let builder = transaction("multisignature");
+        let tx = partially_signed_tx(&builder, &key_pairs[0]);
+        let hash = tx.payload().hash();
+        queue.push(tx, &wsv).expect("Failed to push tx into queue");
+        assert_eq!(known.unseen(&peer, &[announced(&queue, hash)]).len(), 1);
+        // Announcement or the request following it might get lost, so it's announced again
+        assert_eq!(known.unseen(&peer, &[announced(&queue, hash)]).len(), 1);
+        // Transaction becomes known to the peer once it's requested and sent
+        known.mark_seen(&peer, [announced(&queue, hash)]);
+        assert!(known.unseen(&peer, &[announced(&queue, hash)]).is_empty());
+
+        // Signatures of the transaction received from another peer are merged in the queue
+        let tx = partially_signed_tx(&builder, &key_pairs[1]);
+        known.received(&sender, AnnouncedTransaction::new(hash, 1));
+        queue.push(tx, &wsv).expect("Failed to push tx into queue");
+        assert_eq!(queue.tx_len(), 1);
+
+        let merged = announced(&queue, hash);
+        assert_eq!(merged.signatures, 2);
+        assert_eq!(known.unseen(&peer, &[merged]), vec![merged]);
+        assert_eq!(known.unseen(&sender, &[merged]), vec![merged]);
+        known.mark_seen(&peer, [merged]);
+        assert!(known.unseen(&peer, &[merged]).is_empty());
+        assert_eq!(known.unseen(&sender, &[merged]), vec![merged]);
+    }
+
+    #[tokio::test]
+    async fn announcement_with_more_signatures_is_requested() {
+        let key_pairs = [KeyPair::generate().unwrap(), KeyPair::generate().unwrap()];
+        let (queue, wsv) = queue_with_multisignature_account(&key_pairs);
+        let mut known = KnownTransactions::default();
+        let limit = 10;
+        let request_timeout = Duration::from_secs(1);
+        let now = Instant::now();
+
+        let tx = partially_signed_tx(&transaction("queued"), &key_pairs[0]);
+        let hash = tx.payload().hash();
+        queue.push(tx, &wsv).expect("Failed to push tx into queue");
+        let unknown_hash = partially_signed_tx(&transaction("unknown"), &key_pairs[0])
+            .payload()
+            .hash();
+
+        let same_signatures = AnnouncedTransaction::new(hash, 1);
+        assert!(known
+            .missing(&queue, &[same_signatures], limit, now)
+            .is_empty());
+
+        let more_signatures = AnnouncedTransaction::new(hash, 2);
+        let unknown = AnnouncedTransaction::new(unknown_hash, 1);
+        assert_eq!(
+            known.missing(&queue, &[more_signatures, unknown], limit, now),
+            vec![hash, unknown_hash]
+        );
+        // Requested transactions aren't requested again until the request times out
+        assert!(known
+            .missing(&queue, &[more_signatures], limit, now)
+            .is_empty());
+        let later = now + request_timeout;
+        known.forget(&queue, later, request_timeout);
+        assert_eq!(
+            known.missing(&queue, &[more_signatures], limit, later),
+            vec![hash]
+        );
+    }
+}
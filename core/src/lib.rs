@@ -15,7 +15,7 @@ pub mod tx;
 pub mod wsv;
 
 use core::time::Duration;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use gossiper::TransactionGossip;
 use indexmap::{IndexMap, IndexSet};
@@ -54,6 +54,12 @@ pub type PermissionTokensMap = IndexMap<AccountId, Permissions>;
 /// API to work with a collections of [`AccountId`] to [`RoleId`] mappings.
 pub type AccountRolesSet = BTreeSet<role::RoleIdWithOwner>;
 
+/// API to work with a collections of ([`AssetDefinitionId`], token id) : owner [`AccountId`] mappings of [`Nft`]s.
+pub type NftOwnersMap = BTreeMap<(AssetDefinitionId, Name), AccountId>;
+
+/// Public keys which peers used before rotating them.
+pub type RetiredPeerKeys = BTreeSet<PublicKey>;
+
 /// Type of `Sender<Event>` which should be used for channels of `Event` messages.
 pub type EventsSender = broadcast::Sender<Event>;
 
@@ -72,6 +78,26 @@ pub enum NetworkMessage {
     Health,
 }
 
+impl iroha_p2p::Lane for NetworkMessage {
+    fn lane(&self) -> usize {
+        // NOTE: Consensus must not wait for catching up peers or transaction gossip
+        match self {
+            Self::SumeragiBlock(_) | Self::SumeragiControlFlow(_) | Self::Health => 0,
+            Self::BlockSync(_) => 1,
+            Self::TransactionGossiper(_) => 2,
+        }
+    }
+
+    fn is_droppable(&self) -> bool {
+        // NOTE: Peers can't commit or catch up without receiving the blocks
+        match self {
+            Self::SumeragiBlock(_) => false,
+            Self::BlockSync(message) => !matches!(**message, BlockSyncMessage::ShareBlocks(_)),
+            Self::SumeragiControlFlow(_) | Self::TransactionGossiper(_) | Self::Health => true,
+        }
+    }
+}
+
 pub mod handler {
     //! General purpose thread handler. It is responsible for RAII for
     //! threads started for Kura, Sumeragi and other core routines.
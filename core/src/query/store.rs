@@ -2,13 +2,19 @@
 
 use std::{
     cmp::Ordering,
+    collections::BTreeMap,
     num::NonZeroU64,
+    sync::{
+        atomic::{self, AtomicU64},
+        Arc,
+    },
     time::{Duration, Instant},
 };
 
 use indexmap::IndexMap;
 use iroha_config::live_query_store::Configuration;
 use iroha_data_model::{
+    account::AccountId,
     asset::AssetValue,
     query::{
         cursor::ForwardCursor, error::QueryExecutionFail, pagination::Pagination, sorting::Sorting,
@@ -39,6 +45,12 @@ pub enum Error {
     /// Fetch size is too big.
     #[error("Fetch size is too big")]
     FetchSizeTooBig,
+    /// Authority has reached the limit of live queries.
+    #[error("Authority has reached the limit of live queries")]
+    TooManyLiveQueries,
+    /// Total size of live queries would exceed the store capacity.
+    #[error("Live query store capacity is exhausted")]
+    CapacityExceeded,
 }
 
 #[allow(clippy::fallible_impl_from)]
@@ -54,6 +66,12 @@ impl From<Error> for ValidationFail {
             Error::FetchSizeTooBig => {
                 ValidationFail::QueryFailed(QueryExecutionFail::FetchSizeTooBig)
             }
+            Error::TooManyLiveQueries => {
+                ValidationFail::QueryFailed(QueryExecutionFail::TooManyLiveQueries)
+            }
+            Error::CapacityExceeded => {
+                ValidationFail::QueryFailed(QueryExecutionFail::LiveQueryStoreFull)
+            }
         }
     }
 }
@@ -63,13 +81,42 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 type LiveQuery = Batched<Vec<Value>>;
 
+/// Live query together with the data needed to account for it.
+#[derive(Debug)]
+struct LiveQueryEntry {
+    live_query: LiveQuery,
+    authority: AccountId,
+    /// Estimated size of the not yet returned part of the query output
+    size: u64,
+}
+
+/// Query output owned by a streaming connection, accounted for in the store until the stream is dropped.
+#[derive(Debug)]
+struct StreamEntry {
+    authority: AccountId,
+    size: u64,
+}
+
+/// Statistics of [`LiveQueryStore`] shared with all of its handles.
+#[derive(Debug, Default)]
+struct Stats {
+    queries: AtomicU64,
+    size: AtomicU64,
+}
+
 /// Service which stores queries which might be non fully consumed by a client.
 ///
 /// Clients can handle their queries using [`LiveQueryStoreHandle`]
 #[derive(Debug)]
 pub struct LiveQueryStore {
-    queries: IndexMap<QueryId, (LiveQuery, Instant)>,
+    queries: IndexMap<QueryId, (LiveQueryEntry, Instant)>,
+    streams: BTreeMap<QueryId, StreamEntry>,
+    queries_per_authority: BTreeMap<AccountId, u32>,
+    total_size: u64,
     query_idle_time: Duration,
+    max_queries_per_authority: u32,
+    max_total_size: u64,
+    stats: Arc<Stats>,
 }
 
 impl LiveQueryStore {
@@ -77,7 +124,13 @@ impl LiveQueryStore {
     pub fn from_configuration(cfg: Configuration) -> Self {
         Self {
             queries: IndexMap::new(),
+            streams: BTreeMap::new(),
+            queries_per_authority: BTreeMap::new(),
+            total_size: 0,
             query_idle_time: Duration::from_millis(cfg.query_idle_time_ms.into()),
+            max_queries_per_authority: cfg.max_queries_per_authority.get(),
+            max_total_size: cfg.max_total_size_bytes.get(),
+            stats: Arc::default(),
         }
     }
 
@@ -104,15 +157,21 @@ impl LiveQueryStore {
             "All handler to LiveQueryStore are dropped. Shutting down...";
 
         let (message_sender, mut message_receiver) = mpsc::channel(1);
+        // NOTE: Unbounded, so that streams can be released on drop from any context
+        let (release_sender, mut release_receiver) = mpsc::unbounded_channel::<QueryId>();
+        let stats = Arc::clone(&self.stats);
+        let query_idle_time = self.query_idle_time;
 
-        let mut idle_interval = tokio::time::interval(self.query_idle_time);
+        let mut idle_interval = tokio::time::interval(query_idle_time);
 
         tokio::task::spawn(async move {
             loop {
                 tokio::select! {
                     _ = idle_interval.tick() => {
-                        self.queries
-                            .retain(|_, (_, last_access_time)| last_access_time.elapsed() <= self.query_idle_time);
+                        self.remove_idle();
+                    },
+                    Some(query_id) = release_receiver.recv() => {
+                        self.release_stream(&query_id);
                     },
                     msg = message_receiver.recv() => {
                         let Some(msg) = msg else {
@@ -121,12 +180,21 @@ impl LiveQueryStore {
                         };
 
                         match msg {
-                            Message::Insert(query_id, live_query) => {
-                                self.insert(query_id, live_query)
+                            Message::Insert(query_id, entry, response_sender) => {
+                                let _ = response_sender.send(self.insert(query_id, entry));
+                            }
+                            Message::Restore(query_id, entry) => {
+                                self.restore(query_id, entry);
                             }
                             Message::Remove(query_id, response_sender) => {
-                                let live_query_opt = self.remove(&query_id);
-                                let _ = response_sender.send(live_query_opt);
+                                let entry_opt = self.remove(&query_id);
+                                let _ = response_sender.send(entry_opt);
+                            }
+                            Message::Drop(query_id, authority, response_sender) => {
+                                let _ = response_sender.send(self.drop(&query_id, &authority));
+                            }
+                            Message::ReserveStream(query_id, entry, response_sender) => {
+                                let _ = response_sender.send(self.reserve_stream(query_id, entry));
                             }
                         }
                     }
@@ -136,39 +204,157 @@ impl LiveQueryStore {
             }
         });
 
-        LiveQueryStoreHandle { message_sender }
+        LiveQueryStoreHandle {
+            message_sender,
+            release_sender,
+            query_idle_time,
+            stats,
+        }
+    }
+
+    /// Check that `authority` can keep one more query of `size` in the store.
+    fn check_limits(&self, authority: &AccountId, size: u64) -> Result<()> {
+        let authority_queries = self
+            .queries_per_authority
+            .get(authority)
+            .copied()
+            .unwrap_or(0);
+        if authority_queries >= self.max_queries_per_authority {
+            return Err(Error::TooManyLiveQueries);
+        }
+        if self.total_size.saturating_add(size) > self.max_total_size {
+            return Err(Error::CapacityExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Insert new query checking it against the store limits.
+    fn insert(&mut self, query_id: QueryId, entry: LiveQueryEntry) -> Result<()> {
+        self.check_limits(&entry.authority, entry.size)?;
+        self.restore(query_id, entry);
+        Ok(())
+    }
+
+    /// Insert query which was already accounted for before it was removed to serve a batch.
+    fn restore(&mut self, query_id: QueryId, entry: LiveQueryEntry) {
+        self.account(&entry.authority, entry.size);
+
+        if let Some((replaced, _)) = self.queries.insert(query_id, (entry, Instant::now())) {
+            self.forget(&replaced.authority, replaced.size);
+        }
+        self.update_stats();
+    }
+
+    fn remove(&mut self, query_id: &str) -> Option<LiveQueryEntry> {
+        let (entry, _) = self.queries.remove(query_id)?;
+        self.forget(&entry.authority, entry.size);
+        Some(entry)
+    }
+
+    /// Remove query only if it was submitted by `authority`.
+    fn drop(&mut self, query_id: &str, authority: &AccountId) -> bool {
+        let is_owned = self
+            .queries
+            .get(query_id)
+            .is_some_and(|(entry, _)| entry.authority == *authority);
+        is_owned && self.remove(query_id).is_some()
+    }
+
+    /// Account for the query output of a stream checking it against the store limits.
+    fn reserve_stream(&mut self, query_id: QueryId, entry: StreamEntry) -> Result<()> {
+        self.check_limits(&entry.authority, entry.size)?;
+        self.account(&entry.authority, entry.size);
+
+        if let Some(replaced) = self.streams.insert(query_id, entry) {
+            self.forget(&replaced.authority, replaced.size);
+        }
+        self.update_stats();
+        Ok(())
+    }
+
+    fn release_stream(&mut self, query_id: &str) {
+        if let Some(entry) = self.streams.remove(query_id) {
+            self.forget(&entry.authority, entry.size);
+        }
+    }
+
+    fn remove_idle(&mut self) {
+        let idle_queries = self
+            .queries
+            .iter()
+            .filter(|(_, (_, last_access_time))| last_access_time.elapsed() > self.query_idle_time)
+            .map(|(query_id, _)| query_id.clone())
+            .collect::<Vec<_>>();
+
+        for query_id in idle_queries {
+            self.remove(&query_id);
+        }
+    }
+
+    fn account(&mut self, authority: &AccountId, size: u64) {
+        *self
+            .queries_per_authority
+            .entry(authority.clone())
+            .or_default() += 1;
+        self.total_size += size;
     }
 
-    fn insert(&mut self, query_id: QueryId, live_query: LiveQuery) {
-        self.queries.insert(query_id, (live_query, Instant::now()));
+    fn forget(&mut self, authority: &AccountId, size: u64) {
+        if let Some(count) = self.queries_per_authority.get_mut(authority) {
+            *count -= 1;
+            if *count == 0 {
+                self.queries_per_authority.remove(authority);
+            }
+        }
+        self.total_size -= size;
+        self.update_stats();
     }
 
-    fn remove(&mut self, query_id: &str) -> Option<LiveQuery> {
-        self.queries.remove(query_id).map(|(output, _)| output)
+    fn update_stats(&self) {
+        self.stats.queries.store(
+            (self.queries.len() + self.streams.len()) as u64,
+            atomic::Ordering::Relaxed,
+        );
+        self.stats
+            .size
+            .store(self.total_size, atomic::Ordering::Relaxed);
     }
 }
 
 enum Message {
-    Insert(QueryId, Batched<Vec<Value>>),
-    Remove(QueryId, oneshot::Sender<Option<Batched<Vec<Value>>>>),
+    Insert(QueryId, LiveQueryEntry, oneshot::Sender<Result<()>>),
+    Restore(QueryId, LiveQueryEntry),
+    Remove(QueryId, oneshot::Sender<Option<LiveQueryEntry>>),
+    Drop(QueryId, AccountId, oneshot::Sender<bool>),
+    ReserveStream(QueryId, StreamEntry, oneshot::Sender<Result<()>>),
 }
 
 /// Handle to interact with [`LiveQueryStore`].
 #[derive(Clone)]
 pub struct LiveQueryStoreHandle {
     message_sender: mpsc::Sender<Message>,
+    release_sender: mpsc::UnboundedSender<QueryId>,
+    query_idle_time: Duration,
+    stats: Arc<Stats>,
 }
 
 impl LiveQueryStoreHandle {
     /// Apply sorting and pagination to the query output.
     ///
+    /// Query output which doesn't fit into a single batch is kept in the store
+    /// on behalf of the `authority` who submitted the query.
+    ///
     /// # Errors
     ///
     /// - Returns [`Error::ConnectionClosed`] if [`LiveQueryStore`] is dropped,
+    /// - Returns [`Error::TooManyLiveQueries`] if `authority` has reached the limit of live queries,
+    /// - Returns [`Error::CapacityExceeded`] if there is no space left in the store for the query,
     /// - Otherwise throws up query output handling errors.
     pub fn handle_query_output(
         &self,
         query_output: LazyValue<'_>,
+        authority: &AccountId,
         sorting: &Sorting,
         pagination: Pagination,
         fetch_size: FetchSize,
@@ -189,8 +375,15 @@ impl LiveQueryStoreHandle {
                 let query_id = uuid::Uuid::new_v4().to_string();
 
                 let curr_cursor = Some(0);
-                let live_query = live_query.batched(fetch_size);
-                self.construct_query_response(query_id, curr_cursor, live_query)
+                let entry = LiveQueryEntry {
+                    size: live_query
+                        .iter()
+                        .map(|value| value.encoded_size() as u64)
+                        .sum(),
+                    live_query: live_query.batched(fetch_size),
+                    authority: authority.clone(),
+                };
+                self.construct_query_response(query_id, curr_cursor, entry, true)
             }
         }
     }
@@ -202,13 +395,44 @@ impl LiveQueryStoreHandle {
     /// - Returns [`Error::ConnectionClosed`] if [`LiveQueryStore`] is dropped,
     /// - Otherwise throws up query output handling errors.
     pub fn handle_query_cursor(&self, cursor: ForwardCursor) -> Result<BatchedResponse<Value>> {
-        let query_id = cursor.query_id.ok_or(UnknownCursor)?;
-        let live_query = self.remove(query_id.clone())?.ok_or(UnknownCursor)?;
+        self.handle_admitted_query_cursor(cursor, |_| Ok::<_, Error>(()))
+    }
+
+    /// Retrieve next batch of query output using `cursor`, if `admit` allows
+    /// the authority who submitted the query to continue it.
+    ///
+    /// The query is kept in the store if it isn't admitted.
+    ///
+    /// # Errors
+    ///
+    /// - Returns the error of `admit` if the continuation isn't admitted,
+    /// - Otherwise the same as [`Self::handle_query_cursor`].
+    pub fn handle_admitted_query_cursor<E: From<Error>>(
+        &self,
+        cursor: ForwardCursor,
+        admit: impl FnOnce(&AccountId) -> Result<(), E>,
+    ) -> Result<BatchedResponse<Value>, E> {
+        let query_id = cursor.query_id.ok_or_else(|| Error::from(UnknownCursor))?;
+        let entry = self
+            .remove(query_id.clone())?
+            .ok_or_else(|| Error::from(UnknownCursor))?;
+        if let Err(error) = admit(&entry.authority) {
+            self.restore(query_id, entry)?;
+            return Err(error);
+        }
 
-        self.construct_query_response(query_id, cursor.cursor.map(NonZeroU64::get), live_query)
+        Ok(self.construct_query_response(
+            query_id,
+            cursor.cursor.map(NonZeroU64::get),
+            entry,
+            false,
+        )?)
     }
 
-    /// Remove query from the storage if there is any.
+    /// Remove query from the storage if there is any, regardless of who submitted it.
+    ///
+    /// Only meant for the queries executed by smart contracts, requests from
+    /// clients have to go through [`Self::drop_query_of`].
     ///
     /// Returns `true` if query was removed, `false` otherwise.
     ///
@@ -216,18 +440,74 @@ impl LiveQueryStoreHandle {
     ///
     /// - Returns [`Error::ConnectionClosed`] if [`QueryService`] is dropped,
     /// - Otherwise throws up query output handling errors.
-    pub fn drop_query(&self, query_id: QueryId) -> Result<bool> {
+    pub(crate) fn drop_query(&self, query_id: QueryId) -> Result<bool> {
         self.remove(query_id).map(|query_opt| query_opt.is_some())
     }
 
-    fn insert(&self, query_id: QueryId, live_query: LiveQuery) -> Result<()> {
+    /// Remove query from the storage if there is any submitted by `authority`.
+    ///
+    /// Returns `true` if query was removed, `false` otherwise,
+    /// i.e. if there is no such query or it was submitted by someone else.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::ConnectionClosed`] if [`LiveQueryStore`] is dropped
+    pub fn drop_query_of(&self, query_id: QueryId, authority: &AccountId) -> Result<bool> {
+        trace!(%query_id, "Dropping");
+        let (sender, receiver) = oneshot::channel();
+
+        self.message_sender
+            .blocking_send(Message::Drop(query_id, authority.clone(), sender))
+            .or(Err(Error::ConnectionClosed))?;
+
+        receiver.blocking_recv().or(Err(Error::ConnectionClosed))
+    }
+
+    /// Number of live queries currently kept in the store.
+    pub fn live_queries(&self) -> u64 {
+        self.stats.queries.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Estimated size (in bytes) of all live queries currently kept in the store.
+    pub fn live_queries_size(&self) -> u64 {
+        self.stats.size.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Time after which a query which isn't accessed by its client is evicted.
+    pub fn query_idle_time(&self) -> Duration {
+        self.query_idle_time
+    }
+
+    fn insert(&self, query_id: QueryId, entry: LiveQueryEntry) -> Result<()> {
         trace!(%query_id, "Inserting");
+        let (sender, receiver) = oneshot::channel();
+
         self.message_sender
-            .blocking_send(Message::Insert(query_id, live_query))
+            .blocking_send(Message::Insert(query_id, entry, sender))
+            .or(Err(Error::ConnectionClosed))?;
+
+        receiver.blocking_recv().or(Err(Error::ConnectionClosed))?
+    }
+
+    fn restore(&self, query_id: QueryId, entry: LiveQueryEntry) -> Result<()> {
+        trace!(%query_id, "Restoring");
+        self.message_sender
+            .blocking_send(Message::Restore(query_id, entry))
             .map_err(|_| Error::ConnectionClosed)
     }
 
-    fn remove(&self, query_id: QueryId) -> Result<Option<LiveQuery>> {
+    fn reserve_stream(&self, query_id: QueryId, entry: StreamEntry) -> Result<()> {
+        trace!(%query_id, "Reserving stream");
+        let (sender, receiver) = oneshot::channel();
+
+        self.message_sender
+            .blocking_send(Message::ReserveStream(query_id, entry, sender))
+            .or(Err(Error::ConnectionClosed))?;
+
+        receiver.blocking_recv().or(Err(Error::ConnectionClosed))?
+    }
+
+    fn remove(&self, query_id: QueryId) -> Result<Option<LiveQueryEntry>> {
         trace!(%query_id, "Removing");
         let (sender, receiver) = oneshot::channel();
 
@@ -242,12 +522,20 @@ impl LiveQueryStoreHandle {
         &self,
         query_id: QueryId,
         curr_cursor: Option<u64>,
-        mut live_query: Batched<Vec<Value>>,
+        mut entry: LiveQueryEntry,
+        is_new: bool,
     ) -> Result<BatchedResponse<Value>> {
-        let (batch, next_cursor) = live_query.next_batch(curr_cursor)?;
+        let (batch, next_cursor) = entry.live_query.next_batch(curr_cursor)?;
 
-        if !live_query.is_depleted() {
-            self.insert(query_id.clone(), live_query)?
+        if !entry.live_query.is_depleted() {
+            let batch_size = batch.iter().map(|value| value.encoded_size() as u64).sum();
+            entry.size = entry.size.saturating_sub(batch_size);
+
+            if is_new {
+                self.insert(query_id.clone(), entry)?
+            } else {
+                self.restore(query_id.clone(), entry)?
+            }
         }
 
         let query_response = BatchedResponseV1 {
@@ -302,12 +590,130 @@ impl LiveQueryStoreHandle {
     }
 }
 
+/// Query output which is consumed batch by batch over a single streaming connection.
+///
+/// The output is owned by the connection, but it's counted against the limits of [`LiveQueryStore`]
+/// the same way as the queries kept in the store until the stream is dropped.
+#[derive(Debug)]
+pub struct QueryStream {
+    query_id: QueryId,
+    output: Option<StreamedOutput>,
+    /// Set if the output is accounted for in [`LiveQueryStore`]
+    release_sender: Option<mpsc::UnboundedSender<QueryId>>,
+}
+
+#[derive(Debug)]
+enum StreamedOutput {
+    Value(Value),
+    Batched(LiveQuery, Option<u64>),
+}
+
+impl QueryStream {
+    /// Apply sorting and pagination to the query output and prepare it for streaming
+    /// on behalf of the `authority` who submitted the query.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::FetchSizeTooBig`] if `fetch_size` exceeds [`MAX_FETCH_SIZE`],
+    /// - Returns [`Error::ConnectionClosed`] if [`LiveQueryStore`] is dropped,
+    /// - Returns [`Error::TooManyLiveQueries`] if `authority` has reached the limit of live queries,
+    /// - Returns [`Error::CapacityExceeded`] if there is no space left in the store for the query.
+    pub fn new(
+        live_query_store: &LiveQueryStoreHandle,
+        query_output: LazyValue<'_>,
+        authority: &AccountId,
+        sorting: &Sorting,
+        pagination: Pagination,
+        fetch_size: FetchSize,
+    ) -> Result<Self> {
+        let query_id = uuid::Uuid::new_v4().to_string();
+
+        let (output, release_sender) = match query_output {
+            LazyValue::Value(value) => (StreamedOutput::Value(value), None),
+            LazyValue::Iter(iter) => {
+                let fetch_size = fetch_size.fetch_size.unwrap_or(DEFAULT_FETCH_SIZE);
+                if fetch_size > MAX_FETCH_SIZE {
+                    return Err(Error::FetchSizeTooBig);
+                }
+
+                let live_query =
+                    LiveQueryStoreHandle::apply_sorting_and_pagination(iter, sorting, pagination);
+                let entry = StreamEntry {
+                    authority: authority.clone(),
+                    size: live_query
+                        .iter()
+                        .map(|value| value.encoded_size() as u64)
+                        .sum(),
+                };
+                live_query_store.reserve_stream(query_id.clone(), entry)?;
+
+                (
+                    StreamedOutput::Batched(live_query.batched(fetch_size), Some(0)),
+                    Some(live_query_store.release_sender.clone()),
+                )
+            }
+        };
+
+        Ok(Self {
+            query_id,
+            output: Some(output),
+            release_sender,
+        })
+    }
+
+    /// Take the next batch of the query output.
+    ///
+    /// Returns `None` after the last batch has been taken.
+    pub fn next_batch(&mut self) -> Option<BatchedResponse<Value>> {
+        let response = match self.output.take()? {
+            StreamedOutput::Value(batch) => BatchedResponseV1 {
+                batch,
+                cursor: ForwardCursor::default(),
+            },
+            StreamedOutput::Batched(mut live_query, curr_cursor) => {
+                let (batch, next_cursor) = live_query
+                    .next_batch(curr_cursor)
+                    .expect("Cursor is tracked by the stream itself");
+
+                if !live_query.is_depleted() {
+                    self.output = Some(StreamedOutput::Batched(
+                        live_query,
+                        next_cursor.map(NonZeroU64::get),
+                    ));
+                }
+
+                BatchedResponseV1 {
+                    batch: Value::Vec(batch),
+                    cursor: ForwardCursor {
+                        query_id: Some(self.query_id.clone()),
+                        cursor: next_cursor,
+                    },
+                }
+            }
+        };
+
+        Some(response.into())
+    }
+}
+
+impl Drop for QueryStream {
+    fn drop(&mut self) {
+        if let Some(release_sender) = &self.release_sender {
+            let _ = release_sender.send(self.query_id.clone());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::NonZeroU32;
 
     use super::*;
 
+    fn authority() -> AccountId {
+        "alice@wonderland".parse().expect("Valid")
+    }
+
     #[test]
     fn query_message_order_preserved() {
         let query_store = LiveQueryStore::test();
@@ -326,7 +732,7 @@ mod tests {
             let mut counter = 0;
 
             let (batch, mut cursor) = query_store_handle
-                .handle_query_output(query_output, &sorting, pagination, fetch_size)
+                .handle_query_output(query_output, &authority(), &sorting, pagination, fetch_size)
                 .unwrap()
                 .into();
             let Value::Vec(v) = batch else {
@@ -350,4 +756,175 @@ mod tests {
             assert_eq!(counter, 100, "failed on {i} iteration");
         }
     }
+
+    #[test]
+    fn live_queries_are_limited_per_authority() {
+        use iroha_config::base::proxy::Builder as _;
+
+        let cfg = iroha_config::live_query_store::ConfigurationProxy {
+            max_queries_per_authority: NonZeroU32::new(2),
+            ..iroha_config::live_query_store::ConfigurationProxy::default()
+        }
+        .build()
+        .unwrap();
+        let threaded_rt = tokio::runtime::Runtime::new().unwrap();
+        let query_store_handle =
+            threaded_rt.block_on(async { LiveQueryStore::from_configuration(cfg).start() });
+
+        let start_query = |authority: &AccountId| {
+            let query_output = LazyValue::Iter(Box::new((0..10).map(|_| Value::Bool(false))));
+            query_store_handle.handle_query_output(
+                query_output,
+                authority,
+                &Sorting::default(),
+                Pagination::default(),
+                FetchSize {
+                    fetch_size: NonZeroU32::new(1),
+                },
+            )
+        };
+
+        let (_, cursor) = start_query(&authority()).unwrap().into();
+        start_query(&authority()).unwrap();
+        assert!(matches!(
+            start_query(&authority()),
+            Err(Error::TooManyLiveQueries)
+        ));
+        assert_eq!(query_store_handle.live_queries(), 2);
+
+        // Other authorities aren't affected
+        start_query(&"bob@wonderland".parse().unwrap()).unwrap();
+
+        // Fetching next batch of an existing query is still allowed
+        let (_, cursor) = query_store_handle
+            .handle_query_cursor(cursor)
+            .unwrap()
+            .into();
+
+        // Only the authority of a query can drop it
+        let query_id = cursor.query_id.unwrap();
+        assert!(!query_store_handle
+            .drop_query_of(query_id.clone(), &"bob@wonderland".parse().unwrap())
+            .unwrap());
+
+        // Dropping a query frees a slot
+        assert!(query_store_handle
+            .drop_query_of(query_id, &authority())
+            .unwrap());
+        start_query(&authority()).unwrap();
+    }
+
+    #[test]
+    fn query_isnt_continued_unless_admitted() {
+        let query_store = LiveQueryStore::test();
+        let threaded_rt = tokio::runtime::Runtime::new().unwrap();
+        let query_store_handle = threaded_rt.block_on(async { query_store.start() });
+
+        let query_output = LazyValue::Iter(Box::new((0..10).map(|_| Value::Bool(false))));
+        let (_, cursor) = query_store_handle
+            .handle_query_output(
+                query_output,
+                &authority(),
+                &Sorting::default(),
+                Pagination::default(),
+                FetchSize {
+                    fetch_size: NonZeroU32::new(1),
+                },
+            )
+            .unwrap()
+            .into();
+
+        let rejected = query_store_handle.handle_admitted_query_cursor(cursor.clone(), |_| {
+            Err::<(), _>(Error::TooManyLiveQueries)
+        });
+        assert!(matches!(rejected, Err(Error::TooManyLiveQueries)));
+
+        // Query is kept, so that it can be continued later
+        let mut admitted_authority = None;
+        query_store_handle
+            .handle_admitted_query_cursor(cursor, |authority| {
+                admitted_authority = Some(authority.clone());
+                Ok::<_, Error>(())
+            })
+            .unwrap();
+        assert_eq!(admitted_authority, Some(authority()));
+    }
+
+    #[test]
+    fn query_stream_yields_all_batches() {
+        let query_store = LiveQueryStore::test();
+        let threaded_rt = tokio::runtime::Runtime::new().unwrap();
+        let query_store_handle = threaded_rt.block_on(async { query_store.start() });
+
+        let fetch_size = FetchSize {
+            fetch_size: NonZeroU32::new(7),
+        };
+        let query_output = LazyValue::Iter(Box::new((0..100).map(|_| Value::Bool(false))));
+
+        let mut query_stream = QueryStream::new(
+            &query_store_handle,
+            query_output,
+            &authority(),
+            &Sorting::default(),
+            Pagination::default(),
+            fetch_size,
+        )
+        .unwrap();
+
+        let mut counter = 0;
+        while let Some(batched) = query_stream.next_batch() {
+            let (batch, cursor) = batched.into();
+            let Value::Vec(v) = batch else {
+                panic!("not expected result")
+            };
+            counter += v.len();
+
+            if cursor.cursor.is_none() {
+                assert!(query_stream.next_batch().is_none());
+                break;
+            }
+        }
+
+        assert_eq!(counter, 100);
+    }
+
+    #[test]
+    fn query_streams_are_counted_against_limits() {
+        use iroha_config::base::proxy::Builder as _;
+
+        let cfg = iroha_config::live_query_store::ConfigurationProxy {
+            max_queries_per_authority: NonZeroU32::new(1),
+            ..iroha_config::live_query_store::ConfigurationProxy::default()
+        }
+        .build()
+        .unwrap();
+        let threaded_rt = tokio::runtime::Runtime::new().unwrap();
+        let query_store_handle =
+            threaded_rt.block_on(async { LiveQueryStore::from_configuration(cfg).start() });
+
+        let start_stream = || {
+            let query_output = LazyValue::Iter(Box::new((0..10).map(|_| Value::Bool(false))));
+            QueryStream::new(
+                &query_store_handle,
+                query_output,
+                &authority(),
+                &Sorting::default(),
+                Pagination::default(),
+                FetchSize {
+                    fetch_size: NonZeroU32::new(1),
+                },
+            )
+        };
+
+        let query_stream = start_stream().unwrap();
+        assert!(matches!(start_stream(), Err(Error::TooManyLiveQueries)));
+        assert_eq!(query_store_handle.live_queries(), 1);
+
+        // Dropping a stream frees a slot
+        drop(query_stream);
+        while query_store_handle.live_queries() != 0 {
+            std::thread::yield_now();
+        }
+        start_stream().unwrap();
+    }
 }
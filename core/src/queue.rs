@@ -13,7 +13,13 @@ use iroha_primitives::must_use::MustUse;
 use rand::seq::IteratorRandom;
 use thiserror::Error;
 
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    sumeragi::{
+        main_loop::{Clock, SystemClock},
+        wakeup::Wakeup,
+    },
+};
 
 impl AcceptedTransaction {
     // TODO: We should have another type of transaction like `CheckedTransaction` in the type system?
@@ -60,6 +66,10 @@ pub struct Queue {
     /// A point in time that is considered `Future` we cannot use
     /// current time, because of network time synchronisation issues
     future_threshold: Duration,
+    /// Notified whenever a transaction is pushed
+    wakeup: Wakeup,
+    /// Source of the current time to check expiration of transactions
+    clock: Box<dyn Clock>,
 }
 
 /// Queue push error
@@ -106,9 +116,23 @@ impl Queue {
             max_txs_per_user: cfg.max_transactions_in_queue_per_user as usize,
             tx_time_to_live: Duration::from_millis(cfg.transaction_time_to_live_ms),
             future_threshold: Duration::from_millis(cfg.future_threshold_ms),
+            wakeup: Wakeup::default(),
+            clock: Box::new(SystemClock),
         }
     }
 
+    /// Check expiration of transactions against the given [`Clock`] instead of the system one.
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Notification sent whenever a transaction is pushed into the queue.
+    pub fn wakeup(&self) -> &Wakeup {
+        &self.wakeup
+    }
+
     fn is_pending(&self, tx: &AcceptedTransaction, wsv: &WorldStateView) -> bool {
         !self.is_expired(tx) && !tx.is_in_blockchain(wsv)
     }
@@ -126,13 +150,13 @@ impl Queue {
             |tx_time_to_live| core::cmp::min(self.tx_time_to_live, tx_time_to_live),
         );
 
-        iroha_data_model::current_time().saturating_sub(tx_creation_time) > time_limit
+        self.clock.system_time().saturating_sub(tx_creation_time) > time_limit
     }
 
     /// If `true`, this transaction is regarded to have been tampered to have a future timestamp.
     fn is_in_future(&self, tx: &AcceptedTransaction) -> bool {
         let tx_timestamp = Duration::from_millis(tx.payload().creation_time_ms);
-        tx_timestamp.saturating_sub(iroha_data_model::current_time()) > self.future_threshold
+        tx_timestamp.saturating_sub(self.clock.system_time()) > self.future_threshold
     }
 
     /// Returns all pending transactions.
@@ -161,6 +185,30 @@ impl Queue {
             )
     }
 
+    /// Check if the transaction with the given payload hash is in the queue.
+    pub fn contains(&self, hash: &HashOf<TransactionPayload>) -> bool {
+        self.accepted_txs.contains_key(hash)
+    }
+
+    /// Number of signatures of the transaction with the given payload hash, if it is in the queue.
+    pub fn signatures_count(&self, hash: &HashOf<TransactionPayload>) -> Option<usize> {
+        self.accepted_txs
+            .get(hash)
+            .map(|entry| entry.value().signatures().len())
+    }
+
+    /// Get the pending transaction with the given payload hash.
+    pub fn get(
+        &self,
+        hash: &HashOf<TransactionPayload>,
+        wsv: &WorldStateView,
+    ) -> Option<AcceptedTransaction> {
+        self.accepted_txs
+            .get(hash)
+            .map(|entry| entry.value().clone())
+            .filter(|tx| self.is_pending(tx, wsv))
+    }
+
     fn check_tx(
         &self,
         tx: &AcceptedTransaction,
@@ -203,6 +251,7 @@ impl Queue {
                 let new_signatures_amount = signatures_amount_after - signatures_amount_before;
                 if new_signatures_amount > 0 {
                     debug!(%hash, new_signatures_amount, "Signatures added to existing multisignature transaction");
+                    self.wakeup.notify();
                 }
                 return Ok(());
             }
@@ -238,6 +287,7 @@ impl Queue {
             }
         })?;
         trace!("Transaction queue length = {}", self.tx_hashes.len(),);
+        self.wakeup.notify();
         Ok(())
     }
 
@@ -446,6 +496,39 @@ mod tests {
             .expect("Failed to push tx into queue");
     }
 
+    #[test]
+    async fn get_tx_by_hash() {
+        let key_pair = KeyPair::generate().unwrap();
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let wsv = Arc::new(WorldStateView::new(
+            world_with_test_domains([key_pair.public_key().clone()]),
+            kura,
+            query_handle,
+        ));
+
+        let queue = Queue::from_configuration(&Configuration {
+            transaction_time_to_live_ms: 100_000,
+            max_transactions_in_queue: 100,
+            ..ConfigurationProxy::default()
+                .build()
+                .expect("Default queue config should always build")
+        });
+        let tx = accepted_tx("alice@wonderland", &key_pair);
+        let hash = tx.payload().hash();
+        let other_hash = accepted_tx("alice@wonderland", &key_pair).payload().hash();
+
+        queue.push(tx, &wsv).expect("Failed to push tx into queue");
+
+        assert!(queue.contains(&hash));
+        assert!(!queue.contains(&other_hash));
+        assert_eq!(
+            queue.get(&hash, &wsv).map(|tx| tx.payload().hash()),
+            Some(hash)
+        );
+        assert!(queue.get(&other_hash, &wsv).is_none());
+    }
+
     #[test]
     async fn push_tx_overflow() {
         let max_txs_in_queue = 10;
@@ -19,6 +19,7 @@ impl Registrable for iroha_data_model::account::NewAccount {
             assets: AssetsMap::default(),
             signature_check_condition: SignatureCheckCondition::default(),
             metadata: self.metadata,
+            metadata_schema: self.metadata_schema,
         }
     }
 }
@@ -32,7 +33,7 @@ impl Registrable for iroha_data_model::account::NewAccount {
 pub mod isi {
     use iroha_data_model::{
         isi::{
-            error::{MintabilityError, RepetitionError},
+            error::{MathError, MintabilityError, RepetitionError},
             InstructionType,
         },
         query::error::QueryExecutionFail,
@@ -50,6 +51,24 @@ pub mod isi {
                 Err(err) => match err {
                     QueryExecutionFail::Find(FindError::Asset(_)) => {
                         assert_can_register(&asset_id.definition_id, wsv, &self.object.value)?;
+                        if let AssetValue::Nft(nfts) = &self.object.value {
+                            for nft in nfts.iter() {
+                                nft.token_id
+                                    .validate_len(wsv.config.ident_length_limits)
+                                    .map_err(Error::from)?;
+                                asset::isi::assert_nft_is_unique(
+                                    &asset_id.definition_id,
+                                    &nft.token_id,
+                                    wsv,
+                                )?;
+                            }
+                            wsv.world_mut().nft_owners.extend(nfts.iter().map(|nft| {
+                                (
+                                    (asset_id.definition_id.clone(), nft.token_id.clone()),
+                                    asset_id.account_id.clone(),
+                                )
+                            }));
+                        }
                         let asset = wsv
                             .asset_or_insert(asset_id.clone(), self.object.value)
                             .expect("Account exists");
@@ -76,6 +95,14 @@ pub mod isi {
                             AssetValue::Store(_) => {
                                 wsv.increase_asset_total_amount(&asset_id.definition_id, 1_u32)?;
                             }
+                            AssetValue::Nft(ref nfts) => {
+                                let increment =
+                                    u32::try_from(nfts.len()).map_err(|_| MathError::Overflow)?;
+                                wsv.increase_asset_total_amount(
+                                    &asset_id.definition_id,
+                                    increment,
+                                )?;
+                            }
                         }
                         Ok(())
                     }
@@ -115,6 +142,15 @@ pub mod isi {
                 AssetValue::Store(_) => {
                     wsv.decrease_asset_total_amount(&asset.id.definition_id, 1_u32)?;
                 }
+                AssetValue::Nft(ref nfts) => {
+                    let decrement =
+                        u32::try_from(nfts.len()).map_err(|_| MathError::NotEnoughQuantity)?;
+                    wsv.decrease_asset_total_amount(&asset.id.definition_id, decrement)?;
+                    let nft_owners = &mut wsv.world_mut().nft_owners;
+                    for nft in nfts.iter() {
+                        nft_owners.remove(&(asset.id.definition_id.clone(), nft.token_id.clone()));
+                    }
+                }
             }
 
             wsv.emit_events(Some(AccountEvent::Asset(AssetEvent::Removed(
@@ -235,6 +271,9 @@ pub mod isi {
             wsv.account_mut(&account_id)
                 .map_err(Error::from)
                 .and_then(|account| {
+                    if let Some(metadata_schema) = &account.metadata_schema {
+                        metadata_schema.check_entry(&self.key, &self.value)?;
+                    }
                     account
                         .metadata
                         .insert_with_limits(
@@ -260,12 +299,18 @@ pub mod isi {
         fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
             let account_id = self.object_id;
 
-            let value = wsv.account_mut(&account_id).and_then(|account| {
-                account
-                    .metadata
-                    .remove(&self.key)
-                    .ok_or_else(|| FindError::MetadataKey(self.key.clone()))
-            })?;
+            let value = wsv
+                .account_mut(&account_id)
+                .map_err(Error::from)
+                .and_then(|account| {
+                    if let Some(metadata_schema) = &account.metadata_schema {
+                        metadata_schema.check_removal(&self.key)?;
+                    }
+                    account
+                        .metadata
+                        .remove(&self.key)
+                        .ok_or_else(|| FindError::MetadataKey(self.key.clone()).into())
+                })?;
 
             wsv.emit_events(Some(AccountEvent::MetadataRemoved(MetadataChanged {
                 target_id: account_id.clone(),
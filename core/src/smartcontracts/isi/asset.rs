@@ -23,6 +23,7 @@ impl Registrable for NewAssetDefinition {
             mintable: self.mintable,
             logo: self.logo,
             metadata: self.metadata,
+            metadata_schema: self.metadata_schema,
             owned_by: authority.clone(),
         }
     }
@@ -103,6 +104,105 @@ pub mod isi {
         }
     }
 
+    impl Execute for Mint<Nft, Asset> {
+        #[metrics(+"mint_nft")]
+        fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
+            let asset_id = self.destination_id;
+            let nft = self.object;
+
+            nft.token_id
+                .validate_len(wsv.config.ident_length_limits)
+                .map_err(Error::from)?;
+            assert_asset_type(&asset_id.definition_id, wsv, AssetValueType::Nft)?;
+            assert_nft_is_unique(&asset_id.definition_id, &nft.token_id, wsv)?;
+            assert_can_mint(&asset_id.definition_id, wsv, AssetValueType::Nft)?;
+
+            {
+                let asset = wsv.asset_or_insert(asset_id.clone(), Nfts::default())?;
+                let nfts: &mut Nfts = asset
+                    .try_as_mut()
+                    .map_err(eyre::Error::from)
+                    .map_err(|e| Error::Conversion(e.to_string()))?;
+                nfts.insert(nft.clone());
+            }
+            wsv.world_mut().nft_owners.insert(
+                (asset_id.definition_id.clone(), nft.token_id.clone()),
+                asset_id.account_id.clone(),
+            );
+
+            wsv.increase_asset_total_amount(&asset_id.definition_id, 1_u32)?;
+
+            wsv.emit_events(Some(AssetEvent::NftAdded(AssetNftChanged {
+                asset_id,
+                nft,
+            })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Burn<Name, Asset> {
+        #[metrics(+"burn_nft")]
+        fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
+            let asset_id = self.destination_id;
+
+            assert_asset_type(&asset_id.definition_id, wsv, AssetValueType::Nft)?;
+            let nft = remove_nft(&asset_id, &self.object, wsv)?;
+            wsv.world_mut()
+                .nft_owners
+                .remove(&(asset_id.definition_id.clone(), nft.token_id.clone()));
+
+            wsv.decrease_asset_total_amount(&asset_id.definition_id, 1_u32)?;
+
+            wsv.emit_events(Some(AssetEvent::NftRemoved(AssetNftChanged {
+                asset_id,
+                nft,
+            })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Transfer<Asset, Name, Account> {
+        #[metrics(+"transfer_nft")]
+        fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
+            let source_id = self.source_id;
+            let destination_id = AssetId::new(source_id.definition_id.clone(), self.destination_id);
+
+            assert_asset_type(&source_id.definition_id, wsv, AssetValueType::Nft)?;
+            // Make sure destination account exists before the token leaves the source
+            wsv.account(&destination_id.account_id)?;
+            let nft = remove_nft(&source_id, &self.object, wsv)?;
+
+            {
+                let destination_asset =
+                    wsv.asset_or_insert(destination_id.clone(), Nfts::default())?;
+                let nfts: &mut Nfts = destination_asset
+                    .try_as_mut()
+                    .map_err(eyre::Error::from)
+                    .map_err(|e| Error::Conversion(e.to_string()))?;
+                nfts.insert(nft.clone());
+            }
+            wsv.world_mut().nft_owners.insert(
+                (destination_id.definition_id.clone(), nft.token_id.clone()),
+                destination_id.account_id.clone(),
+            );
+
+            wsv.emit_events([
+                AssetEvent::NftRemoved(AssetNftChanged {
+                    asset_id: source_id,
+                    nft: nft.clone(),
+                }),
+                AssetEvent::NftAdded(AssetNftChanged {
+                    asset_id: destination_id,
+                    nft,
+                }),
+            ]);
+
+            Ok(())
+        }
+    }
+
     macro_rules! impl_mint {
         ($ty:ty, $metrics:literal) => {
             impl InnerMint for $ty {}
@@ -392,6 +492,42 @@ pub mod isi {
         }
     }
 
+    /// Remove [`Nft`] with `token_id` from the asset, dropping the asset once it holds no tokens.
+    fn remove_nft(
+        asset_id: &AssetId,
+        token_id: &Name,
+        wsv: &mut WorldStateView,
+    ) -> Result<Nft, Error> {
+        let account = wsv.account_mut(&asset_id.account_id)?;
+        let asset = account
+            .assets
+            .get_mut(asset_id)
+            .ok_or_else(|| FindError::Asset(asset_id.clone()))?;
+        let nfts: &mut Nfts = asset
+            .try_as_mut()
+            .map_err(eyre::Error::from)
+            .map_err(|e| Error::Conversion(e.to_string()))?;
+        let nft = nfts
+            .remove(token_id)
+            .ok_or_else(|| FindError::Nft(token_id.clone()))?;
+        if asset.value.is_zero_value() {
+            assert!(account.remove_asset(asset_id).is_some());
+        }
+        Ok(nft)
+    }
+
+    /// Asserts that no account holds a token with [`token_id`] of the asset definition with [`definition_id`].
+    pub(crate) fn assert_nft_is_unique(
+        definition_id: &AssetDefinitionId,
+        token_id: &Name,
+        wsv: &WorldStateView,
+    ) -> Result<(), Error> {
+        if wsv.nft_owner(definition_id, token_id).is_some() {
+            return Err(Error::NftRepetition(token_id.clone()));
+        }
+        Ok(())
+    }
+
     /// Assert that this asset is `mintable`.
     fn assert_can_mint(
         definition_id: &AssetDefinitionId,
@@ -527,6 +663,22 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindNftsByOwner {
+        #[metrics(+"find_nfts_by_owner")]
+        fn execute<'wsv>(
+            &self,
+            wsv: &'wsv WorldStateView,
+        ) -> Result<Box<dyn Iterator<Item = Asset> + 'wsv>, Error> {
+            let id = &self.account_id;
+            iroha_logger::trace!(%id);
+            Ok(Box::new(
+                wsv.account_assets(id)?
+                    .filter(|asset| asset.value.value_type() == AssetValueType::Nft)
+                    .cloned(),
+            ))
+        }
+    }
+
     impl ValidQuery for FindAssetsByAssetDefinitionId {
         #[metrics(+"find_assets_by_asset_definition_id")]
         fn execute<'wsv>(
@@ -23,6 +23,7 @@ impl Registrable for iroha_data_model::domain::NewDomain {
             asset_definitions: AssetDefinitionsMap::default(),
             asset_total_quantities: AssetTotalQuantityMap::default(),
             metadata: self.metadata,
+            metadata_schema: self.metadata_schema,
             logo: self.logo,
             owned_by: authority.clone(),
         }
@@ -51,6 +52,10 @@ pub mod isi {
                 .validate_len(wsv.config.ident_length_limits)
                 .map_err(Error::from)?;
 
+            if let Some(metadata_schema) = account.metadata_schema() {
+                metadata_schema.check(&account.metadata)?;
+            }
+
             let domain = wsv.domain_mut(&account_id.domain_id)?;
             if domain.accounts.get(&account_id).is_some() {
                 return Err(RepetitionError {
@@ -73,9 +78,10 @@ pub mod isi {
             let account_id = self.object_id;
 
             let domain = wsv.domain_mut(&account_id.domain_id)?;
-            if domain.remove_account(&account_id).is_none() {
+            let Some(account) = domain.remove_account(&account_id) else {
                 return Err(FindError::Account(account_id).into());
-            }
+            };
+            wsv.world_mut().unindex_nfts(&account);
 
             wsv.emit_events(Some(DomainEvent::Account(AccountEvent::Deleted(
                 account_id,
@@ -95,6 +101,10 @@ pub mod isi {
                 .validate_len(wsv.config.ident_length_limits)
                 .map_err(Error::from)?;
 
+            if let Some(metadata_schema) = asset_definition.metadata_schema() {
+                metadata_schema.check(&asset_definition.metadata)?;
+            }
+
             let asset_definition_id = asset_definition.id().clone();
             let domain = wsv.domain_mut(&asset_definition_id.domain_id)?;
             if domain.asset_definitions.get(&asset_definition_id).is_some() {
@@ -116,7 +126,7 @@ pub mod isi {
                 AssetValueType::BigQuantity => {
                     domain.add_asset_total_quantity(asset_definition_id, u128::MIN);
                 }
-                AssetValueType::Store => {
+                AssetValueType::Store | AssetValueType::Nft => {
                     domain.add_asset_total_quantity(asset_definition_id, u32::MIN);
                 }
             }
@@ -169,6 +179,10 @@ pub mod isi {
                 events.push(AccountEvent::Asset(AssetEvent::Deleted(asset_id)).into());
             }
 
+            wsv.world_mut()
+                .nft_owners
+                .retain(|(definition_id, _), _| *definition_id != asset_definition_id);
+
             let domain = wsv.domain_mut(&asset_definition_id.domain_id)?;
             if domain
                 .remove_asset_definition(&asset_definition_id)
@@ -198,6 +212,9 @@ pub mod isi {
             wsv.asset_definition_mut(&asset_definition_id)
                 .map_err(Error::from)
                 .and_then(|asset_definition| {
+                    if let Some(metadata_schema) = &asset_definition.metadata_schema {
+                        metadata_schema.check_entry(&self.key, &self.value)?;
+                    }
                     asset_definition
                         .metadata
                         .insert_with_limits(self.key.clone(), self.value.clone(), metadata_limits)
@@ -221,14 +238,18 @@ pub mod isi {
         fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
             let asset_definition_id = self.object_id;
 
-            let value =
-                wsv.asset_definition_mut(&asset_definition_id)
-                    .and_then(|asset_definition| {
-                        asset_definition
-                            .metadata
-                            .remove(&self.key)
-                            .ok_or_else(|| FindError::MetadataKey(self.key.clone()))
-                    })?;
+            let value = wsv
+                .asset_definition_mut(&asset_definition_id)
+                .map_err(Error::from)
+                .and_then(|asset_definition| {
+                    if let Some(metadata_schema) = &asset_definition.metadata_schema {
+                        metadata_schema.check_removal(&self.key)?;
+                    }
+                    asset_definition
+                        .metadata
+                        .remove(&self.key)
+                        .ok_or_else(|| FindError::MetadataKey(self.key.clone()).into())
+                })?;
 
             wsv.emit_events(Some(AssetDefinitionEvent::MetadataRemoved(
                 MetadataChanged {
@@ -250,6 +271,9 @@ pub mod isi {
             let limits = wsv.config.domain_metadata_limits;
 
             let domain = wsv.domain_mut(&domain_id)?;
+            if let Some(metadata_schema) = &domain.metadata_schema {
+                metadata_schema.check_entry(&self.key, &self.value)?;
+            }
             domain
                 .metadata
                 .insert_with_limits(self.key.clone(), self.value.clone(), limits)?;
@@ -270,6 +294,9 @@ pub mod isi {
             let domain_id = self.object_id;
 
             let domain = wsv.domain_mut(&domain_id)?;
+            if let Some(metadata_schema) = &domain.metadata_schema {
+                metadata_schema.check_removal(&self.key)?;
+            }
             let value = domain
                 .metadata
                 .remove(&self.key)
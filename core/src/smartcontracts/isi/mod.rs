@@ -49,6 +49,7 @@ impl Execute for InstructionBox {
             Self::NewParameter(isi) => isi.execute(authority, wsv),
             Self::Upgrade(isi) => isi.execute(authority, wsv),
             Self::Log(isi) => isi.execute(authority, wsv),
+            Self::RotatePeerKey(isi) => isi.execute(authority, wsv),
         }
     }
 }
@@ -117,6 +118,7 @@ impl Execute for AssetMintBox {
             Self::Quantity(isi) => isi.execute(authority, wsv),
             Self::BigQuantity(isi) => isi.execute(authority, wsv),
             Self::Fixed(isi) => isi.execute(authority, wsv),
+            Self::Nft(isi) => isi.execute(authority, wsv),
         }
     }
 }
@@ -142,6 +144,7 @@ impl Execute for AssetBurnBox {
             Self::Quantity(isi) => isi.execute(authority, wsv),
             Self::BigQuantity(isi) => isi.execute(authority, wsv),
             Self::Fixed(isi) => isi.execute(authority, wsv),
+            Self::Nft(isi) => isi.execute(authority, wsv),
         }
     }
 }
@@ -167,6 +170,7 @@ impl Execute for AssetTransferBox {
             Self::Quantity(isi) => isi.execute(authority, wsv),
             Self::BigQuantity(isi) => isi.execute(authority, wsv),
             Self::Fixed(isi) => isi.execute(authority, wsv),
+            Self::Nft(isi) => isi.execute(authority, wsv),
         }
     }
 }
@@ -231,7 +235,8 @@ mod tests {
     use core::str::FromStr as _;
     use std::sync::Arc;
 
-    use iroha_crypto::KeyPair;
+    use iroha_crypto::{Algorithm, Hash, KeyGenConfiguration, KeyPair, ProofOfPossession};
+    use iroha_data_model::metadata::MetadataError;
     use tokio::test;
 
     use super::*;
@@ -283,6 +288,148 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn asset_nft() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let bob_id = AccountId::from_str("bob@wonderland")?;
+        let (public_key, _) = KeyPair::generate()?.into();
+        Register::account(Account::new(bob_id.clone(), [public_key]))
+            .execute(&alice_id, &mut wsv)?;
+        let definition_id = AssetDefinitionId::from_str("card#wonderland")?;
+        Register::asset_definition(AssetDefinition::nft(definition_id.clone()))
+            .execute(&alice_id, &mut wsv)?;
+        let alice_asset_id = AssetId::new(definition_id.clone(), alice_id.clone());
+        let bob_asset_id = AssetId::new(definition_id.clone(), bob_id.clone());
+        let token_id = Name::from_str("queen_of_hearts")?;
+
+        let nft = Nft::new(token_id.clone()).with_content_hash(Hash::new(b"card"));
+        Mint::asset_nft(nft.clone(), alice_asset_id.clone()).execute(&alice_id, &mut wsv)?;
+        assert!(matches!(
+            Mint::asset_nft(nft, bob_asset_id.clone())
+                .execute(&alice_id, &mut wsv)
+                .expect_err("Token ids must be unique within asset definition"),
+            Error::NftRepetition(_)
+        ));
+
+        Transfer::asset_nft(alice_asset_id.clone(), token_id.clone(), bob_id.clone())
+            .execute(&alice_id, &mut wsv)?;
+        assert!(wsv.asset(&alice_asset_id).is_err());
+        let asset = wsv.asset(&bob_asset_id)?;
+        let nfts: &Nfts = asset.try_as_ref()?;
+        assert_eq!(
+            nfts.get(&token_id).and_then(|nft| nft.content_hash),
+            Some(Hash::new(b"card"))
+        );
+
+        // Tokens can't be duplicated by registering an asset holding them either
+        let duplicate = Asset::new(
+            alice_asset_id.clone(),
+            [Nft::new(token_id.clone())].into_iter().collect::<Nfts>(),
+        );
+        assert!(matches!(
+            Register::asset(duplicate.clone())
+                .execute(&alice_id, &mut wsv)
+                .expect_err("Token ids must be unique within asset definition"),
+            Error::NftRepetition(_)
+        ));
+
+        Burn::asset_nft(token_id, bob_asset_id.clone()).execute(&bob_id, &mut wsv)?;
+        assert!(wsv.asset(&bob_asset_id).is_err());
+        assert_eq!(
+            wsv.asset_total_amount(&definition_id)?,
+            NumericValue::U32(0)
+        );
+
+        // Burnt token id can be reused
+        Register::asset(duplicate).execute(&alice_id, &mut wsv)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn nfts_of_unregistered_account_are_forgotten() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let bob_id = AccountId::from_str("bob@wonderland")?;
+        let (public_key, _) = KeyPair::generate()?.into();
+        Register::account(Account::new(bob_id.clone(), [public_key]))
+            .execute(&alice_id, &mut wsv)?;
+        let definition_id = AssetDefinitionId::from_str("card#wonderland")?;
+        Register::asset_definition(AssetDefinition::nft(definition_id.clone()))
+            .execute(&alice_id, &mut wsv)?;
+        let alice_token_id = Name::from_str("king_of_hearts")?;
+        let bob_token_id = Name::from_str("queen_of_hearts")?;
+        Mint::asset_nft(
+            Nft::new(alice_token_id.clone()),
+            AssetId::new(definition_id.clone(), alice_id.clone()),
+        )
+        .execute(&alice_id, &mut wsv)?;
+        Mint::asset_nft(
+            Nft::new(bob_token_id.clone()),
+            AssetId::new(definition_id.clone(), bob_id.clone()),
+        )
+        .execute(&alice_id, &mut wsv)?;
+        assert_eq!(wsv.nft_owner(&definition_id, &bob_token_id), Some(&bob_id));
+
+        Unregister::account(bob_id).execute(&alice_id, &mut wsv)?;
+        assert_eq!(wsv.nft_owner(&definition_id, &bob_token_id), None);
+        assert_eq!(
+            wsv.world.nft_owners.keys().collect::<Vec<_>>(),
+            [&(definition_id.clone(), alice_token_id)]
+        );
+
+        // Token id of the unregistered account can be reused
+        Mint::asset_nft(
+            Nft::new(bob_token_id),
+            AssetId::new(definition_id, alice_id.clone()),
+        )
+        .execute(&alice_id, &mut wsv)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn nfts_of_unregistered_domain_are_forgotten() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let looking_glass_id = DomainId::from_str("looking_glass")?;
+        let carol_id = AccountId::from_str("carol@looking_glass")?;
+        let (public_key, _) = KeyPair::generate()?.into();
+        Register::domain(Domain::new(looking_glass_id.clone())).execute(&alice_id, &mut wsv)?;
+        Register::account(Account::new(carol_id.clone(), [public_key]))
+            .execute(&alice_id, &mut wsv)?;
+        let definition_id = AssetDefinitionId::from_str("card#wonderland")?;
+        Register::asset_definition(AssetDefinition::nft(definition_id.clone()))
+            .execute(&alice_id, &mut wsv)?;
+        let alice_token_id = Name::from_str("king_of_hearts")?;
+        let carol_token_id = Name::from_str("red_queen")?;
+        Mint::asset_nft(
+            Nft::new(alice_token_id.clone()),
+            AssetId::new(definition_id.clone(), alice_id.clone()),
+        )
+        .execute(&alice_id, &mut wsv)?;
+        Mint::asset_nft(
+            Nft::new(carol_token_id.clone()),
+            AssetId::new(definition_id.clone(), carol_id.clone()),
+        )
+        .execute(&alice_id, &mut wsv)?;
+        assert_eq!(
+            wsv.nft_owner(&definition_id, &carol_token_id),
+            Some(&carol_id)
+        );
+
+        Unregister::domain(looking_glass_id).execute(&alice_id, &mut wsv)?;
+        assert_eq!(wsv.nft_owner(&definition_id, &carol_token_id), None);
+        assert_eq!(
+            wsv.nft_owner(&definition_id, &alice_token_id),
+            Some(&alice_id)
+        );
+        assert_eq!(wsv.world.nft_owners.len(), 1);
+        Ok(())
+    }
+
     #[test]
     async fn account_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -367,6 +514,281 @@ mod tests {
         Ok(())
     }
 
+    /// Schema requiring a string `isin` entry and denying any other entries
+    fn isin_schema() -> Result<MetadataSchema> {
+        Ok(MetadataSchema::new()
+            .with_entry(
+                Name::from_str("isin")?,
+                MetadataEntrySchema::required(MetadataValueType::String),
+            )
+            .deny_unknown())
+    }
+
+    fn isin_metadata() -> Result<Metadata> {
+        let mut metadata = Metadata::new();
+        metadata.insert_with_limits(
+            Name::from_str("isin")?,
+            "US0378331005".to_owned().into(),
+            MetadataLimits::new(1024, 1024),
+        )?;
+        Ok(metadata)
+    }
+
+    fn assert_metadata_error(result: Result<(), Error>, expected: &MetadataError) {
+        assert!(
+            matches!(&result, Err(Error::Metadata(error)) if error == expected),
+            "Expected {expected:?}, got {result:?}"
+        );
+    }
+
+    #[test]
+    async fn account_metadata_schema_is_enforced() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let bob_id = AccountId::from_str("bob@wonderland")?;
+        let isin = Name::from_str("isin")?;
+        let issuer = Name::from_str("issuer")?;
+        let (public_key, _) = KeyPair::generate()?.into();
+        let bob = Account::new(bob_id.clone(), [public_key]).with_metadata_schema(isin_schema()?);
+
+        assert_metadata_error(
+            Register::account(bob.clone()).execute(&alice_id, &mut wsv),
+            &MetadataError::RequiredEntry(isin.clone()),
+        );
+        Register::account(bob.with_metadata(isin_metadata()?)).execute(&alice_id, &mut wsv)?;
+        assert_metadata_error(
+            SetKeyValue::account(bob_id.clone(), isin.clone(), 1_u32).execute(&alice_id, &mut wsv),
+            &MetadataError::SchemaMismatch(isin.clone()),
+        );
+        assert_metadata_error(
+            SetKeyValue::account(bob_id.clone(), issuer.clone(), true).execute(&alice_id, &mut wsv),
+            &MetadataError::UnknownKey(issuer),
+        );
+        assert_metadata_error(
+            RemoveKeyValue::account(bob_id.clone(), isin.clone()).execute(&alice_id, &mut wsv),
+            &MetadataError::RequiredEntry(isin.clone()),
+        );
+        SetKeyValue::account(bob_id, isin, "US5949181045".to_owned())
+            .execute(&alice_id, &mut wsv)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn domain_metadata_schema_is_enforced() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let domain_id = DomainId::from_str("looking_glass")?;
+        let isin = Name::from_str("isin")?;
+        let issuer = Name::from_str("issuer")?;
+        let domain = Domain::new(domain_id.clone()).with_metadata_schema(isin_schema()?);
+
+        assert_metadata_error(
+            Register::domain(domain.clone()).execute(&alice_id, &mut wsv),
+            &MetadataError::RequiredEntry(isin.clone()),
+        );
+        Register::domain(domain.with_metadata(isin_metadata()?)).execute(&alice_id, &mut wsv)?;
+        assert_metadata_error(
+            SetKeyValue::domain(domain_id.clone(), isin.clone(), 1_u32)
+                .execute(&alice_id, &mut wsv),
+            &MetadataError::SchemaMismatch(isin.clone()),
+        );
+        assert_metadata_error(
+            SetKeyValue::domain(domain_id.clone(), issuer.clone(), true)
+                .execute(&alice_id, &mut wsv),
+            &MetadataError::UnknownKey(issuer),
+        );
+        assert_metadata_error(
+            RemoveKeyValue::domain(domain_id.clone(), isin.clone()).execute(&alice_id, &mut wsv),
+            &MetadataError::RequiredEntry(isin.clone()),
+        );
+        SetKeyValue::domain(domain_id, isin, "US5949181045".to_owned())
+            .execute(&alice_id, &mut wsv)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn asset_definition_metadata_schema_is_enforced() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let definition_id = AssetDefinitionId::from_str("bond#wonderland")?;
+        let isin = Name::from_str("isin")?;
+        let issuer = Name::from_str("issuer")?;
+        let definition =
+            AssetDefinition::quantity(definition_id.clone()).with_metadata_schema(isin_schema()?);
+
+        assert_metadata_error(
+            Register::asset_definition(definition.clone()).execute(&alice_id, &mut wsv),
+            &MetadataError::RequiredEntry(isin.clone()),
+        );
+        Register::asset_definition(definition.with_metadata(isin_metadata()?))
+            .execute(&alice_id, &mut wsv)?;
+        assert_metadata_error(
+            SetKeyValue::asset_definition(definition_id.clone(), isin.clone(), 1_u32)
+                .execute(&alice_id, &mut wsv),
+            &MetadataError::SchemaMismatch(isin.clone()),
+        );
+        assert_metadata_error(
+            SetKeyValue::asset_definition(definition_id.clone(), issuer.clone(), true)
+                .execute(&alice_id, &mut wsv),
+            &MetadataError::UnknownKey(issuer),
+        );
+        assert_metadata_error(
+            RemoveKeyValue::asset_definition(definition_id.clone(), isin.clone())
+                .execute(&alice_id, &mut wsv),
+            &MetadataError::RequiredEntry(isin.clone()),
+        );
+        SetKeyValue::asset_definition(definition_id, isin, "US5949181045".to_owned())
+            .execute(&alice_id, &mut wsv)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn rotate_peer_key() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let chain_id = wsv.chain_id.clone();
+        let account_id = AccountId::from_str("alice@wonderland")?;
+        let old_key_pair = KeyPair::generate()?;
+        let new_key_pair = KeyPair::generate()?;
+        let peer_id = PeerId::new("127.0.0.1:1337".parse()?, old_key_pair.public_key().clone());
+        Register::peer(Peer::new(peer_id.clone())).execute(&account_id, &mut wsv)?;
+
+        // Signed by the wrong key
+        assert!(matches!(
+            RotatePeerKey::new(
+                &chain_id,
+                peer_id.clone(),
+                new_key_pair.public_key().clone(),
+                &new_key_pair
+            )
+            .execute(&account_id, &mut wsv)
+            .expect_err("Error expected"),
+            Error::InvalidParameter(error::InvalidParameterError::Signature)
+        ));
+        // Signed for another chain
+        assert!(matches!(
+            RotatePeerKey::new(
+                &ChainId::new("other"),
+                peer_id.clone(),
+                new_key_pair.public_key().clone(),
+                &old_key_pair
+            )
+            .execute(&account_id, &mut wsv)
+            .expect_err("Error expected"),
+            Error::InvalidParameter(error::InvalidParameterError::Signature)
+        ));
+
+        RotatePeerKey::new(
+            &chain_id,
+            peer_id.clone(),
+            new_key_pair.public_key().clone(),
+            &old_key_pair,
+        )
+        .execute(&account_id, &mut wsv)?;
+        let peers = wsv.peers().cloned().collect::<Vec<_>>();
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].public_key(), new_key_pair.public_key());
+        assert_eq!(peers[0].address, peer_id.address);
+
+        // The old key can't be used anymore
+        assert!(matches!(
+            RotatePeerKey::new(
+                &chain_id,
+                peer_id.clone(),
+                old_key_pair.public_key().clone(),
+                &old_key_pair
+            )
+            .execute(&account_id, &mut wsv)
+            .expect_err("Error expected"),
+            Error::Find(_)
+        ));
+
+        // Rotating back to the retired key isn't allowed, otherwise the first rotation could be replayed
+        let new_peer_id = PeerId::new(peer_id.address.clone(), new_key_pair.public_key().clone());
+        assert!(matches!(
+            RotatePeerKey::new(
+                &chain_id,
+                new_peer_id,
+                old_key_pair.public_key().clone(),
+                &new_key_pair
+            )
+            .execute(&account_id, &mut wsv)
+            .expect_err("Error expected"),
+            Error::Repetition(_)
+        ));
+        // Neither is registering it again
+        assert!(matches!(
+            Register::peer(Peer::new(peer_id))
+                .execute(&account_id, &mut wsv)
+                .expect_err("Error expected"),
+            Error::Repetition(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn bls_peer_keys_require_proof_of_possession() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let mut wsv = wsv_with_test_domains(&kura)?;
+        let chain_id = wsv.chain_id.clone();
+        let account_id = AccountId::from_str("alice@wonderland")?;
+        let generate_bls = || {
+            KeyPair::generate_with_configuration(
+                KeyGenConfiguration::from_random().with_algorithm(Algorithm::BlsNormal),
+            )
+        };
+        let key_pair = generate_bls()?;
+        let new_key_pair = generate_bls()?;
+        let peer_id = PeerId::new("127.0.0.1:1337".parse()?, key_pair.public_key().clone());
+
+        assert!(matches!(
+            Register::peer(Peer::new(peer_id.clone()))
+                .execute(&account_id, &mut wsv)
+                .expect_err("Error expected"),
+            Error::InvalidParameter(error::InvalidParameterError::ProofOfPossession)
+        ));
+        // Proof made with another key
+        assert!(matches!(
+            Register::peer(
+                Peer::new(peer_id.clone())
+                    .with_proof_of_possession(ProofOfPossession::new(&new_key_pair)?)
+            )
+            .execute(&account_id, &mut wsv)
+            .expect_err("Error expected"),
+            Error::InvalidParameter(error::InvalidParameterError::ProofOfPossession)
+        ));
+        Register::peer(
+            Peer::new(peer_id.clone()).with_proof_of_possession(ProofOfPossession::new(&key_pair)?),
+        )
+        .execute(&account_id, &mut wsv)?;
+
+        let rotate = RotatePeerKey::new(
+            &chain_id,
+            peer_id.clone(),
+            new_key_pair.public_key().clone(),
+            &key_pair,
+        );
+        assert!(matches!(
+            rotate
+                .clone()
+                .execute(&account_id, &mut wsv)
+                .expect_err("Error expected"),
+            Error::InvalidParameter(error::InvalidParameterError::ProofOfPossession)
+        ));
+        rotate
+            .with_proof_of_possession(ProofOfPossession::new(&new_key_pair)?)
+            .execute(&account_id, &mut wsv)?;
+        assert!(wsv
+            .peers()
+            .any(|peer| peer.public_key() == new_key_pair.public_key()));
+
+        Ok(())
+    }
+
     #[test]
     async fn executing_unregistered_trigger_should_return_error() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
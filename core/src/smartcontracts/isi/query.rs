@@ -79,6 +79,11 @@ impl ValidQueryRequest {
         Ok(Self(query))
     }
 
+    /// Account on behalf of which the query is executed.
+    pub fn authority(&self) -> &AccountId {
+        self.0.authority()
+    }
+
     /// Execute contained query on the [`WorldStateView`].
     ///
     /// # Errors
@@ -146,6 +151,7 @@ impl ValidQuery for QueryBox {
             FindAssetsByAssetDefinitionId,
             FindAssetsByDomainId,
             FindAssetsByDomainIdAndAssetDefinitionId,
+            FindNftsByOwner,
             FindAllDomains,
             FindAllPeers,
             FindAllBlocks,
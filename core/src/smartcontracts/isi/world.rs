@@ -18,6 +18,7 @@ impl Registrable for NewRole {
 /// Iroha Special Instructions that have `World` as their target.
 pub mod isi {
     use eyre::Result;
+    use iroha_crypto::ProofOfPossession;
     use iroha_data_model::{
         isi::error::{InvalidParameterError, RepetitionError},
         prelude::*,
@@ -27,13 +28,38 @@ pub mod isi {
 
     use super::*;
 
+    /// Check that `proof_of_possession` of the private key of `public_key` is given if required.
+    ///
+    /// Signatures of the peers with BLS keys are aggregated into block commit certificates,
+    /// so a peer registering a key without owning its private key could forge a certificate.
+    fn verify_proof_of_possession(
+        public_key: &PublicKey,
+        proof_of_possession: Option<&ProofOfPossession>,
+    ) -> Result<(), Error> {
+        if !ProofOfPossession::is_required(public_key) {
+            return Ok(());
+        }
+
+        proof_of_possession
+            .ok_or(InvalidParameterError::ProofOfPossession)?
+            .verify(public_key)
+            .map_err(|_| InvalidParameterError::ProofOfPossession.into())
+    }
+
     impl Execute for Register<Peer> {
         #[metrics(+"register_peer")]
         fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
-            let peer_id = self.object.id;
+            let Peer {
+                id: peer_id,
+                proof_of_possession,
+            } = self.object;
+            verify_proof_of_possession(&peer_id.public_key, proof_of_possession.as_ref())?;
 
             let world = wsv.world_mut();
-            if !world.trusted_peers_ids.push(peer_id.clone()) {
+            // NOTE: Otherwise rotations signed by the retired key could be replayed
+            if world.retired_peer_keys.contains(&peer_id.public_key)
+                || !world.trusted_peers_ids.push(peer_id.clone())
+            {
                 return Err(RepetitionError {
                     instruction_type: InstructionType::Register,
                     id: IdBox::PeerId(peer_id),
@@ -64,6 +90,68 @@ pub mod isi {
         }
     }
 
+    impl Execute for RotatePeerKey {
+        #[metrics(+"rotate_peer_key")]
+        fn execute(self, _authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
+            let RotatePeerKey {
+                peer_id,
+                new_public_key,
+                signature,
+                proof_of_possession,
+            } = self;
+
+            if signature.public_key() != &peer_id.public_key
+                || signature
+                    .verify(&RotatePeerKey::payload(
+                        &wsv.chain_id,
+                        &peer_id,
+                        &new_public_key,
+                    ))
+                    .is_err()
+            {
+                return Err(InvalidParameterError::Signature.into());
+            }
+            verify_proof_of_possession(&new_public_key, proof_of_possession.as_ref())?;
+
+            let world = wsv.world_mut();
+            let Some(index) = world.trusted_peers_ids.iter().position(|id| id == &peer_id) else {
+                return Err(FindError::Peer(peer_id).into());
+            };
+            // NOTE: Keys are never reused, so that a rotation signed by a retired key can't be replayed
+            if world.retired_peer_keys.contains(&new_public_key)
+                || world
+                    .trusted_peers_ids
+                    .iter()
+                    .any(|id| id.public_key == new_public_key)
+            {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::RotatePeerKey,
+                    id: IdBox::PeerId(PeerId::new(peer_id.address, new_public_key)),
+                }
+                .into());
+            }
+
+            // Address is kept, the peer is still reachable through the one it was registered with
+            let old_peer_id = world.trusted_peers_ids.remove(index);
+            world
+                .retired_peer_keys
+                .insert(old_peer_id.public_key.clone());
+            let new_peer_id = PeerId::new(old_peer_id.address.clone(), new_public_key);
+            world.trusted_peers_ids.push(new_peer_id.clone());
+            if let Some(index) = world.validators.iter().position(|id| id == &old_peer_id) {
+                world.validators.remove(index);
+                world.validators.push(new_peer_id.clone());
+            }
+
+            wsv.emit_events(Some(PeerEvent::KeyRotated(PeerKeyRotated {
+                old_peer_id,
+                new_peer_id,
+            })));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Register<Domain> {
         #[metrics("register_domain")]
         fn execute(self, authority: &AccountId, wsv: &mut WorldStateView) -> Result<(), Error> {
@@ -75,6 +163,10 @@ pub mod isi {
                 .validate_len(wsv.config.ident_length_limits)
                 .map_err(Error::from)?;
 
+            if let Some(metadata_schema) = domain.metadata_schema() {
+                metadata_schema.check(&domain.metadata)?;
+            }
+
             let world = wsv.world_mut();
             if world.domains.contains_key(&domain_id) {
                 return Err(RepetitionError {
@@ -98,8 +190,11 @@ pub mod isi {
             let domain_id = self.object_id;
 
             let world = wsv.world_mut();
-            if world.domains.remove(&domain_id).is_none() {
+            let Some(domain) = world.domains.remove(&domain_id) else {
                 return Err(FindError::Domain(domain_id).into());
+            };
+            for account in domain.accounts.values() {
+                world.unindex_nfts(account);
             }
 
             wsv.emit_events(Some(DomainEvent::Deleted(domain_id)));
@@ -779,8 +779,13 @@ where
                     state.validate_query(&state.authority, query.clone())?;
                     let output = query.execute(wsv)?;
 
-                    wsv.query_handle()
-                        .handle_query_output(output, &sorting, pagination, fetch_size)
+                    wsv.query_handle().handle_query_output(
+                        output,
+                        &state.authority,
+                        &sorting,
+                        pagination,
+                        fetch_size,
+                    )
                 }?;
                 match &batched {
                     BatchedResponse::V1(batched) => {
@@ -8,7 +8,7 @@ use std::{
 
 use iroha_config::snapshot::Configuration;
 use iroha_crypto::HashOf;
-use iroha_data_model::block::SignedBlock;
+use iroha_data_model::{block::SignedBlock, ChainId};
 use iroha_logger::prelude::*;
 use serde::{de::DeserializeSeed, Serialize};
 use tokio::sync::mpsc;
@@ -155,6 +155,7 @@ impl SnapshotMaker {
 /// - Deserialization errors
 pub fn try_read_snapshot(
     snapshot_dir: impl AsRef<Path>,
+    chain_id: ChainId,
     kura: &Arc<Kura>,
     query_handle: LiveQueryStoreHandle,
     BlockCount(block_count): BlockCount,
@@ -169,6 +170,7 @@ pub fn try_read_snapshot(
         .map_err(|err| Error::IO(err, path.clone()))?;
     let mut deserializer = serde_json::Deserializer::from_slice(&bytes);
     let seed = KuraSeed {
+        chain_id,
         kura: Arc::clone(kura),
         query_handle,
     };
@@ -6,12 +6,75 @@ use iroha_data_model::{
     block::*, events::pipeline::PipelineEvent, peer::PeerId,
     transaction::error::TransactionRejectionReason,
 };
-use iroha_p2p::UpdateTopology;
+use iroha_p2p::{reputation::Misbehaviour, UpdateTopology};
 use tracing::{span, Level};
 
 use super::{view_change::ProofBuilder, *};
 use crate::{block::*, sumeragi::tracing::instrument};
 
+/// Outgoing network of [`Sumeragi`].
+///
+/// Allows to drive consensus without sockets, e.g. in the [`simulation`](super::simulation).
+pub trait SumeragiNetwork: Send {
+    /// Send a message to a single peer.
+    fn post(&self, post: iroha_p2p::Post<NetworkMessage>);
+    /// Send a message to all connected peers.
+    fn broadcast(&self, broadcast: iroha_p2p::Broadcast<NetworkMessage>);
+    /// Connect to the given peers and disconnect from all others.
+    fn update_topology(&self, topology: UpdateTopology);
+    /// Switch the identity of this peer in the network.
+    fn update_key_pair(&self, key_pair: KeyPair);
+    /// Lower the score of a misbehaving peer.
+    fn report_misbehaviour(&self, public_key: PublicKey, misbehaviour: Misbehaviour);
+}
+
+impl SumeragiNetwork for IrohaNetwork {
+    fn post(&self, post: iroha_p2p::Post<NetworkMessage>) {
+        IrohaNetwork::post(self, post);
+    }
+
+    fn broadcast(&self, broadcast: iroha_p2p::Broadcast<NetworkMessage>) {
+        IrohaNetwork::broadcast(self, broadcast);
+    }
+
+    fn update_topology(&self, topology: UpdateTopology) {
+        IrohaNetwork::update_topology(self, topology);
+    }
+
+    fn update_key_pair(&self, key_pair: KeyPair) {
+        IrohaNetwork::update_key_pair(self, key_pair);
+    }
+
+    fn report_misbehaviour(&self, public_key: PublicKey, misbehaviour: Misbehaviour) {
+        IrohaNetwork::report_misbehaviour(self, public_key, misbehaviour);
+    }
+}
+
+/// Source of time of [`Sumeragi`].
+///
+/// Allows to drive consensus with a virtual clock, e.g. in the [`simulation`](super::simulation).
+pub trait Clock: core::fmt::Debug + Send + Sync {
+    /// Current instant.
+    fn now(&self) -> Instant;
+
+    /// Current time since the unix epoch, used for timestamps of blocks and transactions.
+    fn system_time(&self) -> Duration;
+}
+
+/// [`Clock`] of the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> Duration {
+        iroha_data_model::current_time()
+    }
+}
+
 /// `Sumeragi` is the implementation of the consensus.
 pub struct Sumeragi {
     /// Unique id of the blockchain. Used for simple replay attack protection.
@@ -39,7 +102,11 @@ pub struct Sumeragi {
     /// Kura instance used for IO
     pub kura: Arc<Kura>,
     /// [`iroha_p2p::Network`] actor address
-    pub network: IrohaNetwork,
+    pub network: Box<dyn SumeragiNetwork>,
+    /// Source of time for the timeouts of consensus rounds
+    pub clock: Box<dyn Clock>,
+    /// Notified once there are new messages or transactions to process
+    pub wakeup: Wakeup,
     /// Receiver channel, for control flow messages.
     pub control_message_receiver: mpsc::Receiver<ControlFlowMessage>,
     /// Receiver channel.
@@ -47,6 +114,13 @@ pub struct Sumeragi {
     /// Only used in testing. Causes the genesis peer to withhold blocks when it
     /// is the proxy tail.
     pub debug_force_soft_fork: bool,
+    /// Only used in testing. Ways in which this peer deviates from the protocol.
+    #[cfg(feature = "byzantine")]
+    pub byzantine_faults: iroha_config::sumeragi::ByzantineFaults,
+    /// The key pair to switch to once the key of this peer is rotated
+    pub next_key_pair: Option<KeyPair>,
+    /// Only follow the chain without taking part in consensus
+    pub is_observer: bool,
     /// The current network topology.
     pub current_topology: Topology,
     /// The sumeragi internal [`WorldStateView`]. This will probably
@@ -113,6 +187,42 @@ impl Sumeragi {
         self.network.broadcast(broadcast);
     }
 
+    /// Send `block` to one half of the `peers` and a conflicting block to the other half.
+    #[cfg(feature = "byzantine")]
+    #[allow(clippy::integer_division)]
+    fn broadcast_equivocating_blocks(&self, block: ValidBlock, peers: &[PeerId]) {
+        let conflicting_block = block.equivocate(&self.key_pair);
+        warn!(
+            block=%block.payload().hash(),
+            conflicting_block=%conflicting_block.payload().hash(),
+            "Byzantine fault: equivocating created block"
+        );
+
+        let (first_half, second_half) = peers.split_at(peers.len() / 2);
+        self.broadcast_packet_to(BlockCreated::from(block).into(), first_half);
+        self.broadcast_packet_to(BlockCreated::from(conflicting_block).into(), second_half);
+    }
+
+    /// Whether this peer withholds its signatures from the proxy tail.
+    #[cfg_attr(not(feature = "byzantine"), allow(clippy::unused_self))]
+    fn withholds_block_signed(&self) -> bool {
+        #[cfg(feature = "byzantine")]
+        if self.byzantine_faults.withhold_block_signed {
+            warn!("Byzantine fault: withholding block signature");
+            return true;
+        }
+
+        false
+    }
+
+    /// Role of this peer in the current round, observers never take part in consensus.
+    fn role(&self) -> Role {
+        if self.is_observer {
+            return Role::Undefined;
+        }
+        self.current_topology.role(&self.peer_id)
+    }
+
     fn broadcast_control_flow_packet(&self, msg: ControlFlowMessage) {
         let broadcast = iroha_p2p::Broadcast {
             data: NetworkMessage::SumeragiControlFlow(Box::new(msg)),
@@ -122,10 +232,46 @@ impl Sumeragi {
 
     /// Connect or disconnect peers according to the current network topology.
     fn connect_peers(&self, topology: &Topology) {
-        let peers = topology.ordered_peers.clone().into_iter().collect();
+        // NOTE: Peers which are not validators in the current epoch still follow the chain through block sync
+        let peers = topology
+            .ordered_peers
+            .iter()
+            .chain(self.wsv.peers())
+            .cloned()
+            .collect();
         self.network.update_topology(UpdateTopology(peers));
     }
 
+    /// Switch to the next key pair if the key of this peer was rotated in the [`WorldStateView`].
+    ///
+    /// Must be called before connecting peers of the topology built from the updated [`WorldStateView`].
+    fn rotate_key_pair(&mut self) {
+        if self.wsv.peers().any(|peer_id| peer_id == &self.peer_id) {
+            return;
+        }
+        let Some(next_key_pair) = self.next_key_pair.take() else {
+            return;
+        };
+        let Some(next_peer_id) = self
+            .wsv
+            .peers()
+            .find(|peer_id| &peer_id.public_key == next_key_pair.public_key())
+            .cloned()
+        else {
+            self.next_key_pair = Some(next_key_pair);
+            return;
+        };
+
+        info!(
+            old_public_key=%self.peer_id.public_key,
+            new_public_key=%next_peer_id.public_key,
+            "Peer key is rotated"
+        );
+        self.network.update_key_pair(next_key_pair.clone());
+        self.key_pair = next_key_pair;
+        self.peer_id = next_peer_id;
+    }
+
     /// The maximum time a sumeragi round can take to produce a block when
     /// there are no faulty peers in the a set.
     fn pipeline_time(&self) -> Duration {
@@ -229,7 +375,6 @@ impl Sumeragi {
         info!(addr = %self.peer_id.address, "Listen for genesis");
 
         loop {
-            std::thread::sleep(Duration::from_millis(50));
             early_return(shutdown_receiver).map_err(|e| {
                 debug!(?e, "Early return.");
                 e
@@ -237,51 +382,51 @@ impl Sumeragi {
 
             match self.message_receiver.try_recv() {
                 Ok(message) => {
-                    let mut new_wsv = self.wsv.clone();
-
-                    let block = match message {
-                        BlockMessage::BlockCreated(BlockCreated { block })
-                        | BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }) => block,
-                        msg => {
-                            trace!(?msg, "Not handling the message, waiting for genesis...");
-                            continue;
-                        }
-                    };
-
-                    let block = match ValidBlock::validate(
-                        block,
-                        &self.current_topology,
-                        &self.chain_id,
-                        &mut new_wsv,
-                    )
-                    .and_then(|block| {
-                        block
-                            .commit(&self.current_topology)
-                            .map_err(|(block, error)| (block.into(), error))
-                    }) {
-                        Ok(block) => block,
-                        Err((_, error)) => {
-                            error!(?error, "Received invalid genesis block");
-                            continue;
-                        }
-                    };
-
-                    new_wsv.world_mut().trusted_peers_ids = block.payload().commit_topology.clone();
-                    self.commit_block(block, new_wsv);
-                    return Err(EarlyReturn::GenesisBlockReceivedAndCommitted);
+                    if self.handle_genesis_message(message) {
+                        return Err(EarlyReturn::GenesisBlockReceivedAndCommitted);
+                    }
                 }
                 Err(mpsc::TryRecvError::Disconnected) => return Err(EarlyReturn::Disconnected),
-                _ => (),
+                Err(mpsc::TryRecvError::Empty) => self.wakeup.wait(),
             }
         }
     }
 
-    fn sumeragi_init_commit_genesis(&mut self, genesis_network: GenesisNetwork) {
-        std::thread::sleep(Duration::from_millis(250));
+    /// Commit the genesis block if the message contains a valid one.
+    ///
+    /// Returns `true` if the genesis block was committed.
+    pub(crate) fn handle_genesis_message(&mut self, message: BlockMessage) -> bool {
+        let mut new_wsv = self.wsv.clone();
 
-        assert_eq!(self.wsv.height(), 0);
-        assert_eq!(self.wsv.latest_block_hash(), None);
+        let block = match message {
+            BlockMessage::BlockCreated(BlockCreated { block })
+            | BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }) => block,
+            msg => {
+                trace!(?msg, "Not handling the message, waiting for genesis...");
+                return false;
+            }
+        };
 
+        let block =
+            match ValidBlock::validate(block, &self.current_topology, &self.chain_id, &mut new_wsv)
+                .and_then(|block| {
+                    block
+                        .commit(&self.current_topology)
+                        .map_err(|(block, error)| (block.into(), error))
+                }) {
+                Ok(block) => block,
+                Err((_, error)) => {
+                    error!(?error, "Received invalid genesis block");
+                    return false;
+                }
+            };
+
+        new_wsv.world_mut().trusted_peers_ids = block.payload().commit_topology.clone();
+        self.commit_block(block, new_wsv);
+        true
+    }
+
+    fn sumeragi_init_commit_genesis(&mut self, genesis_network: GenesisNetwork) {
         let transactions: Vec<_> = genesis_network
             .into_transactions()
             .into_iter()
@@ -289,8 +434,17 @@ impl Sumeragi {
             .collect::<Result<_, _>>()
             .expect("Genesis invalid");
 
+        self.commit_genesis(transactions);
+    }
+
+    /// Create, commit and broadcast the genesis block.
+    pub(crate) fn commit_genesis(&mut self, transactions: Vec<AcceptedTransaction>) {
+        assert_eq!(self.wsv.height(), 0);
+        assert_eq!(self.wsv.latest_block_hash(), None);
+
         let mut new_wsv = self.wsv.clone();
         let genesis = BlockBuilder::new(transactions, self.current_topology.clone(), vec![])
+            .with_timestamp(self.clock.system_time())
             .chain(0, &mut new_wsv)
             .sign(&self.key_pair);
 
@@ -310,7 +464,7 @@ impl Sumeragi {
         );
 
         info!(
-            role = ?self.current_topology.role(&self.peer_id),
+            role = ?self.role(),
             block_hash = %genesis.hash(),
             "Genesis block created",
         );
@@ -332,9 +486,19 @@ impl Sumeragi {
         block: CommittedBlock,
         mut new_wsv: WorldStateView,
     ) {
+        // NOTE: Read from the chain so that every peer commits a block with the same hash
+        let block = if new_wsv
+            .query_param(iroha_data_model::parameter::default::AGGREGATE_COMMIT_SIGNATURES)
+            .unwrap_or(false)
+        {
+            block.aggregate_signatures(&self.current_topology)
+        } else {
+            block
+        };
+
         info!(
             addr=%self.peer_id.address,
-            role=%self.current_topology.role(&self.peer_id),
+            role=%self.role(),
             block_height=%block.payload().header.height,
             block_hash=%block.hash(),
             "{}", Strategy::LOG_MESSAGE,
@@ -352,9 +516,13 @@ impl Sumeragi {
 
         // Parameters are updated before updating public copy of sumeragi
         self.update_params();
+        self.rotate_key_pair();
 
-        let new_topology =
-            Topology::recreate_topology(block.as_ref(), 0, self.wsv.peers().cloned().collect());
+        let new_topology = Topology::recreate_topology(
+            block.as_ref(),
+            0,
+            self.wsv.validators().cloned().collect(),
+        );
         let events = block.produce_events();
 
         // https://github.com/hyperledger/iroha/issues/3396
@@ -410,7 +578,7 @@ impl Sumeragi {
     ) -> Option<VotingBlock> {
         let block_hash = block.payload().hash();
         let addr = &self.peer_id.address;
-        let role = self.current_topology.role(&self.peer_id);
+        let role = self.role();
         trace!(%addr, %role, block_hash=%block_hash, "Block received, voting...");
 
         let mut new_wsv = self.wsv.clone();
@@ -418,6 +586,18 @@ impl Sumeragi {
             Ok(block) => block,
             Err((_, error)) => {
                 warn!(%addr, %role, ?error, "Block validation failed");
+                // NOTE: Only faults provable regardless of the state of this peer are penalised.
+                // Committed or invalid transactions might be caused by this peer lagging behind
+                // or being ahead of the leader, other errors can't be attributed to the leader
+                if matches!(error, BlockValidationError::TransactionHashMismatch) {
+                    if let Some(leader) = topology.is_non_empty().map(|topology| topology.leader())
+                    {
+                        self.network.report_misbehaviour(
+                            leader.public_key().clone(),
+                            Misbehaviour::InvalidBlock,
+                        );
+                    }
+                }
                 return None;
             }
         };
@@ -448,7 +628,7 @@ impl Sumeragi {
         voting_signatures: &mut Vec<SignatureOf<BlockPayload>>,
     ) {
         let current_topology = &self.current_topology;
-        let role = current_topology.role(&self.peer_id);
+        let role = self.role();
         let addr = &self.peer_id.address;
 
         #[allow(clippy::suspicious_operation_groupings)]
@@ -551,8 +731,10 @@ impl Sumeragi {
 
                     let msg = BlockSigned::from(v_block.block.clone()).into();
 
-                    self.broadcast_packet_to(msg, [current_topology.proxy_tail()]);
-                    info!(%addr, %block_hash, "Block validated, signed and forwarded");
+                    if !self.withholds_block_signed() {
+                        self.broadcast_packet_to(msg, [current_topology.proxy_tail()]);
+                        info!(%addr, %block_hash, "Block validated, signed and forwarded");
+                    }
 
                     *voting_block = Some(v_block);
                 }
@@ -566,11 +748,13 @@ impl Sumeragi {
                     if current_view_change_index >= 1 {
                         let block_hash = v_block.block.payload().hash();
 
-                        self.broadcast_packet_to(
-                            BlockSigned::from(v_block.block.clone()).into(),
-                            [current_topology.proxy_tail()],
-                        );
-                        info!(%addr, %block_hash, "Block validated, signed and forwarded");
+                        if !self.withholds_block_signed() {
+                            self.broadcast_packet_to(
+                                BlockSigned::from(v_block.block.clone()).into(),
+                                [current_topology.proxy_tail()],
+                            );
+                            info!(%addr, %block_hash, "Block validated, signed and forwarded");
+                        }
                         *voting_block = Some(v_block);
                     } else {
                         error!(%addr, %role, "Received BlockCreated message, but shouldn't");
@@ -625,14 +809,15 @@ impl Sumeragi {
         #[cfg_attr(not(debug_assertions), allow(unused_variables))] is_genesis_peer: bool,
     ) {
         let current_topology = &self.current_topology;
-        let role = current_topology.role(&self.peer_id);
+        let role = self.role();
         let addr = &self.peer_id.address;
 
         match role {
             Role::Leader => {
                 if voting_block.is_none() {
                     let cache_full = self.transaction_cache.len() >= self.max_txs_in_block;
-                    let deadline_reached = round_start_time.elapsed() > self.block_time;
+                    let deadline_reached =
+                        self.clock.now().duration_since(*round_start_time) > self.block_time;
                     let cache_non_empty = !self.transaction_cache.is_empty();
 
                     if cache_full || (deadline_reached && cache_non_empty) {
@@ -647,6 +832,7 @@ impl Sumeragi {
                             self.current_topology.clone(),
                             event_recommendations,
                         )
+                        .with_timestamp(self.clock.system_time())
                         .chain(current_view_change_index, &mut new_wsv)
                         .sign(&self.key_pair);
 
@@ -654,6 +840,17 @@ impl Sumeragi {
                             info!(%addr, block_payload_hash=%new_block.payload().hash(), "Block created");
                             *voting_block = Some(VotingBlock::new(new_block.clone(), new_wsv));
 
+                            #[cfg(feature = "byzantine")]
+                            if self.byzantine_faults.equivocate_block_created {
+                                let peers: &[PeerId] = if current_view_change_index >= 1 {
+                                    &current_topology.ordered_peers
+                                } else {
+                                    current_topology.voting_peers()
+                                };
+                                self.broadcast_equivocating_blocks(new_block, peers);
+                                return;
+                            }
+
                             let msg = BlockCreated::from(new_block).into();
                             if current_view_change_index >= 1 {
                                 self.broadcast_packet(msg);
@@ -731,6 +928,7 @@ impl Sumeragi {
 fn reset_state(
     peer_id: &PeerId,
     pipeline_time: Duration,
+    now: Instant,
     current_view_change_index: u64,
     old_view_change_index: &mut u64,
     old_latest_block_hash: &mut HashOf<SignedBlock>,
@@ -748,7 +946,7 @@ fn reset_state(
     if current_latest_block_hash != *old_latest_block_hash {
         // Round is only restarted on a block commit, so that in the case of
         // a view change a new block is immediately created by the leader
-        *round_start_time = Instant::now();
+        *round_start_time = now;
         was_commit_or_view_change = true;
         *old_view_change_index = 0;
     }
@@ -771,7 +969,7 @@ fn reset_state(
 
         *voting_block = None;
         voting_signatures.clear();
-        *last_view_change_time = Instant::now();
+        *last_view_change_time = now;
         *view_change_time = pipeline_time;
         info!(addr=%peer_id.address, role=%current_topology.role(peer_id), %current_view_change_index, "View change updated");
     }
@@ -789,6 +987,53 @@ fn should_terminate(shutdown_receiver: &mut tokio::sync::oneshot::Receiver<()>)
     }
 }
 
+/// State of the consensus round carried over between the iterations of the main loop.
+pub(crate) struct RoundState {
+    voting_block: Option<VotingBlock>,
+    /// Proxy tail collection of voting block signatures
+    voting_signatures: Vec<SignatureOf<BlockPayload>>,
+    should_sleep: bool,
+    view_change_proof_chain: ProofChain,
+    old_view_change_index: u64,
+    old_latest_block_hash: HashOf<SignedBlock>,
+    /// Duration after which a view change is suggested
+    view_change_time: Duration,
+    /// Instant when the current round started
+    round_start_time: Instant,
+    /// Instant when the previous view change or round happened.
+    last_view_change_time: Instant,
+    is_genesis_peer: bool,
+}
+
+impl RoundState {
+    /// Initial state of the main loop, must be constructed after the genesis block is committed.
+    pub(crate) fn new(sumeragi: &Sumeragi, is_genesis_peer: bool) -> Self {
+        let now = sumeragi.clock.now();
+
+        Self {
+            voting_block: None,
+            voting_signatures: Vec::new(),
+            should_sleep: false,
+            view_change_proof_chain: ProofChain::default(),
+            old_view_change_index: 0,
+            old_latest_block_hash: sumeragi
+                .wsv
+                .latest_block_ref()
+                .expect("WSV must have blocks")
+                .hash(),
+            view_change_time: sumeragi.pipeline_time(),
+            round_start_time: now,
+            last_view_change_time: now,
+            is_genesis_peer,
+        }
+    }
+
+    /// Whether the last iteration of the main loop had nothing to process.
+    pub(crate) fn should_sleep(&self) -> bool {
+        self.should_sleep
+    }
+}
+
 #[iroha_logger::log(name = "consensus", skip_all)]
 /// Execute the main loop of [`Sumeragi`]
 pub(crate) fn run(
@@ -796,6 +1041,8 @@ pub(crate) fn run(
     mut sumeragi: Sumeragi,
     mut shutdown_receiver: tokio::sync::oneshot::Receiver<()>,
 ) {
+    // The key could have been rotated while the peer was offline
+    sumeragi.rotate_key_pair();
     // Connect peers with initial topology
     sumeragi.connect_peers(&sumeragi.current_topology);
 
@@ -804,6 +1051,7 @@ pub(crate) fn run(
         || sumeragi.wsv.latest_block_hash().is_none()
     {
         if let Some(genesis_network) = genesis_network {
+            std::thread::sleep(Duration::from_millis(250));
             sumeragi.sumeragi_init_commit_genesis(genesis_network);
             true
         } else {
@@ -819,42 +1067,72 @@ pub(crate) fn run(
 
     info!(
         addr=%sumeragi.peer_id.address,
-        role_in_next_round=%sumeragi.current_topology.role(&sumeragi.peer_id),
+        role_in_next_round=%sumeragi.role(),
         "Sumeragi initialized",
     );
 
-    let mut voting_block = None;
-    // Proxy tail collection of voting block signatures
-    let mut voting_signatures = Vec::new();
-    let mut should_sleep = false;
-    let mut view_change_proof_chain = ProofChain::default();
-    let mut old_view_change_index = 0;
-    let mut old_latest_block_hash = sumeragi
-        .wsv
-        .latest_block_ref()
-        .expect("WSV must have blocks")
-        .hash();
-    // Duration after which a view change is suggested
-    let mut view_change_time = sumeragi.pipeline_time();
-    // Instant when the current round started
-    let mut round_start_time = Instant::now();
-    // Instant when the previous view change or round happened.
-    let mut last_view_change_time = Instant::now();
+    let mut state = RoundState::new(&sumeragi, is_genesis_peer);
 
     while !should_terminate(&mut shutdown_receiver) {
-        if should_sleep {
+        if state.should_sleep() {
             let span = span!(Level::TRACE, "main_thread_sleep");
             let _enter = span.enter();
-            std::thread::sleep(std::time::Duration::from_millis(5));
+            sumeragi.wait_for_work(&state);
         }
         let span_for_sumeragi_cycle = span!(Level::TRACE, "main_thread_cycle");
         let _enter_for_sumeragi_cycle = span_for_sumeragi_cycle.enter();
 
-        sumeragi
-            .transaction_cache
+        sumeragi.step(&mut state);
+    }
+}
+
+impl Sumeragi {
+    /// Block until there is a new message or transaction, or until the nearest timeout
+    /// of the current round expires.
+    fn wait_for_work(&self, state: &RoundState) {
+        match self.next_deadline(state) {
+            Some(deadline) => {
+                let timeout = deadline.saturating_duration_since(self.clock.now());
+                if !timeout.is_zero() {
+                    self.wakeup.wait_timeout(timeout);
+                }
+            }
+            None => self.wakeup.wait(),
+        }
+    }
+
+    /// Nearest instant at which [`Self::step`] acts even without new input,
+    /// `None` if the peer is idle until a message or a transaction arrives.
+    fn next_deadline(&self, state: &RoundState) -> Option<Instant> {
+        if self.is_observer {
+            return None;
+        }
+        // NOTE: Same conditions as the ones under which `step` suggests a view change
+        let expects_block = !self.transaction_cache.is_empty();
+        let is_view_change_pending = state.old_view_change_index > 0;
+        if !expects_block && !is_view_change_pending {
+            return None;
+        }
+
+        let view_change_deadline = state.last_view_change_time + state.view_change_time;
+        let block_deadline =
+            (expects_block && state.voting_block.is_none() && self.role() == Role::Leader)
+                .then(|| state.round_start_time + self.block_time);
+
+        Some(
+            block_deadline.map_or(view_change_deadline, |block_deadline| {
+                block_deadline.min(view_change_deadline)
+            }),
+        )
+    }
+
+    /// Perform a single iteration of the main loop.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn step(&mut self, state: &mut RoundState) {
+        self.transaction_cache
             // Checking if transactions are in the blockchain is costly
             .retain(|tx| {
-                let expired = sumeragi.queue.is_expired(tx);
+                let expired = self.queue.is_expired(tx);
                 if expired {
                     debug!(?tx, "Transaction expired")
                 }
@@ -862,114 +1140,126 @@ pub(crate) fn run(
             });
 
         let mut expired_transactions = Vec::new();
-        sumeragi.queue.get_transactions_for_block(
-            &sumeragi.wsv,
-            sumeragi.max_txs_in_block,
-            &mut sumeragi.transaction_cache,
+        self.queue.get_transactions_for_block(
+            &self.wsv,
+            self.max_txs_in_block,
+            &mut self.transaction_cache,
             &mut expired_transactions,
         );
-        sumeragi.send_events(expired_transactions.iter().map(expired_event));
+        self.send_events(expired_transactions.iter().map(expired_event));
 
-        let current_view_change_index = sumeragi
-            .prune_view_change_proofs_and_calculate_current_index(&mut view_change_proof_chain);
+        let current_view_change_index = self.prune_view_change_proofs_and_calculate_current_index(
+            &mut state.view_change_proof_chain,
+        );
 
         reset_state(
-            &sumeragi.peer_id,
-            sumeragi.pipeline_time(),
+            &self.peer_id,
+            self.pipeline_time(),
+            self.clock.now(),
             current_view_change_index,
-            &mut old_view_change_index,
-            &mut old_latest_block_hash,
-            &sumeragi
-                .wsv
-                .latest_block_ref()
-                .expect("WSV must have blocks"),
-            &mut sumeragi.current_topology,
-            &mut voting_block,
-            &mut voting_signatures,
-            &mut round_start_time,
-            &mut last_view_change_time,
-            &mut view_change_time,
+            &mut state.old_view_change_index,
+            &mut state.old_latest_block_hash,
+            &self.wsv.latest_block_ref().expect("WSV must have blocks"),
+            &mut self.current_topology,
+            &mut state.voting_block,
+            &mut state.voting_signatures,
+            &mut state.round_start_time,
+            &mut state.last_view_change_time,
+            &mut state.view_change_time,
         );
 
         if let Some(message) = {
-            let (msg, sleep) = sumeragi.receive_network_packet(&mut view_change_proof_chain);
-            should_sleep = sleep;
+            let (msg, sleep) = self.receive_network_packet(&mut state.view_change_proof_chain);
+            state.should_sleep = sleep;
             msg
         } {
-            sumeragi.handle_message(
+            self.handle_message(
                 message,
-                &mut voting_block,
+                &mut state.voting_block,
                 current_view_change_index,
-                &mut voting_signatures,
+                &mut state.voting_signatures,
             );
         }
 
         // State could be changed after handling message so it is necessary to reset state before handling message independent step
-        let current_view_change_index = sumeragi
-            .prune_view_change_proofs_and_calculate_current_index(&mut view_change_proof_chain);
+        let current_view_change_index = self.prune_view_change_proofs_and_calculate_current_index(
+            &mut state.view_change_proof_chain,
+        );
 
         // We broadcast our view change suggestion after having processed the latest from others inside `receive_network_packet`
-        let node_expects_block = !sumeragi.transaction_cache.is_empty();
-        if (node_expects_block || current_view_change_index > 0)
-            && last_view_change_time.elapsed() > view_change_time
+        let node_expects_block = !self.transaction_cache.is_empty();
+        if !self.is_observer
+            && (node_expects_block || current_view_change_index > 0)
+            && self.clock.now().duration_since(state.last_view_change_time) > state.view_change_time
         {
-            let role = sumeragi.current_topology.role(&sumeragi.peer_id);
+            let role = self.role();
 
             if node_expects_block {
-                if let Some(VotingBlock { block, .. }) = voting_block.as_ref() {
+                if let Some(VotingBlock { block, .. }) = state.voting_block.as_ref() {
                     // NOTE: Suspecting the tail node because it hasn't yet committed a block produced by leader
-                    warn!(peer_public_key=%sumeragi.peer_id.public_key, %role, block=%block.payload().hash(), "Block not committed in due time, requesting view change...");
+                    warn!(peer_public_key=%self.peer_id.public_key, %role, block=%block.payload().hash(), "Block not committed in due time, requesting view change...");
                 } else {
                     // NOTE: Suspecting the leader node because it hasn't produced a block
                     // If the current node has a transaction, the leader should have as well
-                    warn!(peer_public_key=%sumeragi.peer_id.public_key, %role, "No block produced in due time, requesting view change...");
+                    warn!(peer_public_key=%self.peer_id.public_key, %role, "No block produced in due time, requesting view change...");
                 }
 
                 let suspect_proof =
-                    ProofBuilder::new(sumeragi.wsv.latest_block_hash(), current_view_change_index)
-                        .sign(&sumeragi.key_pair);
+                    ProofBuilder::new(self.wsv.latest_block_hash(), current_view_change_index)
+                        .sign(&self.key_pair);
 
-                view_change_proof_chain
+                state
+                    .view_change_proof_chain
                     .insert_proof(
-                        &sumeragi.current_topology.ordered_peers,
-                        sumeragi.current_topology.max_faults(),
-                        sumeragi.wsv.latest_block_hash(),
+                        &self.current_topology.ordered_peers,
+                        self.current_topology.max_faults(),
+                        self.wsv.latest_block_hash(),
                         suspect_proof,
                     )
                     .unwrap_or_else(|err| error!("{err}"));
             }
 
-            let msg = ControlFlowMessage::new(view_change_proof_chain.clone());
-            sumeragi.broadcast_control_flow_packet(msg);
+            #[cfg(feature = "byzantine")]
+            let msg = if self.byzantine_faults.invalid_view_change_proofs {
+                warn!("Byzantine fault: broadcasting invalid view change proofs");
+                // NOTE: Claim one more view change than was requested so far
+                ControlFlowMessage::new(ProofChain::forged(
+                    self.wsv.latest_block_hash(),
+                    state.view_change_proof_chain.len() as u64 + 1,
+                ))
+            } else {
+                ControlFlowMessage::new(state.view_change_proof_chain.clone())
+            };
+            #[cfg(not(feature = "byzantine"))]
+            let msg = ControlFlowMessage::new(state.view_change_proof_chain.clone());
+            self.broadcast_control_flow_packet(msg);
 
             // NOTE: View change must be periodically suggested until it is accepted.
             // Must be initialized to pipeline time but can increase by chosen amount
-            view_change_time += sumeragi.pipeline_time();
+            state.view_change_time += self.pipeline_time();
         }
 
         reset_state(
-            &sumeragi.peer_id,
-            sumeragi.pipeline_time(),
+            &self.peer_id,
+            self.pipeline_time(),
+            self.clock.now(),
             current_view_change_index,
-            &mut old_view_change_index,
-            &mut old_latest_block_hash,
-            &sumeragi
-                .wsv
-                .latest_block_ref()
-                .expect("WSV must have blocks"),
-            &mut sumeragi.current_topology,
-            &mut voting_block,
-            &mut voting_signatures,
-            &mut round_start_time,
-            &mut last_view_change_time,
-            &mut view_change_time,
+            &mut state.old_view_change_index,
+            &mut state.old_latest_block_hash,
+            &self.wsv.latest_block_ref().expect("WSV must have blocks"),
+            &mut self.current_topology,
+            &mut state.voting_block,
+            &mut state.voting_signatures,
+            &mut state.round_start_time,
+            &mut state.last_view_change_time,
+            &mut state.view_change_time,
         );
 
-        sumeragi.process_message_independent(
-            &mut voting_block,
+        self.process_message_independent(
+            &mut state.voting_block,
             current_view_change_index,
-            &round_start_time,
-            is_genesis_peer,
+            &state.round_start_time,
+            state.is_genesis_peer,
         );
     }
 }
@@ -1113,7 +1403,7 @@ fn handle_block_sync(
             let last_committed_block = new_wsv
                 .latest_block_ref()
                 .expect("Not in genesis round so must have at least genesis block");
-            let new_peers = new_wsv.peers().cloned().collect();
+            let new_peers = new_wsv.validators().cloned().collect();
             let view_change_index = block.payload().header().view_change_index;
             Topology::recreate_topology(&last_committed_block, view_change_index, new_peers)
         };
@@ -1133,7 +1423,7 @@ fn handle_block_sync(
             let last_committed_block = new_wsv
                 .latest_block_ref()
                 .expect("Not in genesis round so must have at least genesis block");
-            let new_peers = new_wsv.peers().cloned().collect();
+            let new_peers = new_wsv.validators().cloned().collect();
             let view_change_index = block.payload().header().view_change_index;
             Topology::recreate_topology(&last_committed_block, view_change_index, new_peers)
         };
@@ -8,7 +8,7 @@ use std::{
 };
 
 use eyre::{Result, WrapErr as _};
-use iroha_config::sumeragi::Configuration;
+use iroha_config::sumeragi::{Configuration, NodeRole};
 use iroha_crypto::{KeyPair, SignatureOf};
 use iroha_data_model::{block::SignedBlock, prelude::*};
 use iroha_genesis::GenesisNetwork;
@@ -22,11 +22,15 @@ use crate::{block::ValidBlock, handler::ThreadHandler, kura::BlockCount};
 pub mod main_loop;
 pub mod message;
 pub mod network_topology;
+#[cfg(any(test, feature = "simulation"))]
+pub mod simulation;
+pub mod validator_selection;
 pub mod view_change;
+pub mod wakeup;
 
 use parking_lot::Mutex;
 
-use self::{message::*, view_change::ProofChain};
+use self::{message::*, view_change::ProofChain, wakeup::Wakeup};
 use crate::{kura::Kura, prelude::*, queue::Queue, EventsSender, IrohaNetwork, NetworkMessage};
 
 /*
@@ -53,6 +57,8 @@ pub struct SumeragiHandle {
     // Should be dropped after `_thread_handle` to prevent sumeargi thread from panicking
     control_message_sender: mpsc::SyncSender<ControlFlowMessage>,
     message_sender: mpsc::SyncSender<BlockMessage>,
+    /// Wakes up the sumeragi thread once a message is deposited
+    wakeup: Wakeup,
 }
 
 impl SumeragiHandle {
@@ -176,6 +182,21 @@ impl SumeragiHandle {
 
         self.metrics.connected_peers.set(online_peers_count);
 
+        self.network.reputations(|reputations| {
+            self.metrics.peer_scores.reset();
+            let mut banned_peers = 0;
+            for (public_key, reputation) in reputations {
+                self.metrics
+                    .peer_scores
+                    .with_label_values(&[&public_key.to_string()])
+                    .set(reputation.score.into());
+                if reputation.banned {
+                    banned_peers += 1;
+                }
+            }
+            self.metrics.banned_peers.set(banned_peers);
+        });
+
         let domains = wsv.domains();
         self.metrics.domains.set(domains.len() as u64);
         for domain in domains.values() {
@@ -192,6 +213,12 @@ impl SumeragiHandle {
 
         self.metrics.queue_size.set(self.queue.tx_len() as u64);
 
+        let query_handle = wsv.query_handle();
+        self.metrics.live_queries.set(query_handle.live_queries());
+        self.metrics
+            .live_queries_size_bytes
+            .set(query_handle.live_queries_size());
+
         Ok(())
     }
 
@@ -209,6 +236,8 @@ impl SumeragiHandle {
                 "This peer is faulty. \
                  Incoming control messages have to be dropped due to low processing speed."
             );
+        } else {
+            self.wakeup.notify();
         }
     }
 
@@ -221,6 +250,8 @@ impl SumeragiHandle {
                 "This peer is faulty. \
                  Incoming messages have to be dropped due to low processing speed."
             );
+        } else {
+            self.wakeup.notify();
         }
     }
 
@@ -247,7 +278,7 @@ impl SumeragiHandle {
              Blocks loaded from kura assumed to be valid",
         );
 
-        Topology::recreate_topology(block.as_ref(), 0, wsv.peers().cloned().collect())
+        Topology::recreate_topology(block.as_ref(), 0, wsv.validators().cloned().collect())
     }
 
     /// Start [`Sumeragi`] actor and return handle to it.
@@ -270,6 +301,8 @@ impl SumeragiHandle {
     ) -> SumeragiHandle {
         let (control_message_sender, control_message_receiver) = mpsc::sync_channel(100);
         let (message_sender, message_receiver) = mpsc::sync_channel(100);
+        // NOTE: Shared with the queue, so that the thread also wakes up on new transactions
+        let wakeup = queue.wakeup().clone();
 
         let skip_block_count = wsv.block_hashes.len();
         let mut blocks_iter = (skip_block_count + 1..=block_count).map(|block_height| {
@@ -289,7 +322,7 @@ impl SumeragiHandle {
                     "Sumeragi could not load block that was reported as present. \
                      Please check that the block storage was not disconnected.",
                 );
-                Topology::recreate_topology(&block_ref, 0, wsv.peers().cloned().collect())
+                Topology::recreate_topology(&block_ref, 0, wsv.validators().cloned().collect())
             }
         };
 
@@ -308,6 +341,20 @@ impl SumeragiHandle {
 
         info!("Sumeragi has finished loading blocks and setting up the WSV");
 
+        if wsv
+            .query_param(iroha_data_model::parameter::default::AGGREGATE_COMMIT_SIGNATURES)
+            .unwrap_or(false)
+            && !matches!(
+                configuration.key_pair.public_key().algorithm(),
+                iroha_crypto::Algorithm::BlsNormal | iroha_crypto::Algorithm::BlsSmall
+            )
+        {
+            warn!(
+                "Commit signatures are aggregated on chain, but the key of this peer isn't a BLS key. \
+                 Blocks signed by this peer will keep their signatures individually"
+            );
+        }
+
         let (public_wsv_sender, public_wsv_receiver) = watch::channel(wsv.clone());
         let (public_finalized_wsv_sender, public_finalized_wsv_receiver) =
             watch::channel(finalized_wsv.clone());
@@ -329,10 +376,16 @@ impl SumeragiHandle {
             block_time: Duration::from_millis(configuration.block_time_ms),
             max_txs_in_block: configuration.max_transactions_in_block as usize,
             kura: Arc::clone(&kura),
-            network: network.clone(),
+            network: Box::new(network.clone()),
+            clock: Box::new(main_loop::SystemClock),
+            wakeup: wakeup.clone(),
             control_message_receiver,
             message_receiver,
             debug_force_soft_fork,
+            #[cfg(feature = "byzantine")]
+            byzantine_faults: configuration.debug_byzantine_faults,
+            next_key_pair: configuration.next_key_pair.clone(),
+            is_observer: configuration.node_role == NodeRole::Observer,
             current_topology,
             wsv,
             finalized_wsv,
@@ -349,9 +402,13 @@ impl SumeragiHandle {
             })
             .expect("Sumeragi thread spawn should not fail.");
 
-        let shutdown = move || {
-            if let Err(error) = shutdown_sender.send(()) {
-                iroha_logger::error!(?error);
+        let shutdown = {
+            let wakeup = wakeup.clone();
+            move || {
+                if let Err(error) = shutdown_sender.send(()) {
+                    iroha_logger::error!(?error);
+                }
+                wakeup.notify();
             }
         };
 
@@ -362,6 +419,7 @@ impl SumeragiHandle {
             kura,
             control_message_sender,
             message_sender,
+            wakeup,
             public_wsv_receiver,
             public_finalized_wsv_receiver,
             metrics: Metrics::default(),
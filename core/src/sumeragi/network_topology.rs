@@ -82,12 +82,34 @@ impl Topology {
     }
 
     /// Filter signatures by roles in the topology.
-    #[allow(clippy::comparison_chain)]
     pub fn filter_signatures_by_roles<'a, T: 'a, I: IntoIterator<Item = &'a SignatureOf<T>>>(
         &self,
         roles: &[Role],
         signatures: I,
     ) -> Vec<SignatureOf<T>> {
+        let public_keys = self.public_keys_by_roles(roles);
+        signatures
+            .into_iter()
+            .filter(|signature| public_keys.contains(signature.public_key()))
+            .cloned()
+            .collect()
+    }
+
+    /// Filter public keys of the signers by roles in the topology.
+    pub fn filter_public_keys_by_roles<'a, I: IntoIterator<Item = &'a PublicKey>>(
+        &self,
+        roles: &[Role],
+        signers: I,
+    ) -> Vec<&'a PublicKey> {
+        let public_keys = self.public_keys_by_roles(roles);
+        signers
+            .into_iter()
+            .filter(|public_key| public_keys.contains(public_key))
+            .collect()
+    }
+
+    /// Public keys of the peers which have any of the given roles in the topology.
+    fn public_keys_by_roles(&self, roles: &[Role]) -> IndexSet<&PublicKey> {
         let mut public_keys = IndexSet::with_capacity(self.ordered_peers.len());
         for role in roles {
             match (role, self.is_non_empty(), self.is_consensus_required()) {
@@ -110,11 +132,7 @@ impl Topology {
                 _ => {}
             };
         }
-        signatures
-            .into_iter()
-            .filter(|signature| public_keys.contains(signature.public_key()))
-            .cloned()
-            .collect()
+        public_keys
     }
 
     /// What role does this peer have in the topology.
@@ -183,9 +201,8 @@ impl Topology {
     ) -> Self {
         let mut topology = Topology::new(block.payload().commit_topology.clone());
         let block_signees = block
-            .signatures()
+            .signees()
             .into_iter()
-            .map(|s| s.public_key())
             .cloned()
             .collect::<Vec<PublicKey>>();
 
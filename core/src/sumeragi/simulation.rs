@@ -0,0 +1,785 @@
+//! Deterministic simulation of a network of peers running [`Sumeragi`].
+//!
+//! Peers are stepped one after another in a single thread, time is advanced by a virtual clock
+//! and messages are passed through an in-memory network which delays, drops, reorders and
+//! partitions them using randomness derived from a seed. Key pairs of the peers are derived from
+//! the same seed, so a run (including a consensus failure) can be reproduced by running the
+//! simulation again with the same seed and the same sequence of calls.
+//!
+//! After every tick the simulation checks that no two peers have finalized different blocks at
+//! the same height. Liveness is checked with [`Simulation::run_until_height`].
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use iroha_core::sumeragi::simulation::{NetworkConditions, Simulation, SimulationConfig};
+//! let mut simulation = Simulation::new(SimulationConfig {
+//!     seed: 42,
+//!     network: NetworkConditions {
+//!         drop_rate: 0.1,
+//!         ..NetworkConditions::default()
+//!     },
+//!     ..SimulationConfig::default()
+//! });
+//! simulation.partition(&[&[0], &[1, 2, 3]]);
+//! simulation.submit_transaction();
+//! simulation.run_for(Duration::from_secs(5)).expect("Safety violated");
+//! simulation.heal();
+//! simulation
+//!     .run_until_height(2, Duration::from_secs(60))
+//!     .expect("Consensus violated");
+//! ```
+
+use std::{collections::BTreeMap, str::FromStr as _};
+
+#[cfg(feature = "byzantine")]
+use iroha_config::sumeragi::ByzantineFaults;
+use iroha_crypto::{HashOf, KeyGenConfiguration};
+use iroha_genesis::GenesisTransaction;
+use iroha_p2p::reputation::Misbehaviour;
+use rand::{rngs::StdRng, seq::SliceRandom as _, Rng as _, SeedableRng as _};
+use thiserror::Error;
+
+use super::{
+    main_loop::{Clock, RoundState, Sumeragi, SumeragiNetwork},
+    *,
+};
+use crate::{query::store::LiveQueryStore, smartcontracts::isi::Registrable as _, PeersIds};
+
+/// Conditions of the simulated network.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkConditions {
+    /// Minimal delay of a message
+    pub min_delay: Duration,
+    /// Maximal delay of a message. Messages are reordered within the range of delays.
+    pub max_delay: Duration,
+    /// Probability for a message to be lost, from `0.0` to `1.0`
+    pub drop_rate: f64,
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        Self {
+            min_delay: Duration::from_millis(5),
+            max_delay: Duration::from_millis(50),
+            drop_rate: 0.0,
+        }
+    }
+}
+
+/// Configuration of the [`Simulation`].
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationConfig {
+    /// Number of peers
+    pub peers: usize,
+    /// Seed of all randomness of the simulation
+    pub seed: u64,
+    /// Block time of the peers
+    pub block_time: Duration,
+    /// Commit time limit of the peers
+    pub commit_time: Duration,
+    /// Interval at which peers share blocks with a random peer, like block synchronisation does
+    pub block_sync_interval: Duration,
+    /// Virtual time passing between two steps of the peers
+    pub tick: Duration,
+    /// Conditions of the simulated network
+    pub network: NetworkConditions,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            peers: 4,
+            seed: 0,
+            block_time: Duration::from_millis(100),
+            commit_time: Duration::from_millis(500),
+            block_sync_interval: Duration::from_secs(1),
+            tick: Duration::from_millis(5),
+            network: NetworkConditions::default(),
+        }
+    }
+}
+
+/// Violation of a consensus invariant found by the [`Simulation`].
+#[derive(Error, displaydoc::Display, Debug, Clone, Copy)]
+#[allow(missing_docs)]
+pub enum Violation {
+    /// Peer {peer} finalized block `{actual}` at height {height}, while other peers finalized `{expected}`
+    Safety {
+        peer: usize,
+        height: u64,
+        expected: HashOf<SignedBlock>,
+        actual: HashOf<SignedBlock>,
+    },
+    /// Peer {peer} is at height {actual} after {elapsed:?} of simulated time, while {expected} was expected
+    Liveness {
+        peer: usize,
+        expected: u64,
+        actual: u64,
+        elapsed: Duration,
+    },
+}
+
+/// Time since the unix epoch at the start of every simulation, so that timestamps of blocks
+/// and transactions (and hence their hashes) don't depend on the time the simulation is run at.
+const SYSTEM_TIME_AT_START: Duration = Duration::from_secs(1_700_000_000);
+
+/// Clock shared by all peers of the simulation, advanced only by the simulation itself.
+#[derive(Debug, Clone)]
+struct VirtualClock {
+    started_at: Instant,
+    now: Arc<Mutex<Instant>>,
+}
+
+impl VirtualClock {
+    fn new() -> Self {
+        let started_at = Instant::now();
+        Self {
+            started_at,
+            now: Arc::new(Mutex::new(started_at)),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.now().duration_since(self.started_at)
+    }
+
+    fn advance(&self, duration: Duration) {
+        *self.now.lock() += duration;
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Instant {
+        *self.now.lock()
+    }
+
+    fn system_time(&self) -> Duration {
+        SYSTEM_TIME_AT_START + self.elapsed()
+    }
+}
+
+/// Message sent by a peer, which is yet to be scheduled by the simulation.
+struct Outgoing {
+    from: PeerId,
+    /// `None` for broadcast
+    to: Option<PeerId>,
+    message: NetworkMessage,
+}
+
+/// Network of a single peer, which puts all messages into the outbox shared with the simulation.
+///
+/// All peers are always connected, disconnections are simulated with [`Simulation::partition`].
+struct InMemoryNetwork {
+    peer_id: PeerId,
+    outbox: Arc<Mutex<Vec<Outgoing>>>,
+}
+
+impl SumeragiNetwork for InMemoryNetwork {
+    fn post(&self, post: iroha_p2p::Post<NetworkMessage>) {
+        self.outbox.lock().push(Outgoing {
+            from: self.peer_id.clone(),
+            to: Some(post.peer_id),
+            message: post.data,
+        });
+    }
+
+    fn broadcast(&self, broadcast: iroha_p2p::Broadcast<NetworkMessage>) {
+        self.outbox.lock().push(Outgoing {
+            from: self.peer_id.clone(),
+            to: None,
+            message: broadcast.data,
+        });
+    }
+
+    fn update_topology(&self, _topology: iroha_p2p::UpdateTopology) {}
+
+    fn update_key_pair(&self, _key_pair: KeyPair) {}
+
+    fn report_misbehaviour(&self, _public_key: PublicKey, _misbehaviour: Misbehaviour) {}
+}
+
+struct Node {
+    sumeragi: Sumeragi,
+    /// `None` until the genesis block is committed
+    state: Option<RoundState>,
+    queue: Arc<Queue>,
+    message_sender: mpsc::SyncSender<BlockMessage>,
+    control_message_sender: mpsc::SyncSender<ControlFlowMessage>,
+}
+
+impl Node {
+    fn height(&self) -> u64 {
+        self.sumeragi.wsv.height()
+    }
+
+    fn step(&mut self) {
+        if let Some(state) = &mut self.state {
+            self.sumeragi.step(state);
+            return;
+        }
+
+        while let Ok(message) = self.sumeragi.message_receiver.try_recv() {
+            if self.sumeragi.handle_genesis_message(message) {
+                self.state = Some(RoundState::new(&self.sumeragi, false));
+                return;
+            }
+        }
+    }
+
+    fn receive(&self, message: NetworkMessage) {
+        // NOTE: Messages are dropped if the peer is too slow, same as in `SumeragiHandle`
+        match message {
+            NetworkMessage::SumeragiBlock(message) => {
+                let _ = self.message_sender.try_send(*message);
+            }
+            NetworkMessage::SumeragiControlFlow(message) => {
+                let _ = self.control_message_sender.try_send(*message);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Message scheduled for delivery.
+struct Delivery {
+    to: usize,
+    message: NetworkMessage,
+}
+
+/// Deterministic simulation of a network of peers, see the [module documentation](self).
+pub struct Simulation {
+    config: SimulationConfig,
+    rng: StdRng,
+    clock: VirtualClock,
+    chain_id: ChainId,
+    account_id: AccountId,
+    account_key_pair: KeyPair,
+    peer_ids: Vec<PeerId>,
+    nodes: Vec<Node>,
+    outbox: Arc<Mutex<Vec<Outgoing>>>,
+    /// Messages in flight ordered by delivery time and then by the order of sending
+    in_flight: BTreeMap<(Instant, u64), Delivery>,
+    sent_messages: u64,
+    /// Index of the partition of each peer
+    partitions: Vec<usize>,
+    last_block_sync: Instant,
+    /// Hashes of the blocks finalized by any of the peers
+    finalized_blocks: Vec<HashOf<SignedBlock>>,
+    submitted_transactions: u32,
+}
+
+impl Simulation {
+    /// Create the network of peers and commit the genesis block on the first one.
+    ///
+    /// Must be called in the context of a `tokio` runtime.
+    ///
+    /// # Panics
+    /// If there are no peers in the configuration
+    #[allow(clippy::too_many_lines)]
+    pub fn new(config: SimulationConfig) -> Self {
+        assert!(config.peers > 0, "Simulation requires at least one peer");
+
+        let key_pair = |index: usize| {
+            let seed = [config.seed.to_le_bytes(), (index as u64).to_le_bytes()].concat();
+            KeyPair::generate_with_configuration(KeyGenConfiguration::from_seed(seed))
+                .expect("Key generation from a seed should not fail")
+        };
+
+        let chain_id = ChainId::new("simulation");
+        let account_id = AccountId::from_str("alice@wonderland").expect("Valid");
+        let account_key_pair = key_pair(config.peers);
+        let key_pairs = (0..config.peers).map(key_pair).collect::<Vec<_>>();
+        let peer_ids = key_pairs
+            .iter()
+            .enumerate()
+            .map(|(index, key_pair)| {
+                let port = 1337 + u16::try_from(index).expect("Too many peers");
+                PeerId::new(([127, 0, 0, 1], port).into(), key_pair.public_key().clone())
+            })
+            .collect::<Vec<_>>();
+
+        let world = {
+            let mut domain = Domain::new("wonderland".parse().expect("Valid")).build(&account_id);
+            let account = Account::new(account_id.clone(), [account_key_pair.public_key().clone()])
+                .build(&account_id);
+            domain.add_account(account);
+            World::with([domain], PeersIds::new())
+        };
+        let query_handle = LiveQueryStore::test().start();
+        let clock = VirtualClock::new();
+        let outbox = Arc::new(Mutex::new(Vec::new()));
+
+        let nodes = key_pairs
+            .into_iter()
+            .zip(&peer_ids)
+            .map(|(key_pair, peer_id)| {
+                let (control_message_sender, control_message_receiver) = mpsc::sync_channel(100);
+                let (message_sender, message_receiver) = mpsc::sync_channel(100);
+                let queue = Arc::new(
+                    Queue::from_configuration(&iroha_config::queue::Configuration {
+                        max_transactions_in_queue: 1000,
+                        max_transactions_in_queue_per_user: 1000,
+                        transaction_time_to_live_ms: 86_400_000,
+                        future_threshold_ms: 1000,
+                    })
+                    .with_clock(clock.clone()),
+                );
+                let kura = Kura::blank_kura_for_testing();
+                let wsv =
+                    WorldStateView::new(world.clone(), Arc::clone(&kura), query_handle.clone());
+
+                let sumeragi = Sumeragi {
+                    chain_id: chain_id.clone(),
+                    key_pair,
+                    queue: Arc::clone(&queue),
+                    peer_id: peer_id.clone(),
+                    events_sender: tokio::sync::broadcast::channel(1).0,
+                    public_wsv_sender: watch::channel(wsv.clone()).0,
+                    public_finalized_wsv_sender: watch::channel(wsv.clone()).0,
+                    commit_time: config.commit_time,
+                    block_time: config.block_time,
+                    max_txs_in_block: 512,
+                    kura,
+                    network: Box::new(InMemoryNetwork {
+                        peer_id: peer_id.clone(),
+                        outbox: Arc::clone(&outbox),
+                    }),
+                    clock: Box::new(clock.clone()),
+                    wakeup: queue.wakeup().clone(),
+                    control_message_receiver,
+                    message_receiver,
+                    debug_force_soft_fork: false,
+                    #[cfg(feature = "byzantine")]
+                    byzantine_faults: ByzantineFaults::default(),
+                    next_key_pair: None,
+                    is_observer: false,
+                    current_topology: Topology::new(peer_ids.iter().cloned().collect()),
+                    finalized_wsv: wsv.clone(),
+                    wsv,
+                    transaction_cache: Vec::new(),
+                };
+
+                Node {
+                    sumeragi,
+                    state: None,
+                    queue,
+                    message_sender,
+                    control_message_sender,
+                }
+            })
+            .collect();
+
+        let mut simulation = Self {
+            rng: StdRng::seed_from_u64(config.seed),
+            last_block_sync: clock.now(),
+            config,
+            clock,
+            chain_id,
+            account_id,
+            account_key_pair,
+            partitions: vec![0; peer_ids.len()],
+            peer_ids,
+            nodes,
+            outbox,
+            in_flight: BTreeMap::new(),
+            sent_messages: 0,
+            finalized_blocks: Vec::new(),
+            submitted_transactions: 0,
+        };
+
+        let genesis = AcceptedTransaction::accept_genesis(
+            GenesisTransaction(simulation.transaction()),
+            &simulation.chain_id,
+        )
+        .expect("Genesis transaction should be valid");
+        let genesis_node = &mut simulation.nodes[0];
+        genesis_node.sumeragi.commit_genesis(vec![genesis]);
+        genesis_node.state = Some(RoundState::new(&genesis_node.sumeragi, true));
+        simulation.send_messages();
+
+        simulation
+    }
+
+    /// Virtual time passed since the start of the simulation.
+    pub fn elapsed(&self) -> Duration {
+        self.clock.elapsed()
+    }
+
+    /// Heights of the blockchains of the peers.
+    pub fn heights(&self) -> Vec<u64> {
+        self.nodes.iter().map(Node::height).collect()
+    }
+
+    /// Identifiers of the peers, indices of which are used to refer to the peers.
+    pub fn peer_ids(&self) -> &[PeerId] {
+        &self.peer_ids
+    }
+
+    /// Change the conditions of the network for messages sent from now on.
+    pub fn set_network_conditions(&mut self, network: NetworkConditions) {
+        self.config.network = network;
+    }
+
+    /// Split the network into isolated groups of peers given by their indices.
+    /// Peers not listed in any group form one more group.
+    ///
+    /// Messages already in flight are still delivered.
+    pub fn partition(&mut self, groups: &[&[usize]]) {
+        self.partitions.fill(0);
+        for (group, peers) in groups.iter().enumerate() {
+            for &peer in *peers {
+                self.partitions[peer] = group + 1;
+            }
+        }
+    }
+
+    /// Remove all partitions of the network.
+    pub fn heal(&mut self) {
+        self.partitions.fill(0);
+    }
+
+    /// Make the peer with the given index deviate from the protocol as given by `faults`.
+    #[cfg(feature = "byzantine")]
+    pub fn set_byzantine_faults(&mut self, peer: usize, faults: ByzantineFaults) {
+        self.nodes[peer].sumeragi.byzantine_faults = faults;
+    }
+
+    /// Submit a new transaction to the queues of all peers.
+    ///
+    /// # Panics
+    /// If the transaction is rejected by the queue of a peer
+    pub fn submit_transaction(&mut self) {
+        let transaction = self.transaction();
+
+        for node in &self.nodes {
+            let limits = &node.sumeragi.wsv.transaction_executor().transaction_limits;
+            let transaction =
+                AcceptedTransaction::accept(transaction.clone(), &self.chain_id, limits)
+                    .expect("Transaction should be accepted");
+            node.queue
+                .push(transaction, &node.sumeragi.wsv)
+                .map_err(|failure| failure.err)
+                .expect("Transaction should be queued");
+        }
+    }
+
+    /// Advance the simulation by one tick.
+    ///
+    /// # Errors
+    /// If peers finalized different blocks at the same height
+    pub fn tick(&mut self) -> Result<(), Violation> {
+        self.deliver_messages();
+
+        let mut order = (0..self.nodes.len()).collect::<Vec<_>>();
+        order.shuffle(&mut self.rng);
+        for index in order {
+            self.nodes[index].step();
+        }
+
+        if self.clock.now().duration_since(self.last_block_sync) >= self.config.block_sync_interval
+        {
+            self.last_block_sync = self.clock.now();
+            self.sync_blocks();
+        }
+        self.send_messages();
+
+        self.clock.advance(self.config.tick);
+
+        self.check_safety()
+    }
+
+    /// Run the simulation for the given amount of virtual time.
+    ///
+    /// # Errors
+    /// If peers finalized different blocks at the same height
+    pub fn run_for(&mut self, duration: Duration) -> Result<(), Violation> {
+        let deadline = self.clock.now() + duration;
+        while self.clock.now() < deadline {
+            self.tick()?;
+        }
+        Ok(())
+    }
+
+    /// Run the simulation until all peers reach the given height.
+    ///
+    /// # Errors
+    /// - If peers finalized different blocks at the same height
+    /// - If any peer doesn't reach the height within the given amount of virtual time
+    pub fn run_until_height(&mut self, height: u64, timeout: Duration) -> Result<(), Violation> {
+        let deadline = self.clock.now() + timeout;
+        loop {
+            let lagging_peer = self
+                .nodes
+                .iter()
+                .enumerate()
+                .find(|(_, node)| node.height() < height);
+            let Some((peer, node)) = lagging_peer else {
+                return Ok(());
+            };
+            if self.clock.now() >= deadline {
+                return Err(Violation::Liveness {
+                    peer,
+                    expected: height,
+                    actual: node.height(),
+                    elapsed: self.elapsed(),
+                });
+            }
+
+            self.tick()?;
+        }
+    }
+
+    fn transaction(&mut self) -> SignedTransaction {
+        self.submitted_transactions += 1;
+
+        let creation_time_ms = self
+            .clock
+            .system_time()
+            .as_millis()
+            .try_into()
+            .expect("Time should fit into u64");
+        let mut transaction =
+            TransactionBuilder::new(self.chain_id.clone(), self.account_id.clone())
+                .with_instructions([SetKeyValue::account(
+                    self.account_id.clone(),
+                    "counter".parse().expect("Valid"),
+                    self.submitted_transactions,
+                )]);
+        transaction.set_creation_time(creation_time_ms);
+        transaction.sign(&self.account_key_pair)
+    }
+
+    fn deliver_messages(&mut self) {
+        let now = self.clock.now();
+        while let Some(entry) = self.in_flight.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            let Delivery { to, message } = entry.remove();
+            self.nodes[to].receive(message);
+        }
+    }
+
+    fn send_messages(&mut self) {
+        let outgoing = core::mem::take(&mut *self.outbox.lock());
+
+        for Outgoing { from, to, message } in outgoing {
+            let Some(from) = self.peer_index(&from) else {
+                continue;
+            };
+            let receivers = match to {
+                Some(to) => self.peer_index(&to).into_iter().collect::<Vec<_>>(),
+                None => (0..self.nodes.len()).filter(|to| *to != from).collect(),
+            };
+            for to in receivers {
+                self.schedule(from, to, message.clone());
+            }
+        }
+    }
+
+    /// Emulate block synchronisation: every peer asks a random peer for the block following its latest one.
+    fn sync_blocks(&mut self) {
+        for index in 0..self.nodes.len() {
+            let other = self.rng.gen_range(0..self.nodes.len());
+            if other == index {
+                continue;
+            }
+
+            let (height, other_height) = (self.nodes[index].height(), self.nodes[other].height());
+            let is_top_block_different = self.nodes[index].sumeragi.wsv.latest_block_hash()
+                != self.nodes[other].sumeragi.wsv.latest_block_hash();
+            // NOTE: Top block can be replaced by a soft fork
+            let block_height = if other_height > height {
+                height + 1
+            } else if other_height == height && is_top_block_different {
+                height
+            } else {
+                continue;
+            };
+
+            if let Some(block) = self.nodes[other]
+                .sumeragi
+                .kura
+                .get_block_by_height(block_height)
+            {
+                let message = BlockMessage::from(BlockSyncUpdate::from((*block).clone()));
+                self.schedule(
+                    other,
+                    index,
+                    NetworkMessage::SumeragiBlock(Box::new(message)),
+                );
+            }
+        }
+    }
+
+    fn schedule(&mut self, from: usize, to: usize, message: NetworkMessage) {
+        let NetworkConditions {
+            min_delay,
+            max_delay,
+            drop_rate,
+        } = self.config.network;
+
+        if self.partitions[from] != self.partitions[to] || self.rng.gen_bool(drop_rate) {
+            return;
+        }
+
+        let delay = self.rng.gen_range(min_delay..=max_delay);
+        self.in_flight.insert(
+            (self.clock.now() + delay, self.sent_messages),
+            Delivery { to, message },
+        );
+        self.sent_messages += 1;
+    }
+
+    fn peer_index(&self, peer_id: &PeerId) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| &node.sumeragi.peer_id == peer_id)
+    }
+
+    fn check_safety(&mut self) -> Result<(), Violation> {
+        for (peer, node) in self.nodes.iter().enumerate() {
+            let block_hashes = &node.sumeragi.wsv.block_hashes;
+            // NOTE: Top block isn't final, it can still be replaced by a soft fork
+            let finalized = block_hashes.len().saturating_sub(1);
+
+            for (index, &actual) in block_hashes[..finalized].iter().enumerate() {
+                match self.finalized_blocks.get(index) {
+                    Some(&expected) if expected != actual => {
+                        return Err(Violation::Safety {
+                            peer,
+                            height: index as u64 + 1,
+                            expected,
+                            actual,
+                        });
+                    }
+                    Some(_) => {}
+                    None => self.finalized_blocks.push(actual),
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(120);
+
+    fn commit_blocks(simulation: &mut Simulation, blocks: u64) {
+        let height = simulation.heights().into_iter().max().expect("Not empty");
+        for height in height + 1..=height + blocks {
+            simulation.submit_transaction();
+            simulation
+                .run_until_height(height, TIMEOUT)
+                .unwrap_or_else(|violation| panic!("{violation}"));
+        }
+    }
+
+    #[tokio::test]
+    async fn blocks_are_committed() {
+        let mut simulation = Simulation::new(SimulationConfig::default());
+
+        commit_blocks(&mut simulation, 5);
+
+        assert_eq!(simulation.heights(), vec![6; 4]);
+    }
+
+    #[tokio::test]
+    async fn lossy_network_is_live() {
+        let mut simulation = Simulation::new(SimulationConfig {
+            seed: 7,
+            network: NetworkConditions {
+                min_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(300),
+                drop_rate: 0.1,
+            },
+            ..SimulationConfig::default()
+        });
+
+        commit_blocks(&mut simulation, 5);
+    }
+
+    #[tokio::test]
+    async fn partitioned_peer_catches_up() {
+        let mut simulation = Simulation::new(SimulationConfig {
+            seed: 13,
+            ..SimulationConfig::default()
+        });
+        commit_blocks(&mut simulation, 1);
+
+        for isolated in 0..4 {
+            simulation.partition(&[&[isolated]]);
+            simulation.submit_transaction();
+            simulation
+                .run_for(Duration::from_secs(10))
+                .unwrap_or_else(|violation| panic!("{violation}"));
+            simulation.heal();
+        }
+
+        let height = simulation.heights().into_iter().max().expect("Not empty");
+        simulation
+            .run_until_height(height, TIMEOUT)
+            .unwrap_or_else(|violation| panic!("{violation}"));
+    }
+
+    #[tokio::test]
+    async fn same_seed_reproduces_run() {
+        let run = || {
+            let mut simulation = Simulation::new(SimulationConfig {
+                seed: 21,
+                network: NetworkConditions {
+                    drop_rate: 0.2,
+                    ..NetworkConditions::default()
+                },
+                ..SimulationConfig::default()
+            });
+            simulation.submit_transaction();
+            simulation.submit_transaction();
+
+            let mut heights = Vec::new();
+            for _ in 0..1000 {
+                simulation
+                    .tick()
+                    .unwrap_or_else(|violation| panic!("{violation}"));
+                heights.push(simulation.heights());
+            }
+            let block_hashes = simulation
+                .nodes
+                .iter()
+                .map(|node| node.sumeragi.wsv.block_hashes.clone())
+                .collect::<Vec<_>>();
+            (heights, block_hashes)
+        };
+
+        let (heights, block_hashes) = run();
+        assert!(
+            block_hashes.iter().any(|hashes| hashes.len() > 1),
+            "Blocks after genesis should be committed"
+        );
+        assert_eq!((heights, block_hashes), run());
+    }
+
+    #[cfg(feature = "byzantine")]
+    #[tokio::test]
+    async fn byzantine_peer_is_tolerated() {
+        let mut simulation = Simulation::new(SimulationConfig {
+            seed: 3,
+            ..SimulationConfig::default()
+        });
+        simulation.set_byzantine_faults(
+            1,
+            ByzantineFaults {
+                equivocate_block_created: true,
+                withhold_block_signed: true,
+                invalid_view_change_proofs: true,
+                lie_in_share_blocks: true,
+            },
+        );
+
+        commit_blocks(&mut simulation, 5);
+    }
+}
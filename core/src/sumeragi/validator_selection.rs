@@ -0,0 +1,386 @@
+//! Policies selecting which of the registered peers take part in consensus.
+//!
+//! By default every registered peer is a validator. Setting the `ValidatorSetSize` parameter
+//! limits the number of validators, which are then re-selected among all registered peers
+//! at the end of every epoch of `EpochLength` blocks. Peers which were not selected keep
+//! following the chain through block synchronisation until they are promoted.
+
+use std::collections::HashMap;
+
+use iroha_crypto::{KeyPair, Signature};
+use iroha_data_model::{asset::AssetValue, prelude::*};
+use parity_scale_codec::{DecodeAll as _, Encode as _};
+
+use crate::{
+    wsv::{World, WorldStateView},
+    PeersIds,
+};
+
+/// Epoch length (in blocks) used if the `EpochLength` parameter isn't set.
+pub const DEFAULT_EPOCH_LENGTH: u64 = 100;
+
+/// Key of the account metadata entry by which a peer agrees to be backed by the stake of the account,
+/// see [`stake_delegation`].
+pub const STAKE_DELEGATION_KEY: &str = "validator_stake_delegation";
+
+/// Value of the [`STAKE_DELEGATION_KEY`] metadata entry of the account with `account_id`,
+/// signed with the `key_pair` of the peer which agrees to be backed by the stake of the account.
+pub fn stake_delegation(chain_id: &ChainId, account_id: &AccountId, key_pair: &KeyPair) -> Value {
+    let signature = Signature::new(key_pair, &stake_delegation_payload(chain_id, account_id));
+    Value::String(hex::encode(signature.encode()))
+}
+
+fn stake_delegation_payload(chain_id: &ChainId, account_id: &AccountId) -> Vec<u8> {
+    (STAKE_DELEGATION_KEY, chain_id, account_id).encode()
+}
+
+/// Rule to select the validators of an epoch.
+pub trait ValidatorSelection {
+    /// Select at most `committee_size` validators for the given `epoch` out of the `candidates`.
+    ///
+    /// Must be deterministic as every peer evaluates it independently.
+    fn select(
+        &self,
+        candidates: &PeersIds,
+        committee_size: usize,
+        epoch: u64,
+        world: &World,
+    ) -> PeersIds;
+}
+
+/// Fixed-size committee shifted over the candidates every epoch,
+/// so that every candidate validates the same share of epochs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundRobin;
+
+impl ValidatorSelection for RoundRobin {
+    fn select(
+        &self,
+        candidates: &PeersIds,
+        committee_size: usize,
+        epoch: u64,
+        _world: &World,
+    ) -> PeersIds {
+        if candidates.len() <= committee_size {
+            return candidates.clone();
+        }
+
+        let len = candidates.len() as u64;
+        let start = (epoch % len) * (committee_size as u64) % len;
+        candidates
+            .iter()
+            .cycle()
+            .skip(usize::try_from(start).expect("Less than the number of candidates"))
+            .take(committee_size)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Committee of the candidates with the largest stake.
+///
+/// The stake of a peer is the total quantity of the stake asset held by the accounts
+/// which were delegated to the peer with a [`stake_delegation`] signed by the peer,
+/// so that nobody can move stake to or from a peer without its consent.
+/// Peers with equal stake are ordered by the order of their registration.
+#[derive(Debug, Clone)]
+pub struct StakeWeighted {
+    /// Asset definition of the asset used as stake, must be of a numeric integer type
+    pub stake_asset: AssetDefinitionId,
+    /// Id of the chain, delegations signed for other chains are ignored
+    pub chain_id: ChainId,
+}
+
+impl StakeWeighted {
+    /// Stakes of the peers by their public keys, collected in a single pass over the accounts.
+    fn stakes(&self, world: &World) -> HashMap<PublicKey, u128> {
+        let mut stakes = HashMap::new();
+
+        for account in world
+            .domains
+            .values()
+            .flat_map(|domain| domain.accounts.values())
+        {
+            let Some(peer_public_key) = self.delegate(account) else {
+                continue;
+            };
+            let stake = account
+                .assets
+                .get(&AssetId::new(
+                    self.stake_asset.clone(),
+                    account.id().clone(),
+                ))
+                .map_or(0, |asset| match asset.value {
+                    AssetValue::Quantity(quantity) => quantity.into(),
+                    AssetValue::BigQuantity(quantity) => quantity,
+                    _ => 0,
+                });
+
+            let total: &mut u128 = stakes.entry(peer_public_key).or_default();
+            *total = total.saturating_add(stake);
+        }
+
+        stakes
+    }
+
+    /// Public key of the peer which signed a valid stake delegation of the `account`.
+    fn delegate(&self, account: &Account) -> Option<PublicKey> {
+        let Some(Value::String(delegation)) = account.metadata.get(STAKE_DELEGATION_KEY) else {
+            return None;
+        };
+        let signature = hex::decode(delegation)
+            .ok()
+            .and_then(|bytes| Signature::decode_all(&mut bytes.as_slice()).ok())?;
+        signature
+            .verify(&stake_delegation_payload(&self.chain_id, account.id()))
+            .ok()?;
+
+        Some(signature.public_key().clone())
+    }
+}
+
+impl ValidatorSelection for StakeWeighted {
+    fn select(
+        &self,
+        candidates: &PeersIds,
+        committee_size: usize,
+        _epoch: u64,
+        world: &World,
+    ) -> PeersIds {
+        let stakes = self.stakes(world);
+        let mut by_stake = candidates
+            .iter()
+            .map(|peer_id| {
+                let stake = stakes.get(&peer_id.public_key).copied().unwrap_or_default();
+                (stake, peer_id)
+            })
+            .collect::<Vec<_>>();
+        // NOTE: Sort is stable, so the order of registration is preserved for equal stakes
+        by_stake.sort_by(|(stake1, _), (stake2, _)| stake2.cmp(stake1));
+
+        by_stake
+            .into_iter()
+            .take(committee_size)
+            .map(|(_, peer_id)| peer_id.clone())
+            .collect()
+    }
+}
+
+/// Validator selection configured through the parameters of the [`WorldStateView`]:
+///
+/// - `ValidatorSetSize` -- maximal number of validators, every peer is a validator if not set
+/// - `EpochLength` -- number of blocks after which validators are re-selected
+/// - `ValidatorStakeAsset` -- if set, selects validators with [`StakeWeighted`],
+///   otherwise with [`RoundRobin`]
+pub struct ValidatorSelectionPolicy {
+    committee_size: usize,
+    /// Always greater than zero
+    epoch_length: u64,
+    selection: Box<dyn ValidatorSelection>,
+}
+
+impl ValidatorSelectionPolicy {
+    /// Read the policy from the parameters of the [`WorldStateView`].
+    /// Returns `None` if every peer should validate.
+    pub fn from_wsv(wsv: &WorldStateView) -> Option<Self> {
+        use iroha_data_model::parameter::default::*;
+
+        let committee_size = wsv.query_param::<u32, _>(VALIDATOR_SET_SIZE)? as usize;
+        if committee_size == 0 {
+            return None;
+        }
+        let epoch_length = wsv
+            .query_param(EPOCH_LENGTH)
+            .filter(|epoch_length| *epoch_length > 0)
+            .unwrap_or(DEFAULT_EPOCH_LENGTH);
+        let selection = wsv.query_param(VALIDATOR_STAKE_ASSET).map_or_else(
+            || -> Box<dyn ValidatorSelection> { Box::new(RoundRobin) },
+            |stake_asset| {
+                Box::new(StakeWeighted {
+                    stake_asset,
+                    chain_id: wsv.chain_id.clone(),
+                })
+            },
+        );
+
+        Some(Self {
+            committee_size,
+            epoch_length,
+            selection,
+        })
+    }
+
+    /// Is the block at the given height the last block of an epoch.
+    pub fn is_epoch_end(&self, block_height: u64) -> bool {
+        block_height % self.epoch_length == 0
+    }
+
+    /// Epoch of the block following the block at the given height.
+    pub fn epoch(&self, block_height: u64) -> u64 {
+        block_height / self.epoch_length
+    }
+
+    /// Select the validators of the `epoch` among the registered peers.
+    pub fn select(&self, world: &World, epoch: u64) -> PeersIds {
+        self.selection
+            .select(&world.trusted_peers_ids, self.committee_size, epoch, world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr as _;
+
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+    use crate::{smartcontracts::isi::Registrable as _, sumeragi::network_topology::test_peers};
+
+    fn ports(peers: &PeersIds) -> Vec<u16> {
+        peers.iter().map(|peer| peer.address.port()).collect()
+    }
+
+    #[test]
+    fn round_robin_rotates_committee() {
+        let candidates = test_peers![0, 1, 2, 3, 4];
+        let world = World::default();
+
+        let committees = (0..3)
+            .map(|epoch| ports(&RoundRobin.select(&candidates, 3, epoch, &world)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            committees,
+            vec![vec![0, 1, 2], vec![3, 4, 0], vec![1, 2, 3]]
+        );
+    }
+
+    #[test]
+    fn round_robin_selects_all_if_not_enough_candidates() {
+        let candidates = test_peers![0, 1];
+
+        let committee = RoundRobin.select(&candidates, 3, 7, &World::default());
+
+        assert_eq!(committee, candidates);
+    }
+
+    /// Account holding `stake` whose signatory is the key of the peer signing the `delegation`, if any.
+    fn staking_account(
+        name: &str,
+        stake_asset: &AssetDefinitionId,
+        stake: u32,
+        peer_key_pair: &KeyPair,
+        delegation: Option<(&ChainId, &KeyPair)>,
+    ) -> Account {
+        let owner = AccountId::from_str("owner@validators").expect("Valid");
+        let account_id = AccountId::from_str(name).expect("Valid");
+        let mut metadata = Metadata::new();
+        if let Some((chain_id, key_pair)) = delegation {
+            metadata
+                .insert_with_limits(
+                    STAKE_DELEGATION_KEY.parse().expect("Valid"),
+                    stake_delegation(chain_id, &account_id, key_pair),
+                    MetadataLimits::new(16, 1024),
+                )
+                .expect("Valid");
+        }
+        let mut account = Account::new(account_id.clone(), [peer_key_pair.public_key().clone()])
+            .with_metadata(metadata)
+            .build(&owner);
+        account.add_asset(Asset::new(
+            AssetId::new(stake_asset.clone(), account_id),
+            stake,
+        ));
+        account
+    }
+
+    #[test]
+    fn stake_weighted_selects_largest_stakes() {
+        let key_pairs = core::iter::repeat_with(|| KeyPair::generate().expect("Valid"))
+            .take(4)
+            .collect::<Vec<_>>();
+        let mut key_pairs_iter = key_pairs.iter();
+        let candidates = test_peers![0, 1, 2, 3: key_pairs_iter];
+
+        let chain_id = ChainId::new("0");
+        let stake_asset = AssetDefinitionId::from_str("stake#validators").expect("Valid");
+        let domain_id = DomainId::from_str("validators").expect("Valid");
+        let owner = AccountId::from_str("owner@validators").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&owner);
+        for (name, key_pair, stake) in [
+            ("a@validators", &key_pairs[1], 10_u32),
+            ("b@validators", &key_pairs[3], 20),
+        ] {
+            domain.add_account(staking_account(
+                name,
+                &stake_asset,
+                stake,
+                key_pair,
+                Some((&chain_id, key_pair)),
+            ));
+        }
+        let world = World::with([domain], candidates.clone());
+
+        let committee = StakeWeighted {
+            stake_asset,
+            chain_id,
+        }
+        .select(&candidates, 3, 0, &world);
+
+        assert_eq!(ports(&committee), vec![3, 1, 0]);
+    }
+
+    #[test]
+    fn stake_weighted_ignores_stake_not_delegated_by_peer() {
+        let key_pairs = core::iter::repeat_with(|| KeyPair::generate().expect("Valid"))
+            .take(4)
+            .collect::<Vec<_>>();
+        let mut key_pairs_iter = key_pairs.iter();
+        let candidates = test_peers![0, 1, 2, 3: key_pairs_iter];
+
+        let chain_id = ChainId::new("0");
+        let other_key_pair = KeyPair::generate().expect("Valid");
+        let stake_asset = AssetDefinitionId::from_str("stake#validators").expect("Valid");
+        let domain_id = DomainId::from_str("validators").expect("Valid");
+        let owner = AccountId::from_str("owner@validators").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&owner);
+        for account in [
+            // Peer key added to the signatories without a delegation
+            staking_account("a@validators", &stake_asset, 10, &key_pairs[1], None),
+            // Delegation signed by someone other than the peer
+            staking_account(
+                "b@validators",
+                &stake_asset,
+                20,
+                &key_pairs[2],
+                Some((&chain_id, &other_key_pair)),
+            ),
+            // Delegation signed for another chain
+            staking_account(
+                "c@validators",
+                &stake_asset,
+                30,
+                &key_pairs[3],
+                Some((&ChainId::new("other"), &key_pairs[3])),
+            ),
+            staking_account(
+                "d@validators",
+                &stake_asset,
+                1,
+                &key_pairs[0],
+                Some((&chain_id, &key_pairs[0])),
+            ),
+        ] {
+            domain.add_account(account);
+        }
+        let world = World::with([domain], candidates.clone());
+
+        let committee = StakeWeighted {
+            stake_asset,
+            chain_id,
+        }
+        .select(&candidates, 1, 0, &world);
+
+        assert_eq!(ports(&committee), vec![0]);
+    }
+}
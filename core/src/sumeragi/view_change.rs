@@ -204,3 +204,20 @@ impl ProofChain {
         Ok(())
     }
 }
+
+#[cfg(feature = "byzantine")]
+impl ProofChain {
+    /// Forge a chain of `len` view change proofs signed by a key outside of any topology.
+    /// Only used to inject faults in testing.
+    pub fn forged(latest_block_hash: Option<HashOf<SignedBlock>>, len: u64) -> Self {
+        let key_pair = KeyPair::generate().expect("Failed to generate key pair");
+
+        Self(
+            (0..len)
+                .map(|view_change_index| {
+                    ProofBuilder::new(latest_block_hash, view_change_index).sign(&key_pair)
+                })
+                .collect(),
+        )
+    }
+}
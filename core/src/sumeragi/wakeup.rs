@@ -0,0 +1,77 @@
+//! Notification used to wake up the sumeragi thread once there is new work for it,
+//! so that it doesn't have to poll its inputs while idle.
+
+use std::{sync::Arc, time::Duration};
+
+use parking_lot::{Condvar, Mutex};
+
+/// Wakes up a thread blocked in [`Wakeup::wait`] or [`Wakeup::wait_timeout`].
+///
+/// A notification sent while nobody waits is not lost: the next wait returns immediately.
+/// Clones notify the same waiter.
+#[derive(Debug, Clone, Default)]
+pub struct Wakeup(Arc<(Mutex<bool>, Condvar)>);
+
+impl Wakeup {
+    /// Wake up the waiting thread.
+    pub fn notify(&self) {
+        let (notified, condvar) = &*self.0;
+        *notified.lock() = true;
+        condvar.notify_one();
+    }
+
+    /// Block until notified.
+    pub fn wait(&self) {
+        let (notified, condvar) = &*self.0;
+        let mut notified = notified.lock();
+        while !*notified {
+            condvar.wait(&mut notified);
+        }
+        *notified = false;
+    }
+
+    /// Block until notified or until `timeout` elapses.
+    ///
+    /// Returns `true` if notified.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let (notified, condvar) = &*self.0;
+        let mut notified = notified.lock();
+        if !*notified {
+            condvar.wait_while_for(&mut notified, |notified| !*notified, timeout);
+        }
+        core::mem::take(&mut *notified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Instant};
+
+    use super::*;
+
+    #[test]
+    fn notification_before_wait_is_not_lost() {
+        let wakeup = Wakeup::default();
+
+        wakeup.notify();
+
+        assert!(wakeup.wait_timeout(Duration::from_secs(10)));
+        assert!(!wakeup.wait_timeout(Duration::ZERO));
+    }
+
+    #[test]
+    fn notification_wakes_up_waiting_thread() {
+        let wakeup = Wakeup::default();
+        let notifier = wakeup.clone();
+        let started_at = Instant::now();
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            notifier.notify();
+        });
+
+        assert!(wakeup.wait_timeout(Duration::from_secs(10)));
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+        handle.join().expect("Notifier thread panicked");
+    }
+}
@@ -43,8 +43,9 @@ use crate::{
         },
         wasm, Execute,
     },
+    sumeragi::validator_selection::ValidatorSelectionPolicy,
     tx::TransactionExecutor,
-    DomainsMap, Parameters, PeersIds,
+    DomainsMap, NftOwnersMap, Parameters, PeersIds, RetiredPeerKeys,
 };
 
 /// The global entity consisting of `domains`, `triggers` and etc.
@@ -55,6 +56,10 @@ pub struct World {
     pub(crate) parameters: Parameters,
     /// Identifications of discovered trusted peers.
     pub(crate) trusted_peers_ids: PeersIds,
+    /// Identifications of the peers taking part in consensus in the current epoch.
+    pub(crate) validators: PeersIds,
+    /// Keys replaced with `RotatePeerKey`, which can never be used by peers again.
+    pub(crate) retired_peer_keys: RetiredPeerKeys,
     /// Registered domains.
     pub(crate) domains: DomainsMap,
     /// Roles. [`Role`] pairs.
@@ -69,6 +74,10 @@ pub struct World {
     pub(crate) triggers: TriggerSet,
     /// Runtime Executor
     pub(crate) executor: Executor,
+    /// Owners of the non-fungible tokens.
+    /// Derived from `domains`, therefore it's rebuilt instead of being stored in snapshots.
+    #[serde(skip)]
+    pub(crate) nft_owners: NftOwnersMap,
 }
 
 // Loader for [`Set`]
@@ -138,6 +147,7 @@ where
 impl<'de> DeserializeSeed<'de> for WasmSeed<'_, World> {
     type Value = World;
 
+    #[allow(clippy::too_many_lines)]
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -159,6 +169,8 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, World> {
             {
                 let mut parameters = None;
                 let mut trusted_peers_ids = None;
+                let mut validators = None;
+                let mut retired_peer_keys = None;
                 let mut domains = None;
                 let mut roles = None;
                 let mut account_permission_tokens = None;
@@ -175,6 +187,12 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, World> {
                         "trusted_peers_ids" => {
                             trusted_peers_ids = Some(map.next_value()?);
                         }
+                        "validators" => {
+                            validators = Some(map.next_value()?);
+                        }
+                        "retired_peer_keys" => {
+                            retired_peer_keys = Some(map.next_value()?);
+                        }
                         "domains" => {
                             domains = Some(map.next_value()?);
                         }
@@ -200,12 +218,20 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, World> {
                     }
                 }
 
+                let domains: DomainsMap =
+                    domains.ok_or_else(|| serde::de::Error::missing_field("domains"))?;
+                let trusted_peers_ids: PeersIds = trusted_peers_ids
+                    .ok_or_else(|| serde::de::Error::missing_field("trusted_peers_ids"))?;
                 Ok(World {
+                    nft_owners: World::index_nfts(&domains),
                     parameters: parameters
                         .ok_or_else(|| serde::de::Error::missing_field("parameters"))?,
-                    trusted_peers_ids: trusted_peers_ids
-                        .ok_or_else(|| serde::de::Error::missing_field("trusted_peers_ids"))?,
-                    domains: domains.ok_or_else(|| serde::de::Error::missing_field("domains"))?,
+                    // Before the validator set existed every trusted peer validated
+                    validators: validators.unwrap_or_else(|| trusted_peers_ids.clone()),
+                    trusted_peers_ids,
+                    // Snapshots taken before peer retirement existed have no retired keys
+                    retired_peer_keys: retired_peer_keys.unwrap_or_default(),
+                    domains,
                     roles: roles.ok_or_else(|| serde::de::Error::missing_field("roles"))?,
                     account_permission_tokens: account_permission_tokens.ok_or_else(|| {
                         serde::de::Error::missing_field("account_permission_tokens")
@@ -228,6 +254,8 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, World> {
             &[
                 "parameters",
                 "trusted_peers_ids",
+                "validators",
+                "retired_peer_keys",
                 "domains",
                 "roles",
                 "account_permission_tokens",
@@ -257,16 +285,58 @@ impl World {
             .map(|domain| (domain.id().clone(), domain))
             .collect();
         World {
+            validators: trusted_peers_ids.clone(),
             trusted_peers_ids,
+            nft_owners: Self::index_nfts(&domains),
             domains,
             ..World::new()
         }
     }
+
+    /// Index the owners of all [`Nft`]s held by the accounts of `domains`.
+    fn index_nfts(domains: &DomainsMap) -> NftOwnersMap {
+        domains
+            .values()
+            .flat_map(|domain| domain.accounts.values())
+            .flat_map(Self::held_nfts)
+            .collect()
+    }
+
+    /// Remove the [`Nft`]s held by the unregistered `account` from the index of their owners.
+    pub(crate) fn unindex_nfts(&mut self, account: &Account) {
+        for (token, _) in Self::held_nfts(account) {
+            self.nft_owners.remove(&token);
+        }
+    }
+
+    /// Entries of the index of [`Nft`] owners for the tokens held by `account`
+    fn held_nfts(
+        account: &Account,
+    ) -> impl Iterator<Item = ((AssetDefinitionId, Name), AccountId)> + '_ {
+        account
+            .assets
+            .values()
+            .filter_map(|asset| match &asset.value {
+                AssetValue::Nft(nfts) => Some((&asset.id, nfts)),
+                _ => None,
+            })
+            .flat_map(|(asset_id, nfts)| {
+                nfts.iter().map(|nft| {
+                    (
+                        (asset_id.definition_id.clone(), nft.token_id.clone()),
+                        asset_id.account_id.clone(),
+                    )
+                })
+            })
+    }
 }
 
 /// Current state of the blockchain aligned with `Iroha` module.
 #[derive(Serialize)]
 pub struct WorldStateView {
+    /// Id of the chain this state belongs to.
+    #[serde(skip)]
+    pub chain_id: ChainId,
     /// The world. Contains `domains`, `triggers`, `roles` and other data representing the current state of the blockchain.
     pub world: World,
     /// Configuration of World State View.
@@ -295,6 +365,8 @@ pub struct WorldStateView {
 
 /// Context necessary for deserializing [`WorldStateView`]
 pub struct KuraSeed {
+    /// Id of the chain the state belongs to
+    pub chain_id: ChainId,
     /// Kura subsystem reference
     pub kura: Arc<Kura>,
     /// Handle to the [`LiveQueryStore`](crate::query::store::LiveQueryStore).
@@ -354,6 +426,7 @@ impl<'de> DeserializeSeed<'de> for KuraSeed {
                 }
 
                 Ok(WorldStateView {
+                    chain_id: self.loader.chain_id,
                     world: world.ok_or_else(|| serde::de::Error::missing_field("world"))?,
                     config: config.ok_or_else(|| serde::de::Error::missing_field("config"))?,
                     block_hashes: block_hashes
@@ -380,6 +453,7 @@ impl<'de> DeserializeSeed<'de> for KuraSeed {
 impl Clone for WorldStateView {
     fn clone(&self) -> Self {
         Self {
+            chain_id: self.chain_id.clone(),
             world: Clone::clone(&self.world),
             config: self.config,
             block_hashes: self.block_hashes.clone(),
@@ -395,7 +469,7 @@ impl Clone for WorldStateView {
 
 /// WARNING!!! INTERNAL USE ONLY!!!
 impl WorldStateView {
-    /// Construct [`WorldStateView`] with given [`World`].
+    /// Construct [`WorldStateView`] with given [`World`] for the chain with id `0`.
     #[must_use]
     #[inline]
     pub fn new(world: World, kura: Arc<Kura>, query_handle: LiveQueryStoreHandle) -> Self {
@@ -403,7 +477,7 @@ impl WorldStateView {
         let config = ConfigurationProxy::default()
             .build()
             .expect("Wsv proxy always builds");
-        Self::from_configuration(config, world, kura, query_handle)
+        Self::from_configuration(ChainId::new("0"), config, world, kura, query_handle)
     }
 
     /// Get `Account`'s `Asset`s
@@ -675,10 +749,35 @@ impl WorldStateView {
         self.block_hashes.push(block_hash);
 
         self.apply_parameters();
+        self.update_validators(block_height);
 
         Ok(())
     }
 
+    /// Re-select validators at the end of an epoch, see [`ValidatorSelectionPolicy`].
+    ///
+    /// Validators which are no longer registered are removed immediately,
+    /// while newly registered peers have to wait for the next epoch.
+    fn update_validators(&mut self, block_height: u64) {
+        let Some(policy) = ValidatorSelectionPolicy::from_wsv(self) else {
+            self.world.validators = self.world.trusted_peers_ids.clone();
+            return;
+        };
+
+        let world = &mut self.world;
+        world.validators = world
+            .validators
+            .iter()
+            .filter(|peer_id| world.trusted_peers_ids.contains(peer_id))
+            .cloned()
+            .collect();
+
+        // NOTE: If all validators of the current epoch are gone, reselect them to avoid halting consensus
+        if policy.is_epoch_end(block_height) || world.validators.is_empty() {
+            world.validators = policy.select(world, policy.epoch(block_height));
+        }
+    }
+
     fn apply_parameters(&mut self) {
         use iroha_data_model::parameter::default::*;
         macro_rules! update_params {
@@ -924,12 +1023,14 @@ impl WorldStateView {
     /// Construct [`WorldStateView`] with specific [`Configuration`].
     #[inline]
     pub fn from_configuration(
+        chain_id: ChainId,
         config: Configuration,
         world: World,
         kura: Arc<Kura>,
         query_handle: LiveQueryStoreHandle,
     ) -> Self {
         Self {
+            chain_id,
             world,
             config,
             transactions: IndexMap::new(),
@@ -1020,6 +1121,17 @@ impl WorldStateView {
         })
     }
 
+    /// Get the account holding the [`Nft`] with `token_id` of the asset definition with `definition_id`.
+    pub fn nft_owner(
+        &self,
+        definition_id: &AssetDefinitionId,
+        token_id: &Name,
+    ) -> Option<&AccountId> {
+        self.world
+            .nft_owners
+            .get(&(definition_id.clone(), token_id.clone()))
+    }
+
     /// Get mutable reference to [`Account`]
     ///
     /// # Errors
@@ -1067,6 +1179,11 @@ impl WorldStateView {
         self.world.trusted_peers_ids.iter()
     }
 
+    /// Get an immutable iterator over the [`PeerId`]s of the validators of the current epoch.
+    pub fn validators(&self) -> impl ExactSizeIterator<Item = &PeerId> {
+        self.world.validators.iter()
+    }
+
     /// Get all `Parameter`s registered in the world.
     pub fn parameters(&self) -> impl ExactSizeIterator<Item = &Parameter> {
         self.world.parameters.iter()
@@ -1364,12 +1481,15 @@ mod range_bounds {
 
 #[cfg(test)]
 mod tests {
+    use iroha_crypto::KeyPair;
     use iroha_primitives::unique_vec::UniqueVec;
 
     use super::*;
     use crate::{
-        block::ValidBlock, query::store::LiveQueryStore, role::RoleIdWithOwner,
-        sumeragi::network_topology::Topology,
+        block::ValidBlock,
+        query::store::LiveQueryStore,
+        role::RoleIdWithOwner,
+        sumeragi::network_topology::{test_peers, Topology},
     };
 
     #[tokio::test]
@@ -1428,6 +1548,57 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn validators_are_reselected_at_epoch_boundary() {
+        use iroha_data_model::parameter::default::{EPOCH_LENGTH, VALIDATOR_SET_SIZE};
+
+        const EPOCH_LENGTH_IN_BLOCKS: u64 = 3;
+
+        let peers = test_peers![0, 1, 2, 3, 4];
+        let mut world = World::with([], peers.clone());
+        for (id, val) in [
+            (VALIDATOR_SET_SIZE, Value::from(2_u32)),
+            (EPOCH_LENGTH, Value::from(EPOCH_LENGTH_IN_BLOCKS)),
+        ] {
+            world.parameters.insert(Parameter {
+                id: id.parse().unwrap(),
+                val: Box::new(val),
+            });
+        }
+        let topology = Topology::new(UniqueVec::new());
+        let block = ValidBlock::new_dummy().commit(&topology).unwrap();
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let mut wsv = WorldStateView::new(world, kura, query_handle);
+
+        let mut validators = vec![];
+        for height in 1..=2 * EPOCH_LENGTH_IN_BLOCKS {
+            let mut block = block.clone();
+            block.0.payload_mut().header.height = height;
+
+            wsv.apply(&block).unwrap();
+            validators.push(
+                wsv.validators()
+                    .map(|peer_id| peer_id.address.port())
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        // Validators change only with the last block of an epoch
+        assert_eq!(
+            validators,
+            vec![
+                vec![0, 1, 2, 3, 4],
+                vec![0, 1, 2, 3, 4],
+                vec![2, 3],
+                vec![2, 3],
+                vec![2, 3],
+                vec![4, 0],
+            ]
+        );
+        assert_eq!(wsv.peers().cloned().collect::<Vec<_>>(), peers.to_vec());
+    }
+
     #[test]
     fn role_account_range() {
         let account_id: AccountId = "alice@wonderland".parse().unwrap();
@@ -252,6 +252,31 @@ impl Network {
         n_peers: u32,
         offline_peers: u32,
         start_port: Option<u16>,
+    ) -> Result<Self> {
+        Self::new_with_peer_configuration(
+            default_configuration,
+            n_peers,
+            offline_peers,
+            start_port,
+            |_, configuration| configuration,
+        )
+        .await
+    }
+
+    /// Creates new network with the configuration of the `n`-th non-genesis peer
+    /// given by `configure_peer(n, default_configuration)`.
+    ///
+    /// # Panics
+    /// Same as [`Self::new_with_offline_peers`]
+    ///
+    /// # Errors
+    /// Same as [`Self::new_with_offline_peers`]
+    pub async fn new_with_peer_configuration(
+        default_configuration: Option<Configuration>,
+        n_peers: u32,
+        offline_peers: u32,
+        start_port: Option<u16>,
+        configure_peer: impl Fn(usize, Configuration) -> Configuration,
     ) -> Result<Self> {
         let mut builders = core::iter::repeat_with(PeerBuilder::new)
             .enumerate()
@@ -287,14 +312,15 @@ impl Network {
 
         futures.push(genesis_builder.start_with_peer(&mut genesis_peer));
 
-        for (builder, peer) in builders
+        for (n, (builder, peer)) in builders
             .into_iter()
             .zip(peers.iter_mut())
+            .enumerate()
             .choose_multiple(rng, online_peers as usize)
         {
             futures.push(
                 builder
-                    .with_configuration(configuration.clone())
+                    .with_configuration(configure_peer(n, configuration.clone()))
                     .start_with_peer(peer),
             );
         }
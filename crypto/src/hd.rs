@@ -0,0 +1,413 @@
+//! Hierarchical deterministic key derivation ([SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md),
+//! which is compatible with [BIP-0032](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) for `secp256k1`)
+//! and [BIP-0039](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki) mnemonic phrases.
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned as _, boxed::Box, format, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use hmac::{Hmac, Mac as _};
+use k256::elliptic_curve::PrimeField as _;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use zeroize::Zeroize as _;
+
+use crate::{signature, Algorithm, Error, ParseError, PrivateKey, PrivateKeyInner};
+
+type HmacSha512 = Hmac<sha2::Sha512>;
+
+/// Index of a child key. Indices starting from [`ChildIndex::HARDENED_OFFSET`] denote hardened keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChildIndex(u32);
+
+impl ChildIndex {
+    /// Offset of the hardened indices
+    pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+    /// Construct normal (non-hardened) index
+    ///
+    /// # Errors
+    /// If `index` doesn't fit below [`Self::HARDENED_OFFSET`]
+    pub fn normal(index: u32) -> Result<Self, ParseError> {
+        if index >= Self::HARDENED_OFFSET {
+            return Err(ParseError(format!("Child index {index} is too big")));
+        }
+        Ok(Self(index))
+    }
+
+    /// Construct hardened index
+    ///
+    /// # Errors
+    /// If `index` doesn't fit below [`Self::HARDENED_OFFSET`]
+    pub fn hardened(index: u32) -> Result<Self, ParseError> {
+        Self::normal(index).map(|Self(index)| Self(index | Self::HARDENED_OFFSET))
+    }
+
+    /// Whether the index denotes a hardened key
+    pub const fn is_hardened(self) -> bool {
+        self.0 >= Self::HARDENED_OFFSET
+    }
+
+    fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl fmt::Display for ChildIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_hardened() {
+            write!(f, "{}'", self.0 - Self::HARDENED_OFFSET)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl FromStr for ChildIndex {
+    type Err = ParseError;
+
+    fn from_str(index: &str) -> Result<Self, Self::Err> {
+        let (index, hardened) = index
+            .strip_suffix(['\'', 'h', 'H'])
+            .map_or((index, false), |index| (index, true));
+        let index = index
+            .parse()
+            .map_err(|_| ParseError(format!("Invalid child index `{index}`")))?;
+
+        if hardened {
+            Self::hardened(index)
+        } else {
+            Self::normal(index)
+        }
+    }
+}
+
+/// Path of a child key from the master key, e.g. `m/44'/0'/0'/0/0`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, DeserializeFromStr, SerializeDisplay)]
+pub struct DerivationPath(Vec<ChildIndex>);
+
+impl DerivationPath {
+    /// Path of the master key
+    pub const fn master() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Extend the path with a child index
+    #[must_use]
+    pub fn child(mut self, index: ChildIndex) -> Self {
+        self.0.push(index);
+        self
+    }
+
+    /// Iterate over indices of the path
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = ChildIndex> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for index in &self.0 {
+            write!(f, "/{index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = ParseError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut indices = path.split('/');
+        if indices.next() != Some("m") {
+            return Err(ParseError(format!(
+                "Derivation path `{path}` must start with `m`"
+            )));
+        }
+
+        indices
+            .map(ChildIndex::from_str)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// [BIP-0039](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki) mnemonic phrase (english wordlist).
+/// The phrase is zeroized on drop.
+#[derive(Clone, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
+pub struct Mnemonic(bip39::Mnemonic);
+
+impl Mnemonic {
+    /// Generate a new random mnemonic with the given number of words (12, 15, 18, 21 or 24)
+    ///
+    /// # Errors
+    /// If the number of words is not supported
+    #[cfg(feature = "rand")]
+    pub fn generate(word_count: usize) -> Result<Self, Error> {
+        use rand::RngCore as _;
+
+        let mut entropy = [0_u8; 32];
+        let entropy_len = word_count / 3 * 4;
+        if word_count % 3 != 0 || !(16..=32).contains(&entropy_len) {
+            return Err(Error::KeyGen(format!(
+                "Mnemonic can't consist of {word_count} words"
+            )));
+        }
+
+        rand::rngs::OsRng.fill_bytes(&mut entropy[..entropy_len]);
+        let mnemonic = Self::from_entropy(&entropy[..entropy_len]);
+        entropy.zeroize();
+        mnemonic
+    }
+
+    /// Construct mnemonic encoding the given entropy (16 to 32 bytes, multiple of 4)
+    ///
+    /// # Errors
+    /// If the entropy has invalid length
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, Error> {
+        bip39::Mnemonic::from_entropy(entropy)
+            .map(Self)
+            .map_err(|err| Error::KeyGen(format!("{err}")))
+    }
+
+    /// Derive a 64 byte seed suitable for [`KeyGenConfiguration::from_seed`](crate::KeyGenConfiguration::from_seed)
+    /// together with a derivation path.
+    ///
+    /// `passphrase` is expected to be in Unicode normalization form KD (plain ASCII always is).
+    pub fn to_seed(&self, passphrase: &str) -> Vec<u8> {
+        self.0.to_seed_normalized(passphrase).to_vec()
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Mnemonic(<redacted>)")
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = ParseError;
+
+    fn from_str(mnemonic: &str) -> Result<Self, Self::Err> {
+        bip39::Mnemonic::parse_normalized(mnemonic)
+            .map(Self)
+            .map_err(|err| ParseError(format!("{err}")))
+    }
+}
+
+/// Derive the private key at `path` from the master `seed`.
+///
+/// # Errors
+/// - Algorithm doesn't support hierarchical derivation (only `ed25519` and `secp256k1` do)
+/// - Non-hardened derivation is requested for `ed25519`
+pub(crate) fn derive_private_key(
+    algorithm: Algorithm,
+    seed: &[u8],
+    path: &DerivationPath,
+) -> Result<PrivateKey, Error> {
+    let inner = match algorithm {
+        Algorithm::Ed25519 => PrivateKeyInner::Ed25519(derive_ed25519(seed, path)?),
+        Algorithm::Secp256k1 => PrivateKeyInner::Secp256k1(derive_secp256k1(seed, path)),
+        _ => {
+            return Err(Error::KeyGen(format!(
+                "Hierarchical derivation is not supported for {algorithm}"
+            )))
+        }
+    };
+
+    Ok(PrivateKey(Box::new(inner)))
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for data in data {
+        mac.update(data);
+    }
+    let output = mac.finalize().into_bytes();
+
+    let (mut left, mut right) = ([0_u8; 32], [0_u8; 32]);
+    left.copy_from_slice(&output[..32]);
+    right.copy_from_slice(&output[32..]);
+    (left, right)
+}
+
+fn derive_ed25519(
+    seed: &[u8],
+    path: &DerivationPath,
+) -> Result<signature::ed25519::PrivateKey, Error> {
+    let (mut key, mut chain_code) = hmac_sha512(b"ed25519 seed", &[seed]);
+
+    for index in path.iter() {
+        if !index.is_hardened() {
+            key.zeroize();
+            return Err(Error::KeyGen(
+                "Only hardened derivation is supported for ed25519".to_owned(),
+            ));
+        }
+
+        let (child_key, child_chain_code) =
+            hmac_sha512(&chain_code, &[&[0], &key, &index.to_be_bytes()]);
+        key.zeroize();
+        (key, chain_code) = (child_key, child_chain_code);
+    }
+
+    let private_key = signature::ed25519::PrivateKey::from_bytes(&key);
+    key.zeroize();
+    Ok(private_key)
+}
+
+fn derive_secp256k1(seed: &[u8], path: &DerivationPath) -> signature::secp256k1::PrivateKey {
+    const CURVE_SEED: &[u8] = b"Bitcoin seed";
+
+    // NOTE: Retry as specified by SLIP-0010 in the (astronomically unlikely) case of an invalid key
+    let (mut key, mut chain_code) = hmac_sha512(CURVE_SEED, &[seed]);
+    let mut key = loop {
+        if let Some(key) = nonzero_scalar(&key) {
+            break key;
+        }
+        (key, chain_code) = hmac_sha512(CURVE_SEED, &[&key, &chain_code]);
+    };
+
+    for index in path.iter() {
+        let (mut tweak, mut child_chain_code) = if index.is_hardened() {
+            hmac_sha512(&chain_code, &[&[0], &key.to_repr(), &index.to_be_bytes()])
+        } else {
+            let public_key = k256::PublicKey::from_secret_scalar(&key).to_sec1_bytes();
+            hmac_sha512(&chain_code, &[&public_key, &index.to_be_bytes()])
+        };
+
+        key = loop {
+            let child_key = Option::<k256::Scalar>::from(k256::Scalar::from_repr(tweak.into()))
+                .and_then(|tweak| Option::from(k256::NonZeroScalar::new(*key + tweak)));
+            if let Some(child_key) = child_key {
+                break child_key;
+            }
+            (tweak, child_chain_code) = hmac_sha512(
+                &chain_code,
+                &[&[1], &child_chain_code, &index.to_be_bytes()],
+            );
+        };
+        chain_code = child_chain_code;
+    }
+
+    k256::SecretKey::from(key)
+}
+
+fn nonzero_scalar(bytes: &[u8; 32]) -> Option<k256::NonZeroScalar> {
+    Option::<k256::Scalar>::from(k256::Scalar::from_repr((*bytes).into()))
+        .and_then(|scalar| Option::from(k256::NonZeroScalar::new(scalar)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyGenConfiguration, KeyPair};
+
+    fn derive(algorithm: Algorithm, seed: &str, path: &str) -> KeyPair {
+        KeyPair::generate_with_configuration(
+            KeyGenConfiguration::from_seed(hex::decode(seed).unwrap())
+                .with_derivation_path(path.parse().unwrap())
+                .with_algorithm(algorithm),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn derivation_path_parse_display() {
+        let path: DerivationPath = "m/44'/0h/1/2H".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/0'/1/2'");
+        assert_eq!(
+            path,
+            DerivationPath::master()
+                .child(ChildIndex::hardened(44).unwrap())
+                .child(ChildIndex::hardened(0).unwrap())
+                .child(ChildIndex::normal(1).unwrap())
+                .child(ChildIndex::hardened(2).unwrap())
+        );
+
+        assert!("44'/0'".parse::<DerivationPath>().is_err());
+        assert!("m/2147483648".parse::<DerivationPath>().is_err());
+        assert!("m/a".parse::<DerivationPath>().is_err());
+    }
+
+    // Test vector 1 from SLIP-0010
+    #[test]
+    fn ed25519_slip10_test_vector() {
+        let key_pair = derive(
+            Algorithm::Ed25519,
+            "000102030405060708090a0b0c0d0e0f",
+            "m/0'",
+        );
+        let expected = PrivateKey::from_hex(
+            Algorithm::Ed25519,
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a38c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c",
+        )
+        .unwrap();
+        assert_eq!(*key_pair.private_key(), expected);
+
+        assert!(KeyPair::generate_with_configuration(
+            KeyGenConfiguration::from_seed(vec![0; 16])
+                .with_derivation_path("m/0".parse().unwrap())
+        )
+        .is_err());
+    }
+
+    // Test vector 1 from BIP-0032
+    #[test]
+    fn secp256k1_bip32_test_vector() {
+        let key_pair = derive(
+            Algorithm::Secp256k1,
+            "000102030405060708090a0b0c0d0e0f",
+            "m/0'/1",
+        );
+        let expected = PrivateKey::from_hex(
+            Algorithm::Secp256k1,
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+        )
+        .unwrap();
+        assert_eq!(*key_pair.private_key(), expected);
+    }
+
+    #[test]
+    fn derivation_is_not_supported_for_bls() {
+        assert!(KeyPair::generate_with_configuration(
+            KeyGenConfiguration::from_seed(vec![0; 64])
+                .with_derivation_path(DerivationPath::master())
+                .with_algorithm(Algorithm::BlsNormal)
+        )
+        .is_err());
+    }
+
+    // Test vector from BIP-0039 (https://github.com/trezor/python-mnemonic/blob/master/vectors.json)
+    #[test]
+    fn bip39_test_vector() {
+        let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+        assert_eq!(
+            mnemonic.to_string(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+        assert_eq!(mnemonic.to_string().parse::<Mnemonic>().unwrap(), mnemonic);
+        assert_eq!(
+            hex::encode(mnemonic.to_seed("TREZOR")),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+
+        assert!("abandon abandon".parse::<Mnemonic>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn generated_mnemonic_round_trip() {
+        let mnemonic = Mnemonic::generate(24).unwrap();
+        assert_eq!(mnemonic.to_string().split(' ').count(), 24);
+        assert_eq!(mnemonic.to_string().parse::<Mnemonic>().unwrap(), mnemonic);
+
+        assert!(Mnemonic::generate(13).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Password protected storage of a private key.
+//!
+//! The private key is encrypted with [`ChaCha20Poly1305`] under a key derived from the password
+//! with the memory-hard [scrypt](https://www.rfc-editor.org/rfc/rfc7914) function.
+//! The public key is stored in plaintext and authenticated as associated data,
+//! so it can be inspected without the password.
+//!
+//! ```
+//! use iroha_crypto::{keystore::{Keystore, ScryptParams}, KeyPair};
+//!
+//! let key_pair = KeyPair::generate().unwrap();
+//! // NOTE: Weak parameters to keep the example fast, use the default ones in production
+//! let keystore = Keystore::encrypt(&key_pair, b"password", ScryptParams::new(4, 8, 1)).unwrap();
+//!
+//! assert_eq!(keystore.public_key(), key_pair.public_key());
+//! assert_eq!(keystore.decrypt(b"password").unwrap(), key_pair);
+//! assert!(keystore.decrypt(b"wrong password").is_err());
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString as _, vec, vec::Vec};
+
+use displaydoc::Display;
+use rand::{rngs::OsRng, RngCore as _};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize as _;
+
+use crate::{
+    encryption::{self, ChaCha20Poly1305, SymmetricEncryptor},
+    KeyPair, PrivateKey, PublicKey,
+};
+
+/// Version of the keystore format
+const VERSION: u8 = 1;
+const SALT_SIZE: usize = 32;
+const KEY_SIZE: usize = 32;
+
+/// An error that can occur when working with a [`Keystore`]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[derive(Display, Debug)]
+pub enum Error {
+    /// Keystore version {0} is not supported
+    UnsupportedVersion(u8),
+    /// Invalid scrypt parameters
+    InvalidKdfParams,
+    /// Failed to encrypt the private key
+    Encryption(#[cfg_attr(feature = "std", source)] encryption::Error),
+    /// Failed to decrypt the private key, the password is wrong or the keystore is corrupted
+    Decryption,
+    /// Decrypted private key is invalid
+    InvalidPrivateKey(#[cfg_attr(feature = "std", source)] crate::Error),
+}
+
+/// Parameters of the scrypt key derivation function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    /// Base 2 logarithm of the CPU/memory cost
+    pub log_n: u8,
+    /// Block size
+    pub r: u32,
+    /// Parallelization
+    pub p: u32,
+}
+
+impl ScryptParams {
+    /// Construct [`Self`]
+    pub const fn new(log_n: u8, r: u32, p: u32) -> Self {
+        Self { log_n, r, p }
+    }
+
+    fn derive_key(self, password: &[u8], salt: &[u8]) -> Result<[u8; KEY_SIZE], Error> {
+        let params = scrypt::Params::new(self.log_n, self.r, self.p, KEY_SIZE)
+            .map_err(|_| Error::InvalidKdfParams)?;
+
+        let mut key = [0; KEY_SIZE];
+        scrypt::scrypt(password, salt, &params, &mut key).map_err(|_| Error::InvalidKdfParams)?;
+        Ok(key)
+    }
+}
+
+impl Default for ScryptParams {
+    /// 32 MiB of memory, takes a fraction of a second on commodity hardware
+    fn default() -> Self {
+        Self::new(15, 8, 1)
+    }
+}
+
+/// Key derivation function with its parameters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kdf {
+    /// scrypt
+    Scrypt {
+        /// Parameters
+        #[serde(flatten)]
+        params: ScryptParams,
+        /// Random salt
+        #[serde(with = "hex::serde")]
+        salt: Vec<u8>,
+    },
+}
+
+/// Private key encrypted with a password, see the [module-level documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u8,
+    public_key: PublicKey,
+    kdf: Kdf,
+    /// Nonce followed by the encrypted private key
+    #[serde(with = "hex::serde")]
+    ciphertext: Vec<u8>,
+}
+
+impl Keystore {
+    /// Encrypt the private key of `key_pair` with `password`
+    ///
+    /// # Errors
+    /// - Invalid `params`
+    /// - Encryption fails
+    pub fn encrypt(
+        key_pair: &KeyPair,
+        password: &[u8],
+        params: ScryptParams,
+    ) -> Result<Self, Error> {
+        let mut salt = vec![0; SALT_SIZE];
+        OsRng.fill_bytes(&mut salt);
+
+        let mut key = params.derive_key(password, &salt)?;
+        let encryptor = SymmetricEncryptor::<ChaCha20Poly1305>::new_with_key(key);
+        key.zeroize();
+
+        let public_key = key_pair.public_key().clone();
+        let mut plaintext = key_pair.private_key().payload();
+        let ciphertext =
+            encryptor.encrypt_easy(public_key.to_string().as_bytes(), plaintext.as_slice());
+        plaintext.zeroize();
+
+        Ok(Self {
+            version: VERSION,
+            public_key,
+            kdf: Kdf::Scrypt { params, salt },
+            ciphertext: ciphertext.map_err(Error::Encryption)?,
+        })
+    }
+
+    /// Decrypt the key pair with `password`
+    ///
+    /// # Errors
+    /// - Unsupported keystore version
+    /// - Wrong password or the keystore was tampered with
+    /// - Decrypted private key doesn't match the public key
+    pub fn decrypt(&self, password: &[u8]) -> Result<KeyPair, Error> {
+        if self.version != VERSION {
+            return Err(Error::UnsupportedVersion(self.version));
+        }
+
+        let Kdf::Scrypt { params, salt } = &self.kdf;
+        let mut key = params.derive_key(password, salt)?;
+        let encryptor = SymmetricEncryptor::<ChaCha20Poly1305>::new_with_key(key);
+        key.zeroize();
+
+        let mut plaintext = encryptor
+            .decrypt_easy(
+                self.public_key.to_string().as_bytes(),
+                self.ciphertext.as_slice(),
+            )
+            .map_err(|_| Error::Decryption)?;
+        let private_key = PrivateKey::from_raw(self.public_key.algorithm(), &plaintext);
+        plaintext.zeroize();
+
+        let private_key = private_key.map_err(|err| Error::InvalidPrivateKey(err.into()))?;
+        KeyPair::new(self.public_key.clone(), private_key).map_err(Error::InvalidPrivateKey)
+    }
+
+    /// Public key of the stored key pair
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Algorithm, KeyGenConfiguration};
+
+    const PARAMS: ScryptParams = ScryptParams::new(4, 8, 1);
+
+    #[test]
+    fn encrypt_decrypt_all_algorithms() {
+        for algorithm in [
+            Algorithm::Ed25519,
+            Algorithm::Secp256k1,
+            Algorithm::BlsNormal,
+            Algorithm::BlsSmall,
+            Algorithm::Secp256r1,
+            Algorithm::Sm2,
+        ] {
+            let key_pair = KeyPair::generate_with_configuration(
+                KeyGenConfiguration::from_random().with_algorithm(algorithm),
+            )
+            .unwrap();
+
+            let keystore = Keystore::encrypt(&key_pair, b"password", PARAMS).unwrap();
+            let json = serde_json::to_string(&keystore).unwrap();
+            let keystore: Keystore = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(keystore.decrypt(b"password").unwrap(), key_pair);
+        }
+    }
+
+    #[test]
+    fn wrong_password_fails() {
+        let key_pair = KeyPair::generate().unwrap();
+        let keystore = Keystore::encrypt(&key_pair, b"password", PARAMS).unwrap();
+
+        assert!(matches!(
+            keystore.decrypt(b"passw0rd"),
+            Err(Error::Decryption)
+        ));
+    }
+
+    #[test]
+    fn substituted_public_key_fails() {
+        let key_pair = KeyPair::generate().unwrap();
+        let mut keystore = Keystore::encrypt(&key_pair, b"password", PARAMS).unwrap();
+        keystore.public_key = KeyPair::generate().unwrap().public_key().clone();
+
+        assert!(matches!(
+            keystore.decrypt(b"password"),
+            Err(Error::Decryption)
+        ));
+    }
+}
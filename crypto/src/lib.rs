@@ -8,12 +8,18 @@ extern crate alloc;
 pub mod encryption;
 mod hash;
 #[cfg(not(feature = "ffi_import"))]
+pub mod hd;
+#[cfg(not(feature = "ffi_import"))]
 pub mod kex;
+#[cfg(not(feature = "ffi_import"))]
+pub mod keystore;
 mod merkle;
 #[cfg(not(feature = "ffi_import"))]
 mod multihash;
 mod signature;
 #[cfg(not(feature = "ffi_import"))]
+pub mod threshold;
+#[cfg(not(feature = "ffi_import"))]
 mod varint;
 
 #[cfg(not(feature = "std"))]
@@ -60,6 +66,10 @@ pub const SECP_256_K1: &str = "secp256k1";
 pub const BLS_NORMAL: &str = "bls_normal";
 /// String algorithm representation
 pub const BLS_SMALL: &str = "bls_small";
+/// String algorithm representation
+pub const SECP_256_R1: &str = "secp256r1";
+/// String algorithm representation
+pub const SM2: &str = "sm2";
 
 ffi::ffi_item! {
     /// Algorithm for hashing & signing
@@ -75,6 +85,10 @@ ffi::ffi_item! {
         BlsNormal,
         #[allow(missing_docs)]
         BlsSmall,
+        /// ECDSA over NIST P-256
+        Secp256r1,
+        /// Chinese national standard SM2 signature with SM3 hash
+        Sm2,
     }
 }
 
@@ -86,6 +100,8 @@ impl Algorithm {
             Self::Secp256k1 => SECP_256_K1,
             Self::BlsNormal => BLS_NORMAL,
             Self::BlsSmall => BLS_SMALL,
+            Self::Secp256r1 => SECP_256_R1,
+            Self::Sm2 => SM2,
         }
     }
 }
@@ -105,6 +121,8 @@ impl FromStr for Algorithm {
             SECP_256_K1 => Ok(Algorithm::Secp256k1),
             BLS_NORMAL => Ok(Algorithm::BlsNormal),
             BLS_SMALL => Ok(Algorithm::BlsSmall),
+            SECP_256_R1 => Ok(Algorithm::Secp256r1),
+            SM2 => Ok(Algorithm::Sm2),
             _ => Err(NoSuchAlgorithm),
         }
     }
@@ -132,6 +150,8 @@ ffi::ffi_item! {
         key_gen_option: KeyGenOption,
         /// Algorithm
         algorithm: Algorithm,
+        /// Path of the key to derive from the seed
+        derivation_path: Option<hd::DerivationPath>,
     }
 }
 
@@ -144,6 +164,7 @@ impl KeyGenConfiguration {
         Self {
             key_gen_option: KeyGenOption::Random,
             algorithm: Algorithm::default(),
+            derivation_path: None,
         }
     }
 
@@ -153,6 +174,7 @@ impl KeyGenConfiguration {
         Self {
             key_gen_option: KeyGenOption::UseSeed(seed),
             algorithm: Algorithm::default(),
+            derivation_path: None,
         }
     }
 
@@ -162,6 +184,7 @@ impl KeyGenConfiguration {
         Self {
             key_gen_option: KeyGenOption::FromPrivateKey(private_key.into()),
             algorithm: Algorithm::default(),
+            derivation_path: None,
         }
     }
 
@@ -173,6 +196,19 @@ impl KeyGenConfiguration {
     }
 }
 
+#[cfg(not(feature = "ffi_import"))]
+impl KeyGenConfiguration {
+    /// With hierarchical deterministic derivation of the key at `path` from the seed.
+    ///
+    /// Only applies to configurations constructed with [`Self::from_seed`]
+    /// and only to [`Ed25519`](Algorithm::Ed25519) and [`Secp256k1`](Algorithm::Secp256k1) algorithms.
+    #[must_use]
+    pub fn with_derivation_path(mut self, path: hd::DerivationPath) -> Self {
+        self.derivation_path = Some(path);
+        self
+    }
+}
+
 ffi::ffi_item! {
     /// Pair of Public and Private keys.
     #[derive(Clone, PartialEq, Eq, Getters)]
@@ -227,13 +263,28 @@ impl KeyPair {
     /// Generates a pair of Public and Private key with the corresponding [`KeyGenConfiguration`].
     ///
     /// # Errors
-    /// Fails if decoding fails
+    /// - Fails if decoding fails
+    /// - Fails if the requested hierarchical derivation isn't supported
     pub fn generate_with_configuration(configuration: KeyGenConfiguration) -> Result<Self, Error> {
         let key_gen_option = match (configuration.algorithm, configuration.key_gen_option) {
-            (Algorithm::Secp256k1, KeyGenOption::UseSeed(seed)) if seed.len() < 32 => {
-                return Err(Error::KeyGen(
-                    "secp256k1 seed for must be at least 32 bytes long".to_owned(),
-                ))
+            (algorithm, KeyGenOption::UseSeed(mut seed))
+                if configuration.derivation_path.is_some() =>
+            {
+                let path = configuration
+                    .derivation_path
+                    .as_ref()
+                    .expect("Checked above");
+                let private_key = hd::derive_private_key(algorithm, &seed, path);
+                zeroize::Zeroize::zeroize(&mut seed);
+                KeyGenOption::FromPrivateKey(Box::new(private_key?))
+            }
+            (
+                algorithm @ (Algorithm::Secp256k1 | Algorithm::Secp256r1 | Algorithm::Sm2),
+                KeyGenOption::UseSeed(seed),
+            ) if seed.len() < 32 => {
+                return Err(Error::KeyGen(format!(
+                    "{algorithm} seed for must be at least 32 bytes long"
+                )))
             }
             (_, key_gen_option) => key_gen_option,
         };
@@ -245,6 +296,10 @@ impl KeyPair {
             }
             Algorithm::BlsNormal => signature::bls::BlsNormal::keypair(key_gen_option).into(),
             Algorithm::BlsSmall => signature::bls::BlsSmall::keypair(key_gen_option).into(),
+            Algorithm::Secp256r1 => {
+                signature::secp256r1::EcdsaSecp256r1Sha256::keypair(key_gen_option).into()
+            }
+            Algorithm::Sm2 => signature::sm2::Sm2Sm3::keypair(key_gen_option).into(),
         })
     }
 }
@@ -267,6 +322,24 @@ impl From<(secp256k1::PublicKey, secp256k1::PrivateKey)> for KeyPair {
     }
 }
 
+impl From<(secp256r1::PublicKey, secp256r1::PrivateKey)> for KeyPair {
+    fn from((public_key, private_key): (secp256r1::PublicKey, secp256r1::PrivateKey)) -> Self {
+        Self {
+            public_key: PublicKey(Box::new(PublicKeyInner::Secp256r1(public_key))),
+            private_key: PrivateKey(Box::new(PrivateKeyInner::Secp256r1(private_key))),
+        }
+    }
+}
+
+impl From<(sm2::PublicKey, sm2::PrivateKey)> for KeyPair {
+    fn from((public_key, private_key): (sm2::PublicKey, sm2::PrivateKey)) -> Self {
+        Self {
+            public_key: PublicKey(Box::new(PublicKeyInner::Sm2(public_key))),
+            private_key: PrivateKey(Box::new(PrivateKeyInner::Sm2(private_key))),
+        }
+    }
+}
+
 impl From<(bls::BlsNormalPublicKey, bls::BlsNormalPrivateKey)> for KeyPair {
     fn from(
         (public_key, private_key): (bls::BlsNormalPublicKey, bls::BlsNormalPrivateKey),
@@ -326,6 +399,8 @@ enum PublicKeyInner {
     Secp256k1(secp256k1::PublicKey),
     BlsNormal(bls::BlsNormalPublicKey),
     BlsSmall(bls::BlsSmallPublicKey),
+    Secp256r1(secp256r1::PublicKey),
+    Sm2(sm2::PublicKey),
 }
 
 #[cfg(not(feature = "ffi_import"))]
@@ -384,6 +459,8 @@ impl PublicKeyInner {
             Self::Secp256k1(key) => key.to_sec1_bytes().to_vec(),
             Self::BlsNormal(key) => key.to_bytes(),
             Self::BlsSmall(key) => key.to_bytes(),
+            Self::Secp256r1(key) => key.to_sec1_bytes().to_vec(),
+            Self::Sm2(key) => key.to_sec1_bytes().to_vec(),
         }
     }
 
@@ -393,6 +470,8 @@ impl PublicKeyInner {
             Self::Secp256k1(_) => Algorithm::Secp256k1,
             Self::BlsNormal(_) => Algorithm::BlsNormal,
             Self::BlsSmall(_) => Algorithm::BlsSmall,
+            Self::Secp256r1(_) => Algorithm::Secp256r1,
+            Self::Sm2(_) => Algorithm::Sm2,
         }
     }
 }
@@ -427,6 +506,9 @@ impl PublicKey {
             Algorithm::BlsSmall => {
                 bls::BlsSmall::parse_public_key(payload).map(PublicKeyInner::BlsSmall)
             }
+            Algorithm::Secp256r1 => secp256r1::EcdsaSecp256r1Sha256::parse_public_key(payload)
+                .map(PublicKeyInner::Secp256r1),
+            Algorithm::Sm2 => sm2::Sm2Sm3::parse_public_key(payload).map(PublicKeyInner::Sm2),
         }
         .map(Box::new)
         .map(PublicKey)
@@ -561,6 +643,10 @@ impl From<PrivateKey> for PublicKey {
             Algorithm::BlsSmall => {
                 PublicKeyInner::BlsSmall(bls::BlsSmall::keypair(key_gen_option).0)
             }
+            Algorithm::Secp256r1 => PublicKeyInner::Secp256r1(
+                secp256r1::EcdsaSecp256r1Sha256::keypair(key_gen_option).0,
+            ),
+            Algorithm::Sm2 => PublicKeyInner::Sm2(sm2::Sm2Sm3::keypair(key_gen_option).0),
         };
         PublicKey(Box::new(inner))
     }
@@ -573,6 +659,8 @@ enum PrivateKeyInner {
     Secp256k1(secp256k1::PrivateKey),
     BlsNormal(bls::BlsNormalPrivateKey),
     BlsSmall(bls::BlsSmallPrivateKey),
+    Secp256r1(secp256r1::PrivateKey),
+    Sm2(sm2::PrivateKey),
 }
 
 ffi::ffi_item! {
@@ -594,6 +682,8 @@ impl PartialEq for PrivateKey {
             (PrivateKeyInner::BlsSmall(l), PrivateKeyInner::BlsSmall(r)) => {
                 l.to_bytes() == r.to_bytes()
             }
+            (PrivateKeyInner::Secp256r1(l), PrivateKeyInner::Secp256r1(r)) => l == r,
+            (PrivateKeyInner::Sm2(l), PrivateKeyInner::Sm2(r)) => l == r,
             _ => false,
         }
     }
@@ -620,6 +710,9 @@ impl PrivateKey {
             Algorithm::BlsSmall => {
                 bls::BlsSmall::parse_private_key(payload).map(PrivateKeyInner::BlsSmall)
             }
+            Algorithm::Secp256r1 => secp256r1::EcdsaSecp256r1Sha256::parse_private_key(payload)
+                .map(PrivateKeyInner::Secp256r1),
+            Algorithm::Sm2 => sm2::Sm2Sm3::parse_private_key(payload).map(PrivateKeyInner::Sm2),
         }
         .map(Box::new)
         .map(PrivateKey)
@@ -644,6 +737,8 @@ impl PrivateKey {
             PrivateKeyInner::Secp256k1(_) => Algorithm::Secp256k1,
             PrivateKeyInner::BlsNormal(_) => Algorithm::BlsNormal,
             PrivateKeyInner::BlsSmall(_) => Algorithm::BlsSmall,
+            PrivateKeyInner::Secp256r1(_) => Algorithm::Secp256r1,
+            PrivateKeyInner::Sm2(_) => Algorithm::Sm2,
         }
     }
 
@@ -654,6 +749,8 @@ impl PrivateKey {
             PrivateKeyInner::Secp256k1(key) => key.to_bytes().to_vec(),
             PrivateKeyInner::BlsNormal(key) => key.to_bytes(),
             PrivateKeyInner::BlsSmall(key) => key.to_bytes(),
+            PrivateKeyInner::Secp256r1(key) => key.to_bytes().to_vec(),
+            PrivateKeyInner::Sm2(key) => key.to_bytes().to_vec(),
         }
     }
 }
@@ -864,6 +961,8 @@ mod tests {
             Algorithm::Secp256k1,
             Algorithm::BlsNormal,
             Algorithm::BlsSmall,
+            Algorithm::Secp256r1,
+            Algorithm::Sm2,
         ] {
             assert_eq!(
                 algorithm,
@@ -882,6 +981,8 @@ mod tests {
             Algorithm::Secp256k1,
             Algorithm::BlsNormal,
             Algorithm::BlsSmall,
+            Algorithm::Secp256r1,
+            Algorithm::Sm2,
         ] {
             let key_pair = KeyPair::generate_with_configuration(
                 KeyGenConfiguration::from_random().with_algorithm(algorithm),
@@ -904,6 +1005,8 @@ mod tests {
             Algorithm::Secp256k1,
             Algorithm::BlsNormal,
             Algorithm::BlsSmall,
+            Algorithm::Secp256r1,
+            Algorithm::Sm2,
         ] {
             let encoded_algorithm = algorithm.encode();
 
@@ -944,6 +1047,8 @@ mod tests {
             Algorithm::Secp256k1,
             Algorithm::BlsNormal,
             Algorithm::BlsSmall,
+            Algorithm::Secp256r1,
+            Algorithm::Sm2,
         ] {
             let key_pair = KeyPair::generate_with_configuration(
                 KeyGenConfiguration::from_random().with_algorithm(algorithm),
@@ -20,6 +20,10 @@ pub const SECP_256_K1_PUB_STR: &str = "secp256k1-pub";
 pub const BLS12_381_G1_PUB: &str = "bls12_381-g1-pub";
 /// bls12 381 g2 public string
 pub const BLS12_381_G2_PUB: &str = "bls12_381-g2-pub";
+/// NIST P-256 public string
+pub const P_256_PUB_STR: &str = "p256-pub";
+/// SM2 public string
+pub const SM2_PUB_STR: &str = "sm2-pub";
 
 /// Type of digest function.
 /// The corresponding byte codes are taken from [official multihash table](https://github.com/multiformats/multicodec/blob/master/table.csv)
@@ -40,6 +44,12 @@ pub enum DigestFunction {
     /// Bls12381G2
     #[display(fmt = "{BLS12_381_G2_PUB}")]
     Bls12381G2Pub = 0xeb,
+    /// P-256
+    #[display(fmt = "{P_256_PUB_STR}")]
+    P256Pub = 0x1200,
+    /// SM2
+    #[display(fmt = "{SM2_PUB_STR}")]
+    Sm2Pub = 0x1206,
 }
 
 impl From<DigestFunction> for Algorithm {
@@ -49,6 +59,8 @@ impl From<DigestFunction> for Algorithm {
             DigestFunction::Secp256k1Pub => Self::Secp256k1,
             DigestFunction::Bls12381G1Pub => Self::BlsNormal,
             DigestFunction::Bls12381G2Pub => Self::BlsSmall,
+            DigestFunction::P256Pub => Self::Secp256r1,
+            DigestFunction::Sm2Pub => Self::Sm2,
         }
     }
 }
@@ -60,6 +72,8 @@ impl From<Algorithm> for DigestFunction {
             Algorithm::Secp256k1 => Self::Secp256k1Pub,
             Algorithm::BlsNormal => Self::Bls12381G1Pub,
             Algorithm::BlsSmall => Self::Bls12381G2Pub,
+            Algorithm::Secp256r1 => Self::P256Pub,
+            Algorithm::Sm2 => Self::Sm2Pub,
         }
     }
 }
@@ -73,6 +87,8 @@ impl core::str::FromStr for DigestFunction {
             SECP_256_K1_PUB_STR => Ok(DigestFunction::Secp256k1Pub),
             BLS12_381_G1_PUB => Ok(DigestFunction::Bls12381G1Pub),
             BLS12_381_G2_PUB => Ok(DigestFunction::Bls12381G2Pub),
+            P_256_PUB_STR => Ok(DigestFunction::P256Pub),
+            SM2_PUB_STR => Ok(DigestFunction::Sm2Pub),
             _ => Err(Self::Err {}),
         }
     }
@@ -95,6 +111,8 @@ impl TryFrom<u64> for DigestFunction {
             variant if variant == DigestFunction::Bls12381G2Pub as u64 => {
                 Ok(DigestFunction::Bls12381G2Pub)
             }
+            variant if variant == DigestFunction::P256Pub as u64 => Ok(DigestFunction::P256Pub),
+            variant if variant == DigestFunction::Sm2Pub as u64 => Ok(DigestFunction::Sm2Pub),
             _ => Err(Self::Error {}),
         }
     }
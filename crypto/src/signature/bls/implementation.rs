@@ -11,6 +11,9 @@ use w3f_bls::{EngineBLS as _, PublicKey, SecretKeyVT as SecretKey, SerializableT
 use zeroize::Zeroize as _;
 
 pub(super) const MESSAGE_CONTEXT: &[u8; 20] = b"for signing messages";
+/// Context of the proofs of possession, distinct from [`MESSAGE_CONTEXT`] so that a proof
+/// can't be obtained by asking the key owner to sign its public key as an ordinary message
+pub(super) const POSSESSION_CONTEXT: &[u8; 19] = b"proof of possession";
 
 use crate::{Algorithm, Error, KeyGenOption, ParseError};
 
@@ -77,6 +80,71 @@ impl<C: BlsConfiguration + ?Sized> BlsImpl<C> {
         Ok(())
     }
 
+    /// Aggregate signatures of the same message into a single signature.
+    ///
+    /// Aggregation is done assuming proof of possession of every signer's key.
+    #[allow(single_use_lifetimes)] // TODO: uncomment when anonymous lifetimes are stable
+    pub fn aggregate<'sig>(
+        signatures: impl IntoIterator<Item = &'sig [u8]>,
+    ) -> Result<Vec<u8>, Error> {
+        let mut signatures = signatures.into_iter().map(|signature| {
+            w3f_bls::Signature::<C::Engine>::from_bytes(signature)
+                .map_err(|_| ParseError("Failed to parse signature.".to_owned()))
+        });
+
+        let mut aggregate = signatures.next().ok_or(Error::EmptySignatureIter)??;
+        for signature in signatures {
+            aggregate.0 += signature?.0;
+        }
+
+        Ok(aggregate.to_bytes())
+    }
+
+    /// Verify signature aggregated with [`Self::aggregate`] against all of the signers' keys.
+    pub fn verify_aggregate<'key>(
+        message: &[u8],
+        signature: &[u8],
+        pks: impl IntoIterator<Item = &'key PublicKey<C::Engine>>,
+    ) -> Result<(), Error>
+    where
+        C::Engine: 'key,
+    {
+        let signature = w3f_bls::Signature::<C::Engine>::from_bytes(signature)
+            .map_err(|_| ParseError("Failed to parse signature.".to_owned()))?;
+        let message = w3f_bls::Message::new(MESSAGE_CONTEXT, message);
+
+        let mut pks = pks.into_iter();
+        let mut aggregate_pk = PublicKey(pks.next().ok_or(Error::BadSignature)?.0);
+        for pk in pks {
+            aggregate_pk.0 += pk.0;
+        }
+
+        if !signature.verify(&message, &aggregate_pk) {
+            return Err(Error::BadSignature);
+        }
+
+        Ok(())
+    }
+
+    /// Prove possession of `sk` by signing the matching `pk` in [`POSSESSION_CONTEXT`].
+    pub fn prove_possession(pk: &PublicKey<C::Engine>, sk: &SecretKey<C::Engine>) -> Vec<u8> {
+        let message = w3f_bls::Message::new(POSSESSION_CONTEXT, &pk.to_bytes());
+        sk.sign(&message).to_bytes()
+    }
+
+    /// Verify the proof of possession of the private key matching `pk` made with [`Self::prove_possession`].
+    pub fn verify_possession(proof: &[u8], pk: &PublicKey<C::Engine>) -> Result<(), Error> {
+        let proof = w3f_bls::Signature::<C::Engine>::from_bytes(proof)
+            .map_err(|_| ParseError("Failed to parse proof of possession.".to_owned()))?;
+        let message = w3f_bls::Message::new(POSSESSION_CONTEXT, &pk.to_bytes());
+
+        if !proof.verify(&message, pk) {
+            return Err(Error::BadSignature);
+        }
+
+        Ok(())
+    }
+
     pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey<C::Engine>, ParseError> {
         PublicKey::from_bytes(payload).map_err(|err| ParseError(err.to_string()))
     }
@@ -48,6 +48,23 @@ fn test_signature_verification_different_keys<C: BlsConfiguration>() {
         .expect_err("Signature verification for wrong public key should fail");
 }
 
+#[allow(clippy::similar_names)]
+fn test_proof_of_possession<C: BlsConfiguration>() {
+    let (pk_1, sk_1) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+    let (pk_2, _sk_2) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+
+    let proof = BlsImpl::<C>::prove_possession(&pk_1, &sk_1);
+    BlsImpl::<C>::verify_possession(&proof, &pk_1)
+        .expect("Proof of possession verification should succeed");
+    BlsImpl::<C>::verify_possession(&proof, &pk_2)
+        .expect_err("Proof of possession of another key should fail");
+
+    // Public key signed as an ordinary message isn't a proof of possession
+    let signature = BlsImpl::<C>::sign(&pk_1.to_bytes(), &sk_1);
+    BlsImpl::<C>::verify_possession(&signature, &pk_1)
+        .expect_err("Ordinary signature shouldn't be accepted as a proof of possession");
+}
+
 mod normal {
     use super::*;
 
@@ -70,6 +87,11 @@ mod normal {
     fn signature_verification_different_keys() {
         test_signature_verification_different_keys::<NormalConfiguration>();
     }
+
+    #[test]
+    fn proof_of_possession() {
+        test_proof_of_possession::<NormalConfiguration>();
+    }
 }
 
 mod small {
@@ -94,4 +116,9 @@ mod small {
     fn signature_verification_different_keys() {
         test_signature_verification_different_keys::<SmallConfiguration>();
     }
+
+    #[test]
+    fn proof_of_possession() {
+        test_proof_of_possession::<SmallConfiguration>();
+    }
 }
@@ -10,6 +10,12 @@ pub(crate) mod ed25519;
 #[cfg(not(feature = "ffi_import"))]
 pub(crate) mod secp256k1;
 
+#[cfg(not(feature = "ffi_import"))]
+pub(crate) mod secp256r1;
+
+#[cfg(not(feature = "ffi_import"))]
+pub(crate) mod sm2;
+
 #[cfg(not(feature = "std"))]
 use alloc::{
     boxed::Box, collections::btree_set, format, string::String, string::ToString as _, vec,
@@ -64,6 +70,10 @@ impl Signature {
             }
             crate::PrivateKeyInner::BlsSmall(sk) => bls::BlsSmall::sign(payload, sk),
             crate::PrivateKeyInner::BlsNormal(sk) => bls::BlsNormal::sign(payload, sk),
+            crate::PrivateKeyInner::Secp256r1(sk) => {
+                secp256r1::EcdsaSecp256r1Sha256::sign(payload, sk)
+            }
+            crate::PrivateKeyInner::Sm2(sk) => sm2::Sm2Sm3::sign(payload, sk),
         };
         Self {
             public_key: key_pair.public_key.clone(),
@@ -71,6 +81,15 @@ impl Signature {
         }
     }
 
+    /// Construct [`Self`] from a signature `payload` computed elsewhere, e.g. aggregated from threshold shares
+    #[cfg(not(feature = "ffi_import"))]
+    pub(crate) fn from_raw_parts(public_key: PublicKey, payload: Vec<u8>) -> Self {
+        Self {
+            public_key,
+            payload: ConstVec::new(payload),
+        }
+    }
+
     /// Verify `payload` using signed data and [`KeyPair::public_key`].
     ///
     /// # Errors
@@ -89,6 +108,10 @@ impl Signature {
             crate::PublicKeyInner::BlsNormal(pk) => {
                 bls::BlsNormal::verify(payload, self.payload(), pk)
             }
+            crate::PublicKeyInner::Secp256r1(pk) => {
+                secp256r1::EcdsaSecp256r1Sha256::verify(payload, self.payload(), pk)
+            }
+            crate::PublicKeyInner::Sm2(pk) => sm2::Sm2Sm3::verify(payload, self.payload(), pk),
         }?;
 
         Ok(())
@@ -227,6 +250,17 @@ impl<T: parity_scale_codec::Encode> SignatureOf<T> {
         Self::from_hash(key_pair, HashOf::new(value))
     }
 
+    /// Wrap `signature` of the hash of `value` made without a [`KeyPair`],
+    /// e.g. a [threshold](crate::threshold) signature of a group of signers.
+    ///
+    /// # Errors
+    /// Fails if `signature` isn't a valid signature of the hash of `value`
+    pub fn from_signature(signature: Signature, value: &T) -> Result<Self, Error> {
+        let signature = Self(signature, PhantomData);
+        signature.verify(value)?;
+        Ok(signature)
+    }
+
     /// Verifies signature for this item
     ///
     /// # Errors
@@ -524,6 +558,241 @@ impl<T> core::fmt::Display for SignatureVerificationFail<T> {
 #[cfg(not(feature = "ffi_import"))]
 impl<T> std::error::Error for SignatureVerificationFail<T> {}
 
+/// Single signature aggregated from signatures of the same payload made by multiple signers.
+///
+/// Only BLS signatures can be aggregated. Verification assumes that every signer
+/// has proven possession of its private key (e.g. keys of registered peers),
+/// otherwise the aggregate is susceptible to rogue key attacks.
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+#[cfg(not(feature = "ffi_import"))]
+pub struct AggregateSignature {
+    /// Algorithm of the aggregated signatures
+    algorithm: crate::Algorithm,
+    /// Aggregate signature payload
+    payload: ConstVec<u8>,
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl core::fmt::Debug for AggregateSignature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AggregateSignature")
+            .field("algorithm", &self.algorithm)
+            .field("payload", &hex::encode_upper(self.payload()))
+            .finish()
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl AggregateSignature {
+    /// Algorithm of the aggregated signatures
+    pub fn algorithm(&self) -> crate::Algorithm {
+        self.algorithm
+    }
+
+    /// Aggregate signature payload
+    pub fn payload(&self) -> &[u8] {
+        self.payload.as_ref()
+    }
+
+    /// Aggregate `signatures` of the same payload into a single signature.
+    ///
+    /// # Errors
+    /// - No signatures were given
+    /// - Signatures were made with different or non-BLS algorithms
+    /// - Signature can't be parsed
+    #[allow(single_use_lifetimes)] // TODO: uncomment when anonymous lifetimes are stable
+    pub fn aggregate<'sig>(
+        signatures: impl IntoIterator<Item = &'sig Signature>,
+    ) -> Result<Self, Error> {
+        let mut signatures = signatures.into_iter().peekable();
+        let algorithm = signatures
+            .peek()
+            .ok_or(Error::EmptySignatureIter)?
+            .public_key()
+            .algorithm();
+
+        let signatures = signatures
+            .map(|signature| {
+                if signature.public_key().algorithm() == algorithm {
+                    Ok(signature.payload())
+                } else {
+                    Err(Error::Other(
+                        "Only signatures made with the same algorithm can be aggregated"
+                            .to_string(),
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let aggregate = match algorithm {
+            crate::Algorithm::BlsNormal => bls::BlsNormal::aggregate(signatures)?,
+            crate::Algorithm::BlsSmall => bls::BlsSmall::aggregate(signatures)?,
+            crate::Algorithm::Ed25519
+            | crate::Algorithm::Secp256k1
+            | crate::Algorithm::Secp256r1
+            | crate::Algorithm::Sm2 => {
+                return Err(Error::Other(format!(
+                    "Signatures made with `{algorithm}` can't be aggregated"
+                )))
+            }
+        };
+
+        Ok(Self {
+            algorithm,
+            payload: ConstVec::new(aggregate),
+        })
+    }
+
+    /// Verify that `payload` was signed by every one of `public_keys`.
+    ///
+    /// # Errors
+    /// - Algorithm of any of the public keys doesn't match
+    /// - Aggregate signature didn't pass verification
+    #[allow(single_use_lifetimes)] // TODO: uncomment when anonymous lifetimes are stable
+    pub fn verify<'key>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'key PublicKey>,
+        payload: &[u8],
+    ) -> Result<(), Error> {
+        macro_rules! extract_public_keys {
+            ($variant:ident) => {
+                public_keys
+                    .into_iter()
+                    .map(|public_key| match public_key.0.borrow() {
+                        crate::PublicKeyInner::$variant(public_key) => Ok(public_key),
+                        _ => Err(Error::BadSignature),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+        }
+
+        match self.algorithm {
+            crate::Algorithm::BlsNormal => bls::BlsNormal::verify_aggregate(
+                payload,
+                self.payload(),
+                extract_public_keys!(BlsNormal),
+            ),
+            crate::Algorithm::BlsSmall => bls::BlsSmall::verify_aggregate(
+                payload,
+                self.payload(),
+                extract_public_keys!(BlsSmall),
+            ),
+            crate::Algorithm::Ed25519
+            | crate::Algorithm::Secp256k1
+            | crate::Algorithm::Secp256r1
+            | crate::Algorithm::Sm2 => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// Proof that the owner of a BLS public key possesses the matching private key.
+///
+/// Keys of the signers whose signatures are verified as an [`AggregateSignature`]
+/// have to be accompanied by a proof, otherwise anyone could register a rogue key
+/// derived from the keys of others and forge their aggregate signature.
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+#[cfg(not(feature = "ffi_import"))]
+pub struct ProofOfPossession {
+    /// Proof payload
+    payload: ConstVec<u8>,
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl core::fmt::Debug for ProofOfPossession {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProofOfPossession")
+            .field("payload", &hex::encode_upper(self.payload()))
+            .finish()
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl core::fmt::Display for ProofOfPossession {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&hex::encode_upper(self.payload()))
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl core::str::FromStr for ProofOfPossession {
+    type Err = crate::error::ParseError;
+
+    fn from_str(proof: &str) -> Result<Self, Self::Err> {
+        crate::hex_decode(proof).map(|payload| Self {
+            payload: ConstVec::new(payload),
+        })
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl ProofOfPossession {
+    /// Proof payload
+    pub fn payload(&self) -> &[u8] {
+        self.payload.as_ref()
+    }
+
+    /// Whether the proof of possession of `public_key` is required,
+    /// i.e. its signatures can be aggregated.
+    pub fn is_required(public_key: &PublicKey) -> bool {
+        matches!(
+            public_key.algorithm(),
+            crate::Algorithm::BlsNormal | crate::Algorithm::BlsSmall
+        )
+    }
+
+    /// Prove possession of the private key of `key_pair`.
+    ///
+    /// # Errors
+    /// Fails if the key pair isn't a BLS one
+    pub fn new(key_pair: &KeyPair) -> Result<Self, Error> {
+        let proof = match (
+            key_pair.public_key.0.borrow(),
+            key_pair.private_key.0.borrow(),
+        ) {
+            (crate::PublicKeyInner::BlsNormal(pk), crate::PrivateKeyInner::BlsNormal(sk)) => {
+                bls::BlsNormal::prove_possession(pk, sk)
+            }
+            (crate::PublicKeyInner::BlsSmall(pk), crate::PrivateKeyInner::BlsSmall(sk)) => {
+                bls::BlsSmall::prove_possession(pk, sk)
+            }
+            _ => {
+                return Err(Error::Other(format!(
+                    "Proof of possession can't be made with `{}` keys",
+                    key_pair.public_key.algorithm()
+                )))
+            }
+        };
+
+        Ok(Self {
+            payload: ConstVec::new(proof),
+        })
+    }
+
+    /// Verify that the proof was made with the private key matching `public_key`.
+    ///
+    /// # Errors
+    /// - Public key isn't a BLS one
+    /// - Proof didn't pass verification
+    pub fn verify(&self, public_key: &PublicKey) -> Result<(), Error> {
+        match public_key.0.borrow() {
+            crate::PublicKeyInner::BlsNormal(pk) => {
+                bls::BlsNormal::verify_possession(self.payload(), pk)
+            }
+            crate::PublicKeyInner::BlsSmall(pk) => {
+                bls::BlsSmall::verify_possession(self.payload(), pk)
+            }
+            crate::PublicKeyInner::Ed25519(_)
+            | crate::PublicKeyInner::Secp256k1(_)
+            | crate::PublicKeyInner::Secp256r1(_)
+            | crate::PublicKeyInner::Sm2(_) => Err(Error::BadSignature),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -555,6 +824,32 @@ mod tests {
         signature.verify(message).unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "rand")]
+    fn create_signature_secp256r1() {
+        let key_pair = KeyPair::generate_with_configuration(
+            KeyGenConfiguration::from_random().with_algorithm(crate::Algorithm::Secp256r1),
+        )
+        .expect("Failed to generate key pair.");
+        let message = b"Test message to sign.";
+        let signature = Signature::new(&key_pair, message);
+        assert!(*signature.public_key() == *key_pair.public_key());
+        signature.verify(message).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn create_signature_sm2() {
+        let key_pair = KeyPair::generate_with_configuration(
+            KeyGenConfiguration::from_random().with_algorithm(crate::Algorithm::Sm2),
+        )
+        .expect("Failed to generate key pair.");
+        let message = b"Test message to sign.";
+        let signature = Signature::new(&key_pair, message);
+        assert!(*signature.public_key() == *key_pair.public_key());
+        signature.verify(message).unwrap();
+    }
+
     #[test]
     #[cfg(feature = "rand")]
     fn create_signature_bls_normal() {
@@ -581,6 +876,58 @@ mod tests {
         signature.verify(message).unwrap();
     }
 
+    #[test]
+    #[cfg(all(feature = "rand", not(feature = "ffi_import")))]
+    fn aggregate_signature_bls_normal() {
+        let key_pairs = core::iter::repeat_with(|| {
+            KeyPair::generate_with_configuration(
+                KeyGenConfiguration::from_random().with_algorithm(crate::Algorithm::BlsNormal),
+            )
+            .expect("Failed to generate key pair.")
+        })
+        .take(4)
+        .collect::<Vec<_>>();
+        let message = b"Test message to sign.";
+        let signatures = key_pairs
+            .iter()
+            .map(|key_pair| Signature::new(key_pair, message))
+            .collect::<Vec<_>>();
+
+        let aggregate = AggregateSignature::aggregate(&signatures).unwrap();
+        aggregate
+            .verify(key_pairs.iter().map(KeyPair::public_key), message)
+            .unwrap();
+
+        // Missing signer
+        assert!(aggregate
+            .verify(key_pairs.iter().skip(1).map(KeyPair::public_key), message)
+            .is_err());
+        // Different message
+        assert!(aggregate
+            .verify(key_pairs.iter().map(KeyPair::public_key), b"Other message.")
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", not(feature = "ffi_import")))]
+    fn proof_of_possession_bls_small() {
+        let generate = |algorithm| {
+            KeyPair::generate_with_configuration(
+                KeyGenConfiguration::from_random().with_algorithm(algorithm),
+            )
+            .expect("Failed to generate key pair.")
+        };
+        let key_pair = generate(crate::Algorithm::BlsSmall);
+        let other_key_pair = generate(crate::Algorithm::BlsSmall);
+
+        let proof = ProofOfPossession::new(&key_pair).unwrap();
+        proof.verify(key_pair.public_key()).unwrap();
+        assert!(proof.verify(other_key_pair.public_key()).is_err());
+        assert_eq!(proof.to_string().parse::<ProofOfPossession>(), Ok(proof));
+
+        assert!(ProofOfPossession::new(&generate(crate::Algorithm::Ed25519)).is_err());
+    }
+
     #[test]
     #[cfg(all(feature = "rand", not(feature = "ffi_import")))]
     fn signatures_of_deduplication_by_public_key() {
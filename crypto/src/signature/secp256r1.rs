@@ -0,0 +1,209 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use self::ecdsa_secp256r1::EcdsaSecp256r1Impl;
+use crate::{Error, KeyGenOption, ParseError};
+
+pub const PRIVATE_KEY_SIZE: usize = 32;
+
+/// ECDSA over NIST P-256 (a.k.a. `secp256r1` or `prime256v1`) of the raw message.
+///
+/// Signatures are required to have low `S`, so that they aren't malleable.
+/// WebAuthn/passkeys use the same curve, but sign `authenticatorData || SHA-256(clientDataJSON)`
+/// rather than the message itself, so their assertions can't be verified with this algorithm.
+pub struct EcdsaSecp256r1Sha256;
+
+pub type PublicKey = p256::PublicKey;
+pub type PrivateKey = p256::SecretKey;
+
+impl EcdsaSecp256r1Sha256 {
+    pub fn keypair(option: KeyGenOption) -> (PublicKey, PrivateKey) {
+        EcdsaSecp256r1Impl::keypair(option)
+    }
+
+    pub fn sign(message: &[u8], sk: &PrivateKey) -> Vec<u8> {
+        EcdsaSecp256r1Impl::sign(message, sk)
+    }
+
+    pub fn verify(message: &[u8], signature: &[u8], pk: &PublicKey) -> Result<(), Error> {
+        EcdsaSecp256r1Impl::verify(message, signature, pk)
+    }
+
+    pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey, ParseError> {
+        EcdsaSecp256r1Impl::parse_public_key(payload)
+    }
+
+    pub fn parse_private_key(payload: &[u8]) -> Result<PrivateKey, ParseError> {
+        EcdsaSecp256r1Impl::parse_private_key(payload)
+    }
+}
+
+mod ecdsa_secp256r1 {
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::ToString as _, vec::Vec};
+    use core::borrow::Borrow;
+
+    use arrayref::array_ref;
+    use digest::Digest as _;
+    #[cfg(feature = "rand")]
+    use rand::rngs::OsRng;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use signature::{Signer as _, Verifier as _};
+    use zeroize::Zeroize;
+
+    use super::{PrivateKey, PublicKey, PRIVATE_KEY_SIZE};
+    use crate::{Error, KeyGenOption, ParseError};
+
+    pub struct EcdsaSecp256r1Impl;
+    type Digest = sha2::Sha256;
+
+    impl EcdsaSecp256r1Impl {
+        pub fn keypair(mut option: KeyGenOption) -> (PublicKey, PrivateKey) {
+            let signing_key = match option {
+                #[cfg(feature = "rand")]
+                KeyGenOption::Random => PrivateKey::random(&mut OsRng),
+                KeyGenOption::UseSeed(ref mut seed) => {
+                    let mut s = [0u8; PRIVATE_KEY_SIZE];
+                    let mut rng = ChaChaRng::from_seed(*array_ref!(seed.as_slice(), 0, 32));
+                    seed.zeroize();
+                    rng.fill_bytes(&mut s);
+                    let mut k = Digest::digest(s);
+                    s.zeroize();
+                    // NOTE: Group order is below 2^256, so the digest isn't always a valid
+                    // scalar. Re-hashing keeps the derivation deterministic without panicking
+                    loop {
+                        if let Ok(private_key) = PrivateKey::from_slice(k.as_slice()) {
+                            break private_key;
+                        }
+                        k = Digest::digest(k);
+                    }
+                }
+                KeyGenOption::FromPrivateKey(ref s) => {
+                    let crate::PrivateKeyInner::Secp256r1(s) = s.0.borrow() else {
+                        panic!("Wrong private key type, expected `Secp256r1`, got {s:?}")
+                    };
+                    s.clone()
+                }
+            };
+
+            let public_key = signing_key.public_key();
+            (public_key, signing_key)
+        }
+
+        pub fn sign(message: &[u8], sk: &PrivateKey) -> Vec<u8> {
+            let signing_key = p256::ecdsa::SigningKey::from(sk);
+
+            let signature: p256::ecdsa::Signature = signing_key.sign(message);
+            // NOTE: Unlike `k256`, `p256` doesn't normalize signatures to low `S`
+            signature
+                .normalize_s()
+                .unwrap_or(signature)
+                .to_bytes()
+                .to_vec()
+        }
+
+        pub fn verify(message: &[u8], signature: &[u8], pk: &PublicKey) -> Result<(), Error> {
+            let signature = p256::ecdsa::Signature::from_slice(signature)
+                .map_err(|e| Error::Signing(format!("{e:?}")))?;
+            // NOTE: Both `(r, s)` and `(r, n - s)` are valid, so only the one with low `S` is accepted
+            if signature.normalize_s().is_some() {
+                return Err(Error::BadSignature);
+            }
+
+            let verifying_key = p256::ecdsa::VerifyingKey::from(pk);
+
+            verifying_key
+                .verify(message, &signature)
+                .map_err(|_| Error::BadSignature)
+        }
+
+        pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey, ParseError> {
+            PublicKey::from_sec1_bytes(payload).map_err(|err| ParseError(err.to_string()))
+        }
+
+        pub fn parse_private_key(payload: &[u8]) -> Result<PrivateKey, ParseError> {
+            PrivateKey::from_slice(payload).map_err(|err| ParseError(err.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use openssl::{
+        bn::{BigNum, BigNumContext},
+        ec::{EcGroup, EcKey, EcPoint},
+        ecdsa::EcdsaSig,
+        nid::Nid,
+    };
+    use sha2::Digest;
+
+    use super::*;
+
+    const MESSAGE_1: &[u8] = b"This is a dummy message for use with tests";
+
+    #[test]
+    fn secp256r1_sign() {
+        let (pk, sk) = EcdsaSecp256r1Sha256::keypair(KeyGenOption::Random);
+
+        let sig = EcdsaSecp256r1Sha256::sign(MESSAGE_1, &sk);
+        EcdsaSecp256r1Sha256::verify(MESSAGE_1, &sig, &pk).unwrap();
+        assert_eq!(sig.len(), 64);
+
+        assert!(EcdsaSecp256r1Sha256::verify(b"Some other message", &sig, &pk).is_err());
+    }
+
+    #[test]
+    fn secp256r1_openssl_compatibility() {
+        let (pk, sk) = EcdsaSecp256r1Sha256::keypair(KeyGenOption::Random);
+        let h = sha2::Sha256::digest(MESSAGE_1);
+
+        let openssl_group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let mut ctx = BigNumContext::new().unwrap();
+        let openssl_point =
+            EcPoint::from_bytes(&openssl_group, &pk.to_sec1_bytes(), &mut ctx).unwrap();
+        let openssl_public_key = EcKey::from_public_key(&openssl_group, &openssl_point).unwrap();
+
+        // Check if the signatures produced here can be verified by openssl
+        let sig = EcdsaSecp256r1Sha256::sign(MESSAGE_1, &sk);
+        let (r, s) = sig.split_at(sig.len() / 2);
+        let openssl_sig = EcdsaSig::from_private_components(
+            BigNum::from_slice(r).unwrap(),
+            BigNum::from_slice(s).unwrap(),
+        )
+        .unwrap();
+        assert!(openssl_sig
+            .verify(h.as_slice(), &openssl_public_key)
+            .unwrap());
+
+        // Check if openssl signs the message and this module still can verify it
+        let openssl_secret_key = EcKey::from_private_components(
+            &openssl_group,
+            &BigNum::from_slice(&sk.to_bytes()).unwrap(),
+            &openssl_point,
+        )
+        .unwrap();
+        let openssl_sig = EcdsaSig::sign(h.as_slice(), &openssl_secret_key).unwrap();
+        let mut sig = openssl_sig.r().to_vec_padded(32).unwrap();
+        sig.extend(openssl_sig.s().to_vec_padded(32).unwrap());
+        // openssl doesn't normalize signatures to low `S`
+        let sig = p256::ecdsa::Signature::from_slice(&sig).unwrap();
+        let sig = sig.normalize_s().unwrap_or(sig).to_bytes();
+
+        EcdsaSecp256r1Sha256::verify(MESSAGE_1, &sig, &pk).unwrap();
+    }
+
+    #[test]
+    fn secp256r1_high_s_is_rejected() {
+        let (pk, sk) = EcdsaSecp256r1Sha256::keypair(KeyGenOption::Random);
+
+        let sig = EcdsaSecp256r1Sha256::sign(MESSAGE_1, &sk);
+        EcdsaSecp256r1Sha256::verify(MESSAGE_1, &sig, &pk).unwrap();
+
+        let (r, s) = p256::ecdsa::Signature::from_slice(&sig)
+            .unwrap()
+            .split_scalars();
+        let twin = p256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+        assert!(EcdsaSecp256r1Sha256::verify(MESSAGE_1, &twin.to_bytes(), &pk).is_err());
+    }
+}
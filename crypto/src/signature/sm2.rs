@@ -0,0 +1,154 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use self::sm2_sm3::Sm2Impl;
+use crate::{Error, KeyGenOption, ParseError};
+
+pub const PRIVATE_KEY_SIZE: usize = 32;
+
+/// Distinguishing identifier of the signer, the default one from GM/T 0009-2012.
+///
+/// It takes part in the signed digest, so all parties must agree on it.
+pub const DIST_ID: &str = "1234567812345678";
+
+/// SM2 digital signature algorithm with SM3 hash (GB/T 32918, GB/T 32905)
+pub struct Sm2Sm3;
+
+pub type PublicKey = ::sm2::PublicKey;
+pub type PrivateKey = ::sm2::SecretKey;
+
+impl Sm2Sm3 {
+    pub fn keypair(option: KeyGenOption) -> (PublicKey, PrivateKey) {
+        Sm2Impl::keypair(option)
+    }
+
+    pub fn sign(message: &[u8], sk: &PrivateKey) -> Vec<u8> {
+        Sm2Impl::sign(message, sk)
+    }
+
+    pub fn verify(message: &[u8], signature: &[u8], pk: &PublicKey) -> Result<(), Error> {
+        Sm2Impl::verify(message, signature, pk)
+    }
+
+    pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey, ParseError> {
+        Sm2Impl::parse_public_key(payload)
+    }
+
+    pub fn parse_private_key(payload: &[u8]) -> Result<PrivateKey, ParseError> {
+        Sm2Impl::parse_private_key(payload)
+    }
+}
+
+mod sm2_sm3 {
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::ToString as _, vec::Vec};
+    use core::borrow::Borrow;
+
+    use arrayref::array_ref;
+    use digest::Digest as _;
+    #[cfg(feature = "rand")]
+    use rand::rngs::OsRng;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use signature::{Signer as _, Verifier as _};
+    use zeroize::Zeroize;
+
+    use super::{PrivateKey, PublicKey, DIST_ID, PRIVATE_KEY_SIZE};
+    use crate::{Error, KeyGenOption, ParseError};
+
+    pub struct Sm2Impl;
+    type Digest = sha2::Sha256;
+
+    impl Sm2Impl {
+        pub fn keypair(mut option: KeyGenOption) -> (PublicKey, PrivateKey) {
+            let signing_key = match option {
+                #[cfg(feature = "rand")]
+                KeyGenOption::Random => PrivateKey::random(&mut OsRng),
+                KeyGenOption::UseSeed(ref mut seed) => {
+                    let mut s = [0u8; PRIVATE_KEY_SIZE];
+                    let mut rng = ChaChaRng::from_seed(*array_ref!(seed.as_slice(), 0, 32));
+                    seed.zeroize();
+                    rng.fill_bytes(&mut s);
+                    let mut k = Digest::digest(s);
+                    s.zeroize();
+                    // NOTE: Group order is below 2^256, so the digest isn't always a valid
+                    // scalar. Re-hashing keeps the derivation deterministic without panicking
+                    loop {
+                        if let Ok(private_key) = PrivateKey::from_slice(k.as_slice()) {
+                            break private_key;
+                        }
+                        k = Digest::digest(k);
+                    }
+                }
+                KeyGenOption::FromPrivateKey(ref s) => {
+                    let crate::PrivateKeyInner::Sm2(s) = s.0.borrow() else {
+                        panic!("Wrong private key type, expected `Sm2`, got {s:?}")
+                    };
+                    s.clone()
+                }
+            };
+
+            let public_key = signing_key.public_key();
+            (public_key, signing_key)
+        }
+
+        pub fn sign(message: &[u8], sk: &PrivateKey) -> Vec<u8> {
+            let signing_key = ::sm2::dsa::SigningKey::new(DIST_ID, sk)
+                .expect("Default distinguishing identifier is always valid");
+
+            let signature: ::sm2::dsa::Signature = signing_key.sign(message);
+            signature.to_bytes().to_vec()
+        }
+
+        pub fn verify(message: &[u8], signature: &[u8], pk: &PublicKey) -> Result<(), Error> {
+            let signature = ::sm2::dsa::Signature::from_slice(signature)
+                .map_err(|e| Error::Signing(format!("{e:?}")))?;
+
+            let verifying_key = ::sm2::dsa::VerifyingKey::new(DIST_ID, *pk)
+                .map_err(|e| Error::Signing(format!("{e:?}")))?;
+
+            verifying_key
+                .verify(message, &signature)
+                .map_err(|_| Error::BadSignature)
+        }
+
+        pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey, ParseError> {
+            PublicKey::from_sec1_bytes(payload).map_err(|err| ParseError(err.to_string()))
+        }
+
+        pub fn parse_private_key(payload: &[u8]) -> Result<PrivateKey, ParseError> {
+            PrivateKey::from_slice(payload).map_err(|err| ParseError(err.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MESSAGE_1: &[u8] = b"This is a dummy message for use with tests";
+
+    #[test]
+    fn sm2_sign() {
+        let (pk, sk) = Sm2Sm3::keypair(KeyGenOption::Random);
+
+        let sig = Sm2Sm3::sign(MESSAGE_1, &sk);
+        Sm2Sm3::verify(MESSAGE_1, &sig, &pk).unwrap();
+        assert_eq!(sig.len(), 64);
+
+        assert!(Sm2Sm3::verify(b"Some other message", &sig, &pk).is_err());
+    }
+
+    #[test]
+    fn sm2_keypair_from_seed_is_deterministic() {
+        let seed = vec![7_u8; 32];
+        let (pk_1, _) = Sm2Sm3::keypair(KeyGenOption::UseSeed(seed.clone()));
+        let (pk_2, sk) = Sm2Sm3::keypair(KeyGenOption::UseSeed(seed));
+        assert_eq!(pk_1, pk_2);
+
+        let (pk_3, _) = Sm2Sm3::keypair(KeyGenOption::FromPrivateKey(Box::new(crate::PrivateKey(
+            Box::new(crate::PrivateKeyInner::Sm2(sk)),
+        ))));
+        assert_eq!(pk_1, pk_3);
+    }
+}
@@ -0,0 +1,1038 @@
+//! Threshold `Ed25519` signatures following [FROST](https://www.rfc-editor.org/rfc/rfc9591) (`FROST(Ed25519, SHA-512)`).
+//!
+//! The signing rounds are checked against the test vectors of RFC 9591. The RFC doesn't specify
+//! a key generation, so [`dkg`] follows the one of the `frost-ed25519` crate instead.
+//!
+//! `max_signers` participants run a distributed key generation ([`dkg`]) so that every one of them
+//! holds a share of the group private key which never exists in full. Any `min_signers` of them
+//! can then produce a signature in two rounds:
+//!
+//! 1. Each signer generates single-use nonces with [`commit`] and publishes the commitments.
+//! 2. The commitments and the message are collected into a [`SigningPackage`], each signer
+//!    produces a [`SignatureShare`] with [`sign`] and the shares are combined with [`aggregate`].
+//!
+//! The resulting [`Signature`] is an ordinary `Ed25519` signature of the group [`PublicKey`].
+//!
+//! To act on behalf of an account whose signatory is the group [`PublicKey`], e.g. an admin account
+//! allowed to `Upgrade` the executor, the group signs the hash of the transaction payload and the signature
+//! is attached to the transaction with `TransactionBuilder::sign_with` of `iroha_data_model`.
+//!
+//! Threshold signing of the genesis block is out of scope: the peer submitting the genesis block
+//! signs its transactions at startup, so it still needs the full key pair of the genesis account.
+//! Keep the genesis account powerless after genesis and give the privileges to a threshold account instead.
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use iroha_crypto::threshold::{aggregate, commit, dkg, sign, ParticipantId, SigningPackage};
+//!
+//! let ids = (1..=3).map(|id| ParticipantId::new(id).unwrap()).collect::<Vec<_>>();
+//!
+//! // Round 1 of the DKG: each participant broadcasts its package
+//! let round1 = ids
+//!     .iter()
+//!     .map(|&id| (id, dkg::part1(id, 3, 2).unwrap()))
+//!     .collect::<BTreeMap<_, _>>();
+//! let round1_packages_for = |id: ParticipantId| {
+//!     round1
+//!         .iter()
+//!         .filter(|(other, _)| **other != id)
+//!         .map(|(&other, (_, package))| (other, package.clone()))
+//!         .collect::<BTreeMap<_, _>>()
+//! };
+//!
+//! // Round 2 of the DKG: each participant sends a private package to every other one
+//! let mut round2 = BTreeMap::<_, BTreeMap<_, _>>::new();
+//! for (&id, (secret, _)) in &round1 {
+//!     for (receiver, package) in dkg::part2(secret, &round1_packages_for(id)).unwrap() {
+//!         round2.entry(receiver).or_default().insert(id, package);
+//!     }
+//! }
+//!
+//! // Round 3 of the DKG: each participant computes its key share
+//! let shares = round1
+//!     .iter()
+//!     .map(|(&id, (secret, _))| {
+//!         dkg::part3(secret, &round1_packages_for(id), &round2[&id]).unwrap()
+//!     })
+//!     .collect::<Vec<_>>();
+//! let public_key_package = shares[0].1.clone();
+//!
+//! // Any 2 out of 3 participants can sign
+//! let signers = &shares[1..];
+//! let (nonces, commitments): (Vec<_>, BTreeMap<_, _>) = signers
+//!     .iter()
+//!     .map(|(key_share, _)| {
+//!         let (nonces, commitments) = commit(key_share);
+//!         (nonces, (key_share.id(), commitments))
+//!     })
+//!     .unzip();
+//! let package = SigningPackage::new(commitments, b"message".to_vec());
+//! let signature_shares: BTreeMap<_, _> = signers
+//!     .iter()
+//!     .zip(nonces)
+//!     .map(|((key_share, _), nonces)| {
+//!         (key_share.id(), sign(&package, nonces, key_share).unwrap())
+//!     })
+//!     .collect();
+//!
+//! let signature = aggregate(&package, &signature_shares, &public_key_package).unwrap();
+//! assert_eq!(signature.public_key(), &public_key_package.public_key());
+//! signature.verify(b"message").unwrap();
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{fmt, num::NonZeroU16};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use curve25519_dalek::{edwards::CompressedEdwardsY, traits::IsIdentity, EdwardsPoint, Scalar};
+use displaydoc::Display;
+#[cfg(feature = "rand")]
+use rand::{rngs::OsRng, RngCore as _};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest as _, Sha512};
+use zeroize::Zeroize as _;
+
+use crate::{Algorithm, PublicKey, Signature};
+
+/// Domain separation prefix of the ciphersuite
+const CONTEXT_STRING: &[u8] = b"FROST-ED25519-SHA512-v1";
+
+/// An error that can occur during threshold key generation or signing
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Invalid threshold parameters, expected `2 <= min_signers <= max_signers` and identifiers up to `max_signers`
+    InvalidParameters,
+    /// Expected packages from every participant except this one
+    UnexpectedParticipants,
+    /// Participant {0} provided an invalid proof of knowledge of its secret
+    InvalidProofOfKnowledge(ParticipantId),
+    /// Participant {0} sent a secret share inconsistent with its commitment
+    InvalidSecretShare(ParticipantId),
+    /// Not enough signers or this participant isn't one of them
+    InvalidSigners,
+    /// Nonces don't match the commitments of this participant in the signing package
+    NonceMismatch,
+    /// Participant {0} produced an invalid signature share
+    InvalidSignatureShare(ParticipantId),
+}
+
+/// Identifier of a participant, from 1 up to the total number of participants
+#[derive(
+    Debug,
+    derive_more::Display,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[display(fmt = "{_0}")]
+#[serde(transparent)]
+pub struct ParticipantId(NonZeroU16);
+
+impl ParticipantId {
+    /// Construct [`Self`], `None` if `id` is 0
+    pub const fn new(id: u16) -> Option<Self> {
+        match NonZeroU16::new(id) {
+            Some(id) => Some(Self(id)),
+            None => None,
+        }
+    }
+
+    /// Numeric value of the identifier
+    pub const fn get(self) -> u16 {
+        self.0.get()
+    }
+
+    fn to_scalar(self) -> Scalar {
+        Scalar::from(self.get())
+    }
+}
+
+/// Point of the `Ed25519` curve, serialized as hex of its compressed form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Element(EdwardsPoint);
+
+impl Element {
+    fn to_bytes(self) -> [u8; 32] {
+        self.0.compress().to_bytes()
+    }
+}
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        hex::serde::serialize(self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: [u8; 32] = hex::serde::deserialize(deserializer)?;
+        let point = CompressedEdwardsY(bytes)
+            .decompress()
+            .ok_or_else(|| D::Error::custom("Invalid Ed25519 point"))?;
+        // NOTE: Required by `DeserializeElement` of RFC 9591, so that malicious
+        // commitments can't bias or invalidate the group signature
+        if point.is_identity() || !point.is_torsion_free() {
+            return Err(D::Error::custom(
+                "Ed25519 point is the identity or has a small order component",
+            ));
+        }
+        Ok(Self(point))
+    }
+}
+
+/// Scalar modulo the order of the `Ed25519` group, serialized as hex.
+/// Zeroized on drop as it usually holds secret material
+#[derive(Clone, PartialEq, Eq)]
+struct Secret(Scalar);
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(..)")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        hex::serde::serialize(self.0.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut bytes: [u8; 32] = hex::serde::deserialize(deserializer)?;
+        let scalar = Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes));
+        bytes.zeroize();
+        scalar
+            .map(Self)
+            .ok_or_else(|| D::Error::custom("Non-canonical Ed25519 scalar"))
+    }
+}
+
+fn hash(parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut output = [0; 64];
+    output.copy_from_slice(&hasher.finalize());
+    output
+}
+
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    Scalar::from_bytes_mod_order_wide(&hash(parts))
+}
+
+#[cfg(feature = "rand")]
+fn random_scalar() -> Scalar {
+    let mut bytes = [0; 64];
+    OsRng.fill_bytes(&mut bytes);
+    let scalar = Scalar::from_bytes_mod_order_wide(&bytes);
+    bytes.zeroize();
+    scalar
+}
+
+/// Evaluate the polynomial with the given `coefficients` at `x`
+fn evaluate_polynomial(coefficients: &[Secret], x: Scalar) -> Scalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::ZERO, |acc, coefficient| acc * x + coefficient.0)
+}
+
+/// Evaluate the polynomial committed to with `commitment` at `x` in the exponent
+fn evaluate_commitment(commitment: &[Element], x: Scalar) -> EdwardsPoint {
+    commitment
+        .iter()
+        .rev()
+        .fold(EdwardsPoint::default(), |acc, coefficient| {
+            acc * x + coefficient.0
+        })
+}
+
+/// Lagrange coefficient of `id` for interpolation at 0 over `signers`
+fn lagrange_coefficient(id: ParticipantId, signers: impl Iterator<Item = ParticipantId>) -> Scalar {
+    let x = id.to_scalar();
+    let (numerator, denominator) = signers.filter(|&signer| signer != id).fold(
+        (Scalar::ONE, Scalar::ONE),
+        |(numerator, denominator), signer| {
+            let x_j = signer.to_scalar();
+            (numerator * x_j, denominator * (x_j - x))
+        },
+    );
+    numerator * denominator.invert()
+}
+
+/// Distributed key generation without a trusted dealer ([Pedersen DKG](https://eprint.iacr.org/2020/852.pdf) with proofs of knowledge).
+///
+/// Every participant calls [`part1`], broadcasts the [`Round1Package`],
+/// calls [`part2`] with the packages of all other participants, sends each [`Round2Package`]
+/// **privately** to its receiver and finally calls [`part3`] to obtain its [`KeyShare`].
+pub mod dkg {
+    use super::*;
+
+    /// Secret state of a participant kept between the rounds of the DKG
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Round1Secret {
+        id: ParticipantId,
+        min_signers: u16,
+        max_signers: u16,
+        coefficients: Vec<Secret>,
+    }
+
+    impl Round1Secret {
+        /// Identifier of the participant
+        pub fn id(&self) -> ParticipantId {
+            self.id
+        }
+
+        fn commitment(&self) -> Vec<Element> {
+            self.coefficients
+                .iter()
+                .map(|coefficient| Element(EdwardsPoint::mul_base(&coefficient.0)))
+                .collect()
+        }
+    }
+
+    /// Package broadcast to all other participants
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Round1Package {
+        commitment: Vec<Element>,
+        proof_commitment: Element,
+        proof_response: Secret,
+    }
+
+    /// Package sent privately to a single participant
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Round2Package {
+        secret_share: Secret,
+    }
+
+    /// Challenge of the proof of knowledge, `HDKG` of the `frost-ed25519` crate
+    fn proof_challenge(
+        id: ParticipantId,
+        commitment: &Element,
+        proof_commitment: &Element,
+    ) -> Scalar {
+        hash_to_scalar(&[
+            CONTEXT_STRING,
+            b"dkg",
+            &id.to_scalar().to_bytes(),
+            &commitment.to_bytes(),
+            &proof_commitment.to_bytes(),
+        ])
+    }
+
+    /// Generate the secret polynomial of participant `id` and the package to broadcast
+    ///
+    /// # Errors
+    /// If the threshold parameters are invalid
+    #[cfg(feature = "rand")]
+    pub fn part1(
+        id: ParticipantId,
+        max_signers: u16,
+        min_signers: u16,
+    ) -> Result<(Round1Secret, Round1Package), Error> {
+        if min_signers < 2 || min_signers > max_signers || id.get() > max_signers {
+            return Err(Error::InvalidParameters);
+        }
+
+        let secret = Round1Secret {
+            id,
+            min_signers,
+            max_signers,
+            coefficients: (0..min_signers).map(|_| Secret(random_scalar())).collect(),
+        };
+        let commitment = secret.commitment();
+
+        // Proof of knowledge of the constant term prevents rogue key attacks
+        let nonce = Secret(random_scalar());
+        let proof_commitment = Element(EdwardsPoint::mul_base(&nonce.0));
+        let challenge = proof_challenge(id, &commitment[0], &proof_commitment);
+        let proof_response = Secret(nonce.0 + secret.coefficients[0].0 * challenge);
+
+        Ok((
+            secret,
+            Round1Package {
+                commitment,
+                proof_commitment,
+                proof_response,
+            },
+        ))
+    }
+
+    fn check_participants<T>(
+        secret: &Round1Secret,
+        packages: &BTreeMap<ParticipantId, T>,
+    ) -> Result<(), Error> {
+        let expected = usize::from(secret.max_signers) - 1;
+        if packages.len() != expected
+            || packages.contains_key(&secret.id)
+            || packages.keys().any(|id| id.get() > secret.max_signers)
+        {
+            return Err(Error::UnexpectedParticipants);
+        }
+        Ok(())
+    }
+
+    /// Verify the packages broadcast by all other participants and compute the secret shares for them
+    ///
+    /// # Errors
+    /// - Packages of some participants are missing or unexpected
+    /// - Some participant's package is invalid
+    pub fn part2(
+        secret: &Round1Secret,
+        round1_packages: &BTreeMap<ParticipantId, Round1Package>,
+    ) -> Result<BTreeMap<ParticipantId, Round2Package>, Error> {
+        check_participants(secret, round1_packages)?;
+
+        for (&id, package) in round1_packages {
+            if package.commitment.len() != usize::from(secret.min_signers) {
+                return Err(Error::InvalidProofOfKnowledge(id));
+            }
+            let challenge = proof_challenge(id, &package.commitment[0], &package.proof_commitment);
+            let expected = EdwardsPoint::mul_base(&package.proof_response.0)
+                - package.commitment[0].0 * challenge;
+            if expected != package.proof_commitment.0 {
+                return Err(Error::InvalidProofOfKnowledge(id));
+            }
+        }
+
+        Ok(round1_packages
+            .keys()
+            .map(|&id| {
+                let secret_share =
+                    Secret(evaluate_polynomial(&secret.coefficients, id.to_scalar()));
+                (id, Round2Package { secret_share })
+            })
+            .collect())
+    }
+
+    /// Verify the secret shares received from all other participants and compute the key share
+    ///
+    /// # Errors
+    /// - Packages of some participants are missing or unexpected
+    /// - Some participant's secret share doesn't match its commitment
+    pub fn part3(
+        secret: &Round1Secret,
+        round1_packages: &BTreeMap<ParticipantId, Round1Package>,
+        round2_packages: &BTreeMap<ParticipantId, Round2Package>,
+    ) -> Result<(KeyShare, PublicKeyPackage), Error> {
+        check_participants(secret, round1_packages)?;
+        if !round2_packages.keys().eq(round1_packages.keys()) {
+            return Err(Error::UnexpectedParticipants);
+        }
+
+        let x = secret.id.to_scalar();
+        let mut signing_share = Secret(evaluate_polynomial(&secret.coefficients, x));
+        for (&id, package) in round2_packages {
+            let commitment = &round1_packages[&id].commitment;
+            if EdwardsPoint::mul_base(&package.secret_share.0) != evaluate_commitment(commitment, x)
+            {
+                return Err(Error::InvalidSecretShare(id));
+            }
+            signing_share.0 += package.secret_share.0;
+        }
+
+        let own_commitment = secret.commitment();
+        let commitments = round1_packages
+            .values()
+            .map(|package| package.commitment.as_slice())
+            .chain(core::iter::once(own_commitment.as_slice()))
+            .collect::<Vec<_>>();
+
+        let verifying_key = Element(commitments.iter().map(|commitment| commitment[0].0).sum());
+        let verifying_shares = (1..=secret.max_signers)
+            .filter_map(ParticipantId::new)
+            .map(|id| {
+                let share = commitments
+                    .iter()
+                    .map(|commitment| evaluate_commitment(commitment, id.to_scalar()))
+                    .sum();
+                (id, Element(share))
+            })
+            .collect();
+
+        Ok((
+            KeyShare {
+                id: secret.id,
+                min_signers: secret.min_signers,
+                signing_share,
+                verifying_key,
+            },
+            PublicKeyPackage {
+                min_signers: secret.min_signers,
+                verifying_key,
+                verifying_shares,
+            },
+        ))
+    }
+}
+
+/// Share of the group private key held by a single participant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyShare {
+    id: ParticipantId,
+    min_signers: u16,
+    signing_share: Secret,
+    verifying_key: Element,
+}
+
+impl KeyShare {
+    /// Identifier of the participant
+    pub fn id(&self) -> ParticipantId {
+        self.id
+    }
+
+    /// Group public key
+    pub fn public_key(&self) -> PublicKey {
+        group_public_key(self.verifying_key)
+    }
+}
+
+/// Public information about the group, needed to aggregate signature shares
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKeyPackage {
+    min_signers: u16,
+    verifying_key: Element,
+    verifying_shares: BTreeMap<ParticipantId, Element>,
+}
+
+impl PublicKeyPackage {
+    /// Group public key
+    pub fn public_key(&self) -> PublicKey {
+        group_public_key(self.verifying_key)
+    }
+
+    /// Minimal number of signers required to produce a signature
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+fn group_public_key(verifying_key: Element) -> PublicKey {
+    PublicKey::from_raw(Algorithm::Ed25519, &verifying_key.to_bytes())
+        .expect("Valid Ed25519 point is a valid public key")
+}
+
+/// Single-use nonces of a signer. Must never be reused as it would reveal the key share
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SigningNonces {
+    hiding: Secret,
+    binding: Secret,
+}
+
+/// Commitments to the [`SigningNonces`] published by a signer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningCommitments {
+    hiding: Element,
+    binding: Element,
+}
+
+/// Message to sign together with the commitments of the chosen signers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningPackage {
+    commitments: BTreeMap<ParticipantId, SigningCommitments>,
+    #[serde(with = "hex::serde")]
+    message: Vec<u8>,
+}
+
+impl SigningPackage {
+    /// Construct [`Self`]
+    pub fn new(commitments: BTreeMap<ParticipantId, SigningCommitments>, message: Vec<u8>) -> Self {
+        Self {
+            commitments,
+            message,
+        }
+    }
+
+    /// Message to sign
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    fn binding_factors(&self, verifying_key: Element) -> BTreeMap<ParticipantId, Scalar> {
+        let message_hash = hash(&[CONTEXT_STRING, b"msg", &self.message]);
+        let mut encoded_commitments = Vec::with_capacity(self.commitments.len() * 96);
+        for (id, commitments) in &self.commitments {
+            encoded_commitments.extend_from_slice(&id.to_scalar().to_bytes());
+            encoded_commitments.extend_from_slice(&commitments.hiding.to_bytes());
+            encoded_commitments.extend_from_slice(&commitments.binding.to_bytes());
+        }
+        let commitments_hash = hash(&[CONTEXT_STRING, b"com", &encoded_commitments]);
+
+        self.commitments
+            .keys()
+            .map(|&id| {
+                let binding_factor = hash_to_scalar(&[
+                    CONTEXT_STRING,
+                    b"rho",
+                    &verifying_key.to_bytes(),
+                    &message_hash,
+                    &commitments_hash,
+                    &id.to_scalar().to_bytes(),
+                ]);
+                (id, binding_factor)
+            })
+            .collect()
+    }
+
+    /// Group commitment and the `Ed25519` challenge
+    fn group_commitment(
+        &self,
+        verifying_key: Element,
+        binding_factors: &BTreeMap<ParticipantId, Scalar>,
+    ) -> (Element, Scalar) {
+        let group_commitment = Element(
+            self.commitments
+                .iter()
+                .map(|(id, commitments)| {
+                    commitments.hiding.0 + commitments.binding.0 * binding_factors[id]
+                })
+                .sum(),
+        );
+        let challenge = hash_to_scalar(&[
+            &group_commitment.to_bytes(),
+            &verifying_key.to_bytes(),
+            &self.message,
+        ]);
+        (group_commitment, challenge)
+    }
+}
+
+/// Share of the signature produced by a single signer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignatureShare(Secret);
+
+/// Generate single-use nonces and commitments to them for the next signing session
+#[cfg(feature = "rand")]
+pub fn commit(key_share: &KeyShare) -> (SigningNonces, SigningCommitments) {
+    let mut hiding_randomness = [0; 32];
+    let mut binding_randomness = [0; 32];
+    OsRng.fill_bytes(&mut hiding_randomness);
+    OsRng.fill_bytes(&mut binding_randomness);
+    let nonces = commit_with_randomness(key_share, &hiding_randomness, &binding_randomness);
+    hiding_randomness.zeroize();
+    binding_randomness.zeroize();
+    nonces
+}
+
+/// `commit` of RFC 9591 with the given random bytes of the nonces
+#[cfg(any(feature = "rand", test))]
+fn commit_with_randomness(
+    key_share: &KeyShare,
+    hiding_randomness: &[u8; 32],
+    binding_randomness: &[u8; 32],
+) -> (SigningNonces, SigningCommitments) {
+    // `nonce_generate` of RFC 9591
+    let generate_nonce = |random_bytes: &[u8; 32]| {
+        Secret(hash_to_scalar(&[
+            CONTEXT_STRING,
+            b"nonce",
+            random_bytes,
+            &key_share.signing_share.0.to_bytes(),
+        ]))
+    };
+    let nonces = SigningNonces {
+        hiding: generate_nonce(hiding_randomness),
+        binding: generate_nonce(binding_randomness),
+    };
+    let commitments = SigningCommitments {
+        hiding: Element(EdwardsPoint::mul_base(&nonces.hiding.0)),
+        binding: Element(EdwardsPoint::mul_base(&nonces.binding.0)),
+    };
+    (nonces, commitments)
+}
+
+/// Produce the signature share of `key_share` consuming the `nonces` committed to in `package`
+///
+/// # Errors
+/// - Not enough signers in `package` or the owner of `key_share` isn't one of them
+/// - `nonces` don't match the commitments in `package`
+#[allow(clippy::needless_pass_by_value)] // NOTE: Nonces are taken by value so that they can't be reused
+pub fn sign(
+    package: &SigningPackage,
+    nonces: SigningNonces,
+    key_share: &KeyShare,
+) -> Result<SignatureShare, Error> {
+    if package.commitments.len() < usize::from(key_share.min_signers) {
+        return Err(Error::InvalidSigners);
+    }
+    let commitments = package
+        .commitments
+        .get(&key_share.id)
+        .ok_or(Error::InvalidSigners)?;
+    if EdwardsPoint::mul_base(&nonces.hiding.0) != commitments.hiding.0
+        || EdwardsPoint::mul_base(&nonces.binding.0) != commitments.binding.0
+    {
+        return Err(Error::NonceMismatch);
+    }
+
+    let binding_factors = package.binding_factors(key_share.verifying_key);
+    let (_, challenge) = package.group_commitment(key_share.verifying_key, &binding_factors);
+    let lambda = lagrange_coefficient(key_share.id, package.commitments.keys().copied());
+
+    Ok(SignatureShare(Secret(
+        nonces.hiding.0
+            + nonces.binding.0 * binding_factors[&key_share.id]
+            + lambda * key_share.signing_share.0 * challenge,
+    )))
+}
+
+/// Verify signature shares of all signers in `package` and combine them into the group signature
+///
+/// # Errors
+/// - Not enough signers or shares don't correspond to the signers in `package`
+/// - Some signature share is invalid
+pub fn aggregate(
+    package: &SigningPackage,
+    signature_shares: &BTreeMap<ParticipantId, SignatureShare>,
+    public_key_package: &PublicKeyPackage,
+) -> Result<Signature, Error> {
+    if package.commitments.len() < usize::from(public_key_package.min_signers)
+        || !signature_shares.keys().eq(package.commitments.keys())
+    {
+        return Err(Error::InvalidSigners);
+    }
+
+    let verifying_key = public_key_package.verifying_key;
+    let binding_factors = package.binding_factors(verifying_key);
+    let (group_commitment, challenge) = package.group_commitment(verifying_key, &binding_factors);
+
+    for (&id, share) in signature_shares {
+        let verifying_share = public_key_package
+            .verifying_shares
+            .get(&id)
+            .ok_or(Error::InvalidSigners)?;
+        let commitments = &package.commitments[&id];
+        let lambda = lagrange_coefficient(id, package.commitments.keys().copied());
+
+        let expected = commitments.hiding.0
+            + commitments.binding.0 * binding_factors[&id]
+            + verifying_share.0 * (challenge * lambda);
+        if EdwardsPoint::mul_base(&share.0 .0) != expected {
+            return Err(Error::InvalidSignatureShare(id));
+        }
+    }
+
+    let response: Scalar = signature_shares.values().map(|share| share.0 .0).sum();
+    let mut payload = Vec::with_capacity(64);
+    payload.extend_from_slice(&group_commitment.to_bytes());
+    payload.extend_from_slice(&response.to_bytes());
+
+    Ok(Signature::from_raw_parts(
+        public_key_package.public_key(),
+        payload,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Participants = BTreeMap<ParticipantId, (KeyShare, PublicKeyPackage)>;
+
+    fn run_dkg(max_signers: u16, min_signers: u16) -> Participants {
+        let round1 = (1..=max_signers)
+            .map(|id| {
+                let id = ParticipantId::new(id).unwrap();
+                (id, dkg::part1(id, max_signers, min_signers).unwrap())
+            })
+            .collect::<BTreeMap<_, _>>();
+        let others = |id: ParticipantId| {
+            round1
+                .iter()
+                .filter(|(other, _)| **other != id)
+                .map(|(&other, (_, package))| (other, package.clone()))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut round2 = BTreeMap::<_, BTreeMap<_, _>>::new();
+        for (&id, (secret, _)) in &round1 {
+            for (receiver, package) in dkg::part2(secret, &others(id)).unwrap() {
+                round2.entry(receiver).or_default().insert(id, package);
+            }
+        }
+
+        round1
+            .iter()
+            .map(|(&id, (secret, _))| (id, dkg::part3(secret, &others(id), &round2[&id]).unwrap()))
+            .collect()
+    }
+
+    fn threshold_sign(
+        participants: &Participants,
+        signers: &[u16],
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        let signers = signers
+            .iter()
+            .map(|&id| &participants[&ParticipantId::new(id).unwrap()].0)
+            .collect::<Vec<_>>();
+        let (nonces, commitments): (Vec<_>, BTreeMap<_, _>) = signers
+            .iter()
+            .map(|key_share| {
+                let (nonces, commitments) = commit(key_share);
+                (nonces, (key_share.id(), commitments))
+            })
+            .unzip();
+        let package = SigningPackage::new(commitments, message.to_vec());
+
+        let shares: BTreeMap<_, _> = signers
+            .iter()
+            .zip(nonces)
+            .map(|(key_share, nonces)| Ok((key_share.id(), sign(&package, nonces, key_share)?)))
+            .collect::<Result<_, Error>>()?;
+        let public_key_package = &participants.values().next().unwrap().1;
+        aggregate(&package, &shares, public_key_package)
+    }
+
+    /// Test vectors of `FROST(Ed25519, SHA-512)` from RFC 9591, Appendix E.1
+    #[test]
+    fn rfc_9591_test_vectors() {
+        fn bytes(hex: &str) -> [u8; 32] {
+            hex::decode(hex).unwrap().try_into().unwrap()
+        }
+        fn scalar(hex: &str) -> Scalar {
+            Scalar::from_canonical_bytes(bytes(hex)).unwrap()
+        }
+        fn element(hex: &str) -> Element {
+            Element(CompressedEdwardsY(bytes(hex)).decompress().unwrap())
+        }
+
+        let min_signers = 2;
+        let verifying_key =
+            element("15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673");
+        let message = hex::decode("74657374").unwrap();
+        let key_share = |id: u16, signing_share: &str| KeyShare {
+            id: ParticipantId::new(id).unwrap(),
+            min_signers,
+            signing_share: Secret(scalar(signing_share)),
+            verifying_key,
+        };
+        // Participants 1 and 3 out of 3 sign
+        let key_shares = [
+            key_share(1, "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509"),
+            key_share(3, "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02"),
+        ];
+        let randomness = [
+            (
+                "0fd2e39e111cdc266f6c0f4d0fd45c947761f1f5d3cb583dfcb9bbaf8d4c9fec",
+                "69cd85f631d5f7f2721ed5e40519b1366f340a87c2f6856363dbdcda348a7501",
+            ),
+            (
+                "86d64a260059e495d0fb4fcc17ea3da7452391baa494d4b00321098ed2a0062f",
+                "13e6b25afb2eba51716a9a7d44130c0dbae0004a9ef8d7b5550c8a0e07c61775",
+            ),
+        ];
+        let expected_commitments = [
+            (
+                "b5aa8ab305882a6fc69cbee9327e5a45e54c08af61ae77cb8207be3d2ce13de3",
+                "67e98ab55aa310c3120418e5050c9cf76cf387cb20ac9e4b6fdb6f82a469f932",
+            ),
+            (
+                "cfbdb165bd8aad6eb79deb8d287bcc0ab6658ae57fdcc98ed12c0669e90aec91",
+                "7487bc41a6e712eea2f2af24681b58b1cf1da278ea11fe4e8b78398965f13552",
+            ),
+        ];
+
+        let (nonces, commitments): (Vec<_>, BTreeMap<_, _>) = key_shares
+            .iter()
+            .zip(randomness)
+            .map(|(key_share, (hiding, binding))| {
+                let (nonces, commitments) =
+                    commit_with_randomness(key_share, &bytes(hiding), &bytes(binding));
+                (nonces, (key_share.id(), commitments))
+            })
+            .unzip();
+        for (commitments, (hiding, binding)) in commitments.values().zip(expected_commitments) {
+            assert_eq!(commitments.hiding, element(hiding));
+            assert_eq!(commitments.binding, element(binding));
+        }
+
+        let package = SigningPackage::new(commitments, message.clone());
+        assert_eq!(
+            package
+                .binding_factors(verifying_key)
+                .into_values()
+                .collect::<Vec<_>>(),
+            [
+                scalar("f2cb9d7dd9beff688da6fcc83fa89046b3479417f47f55600b106760eb3b5603"),
+                scalar("b087686bf35a13f3dc78e780a34b0fe8a77fef1b9938c563f5573d71d8d7890f"),
+            ]
+        );
+
+        let shares = key_shares
+            .iter()
+            .zip(nonces)
+            .map(|(key_share, nonces)| (key_share.id(), sign(&package, nonces, key_share).unwrap()))
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(
+            shares.values().map(|share| share.0 .0).collect::<Vec<_>>(),
+            [
+                scalar("001719ab5a53ee1a12095cd088fd149702c0720ce5fd2f29dbecf24b7281b603"),
+                scalar("bd86125de990acc5e1f13781d8e32c03a9bbd4c53539bbc106058bfd14326007"),
+            ]
+        );
+
+        let public_key_package = PublicKeyPackage {
+            min_signers,
+            verifying_key,
+            verifying_shares: key_shares
+                .iter()
+                .map(|key_share| {
+                    let verifying_share = EdwardsPoint::mul_base(&key_share.signing_share.0);
+                    (key_share.id(), Element(verifying_share))
+                })
+                .collect(),
+        };
+        let signature = aggregate(&package, &shares, &public_key_package).unwrap();
+        assert_eq!(
+            hex::encode(signature.payload()),
+            "36282629c383bb820a88b71cae937d41f2f2adfcc3d02e55507e2fb9e2dd3cbe\
+             bd9d2b0844e49ae0f3fa935161e1419aab7b47d21a37ebeae1f17d4987b3160b"
+        );
+        signature.verify(&message).unwrap();
+    }
+
+    #[test]
+    fn all_participants_agree_on_public_key() {
+        let participants = run_dkg(4, 3);
+        let (_, public_key_package) = participants.values().next().unwrap();
+
+        for (key_share, package) in participants.values() {
+            assert_eq!(package, public_key_package);
+            assert_eq!(key_share.public_key(), public_key_package.public_key());
+        }
+    }
+
+    #[test]
+    fn any_subset_of_min_signers_produces_valid_signature() {
+        let participants = run_dkg(4, 3);
+        let message = b"genesis";
+
+        for signers in [[1, 2, 3], [1, 2, 4], [1, 3, 4], [2, 3, 4]] {
+            let signature = threshold_sign(&participants, &signers, message).unwrap();
+            signature.verify(message).unwrap();
+        }
+        let signature = threshold_sign(&participants, &[1, 2, 3, 4], message).unwrap();
+        signature.verify(message).unwrap();
+    }
+
+    #[test]
+    fn not_enough_signers_fails() {
+        let participants = run_dkg(4, 3);
+
+        assert_eq!(
+            threshold_sign(&participants, &[1, 2], b"genesis"),
+            Err(Error::InvalidSigners)
+        );
+    }
+
+    #[test]
+    fn invalid_signature_share_is_detected() {
+        let participants = run_dkg(3, 2);
+        let signers = [1, 2].map(|id| &participants[&ParticipantId::new(id).unwrap()].0);
+        let (nonces, commitments): (Vec<_>, BTreeMap<_, _>) = signers
+            .iter()
+            .map(|key_share| {
+                let (nonces, commitments) = commit(key_share);
+                (nonces, (key_share.id(), commitments))
+            })
+            .unzip();
+        let package = SigningPackage::new(commitments, b"genesis".to_vec());
+        let mut shares = signers
+            .iter()
+            .zip(nonces)
+            .map(|(key_share, nonces)| (key_share.id(), sign(&package, nonces, key_share).unwrap()))
+            .collect::<BTreeMap<_, _>>();
+        let id = ParticipantId::new(2).unwrap();
+        shares.get_mut(&id).unwrap().0 .0 += Scalar::ONE;
+
+        assert_eq!(
+            aggregate(&package, &shares, &participants[&id].1),
+            Err(Error::InvalidSignatureShare(id))
+        );
+    }
+
+    #[test]
+    fn tampered_secret_share_is_detected() {
+        let id_1 = ParticipantId::new(1).unwrap();
+        let id_2 = ParticipantId::new(2).unwrap();
+        let (_, package_1) = dkg::part1(id_1, 2, 2).unwrap();
+        let (secret_2, package_2) = dkg::part1(id_2, 2, 2).unwrap();
+        // Share is computed from a polynomial other than the committed one
+        let (other_secret_1, _) = dkg::part1(id_1, 2, 2).unwrap();
+
+        let mut round2 = dkg::part2(&other_secret_1, &BTreeMap::from([(id_2, package_2)])).unwrap();
+
+        assert!(matches!(
+            dkg::part3(
+                &secret_2,
+                &BTreeMap::from([(id_1, package_1)]),
+                &BTreeMap::from([(id_1, round2.remove(&id_2).unwrap())])
+            ),
+            Err(Error::InvalidSecretShare(id)) if id == id_1
+        ));
+    }
+
+    #[test]
+    fn commitments_with_small_order_points_are_rejected() {
+        let participants = run_dkg(2, 2);
+        let (_, commitments) = commit(&participants.values().next().unwrap().0);
+        let with_hiding = |hiding: EdwardsPoint| {
+            serde_json::to_string(&SigningCommitments {
+                hiding: Element(hiding),
+                ..commitments
+            })
+            .unwrap()
+        };
+
+        assert!(
+            serde_json::from_str::<SigningCommitments>(&with_hiding(commitments.hiding.0)).is_ok()
+        );
+        assert!(
+            serde_json::from_str::<SigningCommitments>(&with_hiding(EdwardsPoint::default()))
+                .is_err()
+        );
+        let eight_torsion = curve25519_dalek::constants::EIGHT_TORSION[1];
+        assert!(serde_json::from_str::<SigningCommitments>(&with_hiding(eight_torsion)).is_err());
+        assert!(serde_json::from_str::<SigningCommitments>(&with_hiding(
+            commitments.hiding.0 + eight_torsion
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn key_share_serialization_roundtrip() {
+        let participants = run_dkg(2, 2);
+        let (key_share, public_key_package) = participants.values().next().unwrap();
+
+        let json = serde_json::to_string(key_share).unwrap();
+        let deserialized: KeyShare = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.id(), key_share.id());
+        assert_eq!(deserialized.signing_share, key_share.signing_share);
+
+        let json = serde_json::to_string(public_key_package).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<PublicKeyPackage>(&json).unwrap(),
+            public_key_package
+        );
+    }
+}
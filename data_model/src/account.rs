@@ -26,7 +26,7 @@ use crate::{
         AssetsMap,
     },
     domain::prelude::*,
-    metadata::Metadata,
+    metadata::{Metadata, MetadataSchema},
     name::Name,
     HasMetadata, Identifiable, ParseError, PublicKey, Registered,
 };
@@ -105,6 +105,11 @@ pub mod model {
         pub signature_check_condition: SignatureCheckCondition,
         /// Metadata of this account as a key-value store.
         pub metadata: Metadata,
+        /// Schema which metadata of this account has to conform to.
+        /// Immutable once registered.
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub metadata_schema: Option<MetadataSchema>,
     }
 
     /// Builder which should be submitted in a transaction to create a new [`Account`]
@@ -121,6 +126,9 @@ pub mod model {
         pub signatories: Signatories,
         /// Metadata that should be submitted with the builder
         pub metadata: Metadata,
+        /// Schema which metadata of the account has to conform to.
+        #[serde(default)]
+        pub metadata_schema: Option<MetadataSchema>,
     }
 
     /// Condition which checks if the account has the right signatures.
@@ -219,6 +227,7 @@ impl NewAccount {
             id,
             signatories: signatories.into_iter().collect(),
             metadata: Metadata::default(),
+            metadata_schema: None,
         }
     }
 
@@ -228,6 +237,13 @@ impl NewAccount {
         self.metadata = metadata;
         self
     }
+
+    /// Add [`MetadataSchema`] to the account replacing any previously defined schema
+    #[must_use]
+    pub fn with_metadata_schema(mut self, metadata_schema: MetadataSchema) -> Self {
+        self.metadata_schema = Some(metadata_schema);
+        self
+    }
 }
 
 impl HasMetadata for NewAccount {
@@ -8,6 +8,7 @@ use std::collections::btree_map;
 
 use derive_more::{Constructor, DebugCustom, Display};
 use getset::{CopyGetters, Getters};
+use iroha_crypto::Hash;
 use iroha_data_model_derive::{model, IdEqOrdHash};
 use iroha_macro::FromVariant;
 use iroha_primitives::{fixed, fixed::Fixed};
@@ -19,8 +20,12 @@ use strum::EnumString;
 
 pub use self::model::*;
 use crate::{
-    account::prelude::*, domain::prelude::*, ipfs::IpfsPath, metadata::Metadata, HasMetadata,
-    Identifiable, Name, NumericValue, ParseError, Registered, TryAsMut, TryAsRef, Value,
+    account::prelude::*,
+    domain::prelude::*,
+    ipfs::IpfsPath,
+    metadata::{Metadata, MetadataSchema},
+    HasMetadata, Identifiable, Name, NumericValue, ParseError, Registered, TryAsMut, TryAsRef,
+    Value,
 };
 
 /// API to work with collections of [`Id`] : [`Asset`] mappings.
@@ -130,6 +135,11 @@ pub mod model {
         pub logo: Option<IpfsPath>,
         /// Metadata of this asset definition as a key-value store.
         pub metadata: Metadata,
+        /// Schema which metadata of this asset definition has to conform to.
+        /// Immutable once registered.
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub metadata_schema: Option<MetadataSchema>,
         /// The account that owns this asset. Usually the [`Account`] that registered it.
         #[getset(get = "pub")]
         pub owned_by: AccountId,
@@ -176,6 +186,9 @@ pub mod model {
         pub logo: Option<IpfsPath>,
         /// Metadata associated with the asset definition builder.
         pub metadata: Metadata,
+        /// Schema which metadata of the asset definition has to conform to.
+        #[serde(default)]
+        pub metadata_schema: Option<MetadataSchema>,
     }
     /// Asset's inner value type.
     #[derive(
@@ -209,6 +222,9 @@ pub mod model {
         /// Asset's key-value structured data.
         #[display(fmt = "s")]
         Store,
+        /// Asset's non-fungible tokens.
+        #[display(fmt = "n")]
+        Nft,
     }
 
     /// Asset's inner value.
@@ -240,8 +256,58 @@ pub mod model {
         Fixed(fixed::Fixed),
         /// Asset's key-value structured data.
         Store(Metadata),
+        /// Asset's non-fungible tokens.
+        Nft(Nfts),
+    }
+
+    /// Non-fungible token held in an [`Asset`] of [`AssetValueType::Nft`] type.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{token_id}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Nft {
+        /// Identification of the token, unique among all tokens of the same [`AssetDefinition`].
+        pub token_id: Name,
+        /// Hash of the content this token represents.
+        pub content_hash: Option<Hash>,
+        /// Metadata of this token as a key-value store.
+        pub metadata: Metadata,
     }
 
+    /// Collection of [`Nft`]s of the same [`AssetDefinition`] held by an account.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Encode,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{}n", "_0.len()")]
+    #[ffi_type(opaque)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct Nfts(pub(super) btree_map::BTreeMap<Name, Nft>);
+
     /// An assets mintability scheme. `Infinitely` means elastic
     /// supply. `Once` is what you want to use. Don't use `Not` explicitly
     /// outside of smartcontracts.
@@ -311,6 +377,13 @@ impl AssetDefinition {
     pub fn store(id: AssetDefinitionId) -> <Self as Registered>::With {
         <Self as Registered>::With::new(id, AssetValueType::Store)
     }
+
+    /// Construct builder for [`AssetDefinition`] identifiable by [`Id`].
+    #[must_use]
+    #[inline]
+    pub fn nft(id: AssetDefinitionId) -> <Self as Registered>::With {
+        <Self as Registered>::With::new(id, AssetValueType::Nft)
+    }
 }
 
 impl Asset {
@@ -332,6 +405,7 @@ impl NewAssetDefinition {
             mintable: Mintable::Infinitely,
             logo: None,
             metadata: Metadata::default(),
+            metadata_schema: None,
         }
     }
 
@@ -357,6 +431,14 @@ impl NewAssetDefinition {
         self.metadata = metadata;
         self
     }
+
+    /// Add [`MetadataSchema`] to the asset definition replacing previously defined value
+    #[inline]
+    #[must_use]
+    pub fn with_metadata_schema(mut self, metadata_schema: MetadataSchema) -> Self {
+        self.metadata_schema = Some(metadata_schema);
+        self
+    }
 }
 
 impl HasMetadata for AssetDefinition {
@@ -373,15 +455,144 @@ impl AssetValue {
             Self::BigQuantity(_) => AssetValueType::BigQuantity,
             Self::Fixed(_) => AssetValueType::Fixed,
             Self::Store(_) => AssetValueType::Store,
+            Self::Nft(_) => AssetValueType::Nft,
         }
     }
-    /// Returns true if this value is zero, false if it contains [`Metadata`] or positive value
-    pub const fn is_zero_value(&self) -> bool {
+    /// Returns true if this value is zero or holds no [`Nft`]s, false if it contains [`Metadata`] or positive value
+    pub fn is_zero_value(&self) -> bool {
         match *self {
             Self::Quantity(q) => q == 0_u32,
             Self::BigQuantity(q) => q == 0_u128,
             Self::Fixed(ref q) => q.is_zero(),
             Self::Store(_) => false,
+            Self::Nft(ref nfts) => nfts.is_empty(),
+        }
+    }
+}
+
+impl Nft {
+    /// Construct [`Nft`] with the given token id and no content hash or metadata.
+    #[inline]
+    #[must_use]
+    pub fn new(token_id: Name) -> Self {
+        Self {
+            token_id,
+            content_hash: None,
+            metadata: Metadata::default(),
+        }
+    }
+
+    /// Add hash of the content represented by the token replacing previously defined value
+    #[inline]
+    #[must_use]
+    pub fn with_content_hash(mut self, content_hash: Hash) -> Self {
+        self.content_hash = Some(content_hash);
+        self
+    }
+
+    /// Add [`Metadata`] to the token replacing previously defined value
+    #[inline]
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+}
+
+impl HasMetadata for Nft {
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
+impl Nfts {
+    /// Get the [`Nft`] with the given token id.
+    #[inline]
+    pub fn get(&self, token_id: &Name) -> Option<&Nft> {
+        self.0.get(token_id)
+    }
+
+    /// Check if the [`Nft`] with the given token id is in the collection.
+    #[inline]
+    pub fn contains(&self, token_id: &Name) -> bool {
+        self.0.contains_key(token_id)
+    }
+
+    /// Iterate over the [`Nft`]s ordered by their token ids.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Nft> {
+        self.0.values()
+    }
+
+    /// Number of [`Nft`]s in the collection.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if the collection holds no [`Nft`]s.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Insert [`Nft`] into the collection, returning the previous token with the same id if any.
+    #[inline]
+    pub fn insert(&mut self, nft: Nft) -> Option<Nft> {
+        self.0.insert(nft.token_id.clone(), nft)
+    }
+
+    /// Remove the [`Nft`] with the given token id from the collection.
+    #[inline]
+    pub fn remove(&mut self, token_id: &Name) -> Option<Nft> {
+        self.0.remove(token_id)
+    }
+}
+
+impl FromIterator<Nft> for Nfts {
+    fn from_iter<T: IntoIterator<Item = Nft>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|nft| (nft.token_id.clone(), nft))
+                .collect(),
+        )
+    }
+}
+
+mod candidate {
+    use parity_scale_codec::Input;
+
+    use super::*;
+
+    #[derive(Decode, Deserialize)]
+    #[serde(transparent)]
+    struct NftsCandidate(btree_map::BTreeMap<Name, Nft>);
+
+    impl NftsCandidate {
+        fn validate(self) -> Result<Nfts, &'static str> {
+            if self.0.iter().any(|(token_id, nft)| *token_id != nft.token_id) {
+                return Err("NFT is stored under a key which is not its token id");
+            }
+
+            Ok(Nfts(self.0))
+        }
+    }
+
+    impl Decode for Nfts {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+            NftsCandidate::decode(input)?.validate().map_err(Into::into)
+        }
+    }
+    impl<'de> Deserialize<'de> for Nfts {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error as _;
+
+            NftsCandidate::deserialize(deserializer)?
+                .validate()
+                .map_err(D::Error::custom)
         }
     }
 }
@@ -419,6 +630,7 @@ impl_try_as_for_asset_value! {
     BigQuantity(u128),
     Fixed(Fixed),
     Store(Metadata),
+    Nft(Nfts),
 }
 
 impl TryFrom<AssetValue> for NumericValue {
@@ -570,7 +782,7 @@ impl FromIterator<AssetDefinition> for Value {
 pub mod prelude {
     pub use super::{
         Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetValue, AssetValueType, Mintable,
-        NewAssetDefinition,
+        NewAssetDefinition, Nft, Nfts,
     };
 }
 
@@ -582,4 +794,30 @@ mod tests {
         let _invalid_asset_id = AssetId::from_str("store#alice@wonderland")
             .expect_err("store#alice@wonderland should not be a valid AssetId");
     }
+
+    #[test]
+    fn nfts_under_foreign_keys_are_rejected() {
+        let token = Nft::new("token".parse().expect("Valid"));
+        let nfts = Nfts(btree_map::BTreeMap::from([(
+            "other_token".parse().expect("Valid"),
+            token,
+        )]));
+
+        assert!(Nfts::decode(&mut nfts.encode().as_slice()).is_err());
+        assert!(serde_json::from_value::<Nfts>(serde_json::to_value(&nfts).unwrap()).is_err());
+    }
+
+    #[test]
+    fn nfts_under_their_token_ids_are_accepted() {
+        let nfts = ["first", "second"]
+            .into_iter()
+            .map(|token_id| Nft::new(token_id.parse().expect("Valid")))
+            .collect::<Nfts>();
+
+        assert_eq!(Nfts::decode(&mut nfts.encode().as_slice()).unwrap(), nfts);
+        assert_eq!(
+            serde_json::from_value::<Nfts>(serde_json::to_value(&nfts).unwrap()).unwrap(),
+            nfts
+        );
+    }
 }
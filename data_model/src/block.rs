@@ -12,7 +12,7 @@ use derive_more::Display;
 use getset::Getters;
 #[cfg(all(feature = "std", feature = "transparent_api"))]
 use iroha_crypto::KeyPair;
-use iroha_crypto::{HashOf, MerkleTree, SignaturesOf};
+use iroha_crypto::{AggregateSignature, HashOf, MerkleTree, PublicKey, SignaturesOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_primitives::unique_vec::UniqueVec;
@@ -125,6 +125,37 @@ pub mod model {
         pub signatures: SignaturesOf<BlockPayload>,
         /// Block payload
         pub payload: BlockPayload,
+        /// Signatures of peers which approved this block aggregated into a single signature.
+        ///
+        /// When present, only the leader's signature is kept in [`Self::signatures`].
+        #[getset(skip)]
+        pub commit_certificate: Option<BlockCommitCertificate>,
+    }
+
+    /// Certificate of the block commit made of a single aggregated BLS signature
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct BlockCommitCertificate {
+        /// Signatures of the approving peers aggregated into one
+        pub signature: AggregateSignature,
+        /// Bitmap of the approving peers. Bit `i` is set if `i`-th peer of
+        /// [`BlockPayload::commit_topology`] contributed to [`Self::signature`].
+        #[getset(skip)]
+        pub signers: Vec<u8>,
     }
 }
 
@@ -187,6 +218,33 @@ impl SignedBlock {
         &block.signatures
     }
 
+    /// Aggregated signatures of peers which approved this block, if any.
+    pub fn commit_certificate(&self) -> Option<&BlockCommitCertificate> {
+        let SignedBlock::V1(block) = self;
+        block.commit_certificate.as_ref()
+    }
+
+    /// Public keys of peers which approved this block.
+    pub fn signees(&self) -> Vec<&PublicKey> {
+        let SignedBlock::V1(block) = self;
+
+        block.commit_certificate.as_ref().map_or_else(
+            || {
+                block
+                    .signatures
+                    .iter()
+                    .map(|signature| signature.public_key())
+                    .collect()
+            },
+            |certificate| {
+                certificate
+                    .signers(&block.payload.commit_topology)
+                    .map(peer::PeerId::public_key)
+                    .collect()
+            },
+        )
+    }
+
     /// Calculate block hash
     #[cfg(feature = "std")]
     pub fn hash(&self) -> HashOf<Self> {
@@ -246,6 +304,105 @@ impl SignedBlock {
 
         true
     }
+
+    /// Replace signatures of this block with the given commit certificate
+    /// keeping only `retained_signatures` individually.
+    ///
+    /// # Errors
+    ///
+    /// If certificate or any of the retained signatures don't match the block payload
+    #[cfg(feature = "std")]
+    #[cfg(feature = "transparent_api")]
+    pub fn set_commit_certificate(
+        &mut self,
+        certificate: BlockCommitCertificate,
+        retained_signatures: iroha_crypto::SignaturesOf<BlockPayload>,
+    ) -> Result<(), iroha_crypto::error::Error> {
+        certificate.verify(self.payload())?;
+        retained_signatures
+            .verify(self.payload())
+            .map_err(|error| iroha_crypto::error::Error::Other(error.reason))?;
+
+        let SignedBlock::V1(block) = self;
+        block.signatures = retained_signatures;
+        block.commit_certificate = Some(certificate);
+
+        Ok(())
+    }
+}
+
+impl BlockCommitCertificate {
+    /// Aggregate signatures of the peers from [`BlockPayload::commit_topology`]
+    /// into a single certificate. Signatures of other peers are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - None of the signatures were made by a peer from the commit topology
+    /// - Signatures can't be aggregated, e.g. not all peers use the same BLS algorithm
+    #[cfg(feature = "std")]
+    pub fn aggregate(
+        payload: &BlockPayload,
+        signatures: &SignaturesOf<BlockPayload>,
+    ) -> Result<Self, iroha_crypto::error::Error> {
+        let commit_topology = &payload.commit_topology;
+        let mut signers = vec![0_u8; commit_topology.len().div_ceil(8)];
+
+        let mut aggregated = Vec::new();
+        for signature in signatures {
+            if let Some(index) = commit_topology
+                .iter()
+                .position(|peer| peer.public_key() == signature.public_key())
+            {
+                signers[index / 8] |= 1 << (index % 8);
+                aggregated.push(&**signature);
+            }
+        }
+
+        let signature = AggregateSignature::aggregate(aggregated)?;
+        Ok(Self { signature, signers })
+    }
+
+    /// Peers from the given commit topology which contributed to this certificate.
+    pub fn signers<'cert, 'topology: 'cert>(
+        &'cert self,
+        commit_topology: &'topology [peer::PeerId],
+    ) -> impl Iterator<Item = &'topology peer::PeerId> + 'cert {
+        commit_topology
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                self.signers
+                    .get(index / 8)
+                    .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+            })
+            .map(|(_, peer)| peer)
+    }
+
+    /// Verify that the certificate was produced by the signers of the given block payload.
+    ///
+    /// # Errors
+    ///
+    /// - Bitmap of the signers doesn't match the commit topology or is empty
+    /// - Aggregated signature didn't pass verification
+    #[cfg(feature = "std")]
+    pub fn verify(&self, payload: &BlockPayload) -> Result<(), iroha_crypto::error::Error> {
+        let commit_topology = &payload.commit_topology;
+
+        if self.signers.len() != commit_topology.len().div_ceil(8) {
+            return Err(iroha_crypto::error::Error::Other(
+                "Signers bitmap doesn't match the commit topology".to_owned(),
+            ));
+        }
+        let mut signers = self.signers(commit_topology).peekable();
+        if signers.peek().is_none() {
+            return Err(iroha_crypto::error::Error::EmptySignatureIter);
+        }
+
+        self.signature.verify(
+            signers.map(peer::PeerId::public_key),
+            HashOf::new(payload).as_ref(),
+        )
+    }
 }
 
 mod candidate {
@@ -257,6 +414,7 @@ mod candidate {
     struct SignedBlockCandidate {
         signatures: SignaturesOf<BlockPayload>,
         payload: BlockPayload,
+        commit_certificate: Option<BlockCommitCertificate>,
     }
 
     impl SignedBlockCandidate {
@@ -264,6 +422,8 @@ mod candidate {
             #[cfg(feature = "std")]
             self.validate_signatures()?;
             #[cfg(feature = "std")]
+            self.validate_commit_certificate()?;
+            #[cfg(feature = "std")]
             self.validate_header()?;
 
             if self.payload.transactions.is_empty() {
@@ -273,6 +433,7 @@ mod candidate {
             Ok(SignedBlockV1 {
                 payload: self.payload,
                 signatures: self.signatures,
+                commit_certificate: self.commit_certificate,
             })
         }
 
@@ -302,6 +463,14 @@ mod candidate {
                 .verify(&self.payload)
                 .map_err(|_| "Transaction contains invalid signatures")
         }
+
+        #[cfg(feature = "std")]
+        fn validate_commit_certificate(&self) -> Result<(), &'static str> {
+            self.commit_certificate
+                .as_ref()
+                .map_or(Ok(()), |certificate| certificate.verify(&self.payload))
+                .map_err(|_| "Block contains invalid commit certificate")
+        }
     }
 
     impl Decode for SignedBlockV1 {
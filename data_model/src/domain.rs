@@ -16,7 +16,7 @@ use crate::{
     account::{Account, AccountsMap},
     asset::{AssetDefinition, AssetDefinitionsMap, AssetTotalQuantityMap},
     ipfs::IpfsPath,
-    metadata::Metadata,
+    metadata::{Metadata, MetadataSchema},
     prelude::*,
     HasMetadata, Name, NumericValue, Registered,
 };
@@ -83,6 +83,11 @@ pub mod model {
         pub logo: Option<IpfsPath>,
         /// [`Metadata`] of this `Domain` as a key-value store.
         pub metadata: Metadata,
+        /// Schema which [`Metadata`] of this `Domain` has to conform to.
+        /// Immutable once registered.
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub metadata_schema: Option<MetadataSchema>,
         /// The account that owns this domain. Usually the [`Account`] that registered it.
         #[getset(get = "pub")]
         pub owned_by: AccountId,
@@ -101,6 +106,9 @@ pub mod model {
         pub logo: Option<IpfsPath>,
         /// Metadata associated with the domain builder.
         pub metadata: Metadata,
+        /// Schema which metadata of the domain has to conform to.
+        #[serde(default)]
+        pub metadata_schema: Option<MetadataSchema>,
     }
 }
 
@@ -119,6 +127,7 @@ impl NewDomain {
             id,
             logo: None,
             metadata: Metadata::default(),
+            metadata_schema: None,
         }
     }
 
@@ -135,6 +144,13 @@ impl NewDomain {
         self.metadata = metadata;
         self
     }
+
+    /// Add [`MetadataSchema`] to the domain replacing previously defined value
+    #[must_use]
+    pub fn with_metadata_schema(mut self, metadata_schema: MetadataSchema) -> Self {
+        self.metadata_schema = Some(metadata_schema);
+        self
+    }
 }
 
 impl HasMetadata for Domain {
@@ -120,6 +120,10 @@ mod asset {
             MetadataInserted(AssetMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target_id)]
             MetadataRemoved(AssetMetadataChanged),
+            #[has_origin(nft_changed => &nft_changed.asset_id)]
+            NftAdded(AssetNftChanged),
+            #[has_origin(nft_changed => &nft_changed.asset_id)]
+            NftRemoved(AssetNftChanged),
         }
     }
 
@@ -167,6 +171,28 @@ mod asset {
             pub amount: AssetValue,
         }
 
+        /// Depending on the wrapping event, [`Self`] represents the added or removed non-fungible token.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AssetNftChanged {
+            pub asset_id: AssetId,
+            pub nft: Nft,
+        }
+
         /// [`Self`] represents updated total asset quantity.
         #[derive(
             Debug,
@@ -218,6 +244,7 @@ mod asset {
 mod peer {
     //! This module contains `PeerEvent` and its impls
 
+    pub use self::model::*;
     use super::*;
 
     data_event! {
@@ -225,6 +252,35 @@ mod peer {
         pub enum PeerEvent {
             Added(PeerId),
             Removed(PeerId),
+            #[has_origin(key_rotated => &key_rotated.old_peer_id)]
+            KeyRotated(PeerKeyRotated),
+        }
+    }
+
+    #[model]
+    pub mod model {
+        use super::*;
+
+        /// Event indicate that the key of the [`Peer`] is rotated
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct PeerKeyRotated {
+            pub old_peer_id: PeerId,
+            pub new_peer_id: PeerId,
         }
     }
 }
@@ -648,12 +704,12 @@ pub mod prelude {
         asset::{
             AssetChanged, AssetDefinitionEvent, AssetDefinitionEventFilter, AssetDefinitionFilter,
             AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
-            AssetEventFilter, AssetFilter,
+            AssetEventFilter, AssetFilter, AssetNftChanged,
         },
         config::ConfigurationEvent,
         domain::{DomainEvent, DomainEventFilter, DomainFilter, DomainOwnerChanged},
         executor::{ExecutorEvent, ExecutorFilter},
-        peer::{PeerEvent, PeerEventFilter, PeerFilter},
+        peer::{PeerEvent, PeerEventFilter, PeerFilter, PeerKeyRotated},
         permission::PermissionTokenSchemaUpdateEvent,
         role::{PermissionRemoved, RoleEvent, RoleEventFilter, RoleFilter},
         trigger::{
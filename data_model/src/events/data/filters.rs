@@ -220,6 +220,7 @@ mod tests {
             asset_total_quantities: AssetTotalQuantityMap::default(),
             logo: None,
             metadata: Metadata::default(),
+            metadata_schema: None,
             owned_by: domain_owner_id,
         };
         let account_id = AccountId::new(domain_id.clone(), account_name);
@@ -229,6 +230,7 @@ mod tests {
             signatories: BTreeSet::default(),
             signature_check_condition: SignatureCheckCondition::default(),
             metadata: Metadata::default(),
+            metadata_schema: None,
         };
         let asset_id = AssetId::new(
             AssetDefinitionId::new(domain_id.clone(), asset_name),
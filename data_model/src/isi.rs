@@ -101,6 +101,8 @@ pub mod model {
 
         #[debug(fmt = "{_0:?}")]
         Fail(Fail),
+        #[debug(fmt = "{_0:?}")]
+        RotatePeerKey(RotatePeerKey),
     }
 
     impl Instruction for InstructionBox {}
@@ -136,12 +138,14 @@ pub mod model {
     impl Instruction for Mint<u32, Asset> {}
     impl Instruction for Mint<u128, Asset> {}
     impl Instruction for Mint<Fixed, Asset> {}
+    impl Instruction for Mint<Nft, Asset> {}
     impl Instruction for Mint<u32, Trigger<TriggeringFilterBox>> {}
 
     impl Instruction for Burn<PublicKey, Account> {}
     impl Instruction for Burn<u32, Asset> {}
     impl Instruction for Burn<u128, Asset> {}
     impl Instruction for Burn<Fixed, Asset> {}
+    impl Instruction for Burn<Name, Asset> {}
     impl Instruction for Burn<u32, Trigger<TriggeringFilterBox>> {}
 
     impl Instruction for Transfer<Account, DomainId, Account> {}
@@ -149,6 +153,7 @@ pub mod model {
     impl Instruction for Transfer<Asset, u32, Account> {}
     impl Instruction for Transfer<Asset, u128, Account> {}
     impl Instruction for Transfer<Asset, Fixed, Account> {}
+    impl Instruction for Transfer<Asset, Name, Account> {}
 
     impl Instruction for Grant<PermissionToken> {}
     impl Instruction for Grant<RoleId> {}
@@ -162,6 +167,7 @@ pub mod model {
     impl Instruction for ExecuteTrigger {}
     impl Instruction for Log {}
     impl Instruction for Fail {}
+    impl Instruction for RotatePeerKey {}
 }
 
 mod transparent {
@@ -582,8 +588,8 @@ mod transparent {
 
     isi! {
         /// Generic instruction for a mint of an object to the identifiable destination.
-        #[schema(bounds = "O: Into<Value> + IntoSchema, D: Identifiable, D::Id: IntoSchema")]
-        pub struct Mint<O: Into<Value>, D: Identifiable> {
+        #[schema(bounds = "O: IntoSchema, D: Identifiable, D::Id: IntoSchema")]
+        pub struct Mint<O, D: Identifiable> {
             /// Object which should be minted.
             pub object: O,
             /// Destination object [`Identifiable::Id`].
@@ -644,6 +650,16 @@ mod transparent {
         }
     }
 
+    impl Mint<Nft, Asset> {
+        /// Constructs a new [`Mint`] for an [`Asset`] of [`Nft`] type.
+        pub fn asset_nft(nft: Nft, asset_id: AssetId) -> Self {
+            Self {
+                object: nft,
+                destination_id: asset_id,
+            }
+        }
+    }
+
     impl Mint<u32, Trigger<TriggeringFilterBox>> {
         /// Constructs a new [`Mint`] for repetition count of [`Trigger`].
         pub fn trigger_repetitions(repetitions: u32, trigger_id: TriggerId) -> Self {
@@ -657,7 +673,7 @@ mod transparent {
     impl_display! {
         Mint<O, D>
         where
-            O: Into<Value> + Display,
+            O: Display,
             D: Identifiable,
             D::Id: Display,
         =>
@@ -674,7 +690,8 @@ mod transparent {
     impl_into_box! {
         Mint<u32, Asset> |
         Mint<u128, Asset> |
-        Mint<Fixed, Asset> => AssetMintBox ==> MintBox::Asset
+        Mint<Fixed, Asset> |
+        Mint<Nft, Asset> => AssetMintBox ==> MintBox::Asset
     }
 
     impl_into_box! {
@@ -683,6 +700,7 @@ mod transparent {
         Mint<u32, Asset> |
         Mint<u128, Asset> |
         Mint<Fixed, Asset> |
+        Mint<Nft, Asset> |
         Mint<u32, Trigger<TriggeringFilterBox> > => MintBox ==> InstructionBox::Mint
     }
 
@@ -737,6 +755,16 @@ mod transparent {
         }
     }
 
+    impl Burn<Name, Asset> {
+        /// Constructs a new [`Burn`] for an [`Nft`] of an [`Asset`] of [`Nft`] type.
+        pub fn asset_nft(token_id: Name, asset_id: AssetId) -> Self {
+            Self {
+                object: token_id,
+                destination_id: asset_id,
+            }
+        }
+    }
+
     impl Burn<u32, Trigger<TriggeringFilterBox>> {
         /// Constructs a new [`Burn`] for repetition count of [`Trigger`].
         pub fn trigger_repetitions(repetitions: u32, trigger_id: TriggerId) -> Self {
@@ -762,7 +790,8 @@ mod transparent {
     impl_into_box! {
         Burn<u32, Asset> |
         Burn<u128, Asset> |
-        Burn<Fixed, Asset> => AssetBurnBox ==> BurnBox::Asset
+        Burn<Fixed, Asset> |
+        Burn<Name, Asset> => AssetBurnBox ==> BurnBox::Asset
     }
 
     impl_into_box! {
@@ -770,6 +799,7 @@ mod transparent {
         Burn<u32, Asset> |
         Burn<u128, Asset> |
         Burn<Fixed, Asset> |
+        Burn<Name, Asset> |
         Burn<u32, Trigger<TriggeringFilterBox> > => BurnBox ==> InstructionBox::Burn
     }
 
@@ -847,6 +877,17 @@ mod transparent {
         }
     }
 
+    impl Transfer<Asset, Name, Account> {
+        /// Constructs a new [`Transfer`] for an [`Nft`] of an [`Asset`] of [`Nft`] type.
+        pub fn asset_nft(asset_id: AssetId, token_id: Name, to: AccountId) -> Self {
+            Self {
+                source_id: asset_id,
+                object: token_id,
+                destination_id: to,
+            }
+        }
+    }
+
     impl_display! {
         Transfer<S, O, D>
         where
@@ -865,7 +906,8 @@ mod transparent {
     impl_into_box! {
         Transfer<Asset, u32, Account> |
         Transfer<Asset, u128, Account> |
-        Transfer<Asset, Fixed, Account> => AssetTransferBox ==> TransferBox::Asset
+        Transfer<Asset, Fixed, Account> |
+        Transfer<Asset, Name, Account> => AssetTransferBox ==> TransferBox::Asset
     }
 
     impl_into_box! {
@@ -873,7 +915,8 @@ mod transparent {
         Transfer<Account, AssetDefinitionId, Account> |
         Transfer<Asset, u32, Account> |
         Transfer<Asset, u128, Account> |
-        Transfer<Asset, Fixed, Account> => TransferBox ==> InstructionBox::Transfer
+        Transfer<Asset, Fixed, Account> |
+        Transfer<Asset, Name, Account> => TransferBox ==> InstructionBox::Transfer
     }
 
     isi! {
@@ -1023,6 +1066,75 @@ mod transparent {
     }
 
     impl_into_box!(Log ==> InstructionBox::Log);
+
+    isi! {
+        /// Instruction to replace the public key of a registered peer.
+        ///
+        /// Must be signed by the current key of the peer for this chain, the peer keeps its address.
+        /// The new key takes effect from the block following the one containing this instruction.
+        /// Keys can't be reused, so that an old rotation can't be replayed: once replaced,
+        /// a key can neither be rotated to nor sign another rotation.
+        /// BLS keys have to be accompanied by the proof of possession of the new private key.
+        #[derive(Display)]
+        #[display(fmt = "ROTATE `{peer_id}` KEY TO `{new_public_key}`")]
+        pub struct RotatePeerKey {
+            /// Id of the peer whose key is rotated.
+            pub peer_id: PeerId,
+            /// Public key replacing the current one.
+            pub new_public_key: PublicKey,
+            /// Signature made with the current key of the peer over [`Self::payload`].
+            pub signature: iroha_crypto::Signature,
+            /// Proof of possession of the private key matching [`Self::new_public_key`].
+            #[serde(default)]
+            pub proof_of_possession: Option<iroha_crypto::ProofOfPossession>,
+        }
+    }
+
+    impl RotatePeerKey {
+        /// Construct [`Self`] for the chain with `chain_id`, signing it with the current `key_pair` of the peer.
+        pub fn new(
+            chain_id: &ChainId,
+            peer_id: PeerId,
+            new_public_key: PublicKey,
+            key_pair: &iroha_crypto::KeyPair,
+        ) -> Self {
+            let signature = iroha_crypto::Signature::new(
+                key_pair,
+                &Self::payload(chain_id, &peer_id, &new_public_key),
+            );
+
+            Self {
+                peer_id,
+                new_public_key,
+                signature,
+                proof_of_possession: None,
+            }
+        }
+
+        /// Attach the proof of possession of the new private key.
+        #[must_use]
+        pub fn with_proof_of_possession(
+            mut self,
+            proof_of_possession: iroha_crypto::ProofOfPossession,
+        ) -> Self {
+            self.proof_of_possession = Some(proof_of_possession);
+            self
+        }
+
+        /// Bytes that the current key of the peer has to sign.
+        ///
+        /// Chain id is included, so that a rotation can't be replayed on another chain
+        /// where the same peer key is registered.
+        pub fn payload(
+            chain_id: &ChainId,
+            peer_id: &PeerId,
+            new_public_key: &PublicKey,
+        ) -> Vec<u8> {
+            (chain_id, peer_id, new_public_key).encode()
+        }
+    }
+
+    impl_into_box!(RotatePeerKey ==> InstructionBox::RotatePeerKey);
 }
 
 macro_rules! isi_box {
@@ -1146,6 +1258,8 @@ isi_box! {
         BigQuantity(Mint<u128, Asset>),
         /// Mint [`Asset`] of [`Fixed`] type.
         Fixed(Mint<Fixed, Asset>),
+        /// Mint [`Nft`] into [`Asset`] of [`Nft`] type.
+        Nft(Mint<Nft, Asset>),
     }
 }
 
@@ -1170,6 +1284,8 @@ isi_box! {
         BigQuantity(Burn<u128, Asset>),
         /// Burn [`Asset`] of [`Fixed`] type.
         Fixed(Burn<Fixed, Asset>),
+        /// Burn [`Nft`] of [`Asset`] of [`Nft`] type.
+        Nft(Burn<Name, Asset>),
     }
 }
 
@@ -1194,6 +1310,8 @@ isi_box! {
         BigQuantity(Transfer<Asset, u128, Account>),
         /// Transfer [`Asset`] of [`Fixed`] type.
         Fixed(Transfer<Asset, Fixed, Account>),
+        /// Transfer [`Nft`] of [`Asset`] of [`Nft`] type.
+        Nft(Transfer<Asset, Name, Account>),
     }
 }
 
@@ -1237,7 +1355,7 @@ pub mod error {
         asset::AssetValueType,
         metadata,
         query::error::{FindError, QueryExecutionFail},
-        IdBox, Value,
+        IdBox, Name, Value,
     };
 
     #[model]
@@ -1304,6 +1422,12 @@ pub mod error {
                 #[skip_try_from]
                 String,
             ),
+            /// Token `{0}` already exists for this asset definition
+            NftRepetition(
+                #[skip_from]
+                #[skip_try_from]
+                Name,
+            ),
         }
 
         /// Evaluation error. This error indicates instruction is not a valid Iroha DSL
@@ -1479,6 +1603,10 @@ pub mod error {
             ///
             /// i.e. too long [`AccountId`]
             NameLength,
+            /// Signature verification failed
+            Signature,
+            /// Proof of possession of the private key is missing or invalid
+            ProofOfPossession,
         }
 
         /// Repetition of of `{instruction_type}` for id `{id}`
@@ -1535,7 +1663,8 @@ pub mod prelude {
     pub use super::{
         AccountMintBox, AssetBurnBox, AssetMintBox, AssetTransferBox, Burn, BurnBox,
         ExecuteTrigger, Fail, Grant, GrantBox, InstructionBox, Log, Mint, MintBox, NewParameter,
-        Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox, SetKeyValue,
-        SetKeyValueBox, SetParameter, Transfer, TransferBox, Unregister, UnregisterBox, Upgrade,
+        Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox, RotatePeerKey,
+        SetKeyValue, SetKeyValueBox, SetParameter, Transfer, TransferBox, Unregister,
+        UnregisterBox, Upgrade,
     };
 }
@@ -118,12 +118,14 @@ mod seal {
         Mint<u32, Asset>,
         Mint<u128, Asset>,
         Mint<Fixed, Asset>,
+        Mint<Nft, Asset>,
         Mint<u32, Trigger<TriggeringFilterBox> >,
 
         Burn<PublicKey, Account>,
         Burn<u32, Asset>,
         Burn<u128, Asset>,
         Burn<Fixed, Asset>,
+        Burn<Name, Asset>,
         Burn<u32, Trigger<TriggeringFilterBox> >,
 
         Transfer<Account, DomainId, Account>,
@@ -131,6 +133,7 @@ mod seal {
         Transfer<Asset, u32, Account>,
         Transfer<Asset, u128, Account>,
         Transfer<Asset, Fixed, Account>,
+        Transfer<Asset, Name, Account>,
 
         Grant<PermissionToken>,
         Grant<RoleId>,
@@ -144,6 +147,7 @@ mod seal {
         ExecuteTrigger,
         Log,
         Fail,
+        RotatePeerKey,
 
         // Boxed queries
         QueryBox,
@@ -166,6 +170,7 @@ mod seal {
         FindTotalAssetQuantityByAssetDefinitionId,
         FindAssetKeyValueByIdAndKey,
         FindAssetDefinitionKeyValueByIdAndKey,
+        FindNftsByOwner,
         FindAllDomains,
         FindDomainById,
         FindDomainKeyValueByIdAndKey,
@@ -276,6 +281,10 @@ pub mod parameter {
         pub const WSV_IDENT_LENGTH_LIMITS: &str = "WSVIdentLengthLimits";
         pub const WASM_FUEL_LIMIT: &str = "WASMFuelLimit";
         pub const WASM_MAX_MEMORY: &str = "WASMMaxMemory";
+        pub const VALIDATOR_SET_SIZE: &str = "ValidatorSetSize";
+        pub const EPOCH_LENGTH: &str = "EpochLength";
+        pub const VALIDATOR_STAKE_ASSET: &str = "ValidatorStakeAsset";
+        pub const AGGREGATE_COMMIT_SIGNATURES: &str = "AggregateCommitSignatures";
     }
 
     #[model]
@@ -424,9 +433,11 @@ pub mod parameter {
                             }),
                         };
                         Ok(Self::new(param_id, val))
+                    } else if let Ok(val) = val_candidate.parse::<bool>() {
+                        Ok(Self::new(param_id, Value::Bool(val)))
                     } else {
                         let val = val_candidate.parse::<u64>().map_err(|_| ParseError {
-                            reason: "Failed to parse the `val` part of the `Parameter` as `u64`.",
+                            reason: "Failed to parse the `val` part of the `Parameter` as `bool` or `u64`.",
                         })?;
                         Ok(Self::new(param_id, Value::Numeric(NumericValue::from(val))))
                     }
@@ -560,6 +571,10 @@ pub mod parameter {
                     ParameterId::from_str("Int").expect("Failed to parse `ParameterId`"),
                     Value::Numeric(NumericValue::U64(42)),
                 ),
+                Parameter::new(
+                    ParameterId::from_str("Bool").expect("Failed to parse `ParameterId`"),
+                    Value::Bool(true),
+                ),
             ];
 
             for parameter in parameters {
@@ -8,12 +8,13 @@ use std::collections::btree_map;
 
 use derive_more::Display;
 use iroha_data_model_derive::model;
+use iroha_primitives::fixed::Fixed;
 use iroha_schema::IntoSchema;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::{Name, Value};
+use crate::{Name, NumericValue, Value};
 
 /// Collection of parameters by their names.
 pub type UnlimitedMetadata = btree_map::BTreeMap<Name, Value>;
@@ -69,6 +70,118 @@ pub mod model {
     #[serde(transparent)]
     #[repr(transparent)]
     pub struct Metadata(pub(super) btree_map::BTreeMap<Name, Value>);
+
+    /// Typed schema which [`Metadata`] of an entity has to conform to.
+    ///
+    /// The schema is set when the entity is registered and is immutable afterwards:
+    /// no instruction changes or removes it.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Deserialize,
+        Serialize,
+        Decode,
+        Encode,
+        IntoSchema,
+    )]
+    #[display(fmt = "MetadataSchema")]
+    #[ffi_type(opaque)]
+    pub struct MetadataSchema {
+        /// Constraints on the metadata entries by their keys
+        pub entries: btree_map::BTreeMap<Name, MetadataEntrySchema>,
+        /// Whether entries with keys not present in `entries` are rejected
+        pub deny_unknown: bool,
+    }
+
+    /// Constraints on a single [`Metadata`] entry.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Deserialize,
+        Serialize,
+        Decode,
+        Encode,
+        IntoSchema,
+    )]
+    #[display(fmt = "{value_type}")]
+    #[ffi_type(opaque)]
+    pub struct MetadataEntrySchema {
+        /// Whether the entry has to be present in the metadata
+        pub required: bool,
+        /// Type of the entry value
+        pub value_type: MetadataValueType,
+    }
+
+    /// Type of the [`Metadata`] entry value.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Deserialize,
+        Serialize,
+        Decode,
+        Encode,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    pub enum MetadataValueType {
+        /// Value of any type
+        Any,
+        /// Boolean value
+        Bool,
+        /// String value
+        String,
+        /// Name value
+        Name,
+        /// Unsigned integer value within the bounds
+        #[display(fmt = "Integer{_0}")]
+        Integer(NumericBounds<u128>),
+        /// Fixed point value within the bounds
+        #[display(fmt = "Fixed{_0}")]
+        Fixed(NumericBounds<Fixed>),
+        /// Nested metadata
+        Metadata,
+    }
+}
+
+/// Inclusive bounds of a numeric metadata value.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    Decode,
+    Encode,
+    IntoSchema,
+)]
+pub struct NumericBounds<T> {
+    /// Minimal allowed value
+    pub min: Option<T>,
+    /// Maximal allowed value
+    pub max: Option<T>,
 }
 
 /// Metadata related errors.
@@ -98,6 +211,12 @@ pub enum MetadataError {
     MissingSegment(Name),
     /// `{0}`: path segment not an instance of metadata
     InvalidSegment(Name),
+    /// `{0}`: key isn't declared in the metadata schema
+    UnknownKey(Name),
+    /// `{0}`: value doesn't conform to the metadata schema
+    SchemaMismatch(Name),
+    /// `{0}`: entry is required by the metadata schema
+    RequiredEntry(Name),
 }
 
 /// Size limits exhaustion error
@@ -280,6 +399,129 @@ impl Metadata {
     }
 }
 
+impl MetadataSchema {
+    /// Construct an empty [`MetadataSchema`] which accepts entries with any keys.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add constraints on the entry with the given `key`.
+    #[must_use]
+    pub fn with_entry(mut self, key: Name, entry: MetadataEntrySchema) -> Self {
+        self.entries.insert(key, entry);
+        self
+    }
+
+    /// Reject entries with keys not declared in the schema.
+    #[must_use]
+    pub fn deny_unknown(mut self) -> Self {
+        self.deny_unknown = true;
+        self
+    }
+
+    /// Check that `value` can be stored under `key`.
+    ///
+    /// # Errors
+    /// - If `key` isn't declared and unknown keys are denied
+    /// - If `value` doesn't match the declared type
+    pub fn check_entry(&self, key: &Name, value: &Value) -> Result<(), MetadataError> {
+        match self.entries.get(key) {
+            Some(entry) if entry.value_type.matches(value) => Ok(()),
+            Some(_) => Err(MetadataError::SchemaMismatch(key.clone())),
+            None if self.deny_unknown => Err(MetadataError::UnknownKey(key.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Check that the entry under `key` can be removed.
+    ///
+    /// # Errors
+    /// If the entry is required
+    pub fn check_removal(&self, key: &Name) -> Result<(), MetadataError> {
+        match self.entries.get(key) {
+            Some(entry) if entry.required => Err(MetadataError::RequiredEntry(key.clone())),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that the whole `metadata` conforms to the schema.
+    ///
+    /// # Errors
+    /// - If any of the entries doesn't pass [`Self::check_entry`]
+    /// - If any of the required entries is missing
+    pub fn check(&self, metadata: &Metadata) -> Result<(), MetadataError> {
+        for (key, value) in metadata.iter() {
+            self.check_entry(key, value)?;
+        }
+        for (key, entry) in &self.entries {
+            if entry.required && !metadata.contains(key) {
+                return Err(MetadataError::RequiredEntry(key.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MetadataEntrySchema {
+    /// Entry which has to be present in the metadata.
+    pub const fn required(value_type: MetadataValueType) -> Self {
+        Self {
+            required: true,
+            value_type,
+        }
+    }
+
+    /// Entry which can be omitted from the metadata.
+    pub const fn optional(value_type: MetadataValueType) -> Self {
+        Self {
+            required: false,
+            value_type,
+        }
+    }
+}
+
+impl MetadataValueType {
+    fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Self::Any, _)
+            | (Self::Bool, Value::Bool(_))
+            | (Self::String, Value::String(_))
+            | (Self::Name, Value::Name(_))
+            | (Self::Metadata, Value::LimitedMetadata(_)) => true,
+            (Self::Integer(bounds), Value::Numeric(numeric)) => match *numeric {
+                NumericValue::U32(value) => bounds.contains(value.into()),
+                NumericValue::U64(value) => bounds.contains(value.into()),
+                NumericValue::U128(value) => bounds.contains(value),
+                NumericValue::Fixed(_) => false,
+            },
+            (Self::Fixed(bounds), Value::Numeric(NumericValue::Fixed(value))) => {
+                bounds.contains(*value)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T> NumericBounds<T> {
+    /// Construct [`NumericBounds`], `None` means the value is unbounded from that side.
+    pub const fn new(min: Option<T>, max: Option<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: PartialOrd + Copy> NumericBounds<T> {
+    fn contains(&self, value: T) -> bool {
+        self.min.map_or(true, |min| min <= value) && self.max.map_or(true, |max| value <= max)
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Display for NumericBounds<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{:?}, {:?}]", self.min, self.max)
+    }
+}
+
 fn check_size_limits(key: &Name, value: Value, limits: Limits) -> Result<(), MetadataError> {
     let entry_bytes: Vec<u8> = (key, value).encode();
     let byte_size = entry_bytes.len();
@@ -296,7 +538,10 @@ fn check_size_limits(key: &Name, value: Value, limits: Limits) -> Result<(), Met
 
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::{Limits as MetadataLimits, Metadata, UnlimitedMetadata};
+    pub use super::{
+        Limits as MetadataLimits, Metadata, MetadataEntrySchema, MetadataSchema, MetadataValueType,
+        NumericBounds, UnlimitedMetadata,
+    };
 }
 
 #[cfg(test)]
@@ -450,4 +695,46 @@ mod tests {
             .is_err());
         Ok(())
     }
+
+    #[test]
+    fn schema_is_enforced() -> Result<(), TestError> {
+        let isin = Name::from_str("isin")?;
+        let coupon = Name::from_str("coupon")?;
+        let schema = MetadataSchema::new()
+            .with_entry(
+                isin.clone(),
+                MetadataEntrySchema::required(MetadataValueType::String),
+            )
+            .with_entry(
+                coupon.clone(),
+                MetadataEntrySchema::optional(MetadataValueType::Integer(NumericBounds::new(
+                    None,
+                    Some(100),
+                ))),
+            )
+            .deny_unknown();
+
+        assert_eq!(
+            schema.check(&Metadata::new()),
+            Err(MetadataError::RequiredEntry(isin.clone()))
+        );
+        assert!(schema
+            .check_entry(&isin, &"US0378331005".to_owned().into())
+            .is_ok());
+        assert!(schema.check_entry(&coupon, &5_u32.into()).is_ok());
+        assert_eq!(
+            schema.check_entry(&coupon, &101_u32.into()),
+            Err(MetadataError::SchemaMismatch(coupon.clone()))
+        );
+        assert_eq!(
+            schema.check_entry(&Name::from_str("issuer")?, &true.into()),
+            Err(MetadataError::UnknownKey(Name::from_str("issuer")?))
+        );
+        assert_eq!(
+            schema.check_removal(&isin),
+            Err(MetadataError::RequiredEntry(isin))
+        );
+        assert!(schema.check_removal(&coupon).is_ok());
+        Ok(())
+    }
 }
@@ -9,6 +9,7 @@ use core::{
 };
 
 use derive_more::Display;
+use iroha_crypto::ProofOfPossession;
 use iroha_data_model_derive::{model, IdEqOrdHash};
 use iroha_primitives::addr::SocketAddr;
 use iroha_schema::IntoSchema;
@@ -47,13 +48,15 @@ pub mod model {
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
     )]
     #[display(fmt = "@@{}", "id.address")]
-    #[serde(transparent)]
-    #[repr(transparent)]
-    // TODO: Make it transparent in FFI?
     #[ffi_type(opaque)]
     pub struct Peer {
         /// Peer Identification.
         pub id: PeerId,
+        /// Proof of possession of the private key of the peer.
+        ///
+        /// Required to register peers with BLS keys, since their signatures are aggregated.
+        #[serde(default)]
+        pub proof_of_possession: Option<ProofOfPossession>,
     }
 }
 
@@ -72,7 +75,17 @@ impl Peer {
     /// Construct `Peer` given `id`.
     #[inline]
     pub const fn new(id: PeerId) -> <Self as Registered>::With {
-        Self { id }
+        Self {
+            id,
+            proof_of_possession: None,
+        }
+    }
+
+    /// Attach the proof of possession of the private key of the peer.
+    #[must_use]
+    pub fn with_proof_of_possession(mut self, proof_of_possession: ProofOfPossession) -> Self {
+        self.proof_of_possession = Some(proof_of_possession);
+        self
     }
 }
 
@@ -1196,9 +1196,9 @@ pub mod value {
                 println!("{pred:?}");
 
                 assert!(
-                    !pred.applies(&Value::Identifiable(IdentifiableBox::Peer(Peer {
-                        id: peer::PeerId::new(socket_addr!(127.0.0.1:123), public_key)
-                    })))
+                    !pred.applies(&Value::Identifiable(IdentifiableBox::Peer(Peer::new(
+                        peer::PeerId::new(socket_addr!(127.0.0.1:123), public_key)
+                    ))))
                 );
             }
             let pred = ValuePredicate::Numerical(numerical::SemiRange::U32((0_u32, 42_u32).into()));
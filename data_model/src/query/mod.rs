@@ -173,6 +173,7 @@ pub mod model {
         FindRoleByRoleId(FindRoleByRoleId),
         FindRolesByAccountId(FindRolesByAccountId),
         FindAllParameters(FindAllParameters),
+        FindNftsByOwner(FindNftsByOwner),
     }
 
     /// Output of [`FindAllTransactions`] query
@@ -686,6 +687,18 @@ pub mod asset {
             pub key: Name,
         }
 
+        /// [`FindNftsByOwner`] Iroha Query gets [`AccountId`] as input and finds all [`Asset`]s
+        /// of [`Nft`](crate::asset::AssetValue::Nft) type owned by the [`Account`] in Iroha Peer.
+        #[derive(Display)]
+        #[display(fmt = "Find NFTs owned by the `{account_id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindNftsByOwner` has no trap representation in `EvaluatesTo<AccountId>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindNftsByOwner {
+            /// [`AccountId`] under which NFTs should be found.
+            pub account_id: AccountId,
+        }
+
     }
     impl Query for FindAllAssets {
         type Output = Vec<Asset>;
@@ -739,6 +752,10 @@ pub mod asset {
         type Output = MetadataValue;
     }
 
+    impl Query for FindNftsByOwner {
+        type Output = Vec<Asset>;
+    }
+
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
         pub use super::{
@@ -746,7 +763,7 @@ pub mod asset {
             FindAssetDefinitionKeyValueByIdAndKey, FindAssetKeyValueByIdAndKey,
             FindAssetQuantityById, FindAssetsByAccountId, FindAssetsByAssetDefinitionId,
             FindAssetsByDomainId, FindAssetsByDomainIdAndAssetDefinitionId, FindAssetsByName,
-            FindTotalAssetQuantityByAssetDefinitionId,
+            FindNftsByOwner, FindTotalAssetQuantityByAssetDefinitionId,
         };
     }
 }
@@ -1073,6 +1090,7 @@ pub mod http {
     use crate::{account::AccountId, predicate::PredicateBox};
 
     declare_versioned_with_scale!(SignedQuery 1..2, Debug, Clone, iroha_macro::FromVariant, IntoSchema);
+    declare_versioned_with_scale!(SignedDropQuery 1..2, Debug, Clone, iroha_macro::FromVariant, IntoSchema);
 
     #[model]
     pub mod model {
@@ -1115,6 +1133,27 @@ pub mod http {
         /// End type of a query http clients can send to an endpoint.
         #[derive(Debug, Clone, Decode, Encode)]
         pub struct ClientQueryRequest(pub QueryRequest<SignedQuery>);
+
+        /// Payload of a request to drop a live query.
+        #[derive(
+            Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        pub(crate) struct DropQueryPayload {
+            /// Account id of the user who submitted the query and signs this request.
+            pub authority: AccountId,
+            /// Id of the live query to drop.
+            pub query_id: QueryId,
+        }
+
+        /// Request to drop a live query, only accepted from the authority of the query.
+        #[derive(Debug, Clone, Encode, Serialize, IntoSchema)]
+        #[version_with_scale(version = 1, versioned_alias = "SignedDropQuery")]
+        pub struct SignedDropQueryV1 {
+            /// Signature of the client who sends this request.
+            pub signature: SignatureOf<DropQueryPayload>,
+            /// Payload
+            pub payload: DropQueryPayload,
+        }
     }
 
     impl ClientQueryRequest {
@@ -1181,6 +1220,47 @@ pub mod http {
                     .map_err(D::Error::custom)
             }
         }
+
+        #[derive(Decode, Deserialize)]
+        struct SignedDropQueryCandidate {
+            signature: SignatureOf<DropQueryPayload>,
+            payload: DropQueryPayload,
+        }
+
+        impl SignedDropQueryCandidate {
+            fn validate(self) -> Result<SignedDropQueryV1, &'static str> {
+                #[cfg(feature = "std")]
+                if self.signature.verify(&self.payload).is_err() {
+                    return Err("Drop query signature not valid");
+                }
+
+                Ok(SignedDropQueryV1 {
+                    payload: self.payload,
+                    signature: self.signature,
+                })
+            }
+        }
+
+        impl Decode for SignedDropQueryV1 {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+                SignedDropQueryCandidate::decode(input)?
+                    .validate()
+                    .map_err(Into::into)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for SignedDropQueryV1 {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                use serde::de::Error as _;
+
+                SignedDropQueryCandidate::deserialize(deserializer)?
+                    .validate()
+                    .map_err(D::Error::custom)
+            }
+        }
     }
 
     #[cfg(feature = "transparent_api")]
@@ -1207,6 +1287,45 @@ pub mod http {
         }
     }
 
+    impl SignedDropQuery {
+        /// Construct a request to drop the live query with `query_id` submitted by `authority`.
+        #[must_use]
+        pub fn new(
+            query_id: QueryId,
+            authority: AccountId,
+            key_pair: &iroha_crypto::KeyPair,
+        ) -> Self {
+            let payload = DropQueryPayload {
+                authority,
+                query_id,
+            };
+            SignedDropQueryV1 {
+                signature: SignatureOf::new(key_pair, &payload),
+                payload,
+            }
+            .into()
+        }
+    }
+
+    #[cfg(feature = "transparent_api")]
+    impl SignedDropQuery {
+        /// Return request signature
+        pub fn signature(&self) -> &SignatureOf<DropQueryPayload> {
+            let SignedDropQuery::V1(request) = self;
+            &request.signature
+        }
+        /// Return id of the query to drop
+        pub fn query_id(&self) -> &QueryId {
+            let SignedDropQuery::V1(request) = self;
+            &request.payload.query_id
+        }
+        /// Return authority of the query to drop
+        pub fn authority(&self) -> &AccountId {
+            let SignedDropQuery::V1(request) = self;
+            &request.payload.authority
+        }
+    }
+
     impl QueryBuilder {
         /// Construct a new request with the `query`.
         pub fn new(query: impl Into<QueryBox>, authority: AccountId) -> Self {
@@ -1244,7 +1363,60 @@ pub mod http {
     pub mod prelude {
         //! The prelude re-exports most commonly used traits, structs and macros from this crate.
 
-        pub use super::{QueryBuilder, SignedQuery, SignedQueryV1};
+        pub use super::{
+            QueryBuilder, SignedDropQuery, SignedDropQueryV1, SignedQuery, SignedQueryV1,
+        };
+    }
+}
+
+#[cfg(feature = "http")]
+pub mod stream {
+    //! Structures related to streaming query results over `WebSocket`
+
+    use derive_more::Constructor;
+    use iroha_data_model_derive::model;
+
+    pub use self::model::*;
+    use super::*;
+    use crate::{BatchedResponse, ValidationFail};
+
+    #[model]
+    pub mod model {
+        use super::*;
+
+        /// Request sent to start streaming the results of the query.
+        #[derive(Debug, Clone, Constructor, Decode, Encode)]
+        #[repr(transparent)]
+        pub struct QueryStreamRequest(pub QueryWithParameters<http::SignedQuery>);
+
+        /// Message sent by the stream consumer to request the given number of further batches.
+        ///
+        /// The producer never sends more batches than were requested,
+        /// which lets the consumer control the pace of the stream.
+        #[derive(Debug, Clone, Copy, Constructor, Decode, Encode)]
+        #[repr(transparent)]
+        pub struct QueryStreamDemand(pub NonZeroU32);
+
+        /// Message sent by the stream producer.
+        #[derive(Debug, Clone, Decode, Encode)]
+        pub enum QueryStreamMessage {
+            /// Next batch of the query results.
+            /// The batch with an empty cursor is the last one in the stream.
+            Batch(BatchedResponse<Value>),
+            /// Query was rejected. No more messages follow.
+            Error(ValidationFail),
+        }
+    }
+
+    impl From<QueryStreamDemand> for NonZeroU32 {
+        fn from(source: QueryStreamDemand) -> Self {
+            source.0
+        }
+    }
+
+    /// Exports common structs and enums from this module.
+    pub mod prelude {
+        pub use super::{QueryStreamDemand, QueryStreamMessage, QueryStreamRequest};
     }
 }
 
@@ -1303,6 +1475,10 @@ pub mod error {
             UnknownCursor,
             /// fetch_size could not be greater than {MAX_FETCH_SIZE:?}
             FetchSizeTooBig,
+            /// Authority has reached the limit of live queries it can keep on the peer
+            TooManyLiveQueries,
+            /// Live query store capacity is exhausted
+            LiveQueryStoreFull,
         }
 
         /// Type assertion error
@@ -1350,6 +1526,8 @@ pub mod error {
             Parameter(ParameterId),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Failed to find NFT: `{0}`
+            Nft(Name),
         }
     }
 }
@@ -1359,6 +1537,8 @@ pub mod error {
 pub mod prelude {
     #[cfg(feature = "http")]
     pub use super::http::*;
+    #[cfg(feature = "http")]
+    pub use super::stream::prelude::*;
     pub use super::{
         account::prelude::*, asset::prelude::*, block::prelude::*, domain::prelude::*,
         peer::prelude::*, permission::prelude::*, role::prelude::*, transaction::*,
@@ -609,6 +609,7 @@ pub mod error {
                 NewParameter(_) => "new parameter",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
+                RotatePeerKey(_) => "rotate peer key",
             };
             write!(
                 f,
@@ -750,6 +751,31 @@ mod http {
             }
             .into()
         }
+
+        /// Hash of the transaction payload, i.e. the message signed by the authority.
+        #[cfg(feature = "std")]
+        pub fn hash(&self) -> iroha_crypto::HashOf<TransactionPayload> {
+            self.payload.hash()
+        }
+
+        /// Sign transaction with a `signature` of [`Self::hash`] made without a key pair,
+        /// e.g. a [threshold](iroha_crypto::threshold) signature of the signers sharing the authority's key.
+        ///
+        /// # Errors
+        /// Fails if `signature` isn't a valid signature of the transaction payload
+        #[cfg(feature = "std")]
+        pub fn sign_with(
+            self,
+            signature: iroha_crypto::Signature,
+        ) -> Result<SignedTransaction, iroha_crypto::error::Error> {
+            let signature = iroha_crypto::SignatureOf::from_signature(signature, &self.payload)?;
+
+            Ok(SignedTransactionV1 {
+                payload: self.payload,
+                signatures: signature.into(),
+            }
+            .into())
+        }
     }
 }
 
@@ -43,6 +43,7 @@ pub trait Visit {
         visit_new_parameter(&NewParameter),
         visit_set_parameter(&SetParameter),
         visit_log(&Log),
+        visit_rotate_peer_key(&RotatePeerKey),
 
         // Visit QueryBox
         visit_find_account_by_id(&FindAccountById),
@@ -76,6 +77,7 @@ pub trait Visit {
         visit_find_block_header_by_hash(&FindBlockHeaderByHash),
         visit_find_domain_by_id(&FindDomainById),
         visit_find_domain_key_value_by_id_and_key(&FindDomainKeyValueByIdAndKey),
+        visit_find_nfts_by_owner(&FindNftsByOwner),
         visit_find_permission_tokens_by_account_id(&FindPermissionTokensByAccountId),
         visit_find_role_by_role_id(&FindRoleByRoleId),
         visit_find_roles_by_account_id(&FindRolesByAccountId),
@@ -109,6 +111,7 @@ pub trait Visit {
         visit_mint_asset_quantity(&Mint<u32, Asset>),
         visit_mint_asset_big_quantity(&Mint<u128, Asset>),
         visit_mint_asset_fixed(&Mint<Fixed, Asset>),
+        visit_mint_asset_nft(&Mint<Nft, Asset>),
         visit_mint_account_public_key(&Mint<PublicKey, Account>),
         visit_mint_account_signature_check_condition(&Mint<SignatureCheckCondition, Account>),
         visit_mint_trigger_repetitions(&Mint<u32, Trigger<TriggeringFilterBox>>),
@@ -118,6 +121,7 @@ pub trait Visit {
         visit_burn_asset_quantity(&Burn<u32, Asset>),
         visit_burn_asset_big_quantity(&Burn<u128, Asset>),
         visit_burn_asset_fixed(&Burn<Fixed, Asset>),
+        visit_burn_asset_nft(&Burn<Name, Asset>),
         visit_burn_trigger_repetitions(&Burn<u32, Trigger<TriggeringFilterBox>>),
 
         // Visit TransferBox
@@ -125,6 +129,7 @@ pub trait Visit {
         visit_transfer_asset_quantity(&Transfer<Asset, u32, Account>),
         visit_transfer_asset_big_quantity(&Transfer<Asset, u128, Account>),
         visit_transfer_asset_fixed(&Transfer<Asset, Fixed, Account>),
+        visit_transfer_asset_nft(&Transfer<Asset, Name, Account>),
         visit_transfer_domain(&Transfer<Account, DomainId, Account>),
 
         // Visit SetKeyValueBox
@@ -206,6 +211,7 @@ pub fn visit_query<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, qu
         visit_find_block_header_by_hash(FindBlockHeaderByHash),
         visit_find_domain_by_id(FindDomainById),
         visit_find_domain_key_value_by_id_and_key(FindDomainKeyValueByIdAndKey),
+        visit_find_nfts_by_owner(FindNftsByOwner),
         visit_find_permission_tokens_by_account_id(FindPermissionTokensByAccountId),
         visit_find_role_by_role_id(FindRoleByRoleId),
         visit_find_roles_by_account_id(FindRolesByAccountId),
@@ -246,6 +252,9 @@ pub fn visit_instruction<V: Visit + ?Sized>(
             visitor.visit_execute_trigger(authority, variant_value)
         }
         InstructionBox::Log(variant_value) => visitor.visit_log(authority, variant_value),
+        InstructionBox::RotatePeerKey(variant_value) => {
+            visitor.visit_rotate_peer_key(authority, variant_value)
+        }
         InstructionBox::Burn(variant_value) => visitor.visit_burn(authority, variant_value),
         InstructionBox::Fail(variant_value) => visitor.visit_fail(authority, variant_value),
         InstructionBox::Grant(variant_value) => visitor.visit_grant(authority, variant_value),
@@ -314,6 +323,7 @@ pub fn visit_mint<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi
             AssetMintBox::Quantity(obj) => visitor.visit_mint_asset_quantity(authority, obj),
             AssetMintBox::BigQuantity(obj) => visitor.visit_mint_asset_big_quantity(authority, obj),
             AssetMintBox::Fixed(obj) => visitor.visit_mint_asset_fixed(authority, obj),
+            AssetMintBox::Nft(obj) => visitor.visit_mint_asset_nft(authority, obj),
         },
         MintBox::TriggerRepetitions(obj) => visitor.visit_mint_trigger_repetitions(authority, obj),
     }
@@ -326,6 +336,7 @@ pub fn visit_burn<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi
             AssetBurnBox::Quantity(obj) => visitor.visit_burn_asset_quantity(authority, obj),
             AssetBurnBox::BigQuantity(obj) => visitor.visit_burn_asset_big_quantity(authority, obj),
             AssetBurnBox::Fixed(obj) => visitor.visit_burn_asset_fixed(authority, obj),
+            AssetBurnBox::Nft(obj) => visitor.visit_burn_asset_nft(authority, obj),
         },
         BurnBox::TriggerRepetitions(obj) => visitor.visit_burn_trigger_repetitions(authority, obj),
     }
@@ -349,6 +360,7 @@ pub fn visit_transfer<V: Visit + ?Sized>(
                 visitor.visit_transfer_asset_big_quantity(authority, obj)
             }
             AssetTransferBox::Fixed(obj) => visitor.visit_transfer_asset_fixed(authority, obj),
+            AssetTransferBox::Nft(obj) => visitor.visit_transfer_asset_nft(authority, obj),
         },
     }
 }
@@ -422,9 +434,12 @@ leaf_visitors! {
     visit_burn_asset_big_quantity(&Burn<u128, Asset>),
     visit_mint_asset_fixed(&Mint<Fixed, Asset>),
     visit_burn_asset_fixed(&Burn<Fixed, Asset>),
+    visit_mint_asset_nft(&Mint<Nft, Asset>),
+    visit_burn_asset_nft(&Burn<Name, Asset>),
     visit_transfer_asset_quantity(&Transfer<Asset, u32, Account>),
     visit_transfer_asset_big_quantity(&Transfer<Asset, u128, Account>),
     visit_transfer_asset_fixed(&Transfer<Asset, Fixed, Account>),
+    visit_transfer_asset_nft(&Transfer<Asset, Name, Account>),
     visit_set_asset_key_value(&SetKeyValue<Asset>),
     visit_remove_asset_key_value(&RemoveKeyValue<Asset>),
     visit_register_asset_definition(&Register<AssetDefinition>),
@@ -439,6 +454,7 @@ leaf_visitors! {
     visit_remove_domain_key_value(&RemoveKeyValue<Domain>),
     visit_register_peer(&Register<Peer>),
     visit_unregister_peer(&Unregister<Peer>),
+    visit_rotate_peer_key(&RotatePeerKey),
     visit_grant_account_permission(&Grant<PermissionToken>),
     visit_revoke_account_permission(&Revoke<PermissionToken>),
     visit_register_role(&Register<Role>),
@@ -488,6 +504,7 @@ leaf_visitors! {
     visit_find_block_header_by_hash(&FindBlockHeaderByHash),
     visit_find_domain_by_id(&FindDomainById),
     visit_find_domain_key_value_by_id_and_key(&FindDomainKeyValueByIdAndKey),
+    visit_find_nfts_by_owner(&FindNftsByOwner),
     visit_find_permission_tokens_by_account_id(&FindPermissionTokensByAccountId),
     visit_find_role_by_role_id(&FindRoleByRoleId),
     visit_find_roles_by_account_id(&FindRolesByAccountId),
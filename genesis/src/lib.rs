@@ -335,6 +335,7 @@ impl<S> RawGenesisDomainBuilder<S> {
             AssetValueType::BigQuantity => AssetDefinition::big_quantity(asset_definition_id),
             AssetValueType::Fixed => AssetDefinition::fixed(asset_definition_id),
             AssetValueType::Store => AssetDefinition::store(asset_definition_id),
+            AssetValueType::Nft => AssetDefinition::nft(asset_definition_id),
         };
         self.transaction
             .isi
@@ -0,0 +1,226 @@
+//! Address book of the peers in the network.
+//!
+//! Every peer signs the address it accepts connections at and peers gossip these records,
+//! so the network keeps connecting to a peer after its address changed,
+//! without the peer being re-registered with the new address.
+
+use std::{collections::HashMap, fs, io, path::PathBuf, time::Duration};
+
+use iroha_crypto::{KeyPair, PublicKey, SignatureOf};
+use iroha_primitives::addr::SocketAddr;
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+
+use crate::Error;
+
+/// Records from further in the future are rejected, otherwise they could never be replaced
+const MAX_CLOCK_DRIFT: Duration = Duration::from_secs(60);
+
+/// Address at which a peer accepts connections.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct AddressRecord {
+    /// Address to connect to
+    pub address: SocketAddr,
+    /// Time of the announcement in milliseconds since the unix epoch, newer records replace older ones
+    pub timestamp_ms: u64,
+}
+
+/// [`AddressRecord`] signed by the peer it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SignedAddressRecord {
+    record: AddressRecord,
+    signature: SignatureOf<AddressRecord>,
+}
+
+impl SignedAddressRecord {
+    /// Announce `address` as the current address of the owner of `key_pair`.
+    pub fn new(key_pair: &KeyPair, address: SocketAddr) -> Self {
+        let record = AddressRecord {
+            address,
+            timestamp_ms: now_ms(),
+        };
+        let signature = SignatureOf::new(key_pair, &record);
+        Self { record, signature }
+    }
+
+    /// Public key of the peer the record belongs to
+    pub fn public_key(&self) -> &PublicKey {
+        self.signature.public_key()
+    }
+
+    /// Announced address
+    pub fn address(&self) -> &SocketAddr {
+        &self.record.address
+    }
+
+    /// Time of the announcement in milliseconds since the unix epoch
+    pub fn timestamp_ms(&self) -> u64 {
+        self.record.timestamp_ms
+    }
+
+    /// Verify that the record is signed by the peer it belongs to.
+    ///
+    /// # Errors
+    /// Fails if the signature is invalid
+    pub fn verify(&self) -> Result<(), iroha_crypto::error::Error> {
+        self.signature.verify(&self.record)
+    }
+}
+
+/// Latest known addresses of the peers, optionally persisted to a file.
+#[derive(Debug, Default)]
+pub struct AddressBook {
+    records: HashMap<PublicKey, SignedAddressRecord>,
+    path: Option<PathBuf>,
+}
+
+impl AddressBook {
+    /// Empty address book, which is persisted at `path` if given.
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            records: HashMap::new(),
+            path,
+        }
+    }
+
+    /// Load the address book persisted at `path`, missing file results in an empty book.
+    /// The book is only kept in memory if `path` isn't given.
+    ///
+    /// # Errors
+    /// - Reading the file fails
+    /// - Decoding the records fails
+    pub fn load(path: Option<PathBuf>) -> Result<Self, Error> {
+        let mut address_book = Self::new(None);
+        if let Some(path) = &path {
+            match fs::read(path) {
+                Ok(bytes) => {
+                    let records: Vec<SignedAddressRecord> =
+                        DecodeAll::decode_all(&mut bytes.as_slice())?;
+                    for record in records {
+                        address_book.insert(record);
+                    }
+                }
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        address_book.path = path;
+        Ok(address_book)
+    }
+
+    /// Address of the peer with the given `public_key`
+    pub fn address(&self, public_key: &PublicKey) -> Option<&SocketAddr> {
+        self.records
+            .get(public_key)
+            .map(SignedAddressRecord::address)
+    }
+
+    /// All known records
+    pub fn records(&self) -> impl Iterator<Item = &SignedAddressRecord> {
+        self.records.values()
+    }
+
+    /// Insert `record` if it is valid and newer than the known record of the same peer.
+    ///
+    /// Returns `true` if the book was updated.
+    pub fn insert(&mut self, record: SignedAddressRecord) -> bool {
+        let is_newer = self
+            .records
+            .get(record.public_key())
+            .map_or(true, |known| known.timestamp_ms() < record.timestamp_ms());
+        let max_timestamp_ms = now_ms().saturating_add(
+            MAX_CLOCK_DRIFT
+                .as_millis()
+                .try_into()
+                .expect("Duration should fit into u64"),
+        );
+        if !is_newer || record.timestamp_ms() > max_timestamp_ms || record.verify().is_err() {
+            return false;
+        }
+        self.records.insert(record.public_key().clone(), record);
+        true
+    }
+
+    /// Replace the record of the owner of `key_pair` with a fresh one for `address`.
+    pub fn announce(&mut self, key_pair: &KeyPair, address: SocketAddr) {
+        let record = SignedAddressRecord::new(key_pair, address);
+        self.records.insert(key_pair.public_key().clone(), record);
+    }
+
+    /// Persist the book if it has a file.
+    ///
+    /// # Errors
+    /// Fails if writing the file fails
+    pub fn save(&self) -> Result<(), Error> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let records = self.records.values().collect::<Vec<_>>();
+        // Replace the file at once so it isn't left truncated if the peer crashes while writing
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, records.encode())?;
+        fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+}
+
+fn now_ms() -> u64 {
+    iroha_data_model::current_time()
+        .as_millis()
+        .try_into()
+        .expect("Time should fit into u64")
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_primitives::addr::socket_addr;
+
+    use super::*;
+
+    #[test]
+    fn newer_record_replaces_older_one() {
+        let key_pair = KeyPair::generate().unwrap();
+        let old = SignedAddressRecord::new(&key_pair, socket_addr!(127.0.0.1:1337));
+        std::thread::sleep(Duration::from_millis(2));
+        let new = SignedAddressRecord::new(&key_pair, socket_addr!(127.0.0.2:1337));
+
+        let mut address_book = AddressBook::default();
+        assert!(address_book.insert(new.clone()));
+        assert!(!address_book.insert(old));
+        assert!(!address_book.insert(new));
+        assert_eq!(
+            address_book.address(key_pair.public_key()),
+            Some(&socket_addr!(127.0.0.2:1337))
+        );
+    }
+
+    #[test]
+    fn forged_record_is_rejected() {
+        let key_pair = KeyPair::generate().unwrap();
+        let mut record = SignedAddressRecord::new(&key_pair, socket_addr!(127.0.0.1:1337));
+        record.record.address = socket_addr!(127.0.0.2:1337);
+
+        let mut address_book = AddressBook::default();
+        assert!(!address_book.insert(record));
+        assert_eq!(address_book.address(key_pair.public_key()), None);
+    }
+
+    #[test]
+    fn address_book_is_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("address_book");
+        let key_pair = KeyPair::generate().unwrap();
+
+        let mut address_book = AddressBook::load(Some(path.clone())).unwrap();
+        address_book.announce(&key_pair, socket_addr!(127.0.0.1:1337));
+        address_book.save().unwrap();
+
+        let address_book = AddressBook::load(Some(path)).unwrap();
+        assert_eq!(
+            address_book.address(key_pair.public_key()),
+            Some(&socket_addr!(127.0.0.1:1337))
+        );
+    }
+}
@@ -15,8 +15,11 @@ pub use network::message::*;
 use parity_scale_codec::{Decode, Encode};
 use thiserror::Error;
 
+pub mod address_book;
 pub mod network;
 pub mod peer;
+pub mod reputation;
+pub mod transport;
 
 /// The main type to use for secure communication.
 pub type NetworkHandle<T> = network::NetworkBaseHandle<T, X25519Sha256, ChaCha20Poly1305>;
@@ -30,8 +33,8 @@ pub mod boilerplate {
     use super::*;
 
     /// Shorthand for traits required for payload
-    pub trait Pload: Encode + Decode + Send + Clone + 'static {}
-    impl<T> Pload for T where T: Encode + Decode + Send + Clone + 'static {}
+    pub trait Pload: Encode + Decode + Lane + Send + Clone + 'static {}
+    impl<T> Pload for T where T: Encode + Decode + Lane + Send + Clone + 'static {}
 
     /// Shorthand for traits required for key exchange
     pub trait Kex: KeyExchangeScheme + Send + 'static {}
@@ -42,6 +45,23 @@ pub mod boilerplate {
     impl<T> Enc for T where T: Encryptor + Clone + Send + 'static {}
 }
 
+/// Classification of messages into the lanes of a connection.
+///
+/// Transports which multiplex connections send every lane over a separate stream,
+/// so that a backlog of messages in one lane doesn't delay the messages in the others.
+pub trait Lane {
+    /// Lane of the message, lanes beyond [`transport::LANES`] share the last one
+    fn lane(&self) -> usize {
+        0
+    }
+
+    /// Whether the message is skipped if it exceeds the maximum frame size of the connection.
+    /// Otherwise the connection is closed, as the peers can't make progress without the message.
+    fn is_droppable(&self) -> bool {
+        true
+    }
+}
+
 /// Errors used in [`crate`].
 #[derive(Debug, Error, displaydoc::Display)]
 pub enum Error {
@@ -61,6 +81,21 @@ pub enum Error {
     Addr(#[from] AddrParseError),
     /// Connection reset by peer in the middle of message transfer
     ConnectionResetByPeer,
+    /// Failed to generate certificate
+    Certificate(#[from] rcgen::RcgenError),
+    /// Failed to configure TLS
+    Tls(#[from] rustls::Error),
+    /// Failed to initiate QUIC connection
+    QuicConnect(#[from] quinn::ConnectError),
+    /// QUIC connection failed
+    QuicConnection(#[from] quinn::ConnectionError),
+    /// Message of {size} bytes exceeds the maximum frame size of {max_size} bytes
+    FrameTooLarge {
+        /// Size of the message
+        size: usize,
+        /// Maximum allowed size
+        max_size: usize,
+    },
 }
 
 impl From<io::Error> for Error {
@@ -2,31 +2,38 @@
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    time::Duration,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use futures::{stream::FuturesUnordered, StreamExt};
+use iroha_config::network::Configuration;
 use iroha_crypto::{KeyPair, PublicKey};
 use iroha_data_model::prelude::PeerId;
 use iroha_logger::prelude::*;
 use iroha_primitives::addr::SocketAddr;
 use parity_scale_codec::Encode as _;
-use tokio::{
-    net::{TcpListener, TcpStream},
-    sync::{mpsc, watch},
-};
+use tokio::sync::{mpsc, watch};
 
 use crate::{
+    address_book::AddressBook,
     blake2b_hash,
     boilerplate::*,
     peer::{
         handles::{connected_from, connecting, PeerHandle},
         message::*,
-        Connection, ConnectionId,
+        Connection, ConnectionId, ConnectionSettings,
     },
-    unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, Post, UpdateTopology,
+    reputation::{Misbehaviour, PeerScores, Reputations},
+    transport::{self, Listener, Stream, Transport},
+    unbounded_with_len, Broadcast, Error, Lane, NetworkMessage, OnlinePeers, Post, UpdateTopology,
 };
 
+/// Period of sharing the known peer addresses with connected peers
+const ADDRESS_GOSSIP_INTERVAL: Duration = Duration::from_secs(10);
+/// Time after which a peer connects to a disconnected peer even if it's the other peer's responsibility
+const PASSIVE_CONNECT_DELAY: Duration = Duration::from_secs(5);
+
 /// [`NetworkBase`] actor handle.
 // NOTE: channels are unbounded in order to break communication cycle deadlock.
 // Unbounded channels are ok here because messages frequency is either configurable (and relatively low)
@@ -35,13 +42,21 @@ use crate::{
 #[debug(fmt = "core::any::type_name::<Self>()")]
 pub struct NetworkBaseHandle<T: Pload, K: Kex, E: Enc> {
     /// Sender to subscribe for messages received form other peers in the network
-    subscribe_to_peers_messages_sender: mpsc::UnboundedSender<mpsc::Sender<T>>,
+    subscribe_to_peers_messages_sender: mpsc::UnboundedSender<mpsc::Sender<PeerMessage<T>>>,
     /// Receiver of `OnlinePeer` message
     online_peers_receiver: watch::Receiver<OnlinePeers>,
     /// [`UpdateTopology`] message sender
     update_topology_sender: mpsc::UnboundedSender<UpdateTopology>,
+    /// Sender of the new key pair of this peer
+    update_key_pair_sender: mpsc::UnboundedSender<KeyPair>,
     /// Sender of [`NetworkMessage`] message
     network_message_sender: unbounded_with_len::Sender<NetworkMessage<T>>,
+    /// Receiver of the reputations of misbehaving peers
+    reputations_receiver: watch::Receiver<Reputations>,
+    /// Sender of the misbehaviour of peers detected outside of the network
+    misbehaviour_sender: mpsc::UnboundedSender<(PublicKey, Misbehaviour)>,
+    /// Maximum size of a single message proposed to peers
+    max_frame_size: u32,
     /// Key exchange used by network
     _key_exchange: core::marker::PhantomData<K>,
     /// Encryptor used by the network
@@ -54,7 +69,11 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
             subscribe_to_peers_messages_sender: self.subscribe_to_peers_messages_sender.clone(),
             online_peers_receiver: self.online_peers_receiver.clone(),
             update_topology_sender: self.update_topology_sender.clone(),
+            update_key_pair_sender: self.update_key_pair_sender.clone(),
             network_message_sender: self.network_message_sender.clone(),
+            reputations_receiver: self.reputations_receiver.clone(),
+            misbehaviour_sender: self.misbehaviour_sender.clone(),
+            max_frame_size: self.max_frame_size,
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
         }
@@ -64,23 +83,43 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
 impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
     /// Start network peer and return handle to it
     ///
+    /// `listen_addr` is also announced to other peers as the address to connect to this peer at.
+    ///
     /// # Errors
     /// - If binding to address fail
-    #[log(skip(key_pair))]
-    pub async fn start(listen_addr: SocketAddr, key_pair: KeyPair) -> Result<Self, Error> {
-        let listener = TcpListener::bind(&listen_addr.to_string()).await?;
-        iroha_logger::info!("Network bound to listener");
+    #[log(skip(key_pair, config))]
+    pub async fn start(
+        listen_addr: SocketAddr,
+        key_pair: KeyPair,
+        config: &Configuration,
+    ) -> Result<Self, Error> {
+        let (transport, listener) = transport::bind(config.transport, &listen_addr).await?;
+        iroha_logger::info!(transport = ?config.transport, "Network bound to listener");
+        let mut address_book = AddressBook::load(config.address_book_path.clone())
+            .unwrap_or_else(|error| {
+                iroha_logger::warn!(%error, "Failed to load address book, starting with an empty one");
+                AddressBook::new(config.address_book_path.clone())
+            });
+        address_book.announce(&key_pair, listen_addr.clone());
         let (online_peers_sender, online_peers_receiver) = watch::channel(HashSet::new());
         let (subscribe_to_peers_messages_sender, subscribe_to_peers_messages_receiver) =
             mpsc::unbounded_channel();
         let (update_topology_sender, update_topology_receiver) = mpsc::unbounded_channel();
+        let (update_key_pair_sender, update_key_pair_receiver) = mpsc::unbounded_channel();
+        let (reputations_sender, reputations_receiver) = watch::channel(Reputations::new());
+        let (misbehaviour_sender, misbehaviour_receiver) = mpsc::unbounded_channel();
         let (network_message_sender, network_message_receiver) =
             unbounded_with_len::unbounded_channel();
         let (peer_message_sender, peer_message_receiver) = mpsc::channel(1);
         let (service_message_sender, service_message_receiver) = mpsc::channel(1);
+        let observer_keys: HashSet<_> = config.observer_keys.iter().cloned().collect();
+        let allowlist_sender = config
+            .allowlist
+            .then(|| watch::channel(observer_keys.clone()).0);
         let network = NetworkBase {
             listen_addr,
             listener,
+            transport,
             peers: HashMap::new(),
             connecting_peers: HashMap::new(),
             key_pair,
@@ -88,6 +127,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             subscribe_to_peers_messages_receiver,
             online_peers_sender,
             update_topology_receiver,
+            update_key_pair_receiver,
             network_message_receiver,
             peer_message_receiver,
             peer_message_sender,
@@ -95,22 +135,46 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             service_message_sender,
             current_conn_id: 0,
             current_topology: HashMap::new(),
+            address_book,
+            accept_observers: config.accept_observers,
+            observer_keys,
+            allowlist_sender,
+            connection_settings: config.into(),
+            peer_scores: PeerScores::new(
+                config.peer_message_rate_limit,
+                Duration::from_millis(config.peer_ban_duration_ms),
+            ),
+            reputations_sender,
+            misbehaviour_receiver,
+            connection_attempts: HashMap::new(),
+            disconnected_since: HashMap::new(),
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
         };
+        network.save_address_book();
         tokio::task::spawn(network.run());
         Ok(Self {
             subscribe_to_peers_messages_sender,
             online_peers_receiver,
             update_topology_sender,
+            update_key_pair_sender,
             network_message_sender,
+            reputations_receiver,
+            misbehaviour_sender,
+            max_frame_size: config.max_frame_size,
             _key_exchange: core::marker::PhantomData,
             _encryptor: core::marker::PhantomData,
         })
     }
 
+    /// Maximum size of a single message sent to peers, which may only be lowered by the peers.
+    pub const fn max_frame_size(&self) -> u32 {
+        self.max_frame_size
+    }
+
     /// Subscribe to messages received from other peers in the network
-    pub fn subscribe_to_peers_messages(&self, sender: mpsc::Sender<T>) {
+    /// along with the authenticated ids of the sending peers
+    pub fn subscribe_to_peers_messages(&self, sender: mpsc::Sender<PeerMessage<T>>) {
         self.subscribe_to_peers_messages_sender
             .send(sender)
             .expect("NetworkBase must accept messages until there is at least one handle to it")
@@ -139,6 +203,28 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             .expect("NetworkBase must accept messages until there is at least one handle to it")
     }
 
+    /// Replace the key pair of this peer, e.g. after its key was rotated.
+    ///
+    /// All connections are dropped and re-established under the new identity.
+    /// Must be sent before the [`UpdateTopology`] containing the new identity.
+    pub fn update_key_pair(&self, key_pair: KeyPair) {
+        self.update_key_pair_sender
+            .send(key_pair)
+            .expect("NetworkBase must accept messages until there is at least one handle to it")
+    }
+
+    /// Lower the score of the peer with `public_key`, e.g. after it sent an invalid block.
+    pub fn report_misbehaviour(&self, public_key: PublicKey, misbehaviour: Misbehaviour) {
+        self.misbehaviour_sender
+            .send((public_key, misbehaviour))
+            .expect("NetworkBase must accept messages until there is at least one handle to it")
+    }
+
+    /// Receive latest [`Reputations`] of the misbehaving peers
+    pub fn reputations<P>(&self, f: impl FnOnce(&Reputations) -> P) -> P {
+        f(&self.reputations_receiver.borrow())
+    }
+
     /// Receive latest update of [`OnlinePeers`]
     pub fn online_peers<P>(&self, f: impl FnOnce(&OnlinePeers) -> P) -> P {
         f(&self.online_peers_receiver.borrow())
@@ -165,18 +251,22 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     peers: HashMap<PublicKey, RefPeer<T>>,
     /// [`Peer`]s in process of being connected.
     connecting_peers: HashMap<ConnectionId, PublicKey>,
-    /// [`TcpListener`] that is accepting [`Peer`]s' connections
-    listener: TcpListener,
+    /// [`Listener`] that is accepting [`Peer`]s' connections
+    listener: Box<dyn Listener>,
+    /// [`Transport`] used to connect to [`Peer`]s
+    transport: Arc<dyn Transport>,
     /// Our app-level key pair
     key_pair: KeyPair,
     /// Recipients of messages received from other peers in the network.
-    subscribers_to_peers_messages: Vec<mpsc::Sender<T>>,
+    subscribers_to_peers_messages: Vec<mpsc::Sender<PeerMessage<T>>>,
     /// Receiver to subscribe for messages received from other peers in the network.
-    subscribe_to_peers_messages_receiver: mpsc::UnboundedReceiver<mpsc::Sender<T>>,
+    subscribe_to_peers_messages_receiver: mpsc::UnboundedReceiver<mpsc::Sender<PeerMessage<T>>>,
     /// Sender of `OnlinePeer` message
     online_peers_sender: watch::Sender<OnlinePeers>,
     /// [`UpdateTopology`] message receiver
     update_topology_receiver: mpsc::UnboundedReceiver<UpdateTopology>,
+    /// Receiver of the new key pair of this peer
+    update_key_pair_receiver: mpsc::UnboundedReceiver<KeyPair>,
     /// Receiver of [`Post`] message
     network_message_receiver: unbounded_with_len::Receiver<NetworkMessage<T>>,
     /// Channel to gather messages from all peers
@@ -192,6 +282,26 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     /// Current topology
     /// Bool determines who is responsible for initiating connection
     current_topology: HashMap<PeerId, bool>,
+    /// Latest addresses announced by the peers
+    address_book: AddressBook,
+    /// Whether peers outside of the topology are allowed to connect as observers
+    accept_observers: bool,
+    /// Peers outside of the topology which are allowed to connect as observers
+    observer_keys: HashSet<PublicKey>,
+    /// Sender of the keys allowed to connect, if handshakes with unknown peers are rejected
+    allowlist_sender: Option<watch::Sender<HashSet<PublicKey>>>,
+    /// Settings proposed to peers when connecting
+    connection_settings: ConnectionSettings,
+    /// Scores and message rates of the peers
+    peer_scores: PeerScores,
+    /// Sender of the reputations of misbehaving peers
+    reputations_sender: watch::Sender<Reputations>,
+    /// Receiver of the misbehaviour of peers detected outside of the network
+    misbehaviour_receiver: mpsc::UnboundedReceiver<(PublicKey, Misbehaviour)>,
+    /// Number of attempts to connect to the peer since the last successful connection
+    connection_attempts: HashMap<PublicKey, u32>,
+    /// Since when the peer from the topology isn't connected
+    disconnected_since: HashMap<PublicKey, Instant>,
     /// Key exchange used by network
     _key_exchange: core::marker::PhantomData<K>,
     /// Encryptor used by the network
@@ -204,6 +314,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     async fn run(mut self) {
         // TODO: probably should be configuration parameter
         let mut update_topology_interval = tokio::time::interval(Duration::from_millis(100));
+        let mut address_gossip_interval = tokio::time::interval(ADDRESS_GOSSIP_INTERVAL);
         loop {
             tokio::select! {
                 // Select is biased because we want to service messages to take priority over data messages.
@@ -212,14 +323,27 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 Some(subscriber) = self.subscribe_to_peers_messages_receiver.recv() => {
                     self.subscribe_to_peers_messages(subscriber);
                 }
+                // Key pair is rotated rarely and must be applied before the topology containing the new key
+                Some(key_pair) = self.update_key_pair_receiver.recv() => {
+                    self.set_key_pair(key_pair);
+                }
                 // Update topology is relative low rate message (at most once every block)
                 Some(update_topology) = self.update_topology_receiver.recv() => {
                     self.set_current_topology(update_topology);
                 }
+                // Misbehaviour is reported rarely and banned peers must be dropped as soon as possible
+                Some((public_key, misbehaviour)) = self.misbehaviour_receiver.recv() => {
+                    self.penalize(&public_key, misbehaviour);
+                }
                 // Frequency of update is relatively low, so it won't block other tasks from execution
                 _ = update_topology_interval.tick() => {
+                    self.recover_scores();
                     self.update_topology()
                 }
+                // Peers share updated addresses right away, so this is only needed for peers that missed the update
+                _ = address_gossip_interval.tick() => {
+                    self.gossip_addresses()
+                }
                 // Every peer produce small amount of service messages so this shouldn't starve other tasks
                 Some(service_message) = self.service_message_receiver.recv() => {
                     match service_message {
@@ -229,6 +353,12 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                         ServiceMessage::Connected(connected) => {
                             self.peer_connected(connected);
                         }
+                        ServiceMessage::Addresses(addresses) => {
+                            self.peer_addresses(addresses);
+                        }
+                        ServiceMessage::Misbehaved(PeerMisbehaved { peer_id, misbehaviour }) => {
+                            self.penalize(&peer_id.public_key, misbehaviour);
+                        }
                     }
                 }
                 // Because network messages is responses to incoming messages or relatively low rate messages
@@ -250,10 +380,10 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 // Accept incoming peer connections
                 accept = self.listener.accept() => {
                     match accept {
-                        Ok((stream, addr)) => {
+                        Ok((streams, addr)) => {
                             iroha_logger::debug!(from_addr = %addr, "Accepted connection");
                             // Handle creation of new peer
-                            self.accept_new_peer(stream, &addr.into());
+                            self.accept_new_peer(streams, &addr);
                         },
                         Err(error) => {
                             iroha_logger::warn!(%error, "Error accepting connection");
@@ -270,49 +400,113 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         }
     }
 
-    fn accept_new_peer(&mut self, stream: TcpStream, addr: &SocketAddr) {
+    fn accept_new_peer(&mut self, streams: Vec<Stream>, addr: &SocketAddr) {
         let conn_id = self.get_conn_id();
         let service_message_sender = self.service_message_sender.clone();
         connected_from::<T, K, E>(
             addr.clone(),
             self.key_pair.clone(),
-            Connection::new(conn_id, stream),
+            self.connection_settings,
+            Connection::new(conn_id, streams),
             service_message_sender,
+            self.allowlist_sender.as_ref().map(watch::Sender::subscribe),
         );
     }
 
     fn set_current_topology(&mut self, UpdateTopology(topology): UpdateTopology) {
         iroha_logger::debug!(?topology, "Network receive new topology");
         let self_public_key_hash = blake2b_hash(self.key_pair.public_key().encode());
+        // Peers don't connect to observers, which aren't part of their topology
+        let is_observer = !topology.contains(self.key_pair.public_key());
         let topology = topology
             .into_iter()
             .map(|peer_id| {
                 // Determine who is responsible for connecting
                 let peer_public_key_hash = blake2b_hash(peer_id.public_key().encode());
-                let is_active = self_public_key_hash > peer_public_key_hash;
+                let is_active = is_observer || self_public_key_hash > peer_public_key_hash;
                 (peer_id, is_active)
             })
             .collect();
         self.current_topology = topology;
+        if let Some(allowlist_sender) = &self.allowlist_sender {
+            let allowlist = self
+                .current_topology
+                .keys()
+                .map(|peer_id| peer_id.public_key().clone())
+                .chain(self.observer_keys.iter().cloned())
+                .collect();
+            allowlist_sender.send_replace(allowlist);
+        }
         self.update_topology()
     }
 
+    fn set_key_pair(&mut self, key_pair: KeyPair) {
+        iroha_logger::info!(
+            old_public_key=%self.key_pair.public_key(),
+            new_public_key=%key_pair.public_key(),
+            "Network key pair is rotated, reconnecting peers"
+        );
+        self.key_pair = key_pair;
+
+        // Connections are authenticated with the old key so they have to be re-established
+        let connected_peers = self.peers.keys().cloned().collect::<Vec<_>>();
+        for public_key in connected_peers {
+            self.disconnect_peer(public_key);
+        }
+
+        // Responsibility for connecting depends on our key
+        let topology = self.current_topology.keys().cloned().collect();
+        self.set_current_topology(UpdateTopology(topology));
+
+        self.address_book
+            .announce(&self.key_pair, self.listen_addr.clone());
+        self.save_address_book();
+    }
+
     fn update_topology(&mut self) {
+        let now = Instant::now();
+        let Self {
+            current_topology,
+            peers,
+            disconnected_since,
+            ..
+        } = self;
+        disconnected_since.retain(|public_key, _| {
+            current_topology.contains_key(public_key) && !peers.contains_key(public_key)
+        });
+        for peer in current_topology.keys() {
+            if !peers.contains_key(peer.public_key()) {
+                disconnected_since
+                    .entry(peer.public_key().clone())
+                    .or_insert(now);
+            }
+        }
+        // Peer responsible for connecting might not know our current address, so connect ourselves after a while
+        let is_passive_connect_due = |public_key: &PublicKey| {
+            self.disconnected_since
+                .get(public_key)
+                .map_or(false, |since| {
+                    now.duration_since(*since) > PASSIVE_CONNECT_DELAY
+                })
+        };
+
         let to_connect = self.current_topology
             .iter()
             // Peer is not connected but should
             .filter_map(|(peer, is_active)| (
                 !self.peers.contains_key(&peer.public_key)
+                    && !self.peer_scores.is_banned(&peer.public_key)
                     && !self.connecting_peers.values().any(|public_key| peer.public_key() == public_key)
-                    && *is_active
+                    && (*is_active || is_passive_connect_due(peer.public_key()))
             ).then_some(peer))
             .cloned()
             .collect::<Vec<_>>();
 
         let to_disconnect = self.peers
-            .keys()
+            .iter()
             // Peer is connected but shouldn't
-            .filter(|public_key| !self.current_topology.contains_key(*public_key))
+            .filter(|(public_key, peer)| !peer.is_observer && !self.current_topology.contains_key(*public_key))
+            .map(|(public_key, _)| public_key)
             .cloned()
             .collect::<Vec<_>>();
 
@@ -326,8 +520,9 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     }
 
     fn connect_peer(&mut self, peer: &PeerId) {
+        let address = self.peer_address(peer);
         iroha_logger::trace!(
-            listen_addr = %self.listen_addr, peer.id.address = %peer.address,
+            listen_addr = %self.listen_addr, peer.id.address = %address,
             "Creating new peer actor",
         );
 
@@ -337,13 +532,31 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         let service_message_sender = self.service_message_sender.clone();
         connecting::<T, K, E>(
             // NOTE: we intentionally use peer's address and our public key, it's used during handshake
-            peer.address.clone(),
+            address,
             self.key_pair.clone(),
+            self.connection_settings,
+            Arc::clone(&self.transport),
             conn_id,
             service_message_sender,
         );
     }
 
+    /// Address to connect to `peer` at.
+    ///
+    /// Alternates between the address announced by the peer and the one from the topology,
+    /// because either of them might be outdated.
+    fn peer_address(&mut self, peer: &PeerId) -> SocketAddr {
+        let attempt = self
+            .connection_attempts
+            .entry(peer.public_key().clone())
+            .or_default();
+        *attempt = attempt.wrapping_add(1);
+        match self.address_book.address(peer.public_key()) {
+            Some(address) if *attempt % 2 == 1 => address.clone(),
+            _ => peer.address.clone(),
+        }
+    }
+
     fn disconnect_peer(&mut self, public_key: PublicKey) {
         let peer = match self.peers.remove(&public_key) {
             Some(peer) => peer,
@@ -366,10 +579,15 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             disambiguator,
         }: Connected<T>,
     ) {
-        if !self.current_topology.contains_key(&peer_id) {
+        let is_observer = !self.current_topology.contains_key(&peer_id);
+        if is_observer && !self.is_allowed_observer(&peer_id.public_key) {
             iroha_logger::warn!(%peer_id, topology=?self.current_topology, "Peer not present in topology is trying to connect");
             return;
         }
+        if self.peer_scores.is_banned(&peer_id.public_key) {
+            iroha_logger::debug!("Banned peer is trying to connect");
+            return;
+        }
 
         //  Insert peer if peer not in peers yet or replace peer if it's disambiguator value is smaller than new one (simultaneous connections resolution rule)
         match self.peers.get(&peer_id.public_key) {
@@ -392,13 +610,24 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             conn_id: connection_id,
             p2p_addr: peer_id.address.clone(),
             disambiguator,
+            is_observer,
         };
+        // Share the addresses we know in case the peer's ones are outdated
+        let records = self.address_book.records().cloned().collect();
+        let _ = ref_peer.handle.post_addresses(records);
         let _ = peer_message_sender.send(self.peer_message_sender.clone());
         self.peers.insert(peer_id.public_key().clone(), ref_peer);
         self.connecting_peers.remove(&connection_id);
+        self.connection_attempts.remove(peer_id.public_key());
         Self::add_online_peer(&self.online_peers_sender, peer_id);
     }
 
+    /// Whether the peer outside of the topology is allowed to connect as an observer
+    fn is_allowed_observer(&self, public_key: &PublicKey) -> bool {
+        self.observer_keys.contains(public_key)
+            || (self.accept_observers && self.allowlist_sender.is_none())
+    }
+
     fn peer_terminated(&mut self, Terminated { peer_id, conn_id }: Terminated) {
         self.connecting_peers.remove(&conn_id);
         if let Some(peer_id) = peer_id {
@@ -449,9 +678,65 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         });
     }
 
+    fn peer_addresses(&mut self, PeerAddresses { peer_id, records }: PeerAddresses) {
+        let mut updated = Vec::new();
+        for record in records {
+            // Only addresses of the peers we connect to are of interest
+            if record.public_key() == self.key_pair.public_key()
+                || !self.current_topology.contains_key(record.public_key())
+            {
+                continue;
+            }
+            if self.address_book.insert(record.clone()) {
+                iroha_logger::info!(
+                    peer=%record.public_key(), address=%record.address(), from=%peer_id,
+                    "Learned new peer address"
+                );
+                // Try the new address first
+                self.connection_attempts.remove(record.public_key());
+                updated.push(record);
+            }
+        }
+        if updated.is_empty() {
+            return;
+        }
+        self.save_address_book();
+
+        for (public_key, peer) in &self.peers {
+            if *public_key != peer_id.public_key {
+                let _ = peer.handle.post_addresses(updated.clone());
+            }
+        }
+    }
+
+    fn gossip_addresses(&self) {
+        let records = self.address_book.records().cloned().collect::<Vec<_>>();
+        for peer in self.peers.values() {
+            let _ = peer.handle.post_addresses(records.clone());
+        }
+    }
+
+    fn save_address_book(&self) {
+        if let Err(error) = self.address_book.save() {
+            iroha_logger::warn!(%error, "Failed to save address book");
+        }
+    }
+
     async fn peer_message(&mut self, PeerMessage(peer_id, msg): PeerMessage<T>) {
         // TODO: consider broadcast channel instead
         iroha_logger::trace!(peer=%peer_id, "Received peer message");
+        let lane = msg.lane().min(transport::LANES - 1);
+        let is_in_topology = self.current_topology.contains_key(&peer_id);
+        if !self.peer_scores.message_received(
+            &peer_id.public_key,
+            lane,
+            is_in_topology,
+            Instant::now(),
+        ) {
+            iroha_logger::debug!(peer=%peer_id, "Message rate limit exceeded, dropping message");
+            self.penalize(&peer_id.public_key, Misbehaviour::RateLimitExceeded);
+            return;
+        }
         if self.subscribers_to_peers_messages.is_empty() {
             iroha_logger::warn!("No subscribers to send message to");
             return;
@@ -459,7 +744,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         self.subscribers_to_peers_messages = self
             .subscribers_to_peers_messages
             .drain(..)
-            .zip(core::iter::repeat(msg))
+            .zip(core::iter::repeat(PeerMessage(peer_id, msg)))
             .map(|(subscriber_t, msg)| async move {
                 let is_ok = subscriber_t.send(msg).await.is_ok();
                 (subscriber_t, is_ok)
@@ -472,7 +757,36 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             .await;
     }
 
-    fn subscribe_to_peers_messages(&mut self, subscriber: mpsc::Sender<T>) {
+    fn penalize(&mut self, public_key: &PublicKey, misbehaviour: Misbehaviour) {
+        iroha_logger::debug!(peer=%public_key, %misbehaviour, "Peer misbehaved");
+        if self
+            .peer_scores
+            .penalize(public_key, misbehaviour, Instant::now())
+        {
+            iroha_logger::warn!(peer=%public_key, %misbehaviour, "Banning misbehaving peer");
+            if self.peers.contains_key(public_key) {
+                self.disconnect_peer(public_key.clone());
+            }
+        }
+        self.publish_reputations();
+    }
+
+    fn recover_scores(&mut self) {
+        if self.peer_scores.recover(Instant::now()) {
+            self.publish_reputations();
+        }
+    }
+
+    fn publish_reputations(&self) {
+        let reputations = self.peer_scores.reputations();
+        self.reputations_sender.send_if_modified(|current| {
+            let is_modified = *current != reputations;
+            *current = reputations;
+            is_modified
+        });
+    }
+
+    fn subscribe_to_peers_messages(&mut self, subscriber: mpsc::Sender<PeerMessage<T>>) {
         self.subscribers_to_peers_messages.push(subscriber);
         iroha_logger::trace!(
             subscribers = self.subscribers_to_peers_messages.len(),
@@ -553,4 +867,6 @@ struct RefPeer<T: Pload> {
     /// To solve this situation disambiguator value is used.
     /// It's equal for both peers and when peer receive connection for peer already present in peers set it just select connection with higher value.
     disambiguator: u64,
+    /// Peer isn't part of the topology and only follows the chain
+    is_observer: bool,
 }
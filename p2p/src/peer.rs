@@ -1,20 +1,23 @@
 //! Tokio actor Peer
 
+use std::collections::HashSet;
+
 use bytes::{Buf, BufMut, BytesMut};
+use iroha_crypto::PublicKey;
 use iroha_data_model::prelude::PeerId;
 use message::*;
-use parity_scale_codec::{DecodeAll, Encode};
+use parity_scale_codec::{Decode, DecodeAll, Encode};
 use rand::{Rng, RngCore};
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
-    net::{
-        tcp::{OwnedReadHalf, OwnedWriteHalf},
-        TcpStream,
-    },
-    sync::{mpsc, oneshot},
+    sync::{mpsc, oneshot, watch},
 };
 
-use crate::{boilerplate::*, Error};
+use crate::{
+    boilerplate::*,
+    transport::{ReadHalf, Stream, Transport, WriteHalf},
+    Error, Lane,
+};
 
 /// Max length of message handshake in bytes excluding first message length byte.
 pub const MAX_HANDSHAKE_LENGTH: u8 = 255;
@@ -22,50 +25,110 @@ pub const MAX_HANDSHAKE_LENGTH: u8 = 255;
 /// [`Authenticated encryption`](https://en.wikipedia.org/wiki/Authenticated_encryption)
 pub const DEFAULT_AAD: &[u8; 10] = b"Iroha2 AAD";
 
+/// Compression of the messages exchanged over a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub enum Compression {
+    /// Messages are sent as is
+    None,
+    /// Messages are compressed with zstd
+    Zstd,
+}
+
+impl Compression {
+    /// Compression of a connection, given the compressions proposed by both peers.
+    #[must_use]
+    pub fn negotiate(self, other: Self) -> Self {
+        if self == other {
+            self
+        } else {
+            Self::None
+        }
+    }
+}
+
+/// Keys of the peers allowed to connect, kept up to date by the network as the topology changes.
+pub type Allowlist = watch::Receiver<HashSet<PublicKey>>;
+
+/// Settings of the connections with other peers.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectionSettings {
+    /// Compression proposed during the handshake, used only if the other peer proposes it as well
+    pub compression: Compression,
+    /// Maximum size of a single message in bytes, connection is dropped if the other peer sends a bigger one.
+    /// The smaller of the sizes proposed by both peers is used once connected.
+    pub max_frame_size: u32,
+}
+
+impl From<&iroha_config::network::Configuration> for ConnectionSettings {
+    fn from(config: &iroha_config::network::Configuration) -> Self {
+        Self {
+            compression: if config.compression {
+                Compression::Zstd
+            } else {
+                Compression::None
+            },
+            max_frame_size: config.max_frame_size,
+        }
+    }
+}
+
 pub mod handles {
     //! Module with functions to start peer actor and handle to interact with it.
 
+    use std::sync::Arc;
+
     use iroha_crypto::KeyPair;
     use iroha_logger::Instrument;
     use iroha_primitives::addr::SocketAddr;
 
     use super::{run::RunPeerArgs, *};
-    use crate::unbounded_with_len;
+    use crate::{address_book::SignedAddressRecord, unbounded_with_len};
 
     /// Start Peer in [`state::Connecting`] state
     pub fn connecting<T: Pload, K: Kex, E: Enc>(
         peer_addr: SocketAddr,
         key_pair: KeyPair,
+        settings: ConnectionSettings,
+        transport: Arc<dyn Transport>,
         connection_id: ConnectionId,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
     ) {
         let peer = state::Connecting {
             peer_addr,
             key_pair,
+            settings,
+            transport,
             connection_id,
         };
         let peer = RunPeerArgs {
             peer,
             service_message_sender,
+            allowlist: None,
         };
         tokio::task::spawn(run::run::<T, K, E, _>(peer).in_current_span());
     }
 
     /// Start Peer in [`state::ConnectedFrom`] state
+    ///
+    /// If `allowlist` is given, the handshake with a peer whose key isn't in it is rejected.
     pub fn connected_from<T: Pload, K: Kex, E: Enc>(
         peer_addr: SocketAddr,
         key_pair: KeyPair,
+        settings: ConnectionSettings,
         connection: Connection,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
+        allowlist: Option<Allowlist>,
     ) {
         let peer = state::ConnectedFrom {
             peer_addr,
             key_pair,
+            settings,
             connection,
         };
         let peer = RunPeerArgs {
             peer,
             service_message_sender,
+            allowlist,
         };
         tokio::task::spawn(run::run::<T, K, E, _>(peer).in_current_span());
     }
@@ -74,7 +137,7 @@ pub mod handles {
     pub struct PeerHandle<T: Pload> {
         // NOTE: it's ok for this channel to be unbounded.
         // Because post messages originate inside the system and their rate is configurable..
-        pub(super) post_sender: unbounded_with_len::Sender<T>,
+        pub(super) post_sender: unbounded_with_len::Sender<Message<T>>,
     }
 
     impl<T: Pload> PeerHandle<T> {
@@ -82,8 +145,19 @@ pub mod handles {
         ///
         /// # Errors
         /// Fail if peer terminated
-        pub fn post(&self, msg: T) -> Result<(), mpsc::error::SendError<T>> {
-            self.post_sender.send(msg)
+        pub fn post(&self, msg: T) -> Result<(), mpsc::error::SendError<Message<T>>> {
+            self.post_sender.send(Message::Data(msg))
+        }
+
+        /// Share known peer addresses with Peer
+        ///
+        /// # Errors
+        /// Fail if peer terminated
+        pub fn post_addresses(
+            &self,
+            records: Vec<SignedAddressRecord>,
+        ) -> Result<(), mpsc::error::SendError<Message<T>>> {
+            self.post_sender.send(Message::Addresses(records))
         }
     }
 }
@@ -91,7 +165,8 @@ pub mod handles {
 mod run {
     //! Module with peer [`run`] function.
 
-    use iroha_logger::prelude::*;
+    use futures::stream::{self, BoxStream, SelectAll, StreamExt};
+    use iroha_logger::{prelude::*, Instrument};
 
     use super::{
         cryptographer::Cryptographer,
@@ -99,7 +174,10 @@ mod run {
         state::{ConnectedFrom, Connecting, Ready},
         *,
     };
-    use crate::unbounded_with_len;
+    use crate::{reputation::Misbehaviour, unbounded_with_len};
+
+    /// Maximal number of messages waiting to be sent in a single lane
+    const LANE_CAPACITY: usize = 128;
 
     /// Peer task.
     #[allow(clippy::too_many_lines)]
@@ -108,6 +186,7 @@ mod run {
         RunPeerArgs {
             peer,
             service_message_sender,
+            allowlist,
         }: RunPeerArgs<T, P>,
     ) {
         let conn_id = peer.connection_id();
@@ -132,12 +211,21 @@ mod run {
                     Connection {
                         read,
                         write,
+                        lanes,
                         id: connection_id,
                     },
                 cryptographer,
+                settings,
             } = peer;
             let peer_id = peer_id.insert(new_peer_id);
 
+            if let Some(allowlist) = allowlist {
+                if !allowlist.borrow().contains(peer_id.public_key()) {
+                    iroha_logger::warn!(peer=%peer_id, "Peer not present in allowlist is trying to connect");
+                    return;
+                }
+            }
+
             let disambiguator = cryptographer.disambiguator;
 
             tracing::Span::current().record("peer", &peer_id.to_string());
@@ -170,10 +258,18 @@ mod run {
                 return;
             };
 
-            iroha_logger::trace!("Peer connected");
-
-            let mut message_reader = MessageReader::new(read, cryptographer.clone());
-            let mut message_sender = MessageSender::new(write, cryptographer);
+            iroha_logger::debug!(compression = ?settings.compression, "Peer connected");
+
+            let mut message_readers = SelectAll::new();
+            let mut lane_senders = Vec::with_capacity(lanes.len() + 1);
+            for Stream { read, write } in core::iter::once(Stream { read, write }).chain(lanes) {
+                let message_reader = MessageReader::new(read, cryptographer.clone(), settings);
+                message_readers.push(message_reader.into_stream::<Message<T>>());
+                let message_sender = MessageSender::new(write, cryptographer.clone(), settings);
+                let (lane_sender, lane_receiver) = mpsc::channel(LANE_CAPACITY);
+                tokio::task::spawn(write_lane(message_sender, lane_receiver).in_current_span());
+                lane_senders.push(lane_sender);
+            }
 
             loop {
                 tokio::select! {
@@ -187,30 +283,62 @@ mod run {
                         if post_receiver_len > 100 {
                             iroha_logger::warn!(size=post_receiver_len, "Peer post messages are pilling up");
                         }
-                        if let Err(error) = message_sender.send_message(msg).await {
-                            iroha_logger::error!(%error, "Failed to send message to peer.");
-                            break;
+                        let lane = msg.lane().min(lane_senders.len() - 1);
+                        // NOTE: Waiting for a full lane would block the other lanes and the incoming messages
+                        match lane_senders[lane].try_send(msg) {
+                            Ok(()) => {}
+                            Err(mpsc::error::TrySendError::Full(msg)) if msg.is_droppable() => {
+                                iroha_logger::warn!(lane, "Lane is full, dropping message to peer.");
+                            }
+                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                iroha_logger::error!(lane, "Lane is full, peer doesn't keep up with the messages.");
+                                break;
+                            }
+                            Err(mpsc::error::TrySendError::Closed(_)) => {
+                                iroha_logger::error!("Failed to send message to peer.");
+                                break;
+                            }
                         }
                     }
-                    msg = message_reader.read_message() => {
+                    msg = message_readers.next() => {
                         let msg = match msg {
-                            Ok(Some(msg)) => {
+                            Some(Ok(Some(msg))) => {
                                 msg
                             },
-                            Ok(None) => {
+                            Some(Ok(None)) | None => {
                                 iroha_logger::debug!("Peer send whole message and close connection");
                                 break;
                             }
-                            Err(error) => {
+                            Some(Err(error)) => {
                                 iroha_logger::error!(%error, "Error while reading message from peer.");
+                                let misbehaviour = match error {
+                                    Error::ParityScale(_) | Error::FrameTooLarge { .. } => Some(Misbehaviour::MalformedMessage),
+                                    Error::SymmetricEncryption(_) => Some(Misbehaviour::InvalidSignature),
+                                    _ => None,
+                                };
+                                if let Some(misbehaviour) = misbehaviour {
+                                    let misbehaved = PeerMisbehaved { peer_id: peer_id.clone(), misbehaviour };
+                                    let _ = service_message_sender.send(ServiceMessage::Misbehaved(misbehaved)).await;
+                                }
                                 break;
                             }
                         };
                         iroha_logger::trace!("Received peer message");
-                        let peer_message = PeerMessage(peer_id.clone(), msg);
-                        if peer_message_sender.send(peer_message).await.is_err() {
-                            iroha_logger::error!("Network dropped peer message channel.");
-                            break;
+                        match msg {
+                            Message::Data(data) => {
+                                let peer_message = PeerMessage(peer_id.clone(), data);
+                                if peer_message_sender.send(peer_message).await.is_err() {
+                                    iroha_logger::error!("Network dropped peer message channel.");
+                                    break;
+                                }
+                            }
+                            Message::Addresses(records) => {
+                                let addresses = PeerAddresses { peer_id: peer_id.clone(), records };
+                                if service_message_sender.send(ServiceMessage::Addresses(addresses)).await.is_err() {
+                                    iroha_logger::error!("Network dropped service message channel.");
+                                    break;
+                                }
+                            }
                         }
                     }
                     else => break,
@@ -225,10 +353,33 @@ mod run {
             .await;
     }
 
+    /// Send the messages of a single lane, so that they don't wait for the messages of the other lanes.
+    async fn write_lane<T: Pload, E: Enc>(
+        mut message_sender: MessageSender<E>,
+        mut lane_receiver: mpsc::Receiver<Message<T>>,
+    ) {
+        while let Some(msg) = lane_receiver.recv().await {
+            let is_droppable = msg.is_droppable();
+            match message_sender.send_message(msg).await {
+                Ok(()) => {}
+                Err(error @ Error::FrameTooLarge { .. }) if is_droppable => {
+                    // NOTE: Peer would drop the connection after receiving it
+                    iroha_logger::warn!(%error, "Message not sent to peer.");
+                }
+                Err(error) => {
+                    // NOTE: Peer task notices it once it fails to pass the next message of this lane
+                    iroha_logger::error!(%error, "Failed to send message to peer.");
+                    break;
+                }
+            }
+        }
+    }
+
     /// Args to pass inside [`run`] function.
     pub(super) struct RunPeerArgs<T: Pload, P> {
         pub peer: P,
         pub service_message_sender: mpsc::Sender<ServiceMessage<T>>,
+        pub allowlist: Option<Allowlist>,
     }
 
     /// Trait for peer stages that might be used as starting point for peer's [`run`] function.
@@ -250,18 +401,24 @@ mod run {
 
     /// Cancellation-safe way to read messages from tcp stream
     struct MessageReader<E: Enc> {
-        read: OwnedReadHalf,
+        read: ReadHalf,
         buffer: bytes::BytesMut,
         cryptographer: Cryptographer<E>,
+        settings: ConnectionSettings,
     }
 
     impl<E: Enc> MessageReader<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(read: OwnedReadHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            read: ReadHalf,
+            cryptographer: Cryptographer<E>,
+            settings: ConnectionSettings,
+        ) -> Self {
             Self {
                 read,
                 cryptographer,
+                settings,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: BytesMut::with_capacity(1024),
             }
@@ -289,10 +446,21 @@ mod run {
             }
         }
 
+        /// Turn into an endless stream of [`Self::read_message`] results, which is cancellation safe as well
+        fn into_stream<T: Pload>(self) -> BoxStream<'static, Result<Option<T>, Error>> {
+            stream::unfold(self, |mut message_reader| async move {
+                let msg = message_reader.read_message::<T>().await;
+                Some((msg, message_reader))
+            })
+            .boxed()
+        }
+
         /// Parse message
         ///
         /// # Errors
+        /// - Message is bigger than [`ConnectionSettings::max_frame_size`]
         /// - Fail to decrypt message
+        /// - Fail to decompress message
         /// - Fail to decode message
         fn parse_message<T: Pload>(&mut self) -> Result<Option<T>, Error> {
             let mut buf = &self.buffer[..];
@@ -301,6 +469,10 @@ mod run {
                 return Ok(None);
             }
             let size = buf.get_u32() as usize;
+            let max_size = self.settings.max_frame_size as usize;
+            if size > max_size {
+                return Err(Error::FrameTooLarge { size, max_size });
+            }
             if buf.remaining() < size {
                 // Not enough data to read the whole data
                 return Ok(None);
@@ -308,7 +480,12 @@ mod run {
 
             let data = &buf[..size];
             let decrypted = self.cryptographer.decrypt(data)?;
-            let decoded = DecodeAll::decode_all(&mut decrypted.as_slice())?;
+            let decompressed = match self.settings.compression {
+                Compression::None => decrypted,
+                // NOTE: Decompressed message is limited as well, otherwise a small frame could expand into an arbitrary amount of memory
+                Compression::Zstd => zstd::bulk::decompress(&decrypted, max_size)?,
+            };
+            let decoded = DecodeAll::decode_all(&mut decompressed.as_slice())?;
 
             self.buffer.advance(size + Self::U32_SIZE);
 
@@ -317,18 +494,24 @@ mod run {
     }
 
     struct MessageSender<E: Enc> {
-        write: OwnedWriteHalf,
+        write: WriteHalf,
         cryptographer: Cryptographer<E>,
+        settings: ConnectionSettings,
         buffer: BytesMut,
     }
 
     impl<E: Enc> MessageSender<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(write: OwnedWriteHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            write: WriteHalf,
+            cryptographer: Cryptographer<E>,
+            settings: ConnectionSettings,
+        ) -> Self {
             Self {
                 write,
                 cryptographer,
+                settings,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: BytesMut::with_capacity(1024),
             }
@@ -337,7 +520,10 @@ mod run {
         /// Send byte-encoded message to the peer
         ///
         /// # Errors
+        /// - If compression fail.
         /// - If encryption fail.
+        /// - If message is bigger than [`ConnectionSettings::max_frame_size`] either before
+        ///   compression or after encryption, nothing is sent in this case.
         /// - If write to `stream` fail.
         async fn send_message<T: Pload>(&mut self, msg: T) -> Result<(), Error> {
             // Start with fresh buffer
@@ -345,13 +531,37 @@ mod run {
             let mut writer = (&mut self.buffer).writer();
             msg.encode_to(&mut writer);
             let encoded_size = self.buffer.remaining();
-            let encrypted = self.cryptographer.encrypt(&self.buffer[..encoded_size])?;
+            let max_size = self.settings.max_frame_size as usize;
+            // NOTE: Receiver limits the decompressed message as well, so the limit is checked before compression
+            if encoded_size > max_size {
+                return Err(Error::FrameTooLarge {
+                    size: encoded_size,
+                    max_size,
+                });
+            }
+            let encrypted = match self.settings.compression {
+                Compression::None => self.cryptographer.encrypt(&self.buffer[..encoded_size]),
+                Compression::Zstd => {
+                    let compressed = zstd::bulk::compress(
+                        &self.buffer[..encoded_size],
+                        zstd::DEFAULT_COMPRESSION_LEVEL,
+                    )?;
+                    self.cryptographer.encrypt(&compressed)
+                }
+            };
             self.buffer.advance(encoded_size);
             assert!(
                 !self.buffer.has_remaining(),
                 "Buffer must be empty at this point"
             );
+            let encrypted = encrypted?;
             let encrypted_size = encrypted.len();
+            if encrypted_size > max_size {
+                return Err(Error::FrameTooLarge {
+                    size: encrypted_size,
+                    max_size,
+                });
+            }
             #[allow(clippy::cast_possible_truncation)]
             self.buffer.put_u32(encrypted_size as u32);
             self.buffer.put_slice(encrypted.as_slice());
@@ -370,6 +580,8 @@ mod run {
 mod state {
     //! Module for peer stages.
 
+    use std::sync::Arc;
+
     use iroha_crypto::{KeyGenOption, KeyPair, PublicKey, Signature};
     use iroha_primitives::addr::SocketAddr;
 
@@ -380,6 +592,8 @@ mod state {
     pub(super) struct Connecting {
         pub peer_addr: SocketAddr,
         pub key_pair: KeyPair,
+        pub settings: ConnectionSettings,
+        pub transport: Arc<dyn Transport>,
         pub connection_id: ConnectionId,
     }
 
@@ -388,14 +602,17 @@ mod state {
             Self {
                 peer_addr,
                 key_pair,
+                settings,
+                transport,
                 connection_id,
             }: Self,
         ) -> Result<ConnectedTo, crate::Error> {
-            let stream = TcpStream::connect(peer_addr.to_string()).await?;
-            let connection = Connection::new(connection_id, stream);
+            let streams = transport.connect(&peer_addr).await?;
+            let connection = Connection::new(connection_id, streams);
             Ok(ConnectedTo {
                 peer_addr,
                 key_pair,
+                settings,
                 connection,
             })
         }
@@ -405,6 +622,7 @@ mod state {
     pub(super) struct ConnectedTo {
         peer_addr: SocketAddr,
         key_pair: KeyPair,
+        settings: ConnectionSettings,
         connection: Connection,
     }
 
@@ -414,6 +632,7 @@ mod state {
             Self {
                 peer_addr,
                 key_pair,
+                settings,
                 mut connection,
             }: Self,
         ) -> Result<SendKey<E>, crate::Error> {
@@ -437,6 +656,7 @@ mod state {
             Ok(SendKey {
                 peer_addr,
                 key_pair,
+                settings,
                 kx_local_pk,
                 kx_remote_pk,
                 connection,
@@ -449,6 +669,7 @@ mod state {
     pub(super) struct ConnectedFrom {
         pub peer_addr: SocketAddr,
         pub key_pair: KeyPair,
+        pub settings: ConnectionSettings,
         pub connection: Connection,
     }
 
@@ -458,6 +679,7 @@ mod state {
             Self {
                 peer_addr,
                 key_pair,
+                settings,
                 mut connection,
                 ..
             }: Self,
@@ -481,6 +703,7 @@ mod state {
             Ok(SendKey {
                 peer_addr,
                 key_pair,
+                settings,
                 kx_local_pk,
                 kx_remote_pk,
                 connection,
@@ -493,6 +716,7 @@ mod state {
     pub(super) struct SendKey<E: Enc> {
         peer_addr: SocketAddr,
         key_pair: KeyPair,
+        settings: ConnectionSettings,
         kx_local_pk: PublicKey,
         kx_remote_pk: PublicKey,
         connection: Connection,
@@ -504,6 +728,7 @@ mod state {
             Self {
                 peer_addr,
                 key_pair,
+                settings,
                 kx_local_pk,
                 kx_remote_pk,
                 mut connection,
@@ -514,7 +739,12 @@ mod state {
 
             let payload = create_payload(&kx_local_pk, &kx_remote_pk);
             let signature = Signature::new(&key_pair, &payload);
-            let data = signature.encode();
+            let data = HandshakeMessage {
+                signature,
+                compression: settings.compression,
+                max_frame_size: settings.max_frame_size,
+            }
+            .encode();
 
             let data = &cryptographer.encrypt(data.as_slice())?;
 
@@ -526,6 +756,7 @@ mod state {
             write_half.write_all(&buf).await?;
             Ok(GetKey {
                 peer_addr,
+                settings,
                 connection,
                 kx_local_pk,
                 kx_remote_pk,
@@ -537,6 +768,7 @@ mod state {
     /// Peer that needs to get key.
     pub struct GetKey<E: Enc> {
         peer_addr: SocketAddr,
+        settings: ConnectionSettings,
         connection: Connection,
         kx_local_pk: PublicKey,
         kx_remote_pk: PublicKey,
@@ -548,6 +780,7 @@ mod state {
         pub(super) async fn read_their_public_key(
             Self {
                 peer_addr,
+                settings,
                 mut connection,
                 kx_local_pk,
                 kx_remote_pk,
@@ -562,7 +795,11 @@ mod state {
 
             let data = cryptographer.decrypt(data.as_slice())?;
 
-            let signature: Signature = DecodeAll::decode_all(&mut data.as_slice())?;
+            let HandshakeMessage {
+                signature,
+                compression,
+                max_frame_size,
+            } = DecodeAll::decode_all(&mut data.as_slice())?;
 
             // Swap order of keys since we are verifying for other peer order remote/local keys is reversed
             let payload = create_payload(&kx_remote_pk, &kx_local_pk);
@@ -576,6 +813,11 @@ mod state {
                 peer_id,
                 connection,
                 cryptographer,
+                settings: ConnectionSettings {
+                    compression: settings.compression.negotiate(compression),
+                    // NOTE: Neither peer sends messages the other one would drop the connection for
+                    max_frame_size: settings.max_frame_size.min(max_frame_size),
+                },
             })
         }
     }
@@ -586,6 +828,18 @@ mod state {
         pub peer_id: PeerId,
         pub connection: Connection,
         pub cryptographer: Cryptographer<E>,
+        /// Settings agreed upon by both peers
+        pub settings: ConnectionSettings,
+    }
+
+    /// Last message of the handshake, proving the ownership of the peer's key.
+    #[derive(Encode, Decode)]
+    struct HandshakeMessage {
+        signature: Signature,
+        /// Compression proposed by the peer
+        compression: Compression,
+        /// Maximum size of a message accepted by the peer
+        max_frame_size: u32,
     }
 
     fn create_payload(kx_local_pk: &PublicKey, kx_remote_pk: &PublicKey) -> Vec<u8> {
@@ -677,6 +931,16 @@ pub mod message {
     //! Module for peer messages
 
     use super::*;
+    use crate::{address_book::SignedAddressRecord, reputation::Misbehaviour};
+
+    /// Message exchanged by connected peers
+    #[derive(Clone, Encode, Decode)]
+    pub enum Message<T> {
+        /// Message of the network user
+        Data(T),
+        /// Addresses of the peers known to the sender
+        Addresses(Vec<SignedAddressRecord>),
+    }
 
     /// Connection and Handshake was successful
     pub struct Connected<T: Pload> {
@@ -692,7 +956,24 @@ pub mod message {
         pub disambiguator: u64,
     }
 
-    /// Messages received from Peer
+    impl<T: Lane> Lane for Message<T> {
+        fn lane(&self) -> usize {
+            match self {
+                Self::Data(data) => data.lane(),
+                Self::Addresses(_) => 0,
+            }
+        }
+
+        fn is_droppable(&self) -> bool {
+            match self {
+                Self::Data(data) => data.is_droppable(),
+                Self::Addresses(_) => true,
+            }
+        }
+    }
+
+    /// Messages received from Peer, along with the id of the peer authenticated during the handshake
+    #[derive(Clone, Debug)]
     pub struct PeerMessage<T: Pload>(pub PeerId, pub T);
 
     /// Peer faced error or `Terminate` message, send to indicate that it is terminated
@@ -703,12 +984,32 @@ pub mod message {
         pub conn_id: ConnectionId,
     }
 
+    /// Peer addresses received from the other peer
+    pub struct PeerAddresses {
+        /// Peer Id of the sender
+        pub peer_id: PeerId,
+        /// Received records
+        pub records: Vec<SignedAddressRecord>,
+    }
+
+    /// Peer misbehaved on the connection level
+    pub struct PeerMisbehaved {
+        /// Peer Id of the misbehaving peer
+        pub peer_id: PeerId,
+        /// Kind of misbehaviour
+        pub misbehaviour: Misbehaviour,
+    }
+
     /// Messages sent by peer during connection process
     pub enum ServiceMessage<T: Pload> {
         /// Connection and Handshake was successful
         Connected(Connected<T>),
         /// Peer faced error or `Terminate` message, send to indicate that it is terminated
         Terminated(Terminated),
+        /// Peer shared the addresses it knows
+        Addresses(PeerAddresses),
+        /// Peer misbehaved and is about to be terminated
+        Misbehaved(PeerMisbehaved),
     }
 }
 
@@ -765,21 +1066,33 @@ mod cryptographer {
 pub type ConnectionId = u64;
 
 /// P2P connection
-#[derive(Debug)]
 pub struct Connection {
     /// A unique connection id
     pub id: ConnectionId,
-    /// Reading half of `TcpStream`
-    pub read: OwnedReadHalf,
-    /// Writing half of `TcpStream`
-    pub write: OwnedWriteHalf,
+    /// Reading half of the stream of the first lane, which also carries the handshake
+    pub read: ReadHalf,
+    /// Writing half of the stream of the first lane, which also carries the handshake
+    pub write: WriteHalf,
+    /// Streams of the other lanes, empty if the transport doesn't multiplex connections
+    pub lanes: Vec<Stream>,
 }
 
 impl Connection {
-    /// Instantiate new connection from `connection_id` and `stream`.
-    pub fn new(id: ConnectionId, stream: TcpStream) -> Self {
-        let (read, write) = stream.into_split();
-        Connection { id, read, write }
+    /// Instantiate new connection from `connection_id` and the `streams` of its lanes.
+    ///
+    /// # Panics
+    /// If `streams` is empty
+    pub fn new(id: ConnectionId, streams: Vec<Stream>) -> Self {
+        let mut streams = streams.into_iter();
+        let Stream { read, write } = streams
+            .next()
+            .expect("Transport must provide at least one stream");
+        Connection {
+            id,
+            read,
+            write,
+            lanes: streams.collect(),
+        }
     }
 }
 
@@ -790,7 +1103,7 @@ mod garbage {
     use super::*;
 
     /// Generate random garbage bytes and writes then to the stream.
-    pub(super) async fn write(stream: &mut OwnedWriteHalf) -> Result<(), Error> {
+    pub(super) async fn write(stream: &mut WriteHalf) -> Result<(), Error> {
         let size;
         // Additional byte for the length of the garbage to send everything in one go
         let mut garbage = [0u8; MAX_HANDSHAKE_LENGTH as usize + 1];
@@ -806,7 +1119,7 @@ mod garbage {
     }
 
     /// Read and discards random garbage bytes from the stream.
-    pub(super) async fn read(stream: &mut OwnedReadHalf) -> Result<(), Error> {
+    pub(super) async fn read(stream: &mut ReadHalf) -> Result<(), Error> {
         let size = stream.read_u8().await? as usize;
         iroha_logger::trace!(size, "Reading garbage");
         let mut garbage = [0u8; MAX_HANDSHAKE_LENGTH as usize];
@@ -0,0 +1,293 @@
+//! Accounting of peer misbehaviour.
+//!
+//! Every peer has a score which drops whenever the peer misbehaves and slowly recovers over time.
+//! Peers whose score drops to zero are banned for a while: connections with them are dropped and refused.
+//! A peer is banned again on its first misbehaviour after the ban unless its score recovered in between.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use iroha_crypto::PublicKey;
+
+/// Score of a peer that didn't misbehave recently
+pub const MAX_SCORE: u32 = 100;
+/// Time it takes the score to recover by one point
+const SCORE_RECOVERY_INTERVAL: Duration = Duration::from_secs(1);
+/// How many times larger bursts of messages peers of the topology can send,
+/// since consensus relies on bursts of their messages
+pub const TOPOLOGY_BURST_FACTOR: u32 = 10;
+
+/// Misbehaviour of a peer, which lowers its score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+pub enum Misbehaviour {
+    /// Peer exceeded the message rate limit
+    RateLimitExceeded,
+    /// Peer sent a message which couldn't be decoded
+    MalformedMessage,
+    /// Peer sent an invalid block
+    InvalidBlock,
+    /// Peer sent a message with an invalid signature
+    InvalidSignature,
+}
+
+impl Misbehaviour {
+    /// Number of points the score drops by
+    const fn penalty(self) -> u32 {
+        match self {
+            // NOTE: Charged for every dropped message, so sustained spam gets the peer banned
+            Self::RateLimitExceeded => 1,
+            Self::MalformedMessage | Self::InvalidBlock | Self::InvalidSignature => 20,
+        }
+    }
+}
+
+/// Reputation of a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reputation {
+    /// Current score between zero and [`MAX_SCORE`]
+    pub score: u32,
+    /// Whether the peer is currently banned
+    pub banned: bool,
+}
+
+/// Reputations of the peers which misbehaved recently, the others have [`MAX_SCORE`].
+pub type Reputations = HashMap<PublicKey, Reputation>;
+
+#[derive(Debug)]
+struct Score {
+    value: u32,
+    /// Time since which the score recovers
+    recovering_since: Instant,
+    banned_until: Option<Instant>,
+}
+
+/// Scores and message rates of the peers.
+#[derive(Debug)]
+pub struct PeerScores {
+    /// Maximum number of messages accepted from a peer per second
+    message_rate_limit: u32,
+    ban_duration: Duration,
+    scores: HashMap<PublicKey, Score>,
+    /// Token buckets of the lanes of the peers: messages which can be received right away and the instant they were counted at
+    message_tokens: HashMap<(PublicKey, usize), (f64, Instant)>,
+}
+
+impl PeerScores {
+    /// Construct [`Self`] which accepts up to `message_rate_limit` messages per second from a peer
+    /// and bans misbehaving peers for `ban_duration`.
+    pub fn new(message_rate_limit: u32, ban_duration: Duration) -> Self {
+        Self {
+            message_rate_limit,
+            ban_duration,
+            scores: HashMap::new(),
+            message_tokens: HashMap::new(),
+        }
+    }
+
+    /// Count a message of the `lane` received from the peer.
+    ///
+    /// Messages of every lane are limited by a separate token bucket, so that e.g. a flood of
+    /// block sync requests doesn't use up the budget of consensus messages. A peer can send a burst
+    /// of up to `message_rate_limit` messages at once, [`TOPOLOGY_BURST_FACTOR`] times more if it's
+    /// in the topology, while its sustained rate is limited to `message_rate_limit` messages per second.
+    ///
+    /// Returns `false` if the message exceeds the rate limit and should be dropped.
+    pub fn message_received(
+        &mut self,
+        public_key: &PublicKey,
+        lane: usize,
+        is_in_topology: bool,
+        now: Instant,
+    ) -> bool {
+        let rate = f64::from(self.message_rate_limit);
+        let capacity = if is_in_topology {
+            rate * f64::from(TOPOLOGY_BURST_FACTOR)
+        } else {
+            rate
+        };
+        let (tokens, counted_at) = self
+            .message_tokens
+            .entry((public_key.clone(), lane))
+            .or_insert((capacity, now));
+        let refill = now.saturating_duration_since(*counted_at).as_secs_f64() * rate;
+        *tokens = (*tokens + refill).min(capacity);
+        *counted_at = now;
+        if *tokens < 1.0 {
+            return false;
+        }
+        *tokens -= 1.0;
+        true
+    }
+
+    /// Lower the score of the peer.
+    ///
+    /// Returns `true` if the peer got banned.
+    pub fn penalize(
+        &mut self,
+        public_key: &PublicKey,
+        misbehaviour: Misbehaviour,
+        now: Instant,
+    ) -> bool {
+        let score = self.scores.entry(public_key.clone()).or_insert(Score {
+            value: MAX_SCORE,
+            recovering_since: now,
+            banned_until: None,
+        });
+        score.value = score.value.saturating_sub(misbehaviour.penalty());
+        if score.value > 0 || score.banned_until.is_some() {
+            return false;
+        }
+        let banned_until = now + self.ban_duration;
+        score.banned_until = Some(banned_until);
+        score.recovering_since = banned_until;
+        true
+    }
+
+    /// Whether the peer is currently banned
+    pub fn is_banned(&self, public_key: &PublicKey) -> bool {
+        self.scores
+            .get(public_key)
+            .map_or(false, |score| score.banned_until.is_some())
+    }
+
+    /// Recover the scores and lift expired bans.
+    ///
+    /// Returns `true` if any reputation changed.
+    pub fn recover(&mut self, now: Instant) -> bool {
+        let mut changed = false;
+        self.scores.retain(|_, score| {
+            if score.banned_until.map_or(false, |until| until <= now) {
+                score.banned_until = None;
+                changed = true;
+            }
+            let recovered = now
+                .saturating_duration_since(score.recovering_since)
+                .as_secs()
+                / SCORE_RECOVERY_INTERVAL.as_secs();
+            if recovered > 0 {
+                let recovered = u32::try_from(recovered).unwrap_or(u32::MAX);
+                score.value = score.value.saturating_add(recovered).min(MAX_SCORE);
+                score.recovering_since += SCORE_RECOVERY_INTERVAL * recovered;
+                changed = true;
+            }
+            score.value < MAX_SCORE || score.banned_until.is_some()
+        });
+        changed
+    }
+
+    /// Reputations of the peers which misbehaved recently
+    pub fn reputations(&self) -> Reputations {
+        self.scores
+            .iter()
+            .map(|(public_key, score)| {
+                let reputation = Reputation {
+                    score: score.value,
+                    banned: score.banned_until.is_some(),
+                };
+                (public_key.clone(), reputation)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+
+    #[test]
+    fn messages_over_rate_limit_are_dropped() {
+        let public_key = KeyPair::generate().unwrap().public_key().clone();
+        let mut scores = PeerScores::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(scores.message_received(&public_key, 0, false, now));
+        assert!(scores.message_received(&public_key, 0, false, now));
+        assert!(!scores.message_received(&public_key, 0, false, now));
+        assert!(scores.message_received(&public_key, 0, false, now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn rate_limit_refills_gradually() {
+        let public_key = KeyPair::generate().unwrap().public_key().clone();
+        let mut scores = PeerScores::new(10, Duration::from_secs(60));
+        let now = Instant::now();
+
+        for _ in 0..10 {
+            assert!(scores.message_received(&public_key, 0, false, now));
+        }
+        assert!(!scores.message_received(&public_key, 0, false, now));
+
+        // NOTE: A message is allowed every 100 ms, unlike with a window reset every second
+        let later = now + Duration::from_millis(100);
+        assert!(scores.message_received(&public_key, 0, false, later));
+        assert!(!scores.message_received(&public_key, 0, false, later));
+    }
+
+    #[test]
+    fn topology_peers_get_larger_bursts_per_lane() {
+        let public_key = KeyPair::generate().unwrap().public_key().clone();
+        let mut scores = PeerScores::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        for _ in 0..2 * TOPOLOGY_BURST_FACTOR {
+            assert!(scores.message_received(&public_key, 1, true, now));
+        }
+        assert!(!scores.message_received(&public_key, 1, true, now));
+        // Other lanes have their own budget
+        assert!(scores.message_received(&public_key, 0, true, now));
+
+        // Sustained rate isn't larger than the one of the other peers
+        let later = now + Duration::from_millis(500);
+        assert!(scores.message_received(&public_key, 1, true, later));
+        assert!(!scores.message_received(&public_key, 1, true, later));
+    }
+
+    #[test]
+    fn misbehaving_peer_is_banned_until_ban_expires() {
+        let public_key = KeyPair::generate().unwrap().public_key().clone();
+        let ban_duration = Duration::from_secs(60);
+        let mut scores = PeerScores::new(2, ban_duration);
+        let now = Instant::now();
+
+        for _ in 1..MAX_SCORE / Misbehaviour::InvalidBlock.penalty() {
+            assert!(!scores.penalize(&public_key, Misbehaviour::InvalidBlock, now));
+        }
+        assert!(scores.penalize(&public_key, Misbehaviour::InvalidBlock, now));
+        assert!(scores.is_banned(&public_key));
+
+        scores.recover(now + ban_duration);
+        assert!(!scores.is_banned(&public_key));
+        // Score didn't recover yet, so the next misbehaviour results in a ban right away
+        assert!(scores.penalize(
+            &public_key,
+            Misbehaviour::RateLimitExceeded,
+            now + ban_duration
+        ));
+    }
+
+    #[test]
+    fn score_recovers_over_time() {
+        let public_key = KeyPair::generate().unwrap().public_key().clone();
+        let mut scores = PeerScores::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        scores.penalize(&public_key, Misbehaviour::MalformedMessage, now);
+        assert_eq!(
+            scores.reputations()[&public_key].score,
+            MAX_SCORE - Misbehaviour::MalformedMessage.penalty()
+        );
+
+        scores.recover(now + SCORE_RECOVERY_INTERVAL * 5);
+        assert_eq!(
+            scores.reputations()[&public_key].score,
+            MAX_SCORE - Misbehaviour::MalformedMessage.penalty() + 5
+        );
+
+        scores.recover(now + SCORE_RECOVERY_INTERVAL * MAX_SCORE);
+        assert!(scores.reputations().is_empty());
+    }
+}
@@ -0,0 +1,273 @@
+//! Transports over which peers connect to each other.
+//!
+//! Transport only provides ordered byte streams, peers are authenticated and messages are encrypted
+//! by the handshake in [`peer`](crate::peer) regardless of the transport.
+
+use std::{net::SocketAddr as StdSocketAddr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use iroha_config::network::Transport as TransportKind;
+use iroha_primitives::addr::SocketAddr;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+
+use crate::Error;
+
+/// Number of lanes of the multiplexing transports, see [`Lane`](crate::Lane)
+pub const LANES: usize = 3;
+
+/// Reading half of a stream
+pub type ReadHalf = Box<dyn AsyncRead + Send + Unpin>;
+/// Writing half of a stream
+pub type WriteHalf = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Ordered bidirectional byte stream.
+pub struct Stream {
+    /// Reading half
+    pub read: ReadHalf,
+    /// Writing half
+    pub write: WriteHalf,
+}
+
+impl From<TcpStream> for Stream {
+    fn from(stream: TcpStream) -> Self {
+        let (read, write) = stream.into_split();
+        Self {
+            read: Box::new(read),
+            write: Box::new(write),
+        }
+    }
+}
+
+/// Outgoing side of a transport.
+#[async_trait]
+pub trait Transport: Send + Sync + 'static {
+    /// Connect to the peer listening at `address`.
+    ///
+    /// Returns the streams of the connection, one per lane and at least one.
+    ///
+    /// # Errors
+    /// Fails if the connection can't be established
+    async fn connect(&self, address: &SocketAddr) -> Result<Vec<Stream>, Error>;
+}
+
+/// Incoming side of a transport.
+#[async_trait]
+pub trait Listener: Send + 'static {
+    /// Accept the next incoming connection.
+    ///
+    /// Returns the streams of the connection, one per lane and at least one, and the address of the peer.
+    /// Must be cancellation safe, so that it can be used in `tokio::select!`.
+    ///
+    /// # Errors
+    /// Fails if accepting the connection fails
+    async fn accept(&mut self) -> Result<(Vec<Stream>, SocketAddr), Error>;
+}
+
+/// Bind the transport of the given kind to `listen_addr`.
+///
+/// # Errors
+/// Fails if binding to the address fails
+pub async fn bind(
+    kind: TransportKind,
+    listen_addr: &SocketAddr,
+) -> Result<(Arc<dyn Transport>, Box<dyn Listener>), Error> {
+    match kind {
+        TransportKind::Tcp => {
+            let listener = TcpListener::bind(listen_addr.to_string()).await?;
+            Ok((Arc::new(Tcp), Box::new(listener)))
+        }
+        TransportKind::Quic => {
+            let (transport, listener) = Quic::bind(listen_addr).await?;
+            Ok((Arc::new(transport), Box::new(listener)))
+        }
+    }
+}
+
+/// Single TCP stream per connection.
+#[derive(Debug, Clone, Copy)]
+pub struct Tcp;
+
+#[async_trait]
+impl Transport for Tcp {
+    async fn connect(&self, address: &SocketAddr) -> Result<Vec<Stream>, Error> {
+        let stream = TcpStream::connect(address.to_string()).await?;
+        Ok(vec![stream.into()])
+    }
+}
+
+#[async_trait]
+impl Listener for TcpListener {
+    async fn accept(&mut self) -> Result<(Vec<Stream>, SocketAddr), Error> {
+        let (stream, address) = TcpListener::accept(self).await?;
+        Ok((vec![stream.into()], address.into()))
+    }
+}
+
+/// QUIC connection with a separate stream per lane.
+///
+/// Certificates are self-signed and not verified, because peers authenticate each other during the handshake.
+pub struct Quic {
+    endpoint: quinn::Endpoint,
+}
+
+/// Incoming side of [`Quic`].
+pub struct QuicListener {
+    /// Connections whose streams are all opened by the other peer
+    accepted: mpsc::Receiver<(Vec<Stream>, SocketAddr)>,
+}
+
+impl Quic {
+    /// Name of the server expected by the peers, certificates are issued for it
+    const SERVER_NAME: &'static str = "iroha";
+    /// Time the connecting peer has to open the streams of all lanes
+    const ACCEPT_TIMEOUT: Duration = Duration::from_secs(10);
+    /// Interval of keep-alive packets, so that connections without messages aren't closed as idle
+    const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(5);
+    /// Time in milliseconds without packets from the other peer after which the connection is closed
+    const IDLE_TIMEOUT_MS: u32 = 30_000;
+
+    async fn bind(listen_addr: &SocketAddr) -> Result<(Self, QuicListener), Error> {
+        let mut transport_config = quinn::TransportConfig::default();
+        transport_config
+            .keep_alive_interval(Some(Self::KEEP_ALIVE_INTERVAL))
+            .max_idle_timeout(Some(quinn::VarInt::from_u32(Self::IDLE_TIMEOUT_MS).into()));
+        let transport_config = Arc::new(transport_config);
+
+        let certificate = rcgen::generate_simple_self_signed(vec![Self::SERVER_NAME.to_owned()])?;
+        let mut server_config = quinn::ServerConfig::with_single_cert(
+            vec![rustls::Certificate(certificate.serialize_der()?)],
+            rustls::PrivateKey(certificate.serialize_private_key_der()),
+        )?;
+        server_config.transport_config(Arc::clone(&transport_config));
+        let client_crypto = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_custom_certificate_verifier(Arc::new(SkipCertificateVerification))
+            .with_no_client_auth();
+        let mut client_config = quinn::ClientConfig::new(Arc::new(client_crypto));
+        client_config.transport_config(transport_config);
+
+        let mut endpoint = quinn::Endpoint::server(server_config, resolve(listen_addr).await?)?;
+        endpoint.set_default_client_config(client_config);
+
+        let (sender, accepted) = mpsc::channel(1);
+        tokio::task::spawn(Self::accept_connections(endpoint.clone(), sender));
+
+        Ok((Self { endpoint }, QuicListener { accepted }))
+    }
+
+    async fn accept_connections(
+        endpoint: quinn::Endpoint,
+        sender: mpsc::Sender<(Vec<Stream>, SocketAddr)>,
+    ) {
+        loop {
+            let connecting = tokio::select! {
+                connecting = endpoint.accept() => connecting,
+                // Network is shut down
+                () = sender.closed() => break,
+            };
+            let Some(connecting) = connecting else {
+                break;
+            };
+            let sender = sender.clone();
+            tokio::task::spawn(async move {
+                match tokio::time::timeout(Self::ACCEPT_TIMEOUT, Self::accept_streams(connecting))
+                    .await
+                {
+                    Ok(Ok(accepted)) => {
+                        let _ = sender.send(accepted).await;
+                    }
+                    Ok(Err(error)) => {
+                        iroha_logger::warn!(%error, "Failed to accept QUIC connection");
+                    }
+                    Err(_) => {
+                        iroha_logger::warn!(
+                            "Peer didn't open all streams of QUIC connection in time"
+                        );
+                    }
+                }
+            });
+        }
+    }
+
+    async fn accept_streams(
+        connecting: quinn::Connecting,
+    ) -> Result<(Vec<Stream>, SocketAddr), Error> {
+        let connection = connecting.await?;
+        let address = connection.remote_address();
+        let mut lanes = (0..LANES).map(|_| None).collect::<Vec<_>>();
+        for _ in 0..LANES {
+            let (write, mut read) = connection.accept_bi().await?;
+            // NOTE: Streams are only announced once the connecting peer writes to them, so it starts with the lane
+            let lane = read.read_u8().await?;
+            let slot = lanes.get_mut(usize::from(lane)).ok_or(Error::Format)?;
+            *slot = Some(Stream {
+                read: Box::new(read),
+                write: Box::new(write),
+            });
+        }
+        let streams = lanes
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::Format)?;
+        Ok((streams, address.into()))
+    }
+}
+
+#[async_trait]
+impl Transport for Quic {
+    async fn connect(&self, address: &SocketAddr) -> Result<Vec<Stream>, Error> {
+        let connection = self
+            .endpoint
+            .connect(resolve(address).await?, Self::SERVER_NAME)?
+            .await?;
+        let mut streams = Vec::with_capacity(LANES);
+        for lane in 0..LANES {
+            let (mut write, read) = connection.open_bi().await?;
+            #[allow(clippy::cast_possible_truncation)]
+            write.write_u8(lane as u8).await?;
+            streams.push(Stream {
+                read: Box::new(read),
+                write: Box::new(write),
+            });
+        }
+        Ok(streams)
+    }
+}
+
+#[async_trait]
+impl Listener for QuicListener {
+    async fn accept(&mut self) -> Result<(Vec<Stream>, SocketAddr), Error> {
+        self.accepted
+            .recv()
+            .await
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::BrokenPipe).into())
+    }
+}
+
+/// Accepts any certificate, peers are authenticated by the handshake instead.
+struct SkipCertificateVerification;
+
+impl rustls::client::ServerCertVerifier for SkipCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &rustls::Certificate,
+        _intermediates: &[rustls::Certificate],
+        _server_name: &rustls::ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: std::time::SystemTime,
+    ) -> Result<rustls::client::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::ServerCertVerified::assertion())
+    }
+}
+
+async fn resolve(address: &SocketAddr) -> Result<StdSocketAddr, Error> {
+    tokio::net::lookup_host(address.to_string())
+        .await?
+        .next()
+        .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::AddrNotAvailable).into())
+}
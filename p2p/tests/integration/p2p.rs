@@ -12,8 +12,14 @@ use iroha_config_base::proxy::Builder;
 use iroha_crypto::KeyPair;
 use iroha_data_model::prelude::PeerId;
 use iroha_logger::{prelude::*, ConfigurationProxy};
-use iroha_p2p::{network::message::*, NetworkHandle};
-use iroha_primitives::addr::socket_addr;
+use iroha_p2p::{
+    blake2b_hash,
+    network::message::*,
+    peer::message::PeerMessage,
+    reputation::{MAX_SCORE, TOPOLOGY_BURST_FACTOR},
+    Lane, NetworkHandle,
+};
+use iroha_primitives::addr::{socket_addr, SocketAddr};
 use parity_scale_codec::{Decode, Encode};
 use tokio::{
     sync::{mpsc, Barrier},
@@ -23,6 +29,14 @@ use tokio::{
 #[derive(Clone, Debug, Decode, Encode)]
 struct TestMessage(String);
 
+impl Lane for TestMessage {
+    fn lane(&self) -> usize {
+        // NOTE: See `bulk_messages_dont_delay_other_lanes`
+        // and `topology_peer_flooding_block_sync_requests_is_throttled`
+        usize::from(self.0.starts_with("bulk") || self.0.starts_with("GetBlocksAfter"))
+    }
+}
+
 fn setup_logger() {
     static INIT: Once = Once::new();
 
@@ -48,7 +62,7 @@ async fn network_create() {
     let address = socket_addr!(127.0.0.1:12_000);
     let key_pair = KeyPair::generate().unwrap();
     let public_key = key_pair.public_key().clone();
-    let network = NetworkHandle::start(address.clone(), key_pair)
+    let network = NetworkHandle::start(address.clone(), key_pair, &network_config())
         .await
         .unwrap();
     tokio::time::sleep(delay).await;
@@ -68,6 +82,12 @@ async fn network_create() {
     tokio::time::sleep(delay).await;
 }
 
+fn network_config() -> iroha_config::network::Configuration {
+    iroha_config::network::ConfigurationProxy::default()
+        .build()
+        .expect("Default network config failed to build. This is a programmer error")
+}
+
 #[derive(Clone, Debug)]
 struct WaitForN(Arc<Inner>);
 
@@ -122,11 +142,11 @@ impl Future for WaitForN {
 #[derive(Debug)]
 pub struct TestActor {
     messages: WaitForN,
-    receiver: mpsc::Receiver<TestMessage>,
+    receiver: mpsc::Receiver<PeerMessage<TestMessage>>,
 }
 
 impl TestActor {
-    fn start(messages: WaitForN) -> mpsc::Sender<TestMessage> {
+    fn start(messages: WaitForN) -> mpsc::Sender<PeerMessage<TestMessage>> {
         let (sender, receiver) = mpsc::channel(10);
         let mut test_actor = Self { messages, receiver };
         tokio::task::spawn(async move {
@@ -156,13 +176,13 @@ async fn two_networks() {
     let public_key2 = key_pair2.public_key().clone();
     info!("Starting first network...");
     let address1 = socket_addr!(127.0.0.1:12_005);
-    let mut network1 = NetworkHandle::start(address1.clone(), key_pair1)
+    let mut network1 = NetworkHandle::start(address1.clone(), key_pair1, &network_config())
         .await
         .unwrap();
 
     info!("Starting second network...");
     let address2 = socket_addr!(127.0.0.1:12_010);
-    let network2 = NetworkHandle::start(address2.clone(), key_pair2)
+    let network2 = NetworkHandle::start(address2.clone(), key_pair2, &network_config())
         .await
         .unwrap();
 
@@ -297,7 +317,9 @@ async fn start_network(
     let actor = TestActor::start(messages);
 
     let PeerId { address, .. } = peer.clone();
-    let mut network = NetworkHandle::start(address, key_pair).await.unwrap();
+    let mut network = NetworkHandle::start(address, key_pair, &network_config())
+        .await
+        .unwrap();
     network.subscribe_to_peers_messages(actor);
 
     let _ = barrier.wait().await;
@@ -324,6 +346,390 @@ async fn start_network(
     (peer, network)
 }
 
+/// This test creates three networks, the first of which only knows an outdated address of the last one.
+/// The first network must learn the current address from the second one and connect.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn outdated_address_is_replaced() {
+    setup_logger();
+    // Make the first network responsible for connecting to the last one
+    let key_pairs = loop {
+        let key_pairs = (0..3)
+            .map(|_| KeyPair::generate().unwrap())
+            .collect::<Vec<_>>();
+        if blake2b_hash(key_pairs[0].public_key().encode())
+            > blake2b_hash(key_pairs[2].public_key().encode())
+        {
+            break key_pairs;
+        }
+    };
+    let addresses = [
+        socket_addr!(127.0.0.1:12_100),
+        socket_addr!(127.0.0.1:12_105),
+        socket_addr!(127.0.0.1:12_110),
+    ];
+    let outdated_address = socket_addr!(127.0.0.1:12_115);
+    let peers = addresses
+        .iter()
+        .zip(&key_pairs)
+        .map(|(address, key_pair)| PeerId::new(address.clone(), key_pair.public_key().clone()))
+        .collect::<Vec<_>>();
+
+    let mut networks = Vec::new();
+    for (address, key_pair) in addresses.iter().zip(key_pairs) {
+        let network =
+            NetworkHandle::<TestMessage>::start(address.clone(), key_pair, &network_config())
+                .await
+                .unwrap();
+        networks.push(network);
+    }
+
+    for (i, network) in networks.iter().enumerate() {
+        let topology = peers
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(j, peer)| {
+                if (i, j) == (0, 2) {
+                    PeerId::new(outdated_address.clone(), peer.public_key().clone())
+                } else {
+                    peer.clone()
+                }
+            })
+            .collect::<HashSet<_>>();
+        network.update_topology(UpdateTopology(topology));
+    }
+
+    // NOTE: shorter than the delay after which the last network would connect to the first one itself
+    tokio::time::timeout(Duration::from_millis(4000), async {
+        let network = &mut networks[0];
+        let mut connections = network.online_peers(HashSet::len);
+        while connections != 2 {
+            connections = network.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to connect to the peer with outdated address");
+}
+
+/// This test creates two networks, the second of which only accepts small messages.
+/// The first network must learn the limit during the handshake and skip the bigger messages
+/// instead of sending them and getting disconnected.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn oversized_message_is_not_sent() {
+    oversized_message_is_not_sent_with_compression(
+        false,
+        socket_addr!(127.0.0.1:12_120),
+        socket_addr!(127.0.0.1:12_125),
+    )
+    .await;
+}
+
+/// Same as [`oversized_message_is_not_sent`], but the oversized message would
+/// shrink below the limit once compressed. It must be skipped nonetheless,
+/// because the receiver limits the decompressed message as well.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn oversized_compressible_message_is_not_sent() {
+    oversized_message_is_not_sent_with_compression(
+        true,
+        socket_addr!(127.0.0.1:12_165),
+        socket_addr!(127.0.0.1:12_170),
+    )
+    .await;
+}
+
+async fn oversized_message_is_not_sent_with_compression(
+    compression: bool,
+    address1: SocketAddr,
+    address2: SocketAddr,
+) {
+    setup_logger();
+    let key_pair1 = KeyPair::generate().unwrap();
+    let key_pair2 = KeyPair::generate().unwrap();
+    let peer1 = PeerId::new(address1.clone(), key_pair1.public_key().clone());
+    let peer2 = PeerId::new(address2.clone(), key_pair2.public_key().clone());
+
+    let mut config1 = network_config();
+    config1.compression = compression;
+    let network1 = NetworkHandle::start(address1, key_pair1, &config1)
+        .await
+        .unwrap();
+    let mut config2 = network_config();
+    config2.compression = compression;
+    config2.max_frame_size = 1024;
+    let mut network2 = NetworkHandle::start(address2, key_pair2, &config2)
+        .await
+        .unwrap();
+
+    let messages2 = WaitForN::new(1);
+    let actor2 = TestActor::start(messages2.clone());
+    network2.subscribe_to_peers_messages(actor2);
+
+    network1.update_topology(UpdateTopology(HashSet::from([peer2.clone()])));
+    network2.update_topology(UpdateTopology(HashSet::from([peer1])));
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network2.online_peers(HashSet::len);
+        while connections != 1 {
+            connections = network2.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    network1.post(Post {
+        data: TestMessage("a".repeat(2048)),
+        peer_id: peer2.clone(),
+    });
+    network1.post(Post {
+        data: TestMessage("b".repeat(16)),
+        peer_id: peer2,
+    });
+
+    tokio::time::timeout(Duration::from_millis(2000), messages2.clone())
+        .await
+        .expect("Message following the oversized one wasn't received");
+    assert_eq!(messages2.current(), 1);
+    assert_eq!(network2.online_peers(HashSet::len), 1);
+}
+
+/// This test creates two networks connected over QUIC and sends messages in both directions.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn quic_networks() {
+    setup_logger();
+    let key_pair1 = KeyPair::generate().unwrap();
+    let key_pair2 = KeyPair::generate().unwrap();
+    let address1 = socket_addr!(127.0.0.1:12_130);
+    let address2 = socket_addr!(127.0.0.1:12_135);
+    let peer1 = PeerId::new(address1.clone(), key_pair1.public_key().clone());
+    let peer2 = PeerId::new(address2.clone(), key_pair2.public_key().clone());
+
+    let mut config = network_config();
+    config.transport = iroha_config::network::Transport::Quic;
+    let mut network1 = NetworkHandle::start(address1, key_pair1, &config)
+        .await
+        .unwrap();
+    let network2 = NetworkHandle::start(address2, key_pair2, &config)
+        .await
+        .unwrap();
+
+    let mut messages1 = WaitForN::new(1);
+    network1.subscribe_to_peers_messages(TestActor::start(messages1.clone()));
+    let mut messages2 = WaitForN::new(1);
+    network2.subscribe_to_peers_messages(TestActor::start(messages2.clone()));
+
+    network1.update_topology(UpdateTopology(HashSet::from([peer2.clone()])));
+    network2.update_topology(UpdateTopology(HashSet::from([peer1.clone()])));
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network1.online_peers(HashSet::len);
+        while connections != 1 {
+            connections = network1.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    network1.post(Post {
+        data: TestMessage("Some data to send to peer".to_owned()),
+        peer_id: peer2,
+    });
+    network2.post(Post {
+        data: TestMessage("Some data to send back".to_owned()),
+        peer_id: peer1,
+    });
+
+    for messages in [&mut messages1, &mut messages2] {
+        tokio::time::timeout(Duration::from_millis(2000), messages)
+            .await
+            .expect("Failed to get all messages in given time");
+    }
+}
+
+/// This test creates two networks connected over QUIC. The first network sends a backlog of
+/// big messages in one lane followed by a small message in another lane, which must not wait
+/// for the backlog to be sent.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn bulk_messages_dont_delay_other_lanes() {
+    const BULK_MESSAGES: usize = 100;
+
+    setup_logger();
+    let key_pair1 = KeyPair::generate().unwrap();
+    let key_pair2 = KeyPair::generate().unwrap();
+    let address1 = socket_addr!(127.0.0.1:12_155);
+    let address2 = socket_addr!(127.0.0.1:12_160);
+    let peer1 = PeerId::new(address1.clone(), key_pair1.public_key().clone());
+    let peer2 = PeerId::new(address2.clone(), key_pair2.public_key().clone());
+
+    // NOTE: Backlog would be sent right away if compressed
+    let mut config = network_config();
+    config.transport = iroha_config::network::Transport::Quic;
+    config.compression = false;
+    let network1 = NetworkHandle::start(address1, key_pair1, &config)
+        .await
+        .unwrap();
+    let mut network2 = NetworkHandle::start(address2, key_pair2, &config)
+        .await
+        .unwrap();
+
+    let (sender, mut receiver) = mpsc::channel(BULK_MESSAGES + 1);
+    network2.subscribe_to_peers_messages(sender);
+
+    network1.update_topology(UpdateTopology(HashSet::from([peer2.clone()])));
+    network2.update_topology(UpdateTopology(HashSet::from([peer1])));
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network2.online_peers(HashSet::len);
+        while connections != 1 {
+            connections = network2.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    for i in 0..BULK_MESSAGES {
+        network1.post(Post {
+            data: TestMessage(format!("bulk {i} {}", "a".repeat(256 * 1024))),
+            peer_id: peer2.clone(),
+        });
+    }
+    network1.post(Post {
+        data: TestMessage("urgent".to_owned()),
+        peer_id: peer2,
+    });
+
+    let bulk_received = tokio::time::timeout(Duration::from_millis(10_000), async {
+        let mut bulk_received = 0;
+        while let Some(PeerMessage(_, TestMessage(msg))) = receiver.recv().await {
+            if msg == "urgent" {
+                return bulk_received;
+            }
+            bulk_received += 1;
+        }
+        panic!("Network dropped the subscriber");
+    })
+    .await
+    .expect("Failed to receive the message of the other lane");
+    assert!(
+        bulk_received < BULK_MESSAGES,
+        "Message waited for the whole backlog of the other lane"
+    );
+}
+
+/// This test creates two networks in the topology of each other. The first network floods the second one
+/// with block sync requests beyond the burst limit of topology peers, which must be dropped and lower its score.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn topology_peer_flooding_block_sync_requests_is_throttled() {
+    const RATE_LIMIT: u32 = 10;
+    const REQUESTS: u32 = RATE_LIMIT * TOPOLOGY_BURST_FACTOR + 50;
+
+    setup_logger();
+    let key_pair1 = KeyPair::generate().unwrap();
+    let key_pair2 = KeyPair::generate().unwrap();
+    let address1 = socket_addr!(127.0.0.1:12_175);
+    let address2 = socket_addr!(127.0.0.1:12_180);
+    let public_key1 = key_pair1.public_key().clone();
+    let peer1 = PeerId::new(address1.clone(), public_key1.clone());
+    let peer2 = PeerId::new(address2.clone(), key_pair2.public_key().clone());
+
+    let mut config = network_config();
+    config.peer_message_rate_limit = RATE_LIMIT;
+    let network1 = NetworkHandle::start(address1, key_pair1, &config)
+        .await
+        .unwrap();
+    let mut network2 = NetworkHandle::start(address2, key_pair2, &config)
+        .await
+        .unwrap();
+
+    let (sender, mut receiver) = mpsc::channel(REQUESTS as usize);
+    network2.subscribe_to_peers_messages(sender);
+
+    network1.update_topology(UpdateTopology(HashSet::from([peer2.clone()])));
+    network2.update_topology(UpdateTopology(HashSet::from([peer1])));
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network2.online_peers(HashSet::len);
+        while connections != 1 {
+            connections = network2.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    for _ in 0..REQUESTS {
+        network1.post(Post {
+            data: TestMessage("GetBlocksAfter".to_owned()),
+            peer_id: peer2.clone(),
+        });
+    }
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        while network2.reputations(|reputations| !reputations.contains_key(&public_key1)) {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Flooding peer wasn't penalised");
+    let score = network2.reputations(|reputations| reputations[&public_key1].score);
+    assert!(score < MAX_SCORE);
+
+    // Let the rest of the flood arrive
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let mut received = 0;
+    while receiver.try_recv().is_ok() {
+        received += 1;
+    }
+    assert!(received < REQUESTS, "All block sync requests were accepted");
+}
+
+/// This test creates a network in allowlist mode and two networks trying to connect to it.
+/// Only the one whose key is allowed to connect as an observer must get connected.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn unknown_peer_is_rejected_in_allowlist_mode() {
+    setup_logger();
+    let key_pair1 = KeyPair::generate().unwrap();
+    let key_pair2 = KeyPair::generate().unwrap();
+    let key_pair3 = KeyPair::generate().unwrap();
+    let address1 = socket_addr!(127.0.0.1:12_140);
+    let address2 = socket_addr!(127.0.0.1:12_145);
+    let address3 = socket_addr!(127.0.0.1:12_150);
+    let peer1 = PeerId::new(address1.clone(), key_pair1.public_key().clone());
+    let observer_key = key_pair3.public_key().clone();
+
+    let mut config1 = network_config();
+    config1.allowlist = true;
+    config1.observer_keys = vec![observer_key.clone()];
+    let mut network1 = NetworkHandle::<TestMessage>::start(address1, key_pair1, &config1)
+        .await
+        .unwrap();
+    let network2 = NetworkHandle::<TestMessage>::start(address2, key_pair2, &network_config())
+        .await
+        .unwrap();
+    let network3 = NetworkHandle::<TestMessage>::start(address3, key_pair3, &network_config())
+        .await
+        .unwrap();
+
+    network1.update_topology(UpdateTopology(HashSet::new()));
+    network2.update_topology(UpdateTopology(HashSet::from([peer1.clone()])));
+    network3.update_topology(UpdateTopology(HashSet::from([peer1])));
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network1.online_peers(HashSet::len);
+        while connections != 1 {
+            connections = network1.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Allowed observer failed to connect");
+
+    // Give the unknown peer a chance to retry
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let online_peers = network1.online_peers(Clone::clone);
+    assert_eq!(online_peers.len(), 1);
+    assert!(online_peers
+        .iter()
+        .all(|peer_id| *peer_id.public_key() == observer_key));
+}
+
 #[test]
 fn test_encryption() {
     use iroha_crypto::encryption::{ChaCha20Poly1305, SymmetricEncryptor};
@@ -49,6 +49,7 @@ pub fn build_schemas() -> MetaMap {
         BatchedResponse<Value>,
         BatchedResponse<Vec<SignedTransaction>>,
         SignedQuery,
+        SignedDropQuery,
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
@@ -70,6 +71,7 @@ types!(
     AccountPermissionChanged,
     AccountRoleChanged,
     Action<TriggeringFilterBox>,
+    AggregateSignature,
     Algorithm,
     Asset,
     AssetBurnBox,
@@ -86,6 +88,7 @@ types!(
     AssetFilter,
     AssetId,
     AssetMintBox,
+    AssetNftChanged,
     AssetTransferBox,
     AssetValue,
     AssetValueType,
@@ -94,6 +97,8 @@ types!(
     BTreeMap<AssetDefinitionId, AssetDefinition>,
     BTreeMap<AssetDefinitionId, NumericValue>,
     BTreeMap<AssetId, Asset>,
+    BTreeMap<Name, MetadataEntrySchema>,
+    BTreeMap<Name, Nft>,
     BTreeMap<Name, Value>,
     BTreeSet<PermissionToken>,
     BTreeSet<PublicKey>,
@@ -101,6 +106,7 @@ types!(
     BatchedResponse<Vec<SignedTransaction>>,
     BatchedResponseV1<Value>,
     BatchedResponseV1<Vec<SignedTransaction>>,
+    BlockCommitCertificate,
     BlockHeader,
     BlockMessage,
     BlockRejectionReason,
@@ -120,6 +126,7 @@ types!(
     DomainEventFilter,
     DomainFilter,
     DomainId,
+    DropQueryPayload,
     Duration,
     Event,
     EventMessage,
@@ -185,6 +192,7 @@ types!(
     FindDomainById,
     FindDomainKeyValueByIdAndKey,
     FindError,
+    FindNftsByOwner,
     FindPermissionTokenSchema,
     FindPermissionTokensByAccountId,
     FindRoleByRoleId,
@@ -221,7 +229,10 @@ types!(
     MetadataChanged<AssetDefinitionId>,
     MetadataChanged<AssetId>,
     MetadataChanged<DomainId>,
+    MetadataEntrySchema,
     MetadataLimits,
+    MetadataSchema,
+    MetadataValueType,
     MintBox,
     Mintable,
     Name,
@@ -230,22 +241,31 @@ types!(
     NewDomain,
     NewParameter,
     NewRole,
+    Nft,
+    Nfts,
     NonTrivial<PredicateBox>,
     NonZeroU64,
     NotificationEventFilter,
+    NumericBounds<Fixed>,
+    NumericBounds<u128>,
     NumericValue,
+    Option<BlockCommitCertificate>,
     Option<DomainId>,
     Option<Duration>,
+    Option<Fixed>,
     Option<Hash>,
     Option<HashOf<MerkleTree<SignedTransaction>>>,
     Option<HashOf<SignedBlock>>,
     Option<IpfsPath>,
+    Option<MetadataSchema>,
     Option<PipelineEntityKind>,
     Option<PipelineStatusKind>,
+    Option<ProofOfPossession>,
     Option<String>,
     Option<TimeInterval>,
     Option<TriggerCompletedOutcomeType>,
     Option<TriggerId>,
+    Option<u128>,
     OriginFilter<AccountEvent>,
     OriginFilter<AssetDefinitionEvent>,
     OriginFilter<AssetEvent>,
@@ -260,6 +280,7 @@ types!(
     PeerEventFilter,
     PeerFilter,
     PeerId,
+    PeerKeyRotated,
     PermissionRemoved,
     PermissionToken,
     PermissionTokenSchema,
@@ -271,6 +292,7 @@ types!(
     PipelineStatus,
     PipelineStatusKind,
     PredicateBox,
+    ProofOfPossession,
     PublicKey,
     QueryBox,
     QueryExecutionFail,
@@ -285,6 +307,7 @@ types!(
     RoleEventFilter,
     RoleFilter,
     RoleId,
+    RotatePeerKey,
     SemiInterval<Fixed>,
     SemiInterval<u128>,
     SemiInterval<u32>,
@@ -293,6 +316,7 @@ types!(
     SetParameter,
     Signature,
     SignatureCheckCondition,
+    SignatureOf<DropQueryPayload>,
     SignatureOf<QueryPayload>,
     SignatureOf<TransactionPayload>,
     SignatureWrapperOf<TransactionPayload>,
@@ -300,6 +324,8 @@ types!(
     SignedBlock,
     SignedBlockV1,
     SignedBlockWrapper,
+    SignedDropQuery,
+    SignedDropQueryV1,
     SignedQuery,
     SignedQueryV1,
     SignedTransaction,
@@ -370,7 +396,7 @@ mod tests {
         block::{
             error::BlockRejectionReason,
             stream::{BlockMessage, BlockSubscriptionRequest},
-            BlockHeader, SignedBlock, SignedBlockV1,
+            BlockCommitCertificate, BlockHeader, SignedBlock, SignedBlockV1,
         },
         domain::NewDomain,
         executor::Executor,
@@ -118,6 +118,7 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn2::DeriveInput) -> To
         "fn visit_instruction(operation: &InstructionBox)",
         "fn visit_register_peer(operation: &Register<Peer>)",
         "fn visit_unregister_peer(operation: &Unregister<Peer>)",
+        "fn visit_rotate_peer_key(operation: &RotatePeerKey)",
         "fn visit_register_domain(operation: &Register<Domain>)",
         "fn visit_unregister_domain(operation: &Unregister<Domain>)",
         "fn visit_transfer_domain(operation: &Transfer<Account, DomainId, Account>)",
@@ -138,9 +139,12 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn2::DeriveInput) -> To
         "fn visit_burn_asset_big_quantity(operation: &Burn<u128, Asset>)",
         "fn visit_mint_asset_fixed(operation: &Mint<Fixed, Asset>)",
         "fn visit_burn_asset_fixed(operation: &Burn<Fixed, Asset>)",
+        "fn visit_mint_asset_nft(operation: &Mint<Nft, Asset>)",
+        "fn visit_burn_asset_nft(operation: &Burn<Name, Asset>)",
         "fn visit_transfer_asset_quantity(operation: &Transfer<Asset, u32, Account>)",
         "fn visit_transfer_asset_big_quantity(operation: &Transfer<Asset, u128, Account>)",
         "fn visit_transfer_asset_fixed(operation: &Transfer<Asset, Fixed, Account>)",
+        "fn visit_transfer_asset_nft(operation: &Transfer<Asset, Name, Account>)",
         "fn visit_set_asset_key_value(operation: &SetKeyValue<Asset>)",
         "fn visit_remove_asset_key_value(operation: &RemoveKeyValue<Asset>)",
         "fn visit_register_asset_definition(operation: &Register<AssetDefinition>)",
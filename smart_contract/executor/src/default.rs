@@ -11,10 +11,11 @@ pub use account::{
     visit_remove_account_key_value, visit_set_account_key_value, visit_unregister_account,
 };
 pub use asset::{
-    visit_burn_asset_big_quantity, visit_burn_asset_fixed, visit_burn_asset_quantity,
-    visit_mint_asset_big_quantity, visit_mint_asset_fixed, visit_mint_asset_quantity,
-    visit_register_asset, visit_remove_asset_key_value, visit_set_asset_key_value,
-    visit_transfer_asset_big_quantity, visit_transfer_asset_fixed, visit_transfer_asset_quantity,
+    visit_burn_asset_big_quantity, visit_burn_asset_fixed, visit_burn_asset_nft,
+    visit_burn_asset_quantity, visit_mint_asset_big_quantity, visit_mint_asset_fixed,
+    visit_mint_asset_nft, visit_mint_asset_quantity, visit_register_asset,
+    visit_remove_asset_key_value, visit_set_asset_key_value, visit_transfer_asset_big_quantity,
+    visit_transfer_asset_fixed, visit_transfer_asset_nft, visit_transfer_asset_quantity,
     visit_unregister_asset,
 };
 pub use asset_definition::{
@@ -31,7 +32,7 @@ pub use fail::visit_fail;
 use iroha_smart_contract::data_model::isi::InstructionBox;
 pub use log::visit_log;
 pub use parameter::{visit_new_parameter, visit_set_parameter};
-pub use peer::{visit_register_peer, visit_unregister_peer};
+pub use peer::{visit_register_peer, visit_rotate_peer_key, visit_unregister_peer};
 pub use permission_token::{visit_grant_account_permission, visit_revoke_account_permission};
 pub use role::{
     visit_grant_account_role, visit_register_role, visit_revoke_account_role, visit_unregister_role,
@@ -141,6 +142,9 @@ pub fn visit_instruction<V: Validate + ?Sized>(
         InstructionBox::Upgrade(isi) => {
             executor.visit_upgrade(authority, isi);
         }
+        InstructionBox::RotatePeerKey(isi) => {
+            executor.visit_rotate_peer_key(authority, isi);
+        }
     }
 }
 
@@ -170,6 +174,15 @@ pub mod peer {
 
         deny!(executor, "Can't unregister peer");
     }
+
+    pub fn visit_rotate_peer_key<V: Validate + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &RotatePeerKey,
+    ) {
+        // Authorized by the signature of the current peer key which is checked on execution
+        execute!(executor, isi)
+    }
 }
 
 pub mod domain {
@@ -994,7 +1007,6 @@ pub mod asset {
     fn validate_mint_asset<V, Q>(executor: &mut V, authority: &AccountId, isi: &Mint<Q, Asset>)
     where
         V: Validate + ?Sized,
-        Q: Into<Value>,
         Mint<Q, Asset>: Instruction + Encode + Clone,
     {
         let asset_id = isi.destination_id();
@@ -1049,6 +1061,14 @@ pub mod asset {
         validate_mint_asset(executor, authority, isi);
     }
 
+    pub fn visit_mint_asset_nft<V: Validate + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Mint<Nft, Asset>,
+    ) {
+        validate_mint_asset(executor, authority, isi);
+    }
+
     fn validate_burn_asset<V, Q>(executor: &mut V, authority: &AccountId, isi: &Burn<Q, Asset>)
     where
         V: Validate + ?Sized,
@@ -1109,6 +1129,14 @@ pub mod asset {
         validate_burn_asset(executor, authority, isi);
     }
 
+    pub fn visit_burn_asset_nft<V: Validate + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Burn<Name, Asset>,
+    ) {
+        validate_burn_asset(executor, authority, isi);
+    }
+
     fn validate_transfer_asset<V, Q>(
         executor: &mut V,
         authority: &AccountId,
@@ -1173,6 +1201,14 @@ pub mod asset {
         validate_transfer_asset(executor, authority, isi);
     }
 
+    pub fn visit_transfer_asset_nft<V: Validate + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Transfer<Asset, Name, Account>,
+    ) {
+        validate_transfer_asset(executor, authority, isi);
+    }
+
     pub fn visit_set_asset_key_value<V: Validate + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -55,6 +55,9 @@ pub struct Status {
     /// Number of the transactions in the queue
     #[codec(compact)]
     pub queue_size: u64,
+    /// Number of currently banned peers
+    #[codec(compact)]
+    pub banned_peers: u64,
 }
 
 impl<T: Deref<Target = Metrics>> From<&T> for Status {
@@ -68,6 +71,7 @@ impl<T: Deref<Target = Metrics>> From<&T> for Status {
             uptime: Uptime(Duration::from_millis(val.uptime_since_genesis_ms.get())),
             view_changes: val.view_changes.get(),
             queue_size: val.queue_size.get(),
+            banned_peers: val.banned_peers.get(),
         }
     }
 }
@@ -99,11 +103,22 @@ pub struct Metrics {
     pub queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: IntCounter,
+    /// Number of live queries kept by this peer
+    pub live_queries: GenericGauge<AtomicU64>,
+    /// Estimated size of live queries kept by this peer
+    pub live_queries_size_bytes: GenericGauge<AtomicU64>,
+    /// Scores of the peers which misbehaved recently, the others have the maximum score
+    pub peer_scores: GenericGaugeVec<AtomicU64>,
+    /// Number of currently banned peers
+    pub banned_peers: GenericGauge<AtomicU64>,
+    /// Requests rejected because the client exceeded its quota
+    pub rate_limited_requests: IntCounterVec,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
 
 impl Default for Metrics {
+    #[allow(clippy::too_many_lines)]
     fn default() -> Self {
         let txs = IntCounterVec::new(Opts::new("txs", "Transactions committed"), &["type"])
             .expect("Infallible");
@@ -149,6 +164,34 @@ impl Default for Metrics {
             .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let live_queries = GenericGauge::new(
+            "live_queries",
+            "Number of live queries kept in the live query store",
+        )
+        .expect("Infallible");
+        let live_queries_size_bytes = GenericGauge::new(
+            "live_queries_size_bytes",
+            "Estimated size of live queries kept in the live query store",
+        )
+        .expect("Infallible");
+        let peer_scores = GenericGaugeVec::new(
+            Opts::new(
+                "peer_scores",
+                "Scores of the peers which misbehaved recently",
+            ),
+            &["peer"],
+        )
+        .expect("Infallible");
+        let banned_peers = GenericGauge::new("banned_peers", "Number of currently banned peers")
+            .expect("Infallible");
+        let rate_limited_requests = IntCounterVec::new(
+            Opts::new(
+                "rate_limited_requests",
+                "Requests rejected because the client exceeded its quota",
+            ),
+            &["request", "client"],
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -173,7 +216,12 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            dropped_messages,
+            live_queries,
+            live_queries_size_bytes,
+            peer_scores,
+            banned_peers,
+            rate_limited_requests
         );
 
         Self {
@@ -189,6 +237,11 @@ impl Default for Metrics {
             view_changes,
             queue_size,
             dropped_messages,
+            live_queries,
+            live_queries_size_bytes,
+            peer_scores,
+            banned_peers,
+            rate_limited_requests,
             registry,
         }
     }
@@ -248,6 +301,7 @@ mod test {
             uptime: Uptime(Duration::new(5, 937_000_000)),
             view_changes: 2,
             queue_size: 18,
+            banned_peers: 1,
         }
     }
 
@@ -269,7 +323,8 @@ mod test {
                 "nanos": 937000000
               },
               "view_changes": 2,
-              "queue_size": 18
+              "queue_size": 18,
+              "banned_peers": 1
             }"#]];
         expected.assert_eq(&actual);
     }
@@ -282,7 +337,7 @@ mod test {
         let actual = hex::encode_upper(bytes);
         // CAUTION: if this is outdated, make sure to update the documentation:
         // https://hyperledger.github.io/iroha-2-docs/api/torii-endpoints#status
-        let expected = expect_test::expect!["10147C0C14407CD9370848"];
+        let expected = expect_test::expect!["10147C0C14407CD937084804"];
         expected.assert_eq(&actual);
     }
 }
@@ -1,6 +1,12 @@
+use std::path::PathBuf;
+
 use clap::{builder::PossibleValue, ArgGroup, ValueEnum};
-use color_eyre::eyre::WrapErr as _;
-use iroha_crypto::{Algorithm, KeyGenConfiguration, KeyPair, PrivateKey};
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha_crypto::{
+    hd::{DerivationPath, Mnemonic},
+    keystore::{Keystore, ScryptParams},
+    Algorithm, KeyGenConfiguration, KeyPair, PrivateKey, ProofOfPossession,
+};
 
 use super::*;
 
@@ -18,6 +24,33 @@ pub struct Args {
     /// The Unicode `seed` string to generate the key-pair from
     #[clap(long, short, group = "generate_from")]
     seed: Option<String>,
+    /// Environment variable holding the BIP-0039 mnemonic phrase to derive the key-pair from
+    #[clap(long, group = "generate_from")]
+    mnemonic_env: Option<String>,
+    /// Generate a new 24 word BIP-0039 mnemonic phrase, output it and derive the key-pair from it
+    #[clap(long, group = "generate_from")]
+    generate_mnemonic: bool,
+    /// Environment variable holding the passphrase protecting the mnemonic phrase.
+    /// The mnemonic phrase isn't protected by a passphrase if not given
+    #[clap(long)]
+    passphrase_env: Option<String>,
+    /// The hierarchical deterministic derivation path (e.g. `m/44'/0'/0'`) of the key
+    /// derived from the seed or the mnemonic phrase (`ed25519` and `secp256k1` only)
+    #[clap(long, short)]
+    derivation_path: Option<DerivationPath>,
+    /// Path to the encrypted keystore to read the key-pair from (e.g. to export or rotate it)
+    #[clap(long, group = "generate_from")]
+    from_keystore: Option<PathBuf>,
+    /// Path to the encrypted keystore to write the key-pair into instead of printing the private key
+    #[clap(long, short, conflicts_with = "format")]
+    keystore: Option<PathBuf>,
+    /// Environment variable holding the keystore password
+    #[clap(long, default_value = iroha_config::keystore::DEFAULT_PASSWORD_ENV)]
+    password_env: String,
+    /// Environment variable holding the new password when writing the keystore, e.g. to rotate it.
+    /// Defaults to `--password-env`
+    #[clap(long, requires = "keystore")]
+    new_password_env: Option<String>,
     /// Output the key-pair in JSON format
     #[clap(long, short, group = "format")]
     json: bool,
@@ -37,6 +70,8 @@ impl ValueEnum for AlgorithmArg {
             Self(Algorithm::Secp256k1),
             Self(Algorithm::BlsNormal),
             Self(Algorithm::BlsSmall),
+            Self(Algorithm::Secp256r1),
+            Self(Algorithm::Sm2),
         ]
     }
 
@@ -47,18 +82,55 @@ impl ValueEnum for AlgorithmArg {
 
 impl<T: Write> RunArgs<T> for Args {
     fn run(self, writer: &mut BufWriter<T>) -> Outcome {
-        if self.json {
-            let key_pair = self.key_pair()?;
+        let keystore = self.keystore.clone().map(|path| {
+            (
+                path,
+                self.new_password_env
+                    .clone()
+                    .unwrap_or_else(|| self.password_env.clone()),
+            )
+        });
+        let (json, compact) = (self.json, self.compact);
+        let (key_pair, mnemonic) = self.key_pair()?;
+
+        if let Some((path, password_env)) = keystore {
+            let password = read_password(&password_env)?;
+            let keystore =
+                Keystore::encrypt(&key_pair, password.as_bytes(), ScryptParams::default())
+                    .wrap_err("Failed to encrypt key pair")?;
             let output =
-                serde_json::to_string_pretty(&key_pair).wrap_err("Failed to serialise to JSON.")?;
+                serde_json::to_string_pretty(&keystore).wrap_err("Failed to serialise to JSON.")?;
+            std::fs::write(&path, output + "\n").wrap_err("Failed to write keystore")?;
+
+            if let Some(mnemonic) = mnemonic {
+                writeln!(writer, "Mnemonic: \"{mnemonic}\"")?;
+            }
+            writeln!(
+                writer,
+                "Public key (multihash): \"{}\"",
+                &key_pair.public_key()
+            )?;
+            writeln!(writer, "Keystore: {}", path.display())?;
+        } else if json {
+            let mut output =
+                serde_json::to_value(&key_pair).wrap_err("Failed to serialise to JSON.")?;
+            if let (Some(mnemonic), Some(output)) = (mnemonic, output.as_object_mut()) {
+                output.insert("mnemonic".to_owned(), mnemonic.to_string().into());
+            }
+            let output =
+                serde_json::to_string_pretty(&output).wrap_err("Failed to serialise to JSON.")?;
             writeln!(writer, "{output}")?;
-        } else if self.compact {
-            let key_pair = self.key_pair()?;
+        } else if compact {
             writeln!(writer, "{}", &key_pair.public_key())?;
             writeln!(writer, "{}", &key_pair.private_key())?;
             writeln!(writer, "{}", &key_pair.public_key().algorithm())?;
+            if let Some(mnemonic) = mnemonic {
+                writeln!(writer, "{mnemonic}")?;
+            }
         } else {
-            let key_pair = self.key_pair()?;
+            if let Some(mnemonic) = mnemonic {
+                writeln!(writer, "Mnemonic: \"{mnemonic}\"")?;
+            }
             writeln!(
                 writer,
                 "Public key (multihash): \"{}\"",
@@ -70,31 +142,103 @@ impl<T: Write> RunArgs<T> for Args {
                 &key_pair.public_key().algorithm(),
                 &key_pair.private_key()
             )?;
+            // NOTE: Required to register a peer with this key
+            if ProofOfPossession::is_required(key_pair.public_key()) {
+                let proof = ProofOfPossession::new(&key_pair)
+                    .wrap_err("Failed to prove possession of the private key")?;
+                writeln!(writer, "Proof of possession: \"{proof}\"")?;
+            }
         }
         Ok(())
     }
 }
 
+fn read_password(password_env: &str) -> color_eyre::Result<String> {
+    read_secret(password_env, "keystore password")
+}
+
+/// Read a secret from an environment variable rather than from the command line,
+/// so that it doesn't end up in the shell history or the list of processes
+fn read_secret(env: &str, secret: &str) -> color_eyre::Result<String> {
+    std::env::var(env)
+        .wrap_err_with(|| format!("Failed to read {secret} from `{env}` environment variable"))
+}
+
 impl Args {
-    fn key_pair(self) -> color_eyre::Result<KeyPair> {
+    /// Generate the key pair, returning the mnemonic phrase too if it was generated
+    fn key_pair(self) -> color_eyre::Result<(KeyPair, Option<Mnemonic>)> {
         let algorithm = self.algorithm.0;
 
-        let configuration = match (self.seed, self.private_key) {
-            (None, None) => KeyGenConfiguration::from_random(),
-            (None, Some(private_key_hex)) => {
+        if let Some(path) = self.from_keystore {
+            if self.derivation_path.is_some() {
+                return Err(eyre!("Derivation path can't be applied to a keystore"));
+            }
+            let keystore = std::fs::read(path).wrap_err("Failed to read keystore")?;
+            let keystore: Keystore =
+                serde_json::from_slice(&keystore).wrap_err("Failed to parse keystore")?;
+            let password = read_password(&self.password_env)?;
+            let key_pair = keystore
+                .decrypt(password.as_bytes())
+                .wrap_err("Failed to decrypt keystore")?;
+            return Ok((key_pair, None));
+        }
+
+        let mnemonic = if self.generate_mnemonic {
+            Some(Mnemonic::generate(24).wrap_err("Failed to generate mnemonic")?)
+        } else {
+            None
+        };
+
+        let given_mnemonic = self
+            .mnemonic_env
+            .map(|mnemonic_env| {
+                read_secret(&mnemonic_env, "mnemonic")?
+                    .parse::<Mnemonic>()
+                    .map_err(|error| eyre!("Failed to parse mnemonic: {error}"))
+            })
+            .transpose()?;
+        let passphrase = self
+            .passphrase_env
+            .map(|passphrase_env| read_secret(&passphrase_env, "passphrase"))
+            .transpose()?
+            .unwrap_or_default();
+
+        let has_seed = self.seed.is_some() || given_mnemonic.is_some() || mnemonic.is_some();
+        let configuration = match (
+            self.seed,
+            self.private_key,
+            given_mnemonic.or_else(|| mnemonic.clone()),
+        ) {
+            (None, None, None) => KeyGenConfiguration::from_random(),
+            (None, Some(private_key_hex), None) => {
                 let private_key = PrivateKey::from_hex(algorithm, private_key_hex.as_ref())
                     .wrap_err("Failed to decode private key")?;
                 KeyGenConfiguration::from_private_key(private_key)
             }
-            (Some(seed), None) => {
+            (Some(seed), None, None) => {
                 let seed: Vec<u8> = seed.as_bytes().into();
                 KeyGenConfiguration::from_seed(seed)
             }
+            (None, None, Some(mnemonic)) => {
+                KeyGenConfiguration::from_seed(mnemonic.to_seed(&passphrase))
+            }
             _ => unreachable!("Clap group invariant"),
         };
 
-        KeyPair::generate_with_configuration(configuration.with_algorithm(algorithm))
-            .wrap_err("Failed to generate key pair")
+        let configuration = match self.derivation_path {
+            Some(path) if has_seed => configuration.with_derivation_path(path),
+            Some(_) => {
+                return Err(eyre!(
+                    "Derivation path requires `--seed`, `--mnemonic-env` or `--generate-mnemonic`"
+                ))
+            }
+            None => configuration,
+        };
+
+        let key_pair =
+            KeyPair::generate_with_configuration(configuration.with_algorithm(algorithm))
+                .wrap_err("Failed to generate key pair")?;
+        Ok((key_pair, mnemonic))
     }
 }
 
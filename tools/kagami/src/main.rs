@@ -14,6 +14,7 @@ mod config;
 mod crypto;
 mod genesis;
 mod schema;
+mod threshold;
 
 /// Outcome shorthand used throughout this crate
 pub(crate) type Outcome = color_eyre::Result<()>;
@@ -53,6 +54,8 @@ enum Args {
     Genesis(genesis::Args),
     /// Generate the default client/peer configuration
     Config(config::Args),
+    /// Generate threshold keys and combine threshold signatures offline
+    Threshold(threshold::Args),
 }
 
 impl<T: Write> RunArgs<T> for Args {
@@ -64,6 +67,7 @@ impl<T: Write> RunArgs<T> for Args {
             Schema(args) => args.run(writer),
             Genesis(args) => args.run(writer),
             Config(args) => args.run(writer),
+            Threshold(args) => args.run(writer),
         }
     }
 }
@@ -0,0 +1,236 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use clap::{Parser, Subcommand};
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha_crypto::threshold::{
+    self, dkg, KeyShare, ParticipantId, PublicKeyPackage, SignatureShare, SigningCommitments,
+    SigningNonces, SigningPackage,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::*;
+
+/// Run the distributed key generation and threshold signing (FROST `Ed25519`) offline,
+/// so that no single operator ever holds the full private key of e.g. an admin account.
+///
+/// Files passed with `--secret`, `--key-share` and `--nonces` contain secret material
+/// and must never leave the machine of their owner. They're created readable only by their owner.
+#[derive(Parser, Debug, Clone)]
+pub struct Args {
+    #[clap(subcommand)]
+    mode: Mode,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Mode {
+    /// Start the key generation: write the secret state of this participant to `--secret`
+    /// and output the package to broadcast to all other participants
+    DkgPart1 {
+        /// Identifier of this participant, from 1 to `--max-signers`
+        #[clap(long)]
+        id: u16,
+        /// Total number of participants
+        #[clap(long)]
+        max_signers: u16,
+        /// Number of participants required to sign
+        #[clap(long)]
+        min_signers: u16,
+        /// File to write the secret state into
+        #[clap(long, value_name = "PATH")]
+        secret: PathBuf,
+    },
+    /// Verify the packages of all other participants and output the packages
+    /// to send **privately** to each of them, keyed by the receiver
+    DkgPart2 {
+        /// File with the secret state written by `dkg-part1`
+        #[clap(long, value_name = "PATH")]
+        secret: PathBuf,
+        /// JSON file with the `dkg-part1` packages of all other participants, keyed by their identifiers
+        #[clap(long, value_name = "PATH")]
+        round1_packages: PathBuf,
+    },
+    /// Finish the key generation: write the key share of this participant to `--key-share`
+    /// and output the public key package shared by the group
+    DkgPart3 {
+        /// File with the secret state written by `dkg-part1`, removed on success
+        #[clap(long, value_name = "PATH")]
+        secret: PathBuf,
+        /// JSON file with the `dkg-part1` packages of all other participants, keyed by their identifiers
+        #[clap(long, value_name = "PATH")]
+        round1_packages: PathBuf,
+        /// JSON file with the `dkg-part2` packages received from all other participants, keyed by their identifiers
+        #[clap(long, value_name = "PATH")]
+        round2_packages: PathBuf,
+        /// File to write the key share into
+        #[clap(long, value_name = "PATH")]
+        key_share: PathBuf,
+    },
+    /// Generate single-use nonces into `--nonces` and output the commitments to them
+    Commit {
+        /// File with the key share written by `dkg-part3`
+        #[clap(long, value_name = "PATH")]
+        key_share: PathBuf,
+        /// File to write the nonces into
+        #[clap(long, value_name = "PATH")]
+        nonces: PathBuf,
+    },
+    /// Combine the commitments of the signers and the message into a signing package
+    SigningPackage {
+        /// JSON file with the commitments of the signers, keyed by their identifiers
+        #[clap(long, value_name = "PATH")]
+        commitments: PathBuf,
+        /// Hex encoded message to sign, e.g. the hash of a transaction payload (`TransactionBuilder::hash`)
+        #[clap(long)]
+        message: String,
+    },
+    /// Output the signature share of this participant
+    Sign {
+        /// File with the key share written by `dkg-part3`
+        #[clap(long, value_name = "PATH")]
+        key_share: PathBuf,
+        /// File with the nonces written by `commit`, removed before signing so that they are never reused
+        #[clap(long, value_name = "PATH")]
+        nonces: PathBuf,
+        /// File with the signing package
+        #[clap(long, value_name = "PATH")]
+        signing_package: PathBuf,
+    },
+    /// Verify the signature shares and combine them into the signature of the group
+    Aggregate {
+        /// File with the signing package
+        #[clap(long, value_name = "PATH")]
+        signing_package: PathBuf,
+        /// JSON file with the signature shares of the signers, keyed by their identifiers
+        #[clap(long, value_name = "PATH")]
+        signature_shares: PathBuf,
+        /// File with the public key package output by `dkg-part3`
+        #[clap(long, value_name = "PATH")]
+        public_key_package: PathBuf,
+    },
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> color_eyre::Result<T> {
+    let content =
+        std::fs::read(path).wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_slice(&content).wrap_err_with(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Write secret material readable and writable only by its owner
+fn write_secret_json<T: Serialize>(path: &Path, value: &T) -> color_eyre::Result<()> {
+    let content = serde_json::to_string_pretty(value).wrap_err("Failed to serialise to JSON.")?;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(path)
+        .wrap_err_with(|| format!("Failed to create {}", path.display()))?;
+    // NOTE: Mode only applies to newly created files
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt as _;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .wrap_err_with(|| format!("Failed to restrict permissions of {}", path.display()))?;
+    }
+    file.write_all((content + "\n").as_bytes())
+        .wrap_err_with(|| format!("Failed to write {}", path.display()))
+}
+
+fn output_json<T: Serialize, W: Write>(writer: &mut BufWriter<W>, value: &T) -> Outcome {
+    let output = serde_json::to_string_pretty(value).wrap_err("Failed to serialise to JSON.")?;
+    writeln!(writer, "{output}")?;
+    Ok(())
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        match self.mode {
+            Mode::DkgPart1 {
+                id,
+                max_signers,
+                min_signers,
+                secret,
+            } => {
+                let id = ParticipantId::new(id).ok_or_else(|| eyre!("Identifier must not be 0"))?;
+                let (round1_secret, package) = dkg::part1(id, max_signers, min_signers)?;
+                write_secret_json(&secret, &round1_secret)?;
+                output_json(writer, &package)
+            }
+            Mode::DkgPart2 {
+                secret,
+                round1_packages,
+            } => {
+                let secret = read_json(&secret)?;
+                let packages = dkg::part2(&secret, &read_json(&round1_packages)?)?;
+                output_json(writer, &packages)
+            }
+            Mode::DkgPart3 {
+                secret: secret_path,
+                round1_packages,
+                round2_packages,
+                key_share,
+            } => {
+                let secret = read_json(&secret_path)?;
+                let (share, public_key_package) = dkg::part3(
+                    &secret,
+                    &read_json(&round1_packages)?,
+                    &read_json(&round2_packages)?,
+                )?;
+                write_secret_json(&key_share, &share)?;
+                std::fs::remove_file(&secret_path).wrap_err("Failed to remove the secret state")?;
+
+                eprintln!(
+                    "Group public key (multihash): \"{}\"",
+                    public_key_package.public_key()
+                );
+                output_json(writer, &public_key_package)
+            }
+            Mode::Commit { key_share, nonces } => {
+                let key_share: KeyShare = read_json(&key_share)?;
+                let (signing_nonces, commitments) = threshold::commit(&key_share);
+                write_secret_json(&nonces, &signing_nonces)?;
+                output_json(writer, &commitments)
+            }
+            Mode::SigningPackage {
+                commitments,
+                message,
+            } => {
+                let commitments: BTreeMap<ParticipantId, SigningCommitments> =
+                    read_json(&commitments)?;
+                let message = hex::decode(message).wrap_err("Failed to decode message")?;
+                output_json(writer, &SigningPackage::new(commitments, message))
+            }
+            Mode::Sign {
+                key_share,
+                nonces,
+                signing_package,
+            } => {
+                let key_share: KeyShare = read_json(&key_share)?;
+                let signing_package: SigningPackage = read_json(&signing_package)?;
+                let signing_nonces: SigningNonces = read_json(&nonces)?;
+                std::fs::remove_file(&nonces).wrap_err("Failed to remove the used nonces")?;
+
+                let share = threshold::sign(&signing_package, signing_nonces, &key_share)?;
+                output_json(writer, &share)
+            }
+            Mode::Aggregate {
+                signing_package,
+                signature_shares,
+                public_key_package,
+            } => {
+                let signing_package: SigningPackage = read_json(&signing_package)?;
+                let signature_shares: BTreeMap<ParticipantId, SignatureShare> =
+                    read_json(&signature_shares)?;
+                let public_key_package: PublicKeyPackage = read_json(&public_key_package)?;
+
+                let signature =
+                    threshold::aggregate(&signing_package, &signature_shares, &public_key_package)?;
+                output_json(writer, &signature)
+            }
+        }
+    }
+}
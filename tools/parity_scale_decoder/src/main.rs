@@ -18,7 +18,7 @@ use iroha_data_model::{
     block::{
         error::BlockRejectionReason,
         stream::{BlockMessage, BlockSubscriptionRequest},
-        BlockHeader, SignedBlock, SignedBlockV1,
+        BlockCommitCertificate, BlockHeader, SignedBlock, SignedBlockV1,
     },
     domain::NewDomain,
     executor::Executor,
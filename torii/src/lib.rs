@@ -4,6 +4,7 @@
 //!
 //! - `telemetry`: enables Status, Metrics, and API Version endpoints
 //! - `schema`: enables Data Model Schema endpoint
+//! - `json-query`: enables read-only JSON query endpoint
 
 use std::{
     convert::Infallible,
@@ -25,10 +26,17 @@ use iroha_core::{
 };
 use iroha_data_model::ChainId;
 use iroha_primitives::addr::SocketAddr;
-use tokio::{sync::Notify, task};
+use quota::RequestKind;
+use tokio::{net::TcpStream, sync::Notify, task};
+use tokio_rustls::server::TlsStream;
 use utils::*;
 use warp::{
     http::StatusCode,
+    hyper::{
+        self,
+        server::accept,
+        service::{make_service_fn, service_fn, Service as _},
+    },
     reply::{self, Json, Response},
     ws::{WebSocket, Ws},
     Filter as _, Reply,
@@ -37,8 +45,10 @@ use warp::{
 #[macro_use]
 pub(crate) mod utils;
 mod event;
+mod quota;
 mod routing;
 mod stream;
+mod tls;
 
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
@@ -52,10 +62,15 @@ pub struct Torii {
     kura: Arc<Kura>,
     transaction_max_content_length: u64,
     address: SocketAddr,
+    tls: Option<tls::TlsFiles>,
+    quotas: Arc<quota::Quotas>,
 }
 
 impl Torii {
     /// Construct `Torii`.
+    ///
+    /// # Errors
+    /// Fails if the TLS configuration is incomplete
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_id: ChainId,
@@ -67,8 +82,9 @@ impl Torii {
         sumeragi: SumeragiHandle,
         query_service: LiveQueryStoreHandle,
         kura: Arc<Kura>,
-    ) -> Self {
-        Self {
+    ) -> eyre::Result<Self> {
+        let quotas = Arc::new(quota::Quotas::new(config, sumeragi.clone()));
+        Ok(Self {
             chain_id: Arc::new(chain_id),
             kiso,
             queue,
@@ -79,7 +95,9 @@ impl Torii {
             kura,
             address: config.api_url.clone(),
             transaction_max_content_length: config.max_content_len.into(),
-        }
+            tls: tls::TlsFiles::from_configuration(config)?,
+            quotas,
+        })
     }
 
     /// Helper function to create router. This router can be tested without starting up an HTTP server
@@ -153,10 +171,19 @@ impl Torii {
 
         let post_router = warp::post()
             .and(
-                endpoint4(
+                endpoint5(
                     routing::handle_transaction,
                     warp::path(uri::TRANSACTION)
-                        .and(add_state!(self.chain_id, self.queue, self.sumeragi))
+                        .and(quota::address_quota(
+                            Arc::clone(&self.quotas),
+                            RequestKind::Transaction,
+                        ))
+                        .and(add_state!(
+                            self.chain_id,
+                            self.queue,
+                            self.sumeragi,
+                            self.quotas
+                        ))
                         .and(warp::body::content_length_limit(
                             self.transaction_max_content_length,
                         ))
@@ -168,10 +195,14 @@ impl Torii {
                         .and(add_state!(self.queue, self.sumeragi))
                         .and(body::versioned()),
                 ))
-                .or(endpoint3(
+                .or(endpoint4(
                     routing::handle_queries,
                     warp::path(uri::QUERY)
-                        .and(add_state!(self.query_service, self.sumeragi,))
+                        .and(quota::address_quota(
+                            Arc::clone(&self.quotas),
+                            RequestKind::Query,
+                        ))
+                        .and(add_state!(self.query_service, self.sumeragi, self.quotas))
                         .and(routing::client_query_request()),
                 ))
                 .or(endpoint2(
@@ -183,11 +214,78 @@ impl Torii {
             )
             .recover(|rejection| async move { body::recover_versioned(rejection) });
 
+        // Has to be tried before the SCALE query endpoint which would otherwise
+        // accept any request with the `query` path prefix
+        #[cfg(feature = "json-query")]
+        let post_router = {
+            // `warp` panics if there is `/` in the string given to the `warp::path` filter
+            // Path filter has to be boxed to have a single uniform type during iteration
+            let json_query_router_path = uri::JSON_QUERY
+                .split('/')
+                .skip_while(|p| p.is_empty())
+                .fold(warp::any().boxed(), |path_filter, path| {
+                    path_filter.and(warp::path(path)).boxed()
+                });
+            let exposed_cursors = Arc::new(routing::json_query::ExposedCursors::new(
+                self.query_service.query_idle_time(),
+            ));
+
+            warp::post()
+                .and(json_query_router_path)
+                .and(warp::path::end())
+                .and(quota::address_quota(
+                    Arc::clone(&self.quotas),
+                    RequestKind::Query,
+                ))
+                .and(add_state!(
+                    self.query_service,
+                    self.sumeragi,
+                    self.quotas,
+                    exposed_cursors
+                ))
+                .and(warp::body::content_length_limit(
+                    self.transaction_max_content_length,
+                ))
+                .and(warp::body::bytes())
+                .and_then(
+                    |query_service, sumeragi, quotas, exposed_cursors, body| async move {
+                        Ok::<_, Infallible>(
+                            routing::json_query::handle_json_query(
+                                query_service,
+                                sumeragi,
+                                quotas,
+                                exposed_cursors,
+                                body,
+                            )
+                            .await,
+                        )
+                    },
+                )
+                .or(post_router)
+        };
+
+        let delete_router = warp::delete().and(endpoint3(
+            routing::handle_drop_query,
+            warp::path(uri::QUERY)
+                .and(quota::address_quota(
+                    Arc::clone(&self.quotas),
+                    RequestKind::Query,
+                ))
+                .and(add_state!(self.query_service, self.sumeragi))
+                .and(body::versioned()),
+        ));
+
         let events_ws_router = warp::path(uri::SUBSCRIPTION)
+            .and(quota::address_quota(
+                Arc::clone(&self.quotas),
+                RequestKind::Subscription,
+            ))
+            .and(quota::subscription_permit(Arc::clone(&self.quotas)))
             .and(add_state!(self.events))
             .and(warp::ws())
-            .map(|events, ws: Ws| {
+            .map(|permit, events, ws: Ws| {
                 ws.on_upgrade(|this_ws| async move {
+                    let _permit: quota::SubscriptionPermit = permit;
                     if let Err(error) =
                         routing::subscription::handle_subscription(events, this_ws).await
                     {
@@ -206,17 +304,51 @@ impl Torii {
             });
 
         let blocks_ws_router = block_ws_router_path
+            .and(quota::address_quota(
+                Arc::clone(&self.quotas),
+                RequestKind::Subscription,
+            ))
+            .and(quota::subscription_permit(Arc::clone(&self.quotas)))
             .and(add_state!(self.kura))
             .and(warp::ws())
-            .map(|sumeragi: Arc<_>, ws: Ws| {
+            .map(|permit, sumeragi: Arc<_>, ws: Ws| {
                 ws.on_upgrade(|this_ws| async move {
+                    let _permit: quota::SubscriptionPermit = permit;
                     if let Err(error) = routing::handle_blocks_stream(sumeragi, this_ws).await {
                         iroha_logger::error!(%error, "Failed to subscribe to blocks stream");
                     }
                 })
             });
 
-        let ws_router = events_ws_router.or(blocks_ws_router);
+        // `warp` panics if there is `/` in the string given to the `warp::path` filter
+        // Path filter has to be boxed to have a single uniform type during iteration
+        let query_ws_router_path = uri::QUERY_STREAM
+            .split('/')
+            .skip_while(|p| p.is_empty())
+            .fold(warp::any().boxed(), |path_filter, path| {
+                path_filter.and(warp::path(path)).boxed()
+            });
+
+        let query_ws_router = query_ws_router_path
+            .and(quota::address_quota(
+                Arc::clone(&self.quotas),
+                RequestKind::Subscription,
+            ))
+            .and(quota::subscription_permit(Arc::clone(&self.quotas)))
+            .and(add_state!(self.query_service, self.sumeragi, self.quotas))
+            .and(warp::ws())
+            .map(|permit, query_service, sumeragi, quotas, ws: Ws| {
+                ws.on_upgrade(|this_ws| async move {
+                    let _permit: quota::SubscriptionPermit = permit;
+                    if let Err(error) =
+                        routing::handle_query_stream(query_service, sumeragi, quotas, this_ws).await
+                    {
+                        iroha_logger::error!(%error, "Failure during query streaming");
+                    }
+                })
+            });
+
+        let ws_router = events_ws_router.or(blocks_ws_router).or(query_ws_router);
 
         warp::any()
             .and(
@@ -229,6 +361,8 @@ impl Torii {
             .or(ws_router
                 .or(get_router)
                 .or(post_router)
+                .or(delete_router)
+                .recover(|rejection| async move { quota::recover_quota(rejection) })
                 .with(warp::trace::request()))
     }
 
@@ -239,6 +373,7 @@ impl Torii {
     fn start_api(self: Arc<Self>) -> eyre::Result<Vec<task::JoinHandle<()>>> {
         let torii_address = &self.address;
 
+        let tls_config = self.tls.clone().map(tls::TlsFiles::watch).transpose()?;
         let mut handles = vec![];
         match torii_address.to_socket_addrs() {
             Ok(addrs) => {
@@ -247,10 +382,37 @@ impl Torii {
 
                     let api_router = torii.create_api_router();
                     let signal_fut = async move { torii.notify_shutdown.notified().await };
-                    let (_, serve_fut) =
-                        warp::serve(api_router).bind_with_graceful_shutdown(addr, signal_fut);
-
-                    handles.push(task::spawn(serve_fut));
+                    if let Some(tls_config) = &tls_config {
+                        let incoming = tls::incoming(addr, tls_config.clone())?;
+                        // NOTE: `warp` doesn't know the address of the clients connected over TLS,
+                        // so it's passed to the filters in the extensions of the requests
+                        let make_service = make_service_fn(move |stream: &TlsStream<TcpStream>| {
+                            let address = stream.get_ref().0.peer_addr().ok();
+                            let mut service = warp::service(api_router.clone());
+                            futures::future::ok::<_, Infallible>(service_fn(
+                                move |mut request: hyper::Request<hyper::Body>| {
+                                    if let Some(address) = address {
+                                        request
+                                            .extensions_mut()
+                                            .insert(quota::RemoteAddress(address));
+                                    }
+                                    service.call(request)
+                                },
+                            ))
+                        });
+                        let server = hyper::Server::builder(accept::from_stream(incoming))
+                            .serve(make_service)
+                            .with_graceful_shutdown(signal_fut);
+                        handles.push(task::spawn(async move {
+                            if let Err(error) = server.await {
+                                iroha_logger::error!(%error, "API server failed");
+                            }
+                        }));
+                    } else {
+                        let (_, serve_fut) =
+                            warp::serve(api_router).bind_with_graceful_shutdown(addr, signal_fut);
+                        handles.push(task::spawn(serve_fut));
+                    }
                 }
 
                 Ok(handles)
@@ -313,6 +475,8 @@ pub enum Error {
     ConfigurationFailure(#[from] KisoError),
     /// Failed to find status segment by provided path
     StatusSegmentNotFound(#[source] eyre::Report),
+    /// Client exceeded its quota
+    QuotaExceeded(#[from] quota::QuotaExceeded),
 }
 
 impl Reply for Error {
@@ -322,11 +486,18 @@ impl Reply for Error {
                 reply::with_status(utils::Scale(&err), Self::query_status_code(&err))
                     .into_response()
             }
+            Self::QuotaExceeded(err) => err.into_response(),
             _ => reply::with_status(Self::to_string(&self), self.status_code()).into_response(),
         }
     }
 }
 
+impl From<iroha_core::query::store::Error> for Error {
+    fn from(error: iroha_core::query::store::Error) -> Self {
+        Self::Query(error.into())
+    }
+}
+
 impl Error {
     fn status_code(&self) -> StatusCode {
         use Error::*;
@@ -345,6 +516,7 @@ impl Error {
             #[cfg(feature = "profiling")]
             Pprof(_) => StatusCode::INTERNAL_SERVER_ERROR,
             ConfigurationFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            QuotaExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -362,6 +534,8 @@ impl Error {
                 Conversion(_) | UnknownCursor | FetchSizeTooBig => StatusCode::BAD_REQUEST,
                 Signature(_) => StatusCode::UNAUTHORIZED,
                 Find(_) => StatusCode::NOT_FOUND,
+                TooManyLiveQueries => StatusCode::TOO_MANY_REQUESTS,
+                LiveQueryStoreFull => StatusCode::SERVICE_UNAVAILABLE,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
             InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
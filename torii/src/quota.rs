@@ -0,0 +1,504 @@
+//! Quotas of the requests of a single client.
+//!
+//! Every quota is a token bucket, tracked separately for each client IP address and each authority.
+//! Requests are only counted against the quota of an authority once they're known to be signed by it,
+//! so that nobody can exhaust the quota of someone else.
+//! Besides the rate of subscriptions, the number of the subscriptions open at once is limited as well.
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use iroha_config::torii::{Configuration as ToriiConfiguration, Quota};
+use iroha_core::sumeragi::SumeragiHandle;
+use iroha_data_model::account::AccountId;
+use warp::{
+    http::{header::RETRY_AFTER, HeaderValue, StatusCode},
+    reply::{self, Response},
+    Filter, Rejection, Reply,
+};
+
+/// Period of forgetting the clients whose buckets are full
+const PRUNE_INTERVAL: Duration = Duration::from_secs(10);
+/// Maximal number of subscriptions open at once from a single address
+const MAX_SUBSCRIPTIONS_PER_ADDRESS: usize = 32;
+/// Maximal number of subscriptions open at once from all addresses
+const MAX_SUBSCRIPTIONS: usize = 4096;
+/// Time after which the client is asked to try opening a subscription again if there are too many open
+const SUBSCRIPTION_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Kind of the requests sharing a quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+pub enum RequestKind {
+    /// transaction
+    Transaction,
+    /// query
+    Query,
+    /// subscription
+    Subscription,
+}
+
+/// Client whose requests are counted against a quota
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Client {
+    Address(IpAddr),
+    Account(AccountId),
+}
+
+impl Client {
+    const fn label(&self) -> &'static str {
+        match self {
+            Self::Address(_) => "address",
+            Self::Account(_) => "authority",
+        }
+    }
+}
+
+/// Address of the client connected over TLS.
+///
+/// Inserted into the extensions of the request, because `warp` doesn't know the address of such clients.
+#[derive(Debug, Clone, Copy)]
+pub struct RemoteAddress(pub SocketAddr);
+
+/// Quota of {kind} requests exceeded, retry in {retry_after:?}
+#[derive(Debug, Clone, Copy, thiserror::Error, displaydoc::Display)]
+#[allow(clippy::doc_markdown)] // NOTE: Doc comment is the display format
+pub struct QuotaExceeded {
+    /// Kind of the rejected request
+    pub kind: RequestKind,
+    /// Time after which the client can make a request again
+    pub retry_after: Duration,
+}
+
+impl warp::reject::Reject for QuotaExceeded {}
+
+impl Reply for QuotaExceeded {
+    fn into_response(self) -> Response {
+        let mut response =
+            reply::with_status(self.to_string(), StatusCode::TOO_MANY_REQUESTS).into_response();
+        // NOTE: Rounded up, so that clients don't retry too early
+        let retry_after =
+            self.retry_after.as_secs() + u64::from(self.retry_after.subsec_nanos() > 0);
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+        response
+    }
+}
+
+/// Token buckets of the clients, each bucket is represented by the time at which it becomes full.
+#[derive(Debug)]
+struct Buckets {
+    /// Time it takes to replenish a single request
+    interval: Duration,
+    /// How far ahead the bucket can be emptied, i.e. the time it takes to replenish the burst
+    tolerance: Duration,
+    state: Mutex<BucketsState>,
+}
+
+#[derive(Debug)]
+struct BucketsState {
+    full_at: HashMap<Client, Instant>,
+    last_pruned: Instant,
+}
+
+impl Buckets {
+    fn new(quota: Quota, now: Instant) -> Self {
+        let interval = Duration::from_secs(1) / quota.rate_per_sec.get();
+        Self {
+            interval,
+            tolerance: interval * quota.burst.get(),
+            state: Mutex::new(BucketsState {
+                full_at: HashMap::new(),
+                last_pruned: now,
+            }),
+        }
+    }
+
+    /// Time the client has to wait until it can make a request, zero if it can right away
+    fn wait_time(&self, state: &BucketsState, client: &Client, now: Instant) -> Duration {
+        state.full_at.get(client).map_or(Duration::ZERO, |full_at| {
+            (full_at.saturating_duration_since(now) + self.interval).saturating_sub(self.tolerance)
+        })
+    }
+
+    fn take(&self, client: Client, now: Instant) -> Result<(), Duration> {
+        let mut state = self.state.lock().expect("Lock on quota buckets poisoned");
+        let wait_time = self.wait_time(&state, &client, now);
+        if wait_time.is_zero() {
+            self.charge_locked(&mut state, client, now);
+            Ok(())
+        } else {
+            Err(wait_time)
+        }
+    }
+
+    /// Give back the request taken by [`Self::take`]
+    fn refund(&self, client: &Client) {
+        let mut state = self.state.lock().expect("Lock on quota buckets poisoned");
+        if let Some(full_at) = state.full_at.get_mut(client) {
+            *full_at = full_at.checked_sub(self.interval).unwrap_or(*full_at);
+        }
+    }
+
+    fn charge_locked(&self, state: &mut BucketsState, client: Client, now: Instant) {
+        if now.saturating_duration_since(state.last_pruned) >= PRUNE_INTERVAL {
+            state.full_at.retain(|_, full_at| *full_at > now);
+            state.last_pruned = now;
+        }
+        let full_at = state.full_at.entry(client).or_insert(now);
+        *full_at = (*full_at).max(now) + self.interval;
+    }
+}
+
+/// Number of the subscriptions open at once
+#[derive(Debug, Default)]
+struct OpenSubscriptions {
+    total: usize,
+    per_address: HashMap<IpAddr, usize>,
+}
+
+/// Quotas of all kinds of requests.
+pub struct Quotas {
+    transaction: Option<Buckets>,
+    query: Option<Buckets>,
+    subscription: Option<Buckets>,
+    subscriptions: Mutex<OpenSubscriptions>,
+    /// Used to count the rejected requests in the metrics, called with the kind of the request and the client
+    count_rejected: CountRejected,
+}
+
+type CountRejected = Box<dyn Fn(&str, &str) + Send + Sync>;
+
+impl Quotas {
+    /// Construct [`Self`] with the quotas from the configuration.
+    pub fn new(config: &ToriiConfiguration, sumeragi: SumeragiHandle) -> Self {
+        let now = Instant::now();
+        let buckets = |quota: Option<Quota>| quota.map(|quota| Buckets::new(quota, now));
+        Self {
+            transaction: buckets(config.transaction_quota),
+            query: buckets(config.query_quota),
+            subscription: buckets(config.subscription_quota),
+            subscriptions: Mutex::default(),
+            count_rejected: Box::new(move |request, client| {
+                sumeragi
+                    .metrics()
+                    .rate_limited_requests
+                    .with_label_values(&[request, client])
+                    .inc();
+            }),
+        }
+    }
+
+    fn buckets(&self, kind: RequestKind) -> Option<&Buckets> {
+        match kind {
+            RequestKind::Transaction => self.transaction.as_ref(),
+            RequestKind::Query => self.query.as_ref(),
+            RequestKind::Subscription => self.subscription.as_ref(),
+        }
+    }
+
+    fn exceeded(&self, kind: RequestKind, client: &str, retry_after: Duration) -> QuotaExceeded {
+        (self.count_rejected)(kind.to_string().as_str(), client);
+        QuotaExceeded { kind, retry_after }
+    }
+
+    /// Count the request from `address` against its quota.
+    ///
+    /// # Errors
+    /// Fails if the quota of the address is exhausted
+    pub fn check_address(
+        &self,
+        kind: RequestKind,
+        address: Option<SocketAddr>,
+    ) -> Result<(), QuotaExceeded> {
+        let (Some(buckets), Some(address)) = (self.buckets(kind), address) else {
+            return Ok(());
+        };
+        let client = Client::Address(address.ip());
+        buckets
+            .take(client.clone(), Instant::now())
+            .map_err(|retry_after| self.exceeded(kind, client.label(), retry_after))
+    }
+
+    /// Reserve a request from the quota of `authority`, which is given back
+    /// unless the request turns out to be signed by it, see [`Reservation`].
+    ///
+    /// # Errors
+    /// Fails if the quota of the authority is exhausted
+    pub fn reserve_authority(
+        self: &Arc<Self>,
+        kind: RequestKind,
+        authority: &AccountId,
+    ) -> Result<Reservation, QuotaExceeded> {
+        let Some(buckets) = self.buckets(kind) else {
+            return Ok(Reservation(None));
+        };
+        let client = Client::Account(authority.clone());
+        buckets
+            .take(client.clone(), Instant::now())
+            .map_err(|retry_after| self.exceeded(kind, client.label(), retry_after))?;
+        Ok(Reservation(Some((Arc::clone(self), kind, client))))
+    }
+
+    /// Open a subscription from `address`, which stays open until the returned permit is dropped.
+    ///
+    /// # Errors
+    /// Fails if there are too many subscriptions open from the address or from all addresses
+    pub fn open_subscription(
+        self: &Arc<Self>,
+        address: Option<SocketAddr>,
+    ) -> Result<SubscriptionPermit, QuotaExceeded> {
+        let address = address.map(|address| address.ip());
+        let mut subscriptions = self
+            .subscriptions
+            .lock()
+            .expect("Lock on open subscriptions poisoned");
+        if subscriptions.total >= MAX_SUBSCRIPTIONS {
+            return Err(self.exceeded(RequestKind::Subscription, "all", SUBSCRIPTION_RETRY_AFTER));
+        }
+        if let Some(address) = address {
+            let open = subscriptions.per_address.entry(address).or_default();
+            if *open >= MAX_SUBSCRIPTIONS_PER_ADDRESS {
+                return Err(self.exceeded(
+                    RequestKind::Subscription,
+                    Client::Address(address).label(),
+                    SUBSCRIPTION_RETRY_AFTER,
+                ));
+            }
+            *open += 1;
+        }
+        subscriptions.total += 1;
+        Ok(SubscriptionPermit {
+            quotas: Arc::clone(self),
+            address,
+        })
+    }
+
+    fn close_subscription(&self, address: Option<IpAddr>) {
+        let mut subscriptions = self
+            .subscriptions
+            .lock()
+            .expect("Lock on open subscriptions poisoned");
+        subscriptions.total -= 1;
+        if let Some(address) = address {
+            if let Some(open) = subscriptions.per_address.get_mut(&address) {
+                *open -= 1;
+                if *open == 0 {
+                    subscriptions.per_address.remove(&address);
+                }
+            }
+        }
+    }
+}
+
+/// Request reserved from the quota of an authority, given back on drop unless [confirmed](Self::confirm).
+#[must_use]
+pub struct Reservation(Option<(Arc<Quotas>, RequestKind, Client)>);
+
+impl Reservation {
+    /// Keep the request counted against the quota once it's known to be signed by the authority.
+    pub fn confirm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        if let Some((quotas, kind, client)) = self.0.take() {
+            if let Some(buckets) = quotas.buckets(kind) {
+                buckets.refund(&client);
+            }
+        }
+    }
+}
+
+/// Subscription counted as open until dropped.
+pub struct SubscriptionPermit {
+    quotas: Arc<Quotas>,
+    address: Option<IpAddr>,
+}
+
+impl Drop for SubscriptionPermit {
+    fn drop(&mut self) {
+        self.quotas.close_subscription(self.address);
+    }
+}
+
+/// Filter extracting the address of the client, if it's known
+fn remote_address() -> impl Filter<Extract = (Option<SocketAddr>,), Error = Infallible> + Copy {
+    warp::ext::optional::<RemoteAddress>()
+        .and(warp::addr::remote())
+        .map(|tls: Option<RemoteAddress>, address: Option<SocketAddr>| {
+            tls.map(|RemoteAddress(address)| address).or(address)
+        })
+}
+
+/// Filter rejecting the requests of the clients whose address exhausted the quota of `kind`
+pub fn address_quota(
+    quotas: Arc<Quotas>,
+    kind: RequestKind,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    remote_address()
+        .and_then(move |address| {
+            let quotas = Arc::clone(&quotas);
+            async move {
+                quotas
+                    .check_address(kind, address)
+                    .map_err(warp::reject::custom)
+            }
+        })
+        .untuple_one()
+}
+
+/// Filter opening a subscription, rejecting it if there are too many open already
+pub fn subscription_permit(
+    quotas: Arc<Quotas>,
+) -> impl Filter<Extract = (SubscriptionPermit,), Error = Rejection> + Clone {
+    remote_address().and_then(move |address| {
+        let quotas = Arc::clone(&quotas);
+        async move {
+            quotas
+                .open_subscription(address)
+                .map_err(warp::reject::custom)
+        }
+    })
+}
+
+/// Recover from failure in [`address_quota`] or [`subscription_permit`]
+pub fn recover_quota(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    rejection
+        .find::<QuotaExceeded>()
+        .copied()
+        .map(Reply::into_response)
+        .ok_or(rejection)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::*;
+
+    #[test]
+    fn burst_is_allowed_then_requests_are_replenished() {
+        let now = Instant::now();
+        let quota = Quota {
+            rate_per_sec: NonZeroU32::new(10).unwrap(),
+            burst: NonZeroU32::new(3).unwrap(),
+        };
+        let buckets = Buckets::new(quota, now);
+        let client = Client::Address(IpAddr::from([127, 0, 0, 1]));
+
+        for _ in 0..3 {
+            assert!(buckets.take(client.clone(), now).is_ok());
+        }
+        assert_eq!(
+            buckets.take(client.clone(), now),
+            Err(Duration::from_millis(100))
+        );
+        assert!(buckets
+            .take(client.clone(), now + Duration::from_millis(100))
+            .is_ok());
+        assert!(buckets
+            .take(client, now + Duration::from_millis(100))
+            .is_err());
+    }
+
+    fn quotas(quota: Quota) -> Arc<Quotas> {
+        let now = Instant::now();
+        Arc::new(Quotas {
+            transaction: Some(Buckets::new(quota, now)),
+            query: Some(Buckets::new(quota, now)),
+            subscription: Some(Buckets::new(quota, now)),
+            subscriptions: Mutex::default(),
+            count_rejected: Box::new(|_, _| {}),
+        })
+    }
+
+    #[test]
+    fn unconfirmed_reservation_is_refunded() {
+        let quotas = quotas(Quota {
+            rate_per_sec: NonZeroU32::new(1).unwrap(),
+            burst: NonZeroU32::new(1).unwrap(),
+        });
+        let authority: AccountId = "alice@wonderland".parse().unwrap();
+
+        let reservation = quotas
+            .reserve_authority(RequestKind::Query, &authority)
+            .unwrap();
+        assert!(quotas
+            .reserve_authority(RequestKind::Query, &authority)
+            .is_err());
+        drop(reservation);
+
+        quotas
+            .reserve_authority(RequestKind::Query, &authority)
+            .unwrap()
+            .confirm();
+        let exceeded = quotas
+            .reserve_authority(RequestKind::Query, &authority)
+            .map(Reservation::confirm)
+            .unwrap_err();
+        assert_eq!(exceeded.retry_after, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn open_subscriptions_are_limited_per_address() {
+        let quotas = quotas(Quota {
+            rate_per_sec: NonZeroU32::new(1).unwrap(),
+            burst: NonZeroU32::new(1).unwrap(),
+        });
+        let address = SocketAddr::from(([127, 0, 0, 1], 8080));
+
+        let mut permits = (0..MAX_SUBSCRIPTIONS_PER_ADDRESS)
+            .map(|_| quotas.open_subscription(Some(address)).unwrap())
+            .collect::<Vec<_>>();
+        assert!(quotas.open_subscription(Some(address)).is_err());
+        // Other addresses aren't affected
+        assert!(quotas
+            .open_subscription(Some(SocketAddr::from(([127, 0, 0, 2], 8080))))
+            .is_ok());
+
+        permits.pop();
+        assert!(quotas.open_subscription(Some(address)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn router_replies_too_many_requests_with_retry_after() {
+        let quotas = quotas(Quota {
+            rate_per_sec: NonZeroU32::new(1).unwrap(),
+            burst: NonZeroU32::new(1).unwrap(),
+        });
+        let router = warp::path("query")
+            .and(address_quota(quotas, RequestKind::Query))
+            .map(warp::reply)
+            .recover(|rejection| async move { recover_quota(rejection) });
+
+        let request = || {
+            warp::test::request()
+                .path("/query")
+                .remote_addr(SocketAddr::from(([127, 0, 0, 1], 8080)))
+        };
+        assert_eq!(request().reply(&router).await.status(), StatusCode::OK);
+        let response = request().reply(&router).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "1");
+
+        // Address of a client connected over TLS is unknown to `warp`
+        let request = || {
+            warp::test::request()
+                .path("/query")
+                .extension(RemoteAddress(SocketAddr::from(([127, 0, 0, 2], 8080))))
+        };
+        assert_eq!(request().reply(&router).await.status(), StatusCode::OK);
+        assert_eq!(
+            request().reply(&router).await.status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+}
@@ -10,7 +10,8 @@ use eyre::{eyre, WrapErr};
 use futures::TryStreamExt;
 use iroha_config::client_api::ConfigurationDTO;
 use iroha_core::{
-    query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest,
+    query::store::{LiveQueryStoreHandle, QueryStream},
+    smartcontracts::query::ValidQueryRequest,
     sumeragi::SumeragiHandle,
 };
 use iroha_data_model::{
@@ -20,18 +21,26 @@ use iroha_data_model::{
     },
     prelude::*,
     query::{
-        cursor::ForwardCursor, http, sorting::Sorting, Pagination, QueryRequest,
-        QueryWithParameters,
+        cursor::ForwardCursor,
+        error::QueryExecutionFail,
+        http,
+        sorting::Sorting,
+        stream::{QueryStreamDemand, QueryStreamMessage, QueryStreamRequest},
+        Pagination, QueryRequest, QueryWithParameters,
     },
     transaction::TransactionPayload,
     BatchedResponse,
 };
 #[cfg(feature = "telemetry")]
 use iroha_telemetry::metrics::Status;
+use parity_scale_codec::DecodeAll;
 use tokio::task;
 
 use super::*;
-use crate::stream::{Sink, Stream};
+use crate::{
+    quota::{Quotas, Reservation},
+    stream::{Sink, Stream},
+};
 
 /// Filter for warp which extracts [`http::ClientQueryRequest`]
 pub fn client_query_request(
@@ -60,7 +69,7 @@ fn sorting() -> impl warp::Filter<Extract = (Sorting,), Error = warp::Rejection>
 }
 
 /// Filter for warp which extracts cursor
-fn cursor() -> impl warp::Filter<Extract = (ForwardCursor,), Error = warp::Rejection> + Copy {
+pub fn cursor() -> impl warp::Filter<Extract = (ForwardCursor,), Error = warp::Rejection> + Copy {
     warp::query()
 }
 
@@ -79,8 +88,11 @@ pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     sumeragi: SumeragiHandle,
+    quotas: Arc<Quotas>,
     transaction: SignedTransaction,
 ) -> Result<Empty> {
+    let reservation =
+        quotas.reserve_authority(RequestKind::Transaction, transaction.payload().authority())?;
     let wsv = sumeragi.wsv_clone();
     let transaction_limits = wsv.config.transaction_limits;
     let transaction = AcceptedTransaction::accept(transaction, &chain_id, &transaction_limits)
@@ -95,15 +107,17 @@ pub async fn handle_transaction(
 
             Box::new(err)
         })
-        .map_err(Error::PushIntoQueue)
-        .map(|()| Empty)
+        .map_err(Error::PushIntoQueue)?;
+    // NOTE: Counted only once the queue checked that the transaction is signed by its authority
+    reservation.confirm();
+    Ok(Empty)
 }
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
     sumeragi: SumeragiHandle,
-
+    quotas: Arc<Quotas>,
     query_request: http::ClientQueryRequest,
 ) -> Result<Scale<BatchedResponse<Value>>> {
     let handle = task::spawn_blocking(move || match query_request.0 {
@@ -112,24 +126,185 @@ pub async fn handle_queries(
             sorting,
             pagination,
             fetch_size,
-        }) => sumeragi.apply_wsv(|wsv| {
-            let valid_query = ValidQueryRequest::validate(signed_query, wsv)?;
-            let query_output = valid_query.execute(wsv)?;
-            live_query_store
-                .handle_query_output(query_output, &sorting, pagination, fetch_size)
-                .map_err(ValidationFail::from)
-        }),
-        QueryRequest::Cursor(cursor) => live_query_store
-            .handle_query_cursor(cursor)
-            .map_err(ValidationFail::from),
+        }) => {
+            let reservation =
+                quotas.reserve_authority(RequestKind::Query, signed_query.authority())?;
+            sumeragi
+                .apply_wsv(|wsv| {
+                    let valid_query = ValidQueryRequest::validate(signed_query, wsv)?;
+                    reservation.confirm();
+                    let query_output = valid_query.execute(wsv)?;
+                    live_query_store
+                        .handle_query_output(
+                            query_output,
+                            valid_query.authority(),
+                            &sorting,
+                            pagination,
+                            fetch_size,
+                        )
+                        .map_err(ValidationFail::from)
+                })
+                .map_err(Error::from)
+        }
+        // NOTE: Continuations are counted against the authority who submitted the query
+        QueryRequest::Cursor(cursor) => {
+            live_query_store.handle_admitted_query_cursor(cursor, |authority| {
+                quotas
+                    .reserve_authority(RequestKind::Query, authority)
+                    .map(Reservation::confirm)
+                    .map_err(Error::from)
+            })
+        }
     });
     handle
         .await
         .expect("Failed to join query handling task")
         .map(Scale)
+}
+
+/// Handle request to drop a live query before its output is fully consumed
+///
+/// Only the authority who submitted the query can drop it, queries of other
+/// authorities are reported as unknown.
+#[iroha_futures::telemetry_future]
+pub async fn handle_drop_query(
+    live_query_store: LiveQueryStoreHandle,
+    sumeragi: SumeragiHandle,
+    request: SignedDropQuery,
+) -> Result<Empty> {
+    let handle = task::spawn_blocking(move || {
+        let unknown_cursor = || ValidationFail::QueryFailed(QueryExecutionFail::UnknownCursor);
+
+        let account_has_public_key = sumeragi
+            .apply_wsv(|wsv| {
+                wsv.map_account(request.authority(), |account| {
+                    account
+                        .signatories
+                        .contains(request.signature().public_key())
+                })
+            })
+            .map_err(ValidationFail::QueryFailed)?;
+        if !account_has_public_key {
+            return Err(ValidationFail::QueryFailed(QueryExecutionFail::Signature(
+                String::from("Signature public key doesn't correspond to the account."),
+            )));
+        }
+
+        if live_query_store
+            .drop_query_of(request.query_id().clone(), request.authority())
+            .map_err(ValidationFail::from)?
+        {
+            Ok(Empty)
+        } else {
+            Err(unknown_cursor())
+        }
+    });
+    handle
+        .await
+        .expect("Failed to join query dropping task")
         .map_err(Into::into)
 }
 
+/// Handle query streaming request
+///
+/// Executes the query received through the `stream` and sends the results
+/// batch by batch. The client controls the pace of the stream by sending
+/// [`QueryStreamDemand`] messages, no more batches than requested are sent.
+/// The stream is closed if the client doesn't send the query or doesn't demand
+/// more batches for the query idle time of the live query store.
+#[iroha_futures::telemetry_future]
+pub async fn handle_query_stream(
+    live_query_store: LiveQueryStoreHandle,
+    sumeragi: SumeragiHandle,
+    quotas: Arc<Quotas>,
+    mut stream: WebSocket,
+) -> eyre::Result<()> {
+    let idle_time = live_query_store.query_idle_time();
+    // NOTE: Client which never sends the query mustn't hold the stream forever either
+    let Ok(request) = tokio::time::timeout(idle_time, stream.recv()).await else {
+        iroha_logger::debug!("Query stream request wasn't received in time, closing");
+        return stream.close().await.map_err(Into::into);
+    };
+    let QueryStreamRequest(QueryWithParameters {
+        query: signed_query,
+        sorting,
+        pagination,
+        fetch_size,
+    }) = request?;
+
+    let reservation = match quotas.reserve_authority(RequestKind::Query, signed_query.authority()) {
+        Ok(reservation) => reservation,
+        Err(error) => {
+            // NOTE: Close code "Try Again Later" of RFC 6455
+            let close = warp::ws::Message::close_with(1013_u16, error.to_string());
+            return futures::SinkExt::send(&mut stream, close)
+                .await
+                .map_err(Into::into);
+        }
+    };
+
+    let query_stream = task::spawn_blocking(move || {
+        sumeragi.apply_wsv(|wsv| {
+            let valid_query = ValidQueryRequest::validate(signed_query, wsv)?;
+            reservation.confirm();
+            let query_output = valid_query.execute(wsv)?;
+            QueryStream::new(
+                &live_query_store,
+                query_output,
+                valid_query.authority(),
+                &sorting,
+                pagination,
+                fetch_size,
+            )
+            .map_err(ValidationFail::from)
+        })
+    })
+    .await?;
+
+    let mut query_stream = match query_stream {
+        Ok(query_stream) => query_stream,
+        Err(error) => {
+            stream.send(QueryStreamMessage::Error(error)).await?;
+            return stream.close().await.map_err(Into::into);
+        }
+    };
+
+    let mut demand = 0_u64;
+    loop {
+        if demand == 0 {
+            // Stream is accounted for in the live query store,
+            // so it's evicted the same way as the queries idle in the store
+            let Ok(message) = tokio::time::timeout(idle_time, stream.try_next()).await else {
+                iroha_logger::debug!("Query stream is idle, closing");
+                return stream.close().await.map_err(Into::into);
+            };
+            let Some(message) = message? else {
+                eyre::bail!("Can't receive query stream demand");
+            };
+            if message.is_close() {
+                return stream.close().await.map_err(Into::into);
+            }
+            if !message.is_binary() {
+                iroha_logger::warn!(?message, "Unexpected message received");
+                continue;
+            }
+
+            let QueryStreamDemand(batches) =
+                QueryStreamDemand::decode_all(&mut message.as_bytes())?;
+            demand = demand.saturating_add(batches.get().into());
+            continue;
+        }
+
+        let Some(batch) = query_stream.next_batch() else {
+            break;
+        };
+        stream.send(QueryStreamMessage::Batch(batch)).await?;
+        demand -= 1;
+    }
+
+    stream.close().await.map_err(Into::into)
+}
+
 #[derive(serde::Serialize)]
 #[non_exhaustive]
 enum Health {
@@ -464,3 +639,415 @@ pub mod profiling {
         }
     }
 }
+
+#[cfg(feature = "json-query")]
+pub mod json_query {
+    //! Read-only JSON query endpoint for integrations which can't speak SCALE.
+    //!
+    //! Queries are signed exactly like the ones sent to the SCALE endpoint, so the
+    //! same permission checks apply. Only domains, accounts, assets, blocks and
+    //! transactions are exposed.
+
+    use std::{
+        collections::HashMap,
+        sync::Mutex,
+        time::{Duration, Instant},
+    };
+
+    use iroha_data_model::{
+        query::{http::SignedQueryV1, QueryId},
+        IdentifiableBox,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Map, Value as JsonValue};
+    use warp::hyper::body::Bytes;
+
+    use super::*;
+
+    /// Request accepted by the JSON query endpoint
+    #[derive(Debug, Deserialize)]
+    pub struct JsonQueryRequest {
+        /// Either a new query or a cursor of an already started one
+        #[serde(flatten)]
+        pub request: JsonQueryKind,
+        /// Dot-separated paths of the fields to select from each result.
+        /// All fields are returned if empty.
+        #[serde(default)]
+        pub fields: Vec<String>,
+    }
+
+    /// Kind of the request accepted by the JSON query endpoint
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum JsonQueryKind {
+        /// Start a new query
+        Query {
+            /// Query together with the signature of its authority
+            signed: SignedQueryV1,
+            /// Sorting of the query output
+            #[serde(default)]
+            sorting: Sorting,
+            /// Pagination of the query output
+            #[serde(default)]
+            pagination: Pagination,
+            /// Number of results returned in a single batch
+            #[serde(default)]
+            fetch_size: FetchSize,
+        },
+        /// Fetch the next batch of an already started query
+        Cursor(ForwardCursor),
+    }
+
+    /// Response of the JSON query endpoint
+    #[derive(Debug, Serialize)]
+    pub struct JsonQueryResponse {
+        /// Query output with only the selected fields
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub data: Option<JsonValue>,
+        /// Cursor to fetch the next batch with
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub cursor: Option<ForwardCursor>,
+        /// Reason of the query failure
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub error: Option<ValidationFail>,
+    }
+
+    /// Live queries started through the JSON query endpoint.
+    ///
+    /// Cursors of the other live queries aren't accepted by the endpoint,
+    /// so that the output of the queries which aren't exposed can't be fetched through it.
+    #[derive(Debug)]
+    pub struct ExposedCursors {
+        /// Time of the last access of every query
+        queries: Mutex<HashMap<QueryId, Instant>>,
+        /// Time after which the query is evicted from the live query store
+        query_idle_time: Duration,
+    }
+
+    impl ExposedCursors {
+        /// Construct [`Self`] forgetting the queries idle for `query_idle_time`.
+        pub fn new(query_idle_time: Duration) -> Self {
+            Self {
+                queries: Mutex::new(HashMap::new()),
+                query_idle_time,
+            }
+        }
+
+        /// Remember the query with `cursor` if it has more batches to fetch.
+        fn insert(&self, cursor: &ForwardCursor) {
+            let mut queries = self
+                .queries
+                .lock()
+                .expect("Lock on exposed cursors poisoned");
+            let now = Instant::now();
+            queries
+                .retain(|_, last_access| now.duration_since(*last_access) <= self.query_idle_time);
+            if let (Some(query_id), Some(_)) = (&cursor.query_id, cursor.cursor) {
+                queries.insert(query_id.clone(), now);
+            }
+        }
+
+        /// Check whether the query with `cursor` was started through the endpoint.
+        fn contains(&self, cursor: &ForwardCursor) -> bool {
+            let queries = self
+                .queries
+                .lock()
+                .expect("Lock on exposed cursors poisoned");
+            cursor
+                .query_id
+                .as_ref()
+                .is_some_and(|query_id| queries.contains_key(query_id))
+        }
+
+        /// Forget the query with `cursor`.
+        fn remove(&self, cursor: &ForwardCursor) {
+            let mut queries = self
+                .queries
+                .lock()
+                .expect("Lock on exposed cursors poisoned");
+            if let Some(query_id) = &cursor.query_id {
+                queries.remove(query_id);
+            }
+        }
+    }
+
+    /// Handle JSON query request
+    pub async fn handle_json_query(
+        live_query_store: LiveQueryStoreHandle,
+        sumeragi: SumeragiHandle,
+        quotas: Arc<Quotas>,
+        exposed_cursors: Arc<ExposedCursors>,
+        body: Bytes,
+    ) -> Response {
+        let JsonQueryRequest { request, fields } = match serde_json::from_slice(&body) {
+            Ok(request) => request,
+            Err(error) => {
+                return reply_error(ValidationFail::QueryFailed(QueryExecutionFail::Conversion(
+                    format!("Invalid JSON query request: {error}"),
+                )))
+            }
+        };
+
+        let request = match request {
+            JsonQueryKind::Query {
+                signed,
+                sorting,
+                pagination,
+                fetch_size,
+            } => {
+                let signed_query = SignedQuery::from(signed);
+                QueryRequest::Query(QueryWithParameters::new(
+                    signed_query,
+                    sorting,
+                    pagination,
+                    fetch_size,
+                ))
+            }
+            JsonQueryKind::Cursor(cursor) => {
+                // NOTE: Cursor is only forgotten once the continuation succeeds, so that a failed one can be retried
+                if !exposed_cursors.contains(&cursor) {
+                    return reply_error(ValidationFail::QueryFailed(
+                        QueryExecutionFail::UnknownCursor,
+                    ));
+                }
+                QueryRequest::Cursor(cursor)
+            }
+        };
+
+        let continued_cursor = match &request {
+            QueryRequest::Cursor(cursor) => Some(cursor.clone()),
+            QueryRequest::Query(_) => None,
+        };
+        let handle = task::spawn_blocking(move || match request {
+            QueryRequest::Query(QueryWithParameters {
+                query: signed_query,
+                sorting,
+                pagination,
+                fetch_size,
+            }) => {
+                if !is_exposed(signed_query.query()) {
+                    return Err(Error::Query(ValidationFail::NotPermitted(
+                        "Query isn't exposed through the JSON query endpoint".to_owned(),
+                    )));
+                }
+
+                let reservation =
+                    quotas.reserve_authority(RequestKind::Query, signed_query.authority())?;
+
+                sumeragi
+                    .apply_wsv(|wsv| {
+                        let valid_query = ValidQueryRequest::validate(signed_query, wsv)?;
+                        reservation.confirm();
+                        let query_output = valid_query.execute(wsv)?;
+                        live_query_store
+                            .handle_query_output(
+                                query_output,
+                                valid_query.authority(),
+                                &sorting,
+                                pagination,
+                                fetch_size,
+                            )
+                            .map_err(ValidationFail::from)
+                    })
+                    .map_err(Error::from)
+            }
+            // NOTE: Continuations are counted against the authority who submitted the query
+            QueryRequest::Cursor(cursor) => {
+                live_query_store.handle_admitted_query_cursor(cursor, |authority| {
+                    quotas
+                        .reserve_authority(RequestKind::Query, authority)
+                        .map(Reservation::confirm)
+                        .map_err(Error::from)
+                })
+            }
+        });
+
+        let batched = match handle.await.expect("Failed to join query handling task") {
+            Ok(batched) => batched,
+            Err(Error::Query(error)) => return reply_error(error),
+            Err(error) => return error.into_response(),
+        };
+        let (batch, cursor) = batched.into();
+        if let Some(continued_cursor) = &continued_cursor {
+            exposed_cursors.remove(continued_cursor);
+        }
+        exposed_cursors.insert(&cursor);
+
+        match to_json(batch) {
+            Ok(data) => reply::json(&JsonQueryResponse {
+                data: Some(select_fields(data, &fields)),
+                cursor: Some(cursor),
+                error: None,
+            })
+            .into_response(),
+            Err(error) => reply_error(ValidationFail::InternalError(format!(
+                "Failed to serialize query output: {error}"
+            ))),
+        }
+    }
+
+    fn reply_error(error: ValidationFail) -> Response {
+        let status = Error::query_status_code(&error);
+        let response = JsonQueryResponse {
+            data: None,
+            cursor: None,
+            error: Some(error),
+        };
+
+        reply::with_status(reply::json(&response), status).into_response()
+    }
+
+    fn is_exposed(query: &QueryBox) -> bool {
+        use QueryBox::*;
+
+        matches!(
+            query,
+            FindAllDomains(_)
+                | FindDomainById(_)
+                | FindDomainKeyValueByIdAndKey(_)
+                | FindAllAccounts(_)
+                | FindAccountById(_)
+                | FindAccountKeyValueByIdAndKey(_)
+                | FindAccountsByName(_)
+                | FindAccountsByDomainId(_)
+                | FindAccountsWithAsset(_)
+                | FindAllAssets(_)
+                | FindAllAssetsDefinitions(_)
+                | FindAssetById(_)
+                | FindAssetDefinitionById(_)
+                | FindAssetsByName(_)
+                | FindAssetsByAccountId(_)
+                | FindAssetsByAssetDefinitionId(_)
+                | FindAssetsByDomainId(_)
+                | FindAssetsByDomainIdAndAssetDefinitionId(_)
+                | FindAssetQuantityById(_)
+                | FindTotalAssetQuantityByAssetDefinitionId(_)
+                | FindAssetKeyValueByIdAndKey(_)
+                | FindAssetDefinitionKeyValueByIdAndKey(_)
+                | FindNftsByOwner(_)
+                | FindAllBlocks(_)
+                | FindAllBlockHeaders(_)
+                | FindBlockHeaderByHash(_)
+                | FindAllTransactions(_)
+                | FindTransactionsByAccountId(_)
+                | FindTransactionByHash(_)
+        )
+    }
+
+    /// Convert query output to JSON stripping the enum wrappers of the exposed entities
+    fn to_json(value: Value) -> serde_json::Result<JsonValue> {
+        match value {
+            Value::Vec(values) => values
+                .into_iter()
+                .map(to_json)
+                .collect::<serde_json::Result<_>>()
+                .map(JsonValue::Array),
+            Value::Identifiable(IdentifiableBox::Domain(domain)) => serde_json::to_value(domain),
+            Value::Identifiable(IdentifiableBox::Account(account)) => serde_json::to_value(account),
+            Value::Identifiable(IdentifiableBox::AssetDefinition(asset_definition)) => {
+                serde_json::to_value(asset_definition)
+            }
+            Value::Identifiable(IdentifiableBox::Asset(asset)) => serde_json::to_value(asset),
+            Value::Block(block) => serde_json::to_value(block),
+            Value::BlockHeader(header) => serde_json::to_value(header),
+            Value::TransactionQueryOutput(transaction) => serde_json::to_value(transaction),
+            value => serde_json::to_value(value),
+        }
+    }
+
+    /// Keep only the `fields` in every object of `value`
+    fn select_fields(value: JsonValue, fields: &[String]) -> JsonValue {
+        if fields.is_empty() {
+            return value;
+        }
+
+        match value {
+            JsonValue::Array(values) => values
+                .into_iter()
+                .map(|value| select_fields(value, fields))
+                .collect(),
+            JsonValue::Object(_) => {
+                let mut selected = Map::new();
+                for field in fields {
+                    let path = field.split('.').collect::<Vec<_>>();
+                    select_field(&value, &mut selected, &path);
+                }
+                JsonValue::Object(selected)
+            }
+            value => value,
+        }
+    }
+
+    fn select_field(source: &JsonValue, selected: &mut Map<String, JsonValue>, path: &[&str]) {
+        let [segment, rest @ ..] = path else {
+            return;
+        };
+        let Some(field) = source.get(*segment) else {
+            return;
+        };
+
+        if rest.is_empty() {
+            selected.insert((*segment).to_owned(), field.clone());
+        } else if let JsonValue::Object(nested) = selected
+            .entry(*segment)
+            .or_insert_with(|| JsonValue::Object(Map::new()))
+        {
+            select_field(field, nested, rest);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::num::NonZeroU64;
+
+        use serde_json::json;
+
+        use super::*;
+
+        #[test]
+        fn fields_are_selected_from_every_object() {
+            let value = json!([
+                {"id": "a", "metadata": {"x": 1, "y": 2}, "logo": null},
+                {"id": "b", "metadata": {"y": 3}},
+            ]);
+            let fields = ["id".to_owned(), "metadata.x".to_owned()];
+
+            assert_eq!(
+                select_fields(value, &fields),
+                json!([
+                    {"id": "a", "metadata": {"x": 1}},
+                    {"id": "b", "metadata": {}},
+                ])
+            );
+        }
+
+        #[test]
+        fn only_cursors_of_live_json_queries_are_accepted() {
+            let exposed_cursors = ExposedCursors::new(Duration::from_secs(30));
+            let live = ForwardCursor::new(Some("live".to_owned()), NonZeroU64::new(1));
+            let depleted = ForwardCursor::new(Some("depleted".to_owned()), None);
+
+            exposed_cursors.insert(&live);
+            exposed_cursors.insert(&depleted);
+
+            assert!(exposed_cursors.contains(&live));
+            assert!(!exposed_cursors.contains(&depleted));
+            assert!(!exposed_cursors.contains(&ForwardCursor::new(
+                Some("unknown".to_owned()),
+                NonZeroU64::new(1)
+            )));
+
+            // Checking the cursor doesn't forget it, so that a failed continuation can be retried
+            assert!(exposed_cursors.contains(&live));
+            exposed_cursors.remove(&live);
+            assert!(!exposed_cursors.contains(&live));
+        }
+
+        #[test]
+        fn all_fields_are_returned_if_none_selected() {
+            let value = json!({"id": "a", "metadata": {}});
+
+            assert_eq!(select_fields(value.clone(), &[]), value);
+        }
+    }
+}
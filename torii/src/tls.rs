@@ -0,0 +1,438 @@
+//! TLS termination of the client API.
+//!
+//! The certificate, its key and the client authorities are read again whenever any of the files changes,
+//! so that certificates can be rotated without restarting the peer.
+
+use std::{
+    fs::File,
+    io::BufReader,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use eyre::{eyre, Result, WrapErr};
+use futures::Stream;
+use iroha_config::torii::Configuration as ToriiConfiguration;
+use rustls::{
+    server::AllowAnyAuthenticatedClient, Certificate, PrivateKey, RootCertStore, ServerConfig,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, watch, Semaphore},
+};
+use tokio_rustls::{server::TlsStream, TlsAcceptor};
+
+/// Period of checking the files for changes
+const RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+/// Time the client has to complete the handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Delay before accepting connections again after a failure, e.g. due to running out of file descriptors
+const ACCEPT_ERROR_DELAY: Duration = Duration::from_secs(1);
+/// Maximal number of handshakes in progress, connections wait in the backlog of the listener beyond it
+const MAX_PENDING_HANDSHAKES: usize = 256;
+
+/// Latest TLS configuration of the server.
+pub type ServerConfigReceiver = watch::Receiver<Arc<ServerConfig>>;
+
+/// Files the TLS configuration is read from.
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_field_names)]
+pub struct TlsFiles {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    client_ca_path: Option<PathBuf>,
+}
+
+impl TlsFiles {
+    /// Files given in the configuration, `None` if the API is served over plain HTTP.
+    ///
+    /// # Errors
+    /// Fails if only some of the files required for TLS are given
+    pub fn from_configuration(config: &ToriiConfiguration) -> Result<Option<Self>> {
+        match (&config.tls_cert_path, &config.tls_key_path) {
+            (Some(cert_path), Some(key_path)) => Ok(Some(Self {
+                cert_path: cert_path.clone(),
+                key_path: key_path.clone(),
+                client_ca_path: config.tls_client_ca_path.clone(),
+            })),
+            (None, None) if config.tls_client_ca_path.is_none() => Ok(None),
+            _ => Err(eyre!(
+                "Both `TLS_CERT_PATH` and `TLS_KEY_PATH` have to be set to serve the API over TLS"
+            )),
+        }
+    }
+
+    /// Load the configuration and keep it up to date with the files until all receivers are dropped.
+    ///
+    /// # Errors
+    /// Fails if the files can't be read or don't form a valid configuration
+    pub fn watch(self) -> Result<ServerConfigReceiver> {
+        let (sender, receiver) = watch::channel(self.load()?);
+        tokio::task::spawn(self.reload(sender));
+        Ok(receiver)
+    }
+
+    async fn reload(self, sender: watch::Sender<Arc<ServerConfig>>) {
+        let mut modified = self.modified();
+        let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                () = sender.closed() => break,
+            }
+            self.reload_if_modified(&mut modified, &sender);
+        }
+    }
+
+    /// Load the configuration again if the files were modified since `modified`.
+    fn reload_if_modified(
+        &self,
+        modified: &mut Vec<Option<SystemTime>>,
+        sender: &watch::Sender<Arc<ServerConfig>>,
+    ) {
+        let latest_modified = self.modified();
+        if latest_modified == *modified {
+            return;
+        }
+        *modified = latest_modified;
+        match self.load() {
+            Ok(config) => {
+                iroha_logger::info!("TLS certificate reloaded");
+                sender.send_replace(config);
+            }
+            Err(error) => {
+                iroha_logger::error!(
+                    ?error,
+                    "Failed to reload TLS certificate, keeping the previous one"
+                );
+            }
+        }
+    }
+
+    /// Modification times of the files
+    fn modified(&self) -> Vec<Option<SystemTime>> {
+        [self.cert_path.as_path(), self.key_path.as_path()]
+            .into_iter()
+            .chain(self.client_ca_path.as_deref())
+            .map(|path| {
+                std::fs::metadata(path)
+                    .and_then(|meta| meta.modified())
+                    .ok()
+            })
+            .collect()
+    }
+
+    fn load(&self) -> Result<Arc<ServerConfig>> {
+        let certificates = read_certificates(&self.cert_path)?
+            .into_iter()
+            .map(Certificate)
+            .collect();
+        let key = read_private_key(&self.key_path)?;
+
+        let builder = ServerConfig::builder().with_safe_defaults();
+        let builder = if let Some(client_ca_path) = &self.client_ca_path {
+            let mut roots = RootCertStore::empty();
+            let (valid, _) = roots.add_parsable_certificates(&read_certificates(client_ca_path)?);
+            if valid == 0 {
+                return Err(eyre!(
+                    "No valid certificates found in {}",
+                    client_ca_path.display()
+                ));
+            }
+            builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+        } else {
+            builder.with_no_client_auth()
+        };
+        let mut config = builder
+            .with_single_cert(certificates, key)
+            .wrap_err("Invalid TLS certificate or private key")?;
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        Ok(Arc::new(config))
+    }
+}
+
+fn open(path: &Path) -> Result<BufReader<File>> {
+    File::open(path)
+        .map(BufReader::new)
+        .wrap_err_with(|| format!("Failed to open {}", path.display()))
+}
+
+fn read_certificates(path: &Path) -> Result<Vec<Vec<u8>>> {
+    let certificates = rustls_pemfile::certs(&mut open(path)?)
+        .wrap_err_with(|| format!("Failed to read certificates from {}", path.display()))?;
+    if certificates.is_empty() {
+        return Err(eyre!("No certificates found in {}", path.display()));
+    }
+    Ok(certificates)
+}
+
+fn read_private_key(path: &Path) -> Result<PrivateKey> {
+    let mut reader = open(path)?;
+    loop {
+        match rustls_pemfile::read_one(&mut reader)
+            .wrap_err_with(|| format!("Failed to read private key from {}", path.display()))?
+        {
+            Some(
+                rustls_pemfile::Item::RSAKey(key)
+                | rustls_pemfile::Item::PKCS8Key(key)
+                | rustls_pemfile::Item::ECKey(key),
+            ) => return Ok(PrivateKey(key)),
+            Some(_) => {}
+            None => return Err(eyre!("No private key found in {}", path.display())),
+        }
+    }
+}
+
+/// Bind to `address` and accept TLS connections with the latest configuration.
+///
+/// # Errors
+/// Fails if binding to the address fails
+pub fn incoming(
+    address: SocketAddr,
+    config: ServerConfigReceiver,
+) -> Result<impl Stream<Item = std::io::Result<TlsStream<TcpStream>>> + Send> {
+    let listener = std::net::TcpListener::bind(address)?;
+    listener.set_nonblocking(true)?;
+    let listener = TcpListener::from_std(listener)?;
+
+    let (sender, mut receiver) = mpsc::channel(1);
+    tokio::task::spawn(accept(listener, config, sender));
+    Ok(futures::stream::poll_fn(move |cx| {
+        receiver.poll_recv(cx).map(|stream| stream.map(Ok))
+    }))
+}
+
+async fn accept(
+    listener: TcpListener,
+    config: ServerConfigReceiver,
+    sender: mpsc::Sender<TlsStream<TcpStream>>,
+) {
+    let handshakes = Arc::new(Semaphore::new(MAX_PENDING_HANDSHAKES));
+    loop {
+        // NOTE: Connections aren't accepted while too many handshakes are in progress,
+        // so that clients which never finish the handshake can't exhaust the memory
+        let permit = tokio::select! {
+            permit = Arc::clone(&handshakes).acquire_owned() => permit.expect("Semaphore is never closed"),
+            () = sender.closed() => break,
+        };
+        let accepted = tokio::select! {
+            accepted = listener.accept() => accepted,
+            // Server is shut down
+            () = sender.closed() => break,
+        };
+        let (stream, address) = match accepted {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                iroha_logger::warn!(%error, "Failed to accept connection");
+                tokio::time::sleep(ACCEPT_ERROR_DELAY).await;
+                continue;
+            }
+        };
+        // NOTE: Handshake is done in a separate task, so that slow clients don't hold up the others
+        let acceptor = TlsAcceptor::from(Arc::clone(&config.borrow()));
+        let sender = sender.clone();
+        tokio::task::spawn(async move {
+            let handshake = tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await;
+            drop(permit);
+            match handshake {
+                Ok(Ok(stream)) => {
+                    let _ = sender.send(stream).await;
+                }
+                Ok(Err(error)) => {
+                    iroha_logger::debug!(%address, %error, "TLS handshake failed");
+                }
+                Err(_) => {
+                    iroha_logger::debug!(%address, "TLS handshake timed out");
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt as _;
+    use iroha_config::torii::{uri, DEFAULT_TORII_P2P_ADDR};
+    use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+
+    use super::*;
+
+    /// Client trusting `server_cert_path` and authenticating with `client_cert` if given
+    fn client_config(
+        server_cert_path: &Path,
+        client_cert: Option<(&Path, &Path)>,
+    ) -> rustls::ClientConfig {
+        let mut roots = RootCertStore::empty();
+        roots.add_parsable_certificates(&read_certificates(server_cert_path).unwrap());
+        let builder = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+        match client_cert {
+            Some((cert_path, key_path)) => {
+                let certificates = read_certificates(cert_path)
+                    .unwrap()
+                    .into_iter()
+                    .map(Certificate)
+                    .collect();
+                builder
+                    .with_client_auth_cert(certificates, read_private_key(key_path).unwrap())
+                    .unwrap()
+            }
+            None => builder.with_no_client_auth(),
+        }
+    }
+
+    async fn connect(
+        address: SocketAddr,
+        config: rustls::ClientConfig,
+    ) -> std::io::Result<tokio_rustls::client::TlsStream<TcpStream>> {
+        let stream = TcpStream::connect(address).await?;
+        let server_name = rustls::ServerName::try_from("localhost").unwrap();
+        tokio_rustls::TlsConnector::from(Arc::new(config))
+            .connect(server_name, stream)
+            .await
+    }
+
+    fn write_certificate(dir: &Path, name: &str) -> (PathBuf, PathBuf) {
+        let certificate = rcgen::generate_simple_self_signed(vec!["localhost".to_owned()]).unwrap();
+        let cert_path = dir.join(format!("{name}.crt"));
+        let key_path = dir.join(format!("{name}.key"));
+        std::fs::write(&cert_path, certificate.serialize_pem().unwrap()).unwrap();
+        std::fs::write(&key_path, certificate.serialize_private_key_pem()).unwrap();
+        (cert_path, key_path)
+    }
+
+    #[test]
+    fn configuration_is_loaded_from_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_certificate(dir.path(), "server");
+        let (client_ca_path, _) = write_certificate(dir.path(), "client_ca");
+
+        let files = TlsFiles {
+            cert_path,
+            key_path,
+            client_ca_path: Some(client_ca_path),
+        };
+        assert!(files.load().is_ok());
+    }
+
+    #[test]
+    fn key_is_required_for_tls() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, _) = write_certificate(dir.path(), "server");
+
+        let config = ToriiConfiguration {
+            p2p_addr: DEFAULT_TORII_P2P_ADDR,
+            api_url: uri::DEFAULT_API_ADDR,
+            max_transaction_size: 0,
+            max_content_len: 0,
+            tls_cert_path: Some(cert_path.clone()),
+            tls_key_path: None,
+            tls_client_ca_path: None,
+            transaction_quota: None,
+            query_quota: None,
+            subscription_quota: None,
+        };
+        assert!(TlsFiles::from_configuration(&config).is_err());
+
+        // Certificate doesn't contain the key
+        let files = TlsFiles {
+            key_path: cert_path.clone(),
+            cert_path,
+            client_ca_path: None,
+        };
+        assert!(files.load().is_err());
+    }
+
+    #[tokio::test]
+    async fn client_connects_over_tls() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_certificate(dir.path(), "server");
+        let files = TlsFiles {
+            cert_path: cert_path.clone(),
+            key_path,
+            client_ca_path: None,
+        };
+        let address = SocketAddr::from(([127, 0, 0, 1], 12_400));
+        let mut connections = Box::pin(incoming(address, files.watch().unwrap()).unwrap());
+
+        let (client, server) = tokio::join!(
+            connect(address, client_config(&cert_path, None)),
+            connections.next()
+        );
+        let mut client = client.expect("Handshake must succeed");
+        let mut server = server
+            .expect("Connection must be accepted")
+            .expect("Connection must be valid");
+
+        client.write_all(b"ping").await.unwrap();
+        let mut buf = [0; 4];
+        server.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+    }
+
+    #[tokio::test]
+    async fn reloaded_certificate_is_used_for_new_connections() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_certificate(dir.path(), "server");
+        let old_client = client_config(&cert_path, None);
+        let files = TlsFiles {
+            cert_path: cert_path.clone(),
+            key_path,
+            client_ca_path: None,
+        };
+        let (sender, receiver) = watch::channel(files.load().unwrap());
+        let mut modified = files.modified();
+        let address = SocketAddr::from(([127, 0, 0, 1], 12_405));
+        let mut connections = Box::pin(incoming(address, receiver).unwrap());
+
+        write_certificate(dir.path(), "server");
+        // NOTE: Modification times might not change within the resolution of the file system
+        modified.clear();
+        files.reload_if_modified(&mut modified, &sender);
+
+        assert!(connect(address, old_client).await.is_err());
+        let (client, server) = tokio::join!(
+            connect(address, client_config(&cert_path, None)),
+            connections.next()
+        );
+        assert!(client.is_ok());
+        assert!(matches!(server, Some(Ok(_))));
+    }
+
+    #[tokio::test]
+    async fn client_with_unknown_certificate_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_certificate(dir.path(), "server");
+        let (client_ca_path, client_key_path) = write_certificate(dir.path(), "client_ca");
+        let (unknown_cert_path, unknown_key_path) = write_certificate(dir.path(), "unknown");
+        let files = TlsFiles {
+            cert_path: cert_path.clone(),
+            key_path,
+            client_ca_path: Some(client_ca_path.clone()),
+        };
+        let address = SocketAddr::from(([127, 0, 0, 1], 12_410));
+        let mut connections = Box::pin(incoming(address, files.watch().unwrap()).unwrap());
+
+        let known_client = client_config(&cert_path, Some((&client_ca_path, &client_key_path)));
+        let (client, server) = tokio::join!(connect(address, known_client), connections.next());
+        assert!(client.is_ok());
+        assert!(matches!(server, Some(Ok(_))));
+
+        let unknown_client =
+            client_config(&cert_path, Some((&unknown_cert_path, &unknown_key_path)));
+        // NOTE: With TLS 1.3 the client completes the handshake before the server verifies its certificate
+        if let Ok(mut client) = connect(address, unknown_client).await {
+            let mut buf = [0; 1];
+            assert!(!matches!(client.read(&mut buf).await, Ok(read) if read > 0));
+        }
+        assert!(
+            tokio::time::timeout(Duration::from_millis(500), connections.next())
+                .await
+                .is_err(),
+            "Client with an unknown certificate must not be accepted"
+        );
+    }
+}